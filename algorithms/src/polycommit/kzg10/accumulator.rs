@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{KZGCommitment, KZGProof, KZGRandomness, Powers, VerifierKey, KZG10};
+use crate::{
+    fft::{DensePolynomial, Polynomial},
+    polycommit::PCError,
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{One, Zero};
+
+use anyhow::anyhow;
+use std::{borrow::Cow, collections::BTreeSet};
+
+/// A proof that an element is not in the set committed to by a [`KZGAccumulator`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonMembershipProof<E: PairingEngine> {
+    /// The non-zero evaluation of the set polynomial at the element.
+    pub evaluation: E::Fr,
+    /// The opening proof for the evaluation.
+    pub proof: KZGProof<E>,
+}
+
+/// An accumulator for a set of field elements, such as spent serial numbers, based on [KZG10].
+///
+/// The accumulator commits to the set polynomial `P(X) = ∏ (X - x_i)`, whose roots are the elements of the set.
+/// An element `x` is in the set if and only if `P(x) = 0`, so a membership witness is an opening proof
+/// for `P(x) = 0`, and a non-membership witness is an opening proof for `P(x) = v`, where `v != 0`.
+/// The commitment is a single group element, so verifiers need not store the set.
+///
+/// The number of elements is bounded by the number of powers in the SRS.
+#[derive(Clone, Debug)]
+pub struct KZGAccumulator<E: PairingEngine> {
+    /// The elements in the set.
+    elements: BTreeSet<E::Fr>,
+    /// The set polynomial.
+    polynomial: DensePolynomial<E::Fr>,
+    /// The commitment to the set polynomial.
+    commitment: KZGCommitment<E>,
+}
+
+impl<E: PairingEngine> KZGAccumulator<E> {
+    /// Initializes an accumulator for the empty set.
+    pub fn new(powers: &Powers<E>) -> Result<Self, PCError> {
+        // The empty set polynomial is the constant `1`.
+        let polynomial = DensePolynomial::from_coefficients_vec(vec![E::Fr::one()]);
+        let (commitment, _) = KZG10::commit(powers, &Polynomial::Dense(Cow::Borrowed(&polynomial)), None, None)?;
+        Ok(Self { elements: Default::default(), polynomial, commitment })
+    }
+
+    /// Returns the commitment to the set.
+    pub const fn commitment(&self) -> &KZGCommitment<E> {
+        &self.commitment
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns `true` if the given element is in the set.
+    pub fn contains(&self, element: &E::Fr) -> bool {
+        self.elements.contains(element)
+    }
+
+    /// Adds the given elements to the set, and updates the commitment once for the batch.
+    /// Elements that are already in the set are ignored.
+    pub fn insert_batch(&mut self, powers: &Powers<E>, elements: &[E::Fr]) -> Result<(), PCError> {
+        // Select the new elements, ignoring duplicates.
+        let mut new_elements = BTreeSet::new();
+        for element in elements {
+            if !self.elements.contains(element) {
+                new_elements.insert(*element);
+            }
+        }
+        if new_elements.is_empty() {
+            return Ok(());
+        }
+
+        // Ensure the updated set polynomial can be committed to.
+        let num_coefficients = self.elements.len() + new_elements.len() + 1;
+        if num_coefficients > powers.size() {
+            return Err(PCError::TooManyCoefficients { num_coefficients, num_powers: powers.size() });
+        }
+
+        // Multiply the set polynomial by `(X - x)`, for each new element `x`.
+        let mut coefficients = self.polynomial.coeffs.clone();
+        for element in &new_elements {
+            coefficients.push(E::Fr::zero());
+            for i in (0..coefficients.len()).rev() {
+                let lower = if i == 0 { E::Fr::zero() } else { coefficients[i - 1] };
+                coefficients[i] = lower - (coefficients[i] * element);
+            }
+        }
+        let polynomial = DensePolynomial::from_coefficients_vec(coefficients);
+
+        // Commit to the updated set polynomial.
+        let (commitment, _) = KZG10::commit(powers, &Polynomial::Dense(Cow::Borrowed(&polynomial)), None, None)?;
+
+        // Update the accumulator.
+        self.elements.extend(new_elements);
+        self.polynomial = polynomial;
+        self.commitment = commitment;
+        Ok(())
+    }
+
+    /// Returns a witness that the given element is in the set.
+    pub fn prove_membership(&self, powers: &Powers<E>, element: E::Fr) -> Result<KZGProof<E>, PCError> {
+        // Ensure the element is in the set.
+        if !self.contains(&element) {
+            return Err(anyhow!("The element is not in the accumulated set").into());
+        }
+        KZG10::open(powers, &self.polynomial, element, &KZGRandomness::empty())
+    }
+
+    /// Returns a witness that the given element is not in the set.
+    pub fn prove_non_membership(&self, powers: &Powers<E>, element: E::Fr) -> Result<NonMembershipProof<E>, PCError> {
+        // Ensure the element is not in the set.
+        if self.contains(&element) {
+            return Err(anyhow!("The element is in the accumulated set").into());
+        }
+        let evaluation = self.polynomial.evaluate(element);
+        let proof = KZG10::open(powers, &self.polynomial, element, &KZGRandomness::empty())?;
+        Ok(NonMembershipProof { evaluation, proof })
+    }
+
+    /// Returns `true` if the witness shows the given element is in the set with the given commitment.
+    pub fn verify_membership(
+        vk: &VerifierKey<E>,
+        commitment: &KZGCommitment<E>,
+        element: E::Fr,
+        proof: &KZGProof<E>,
+    ) -> Result<bool, PCError> {
+        // Ensure the proof is not hiding, as the set polynomial is committed without randomness.
+        if proof.is_hiding() {
+            return Ok(false);
+        }
+        KZG10::check(vk, commitment, element, E::Fr::zero(), proof)
+    }
+
+    /// Returns `true` if the witness shows the given element is not in the set with the given commitment.
+    pub fn verify_non_membership(
+        vk: &VerifierKey<E>,
+        commitment: &KZGCommitment<E>,
+        element: E::Fr,
+        proof: &NonMembershipProof<E>,
+    ) -> Result<bool, PCError> {
+        // Ensure the evaluation is non-zero, and the proof is not hiding.
+        if proof.evaluation.is_zero() || proof.proof.is_hiding() {
+            return Ok(false);
+        }
+        KZG10::check(vk, commitment, element, proof.evaluation, &proof.proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::{rand::TestRng, Uniform};
+
+    type Accumulator = KZGAccumulator<Bls12_377>;
+
+    #[test]
+    fn test_accumulator_membership() {
+        let rng = &mut TestRng::default();
+
+        let pp = KZG10::<Bls12_377>::load_srs(16).unwrap();
+        let (powers, vk) = KZG10::trim(&pp, 16, None);
+
+        // Initialize the accumulator, and insert two batches of elements.
+        let mut accumulator = Accumulator::new(&powers).unwrap();
+        let empty_commitment = *accumulator.commitment();
+        let elements = (0..10).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        accumulator.insert_batch(&powers, &elements[..6]).unwrap();
+        accumulator.insert_batch(&powers, &elements[4..]).unwrap();
+        assert_eq!(accumulator.len(), 10);
+        assert_ne!(*accumulator.commitment(), empty_commitment);
+
+        // Ensure the commitment does not depend on the insertion order.
+        let mut reordered = Accumulator::new(&powers).unwrap();
+        reordered.insert_batch(&powers, &elements.iter().rev().copied().collect::<Vec<_>>()).unwrap();
+        assert_eq!(reordered.commitment(), accumulator.commitment());
+
+        let commitment = accumulator.commitment();
+        for element in &elements {
+            // Ensure the membership witness verifies.
+            let proof = accumulator.prove_membership(&powers, *element).unwrap();
+            assert!(Accumulator::verify_membership(&vk, commitment, *element, &proof).unwrap());
+            // Ensure the membership witness does not verify against the empty set.
+            assert!(!Accumulator::verify_membership(&vk, &empty_commitment, *element, &proof).unwrap());
+            // Ensure no non-membership witness can be produced.
+            assert!(accumulator.prove_non_membership(&powers, *element).is_err());
+        }
+
+        for _ in 0..10 {
+            let element = Fr::rand(rng);
+            // Ensure the non-membership witness verifies.
+            let proof = accumulator.prove_non_membership(&powers, element).unwrap();
+            assert!(Accumulator::verify_non_membership(&vk, commitment, element, &proof).unwrap());
+            // Ensure the witness does not verify with a zero evaluation, or against another element.
+            let zero = NonMembershipProof { evaluation: Fr::zero(), ..proof.clone() };
+            assert!(!Accumulator::verify_non_membership(&vk, commitment, element, &zero).unwrap());
+            assert!(!Accumulator::verify_non_membership(&vk, commitment, elements[0], &proof).unwrap());
+            // Ensure no membership witness can be produced.
+            assert!(accumulator.prove_membership(&powers, element).is_err());
+        }
+    }
+
+    #[test]
+    fn test_accumulator_capacity() {
+        let rng = &mut TestRng::default();
+
+        let pp = KZG10::<Bls12_377>::load_srs(4).unwrap();
+        let (powers, _) = KZG10::trim(&pp, 4, None);
+
+        // Ensure the set is bounded by the number of powers.
+        let mut accumulator = Accumulator::new(&powers).unwrap();
+        accumulator.insert_batch(&powers, &(0..4).map(|_| Fr::rand(rng)).collect::<Vec<_>>()).unwrap();
+        assert!(accumulator.insert_batch(&powers, &[Fr::rand(rng)]).is_err());
+        assert_eq!(accumulator.len(), 4);
+    }
+}
@@ -36,6 +36,9 @@ use rand_core::RngCore;
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+mod accumulator;
+pub use accumulator::*;
+
 mod data_structures;
 pub use data_structures::*;
 
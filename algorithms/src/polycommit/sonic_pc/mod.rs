@@ -643,15 +643,16 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
         let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
 
+        // Note: The G2 elements are fixed, and are prepared once when the verifier key is loaded.
+        // They are borrowed here, to avoid copying their Miller loop coefficients for every proof.
         for (degree_bound, comm) in combined_comms.into_iter() {
             let shift_power = if let Some(degree_bound) = degree_bound {
                 // Find the appropriate prepared shift for the degree bound.
                 vk.prepared_negative_powers_of_beta_h
                     .get(&degree_bound)
-                    .cloned()
                     .ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
             } else {
-                vk.vk.prepared_h.clone()
+                &vk.vk.prepared_h
             };
 
             g1_projective_elems.push(comm);
@@ -659,10 +660,10 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         }
 
         g1_projective_elems.push(-combined_adjusted_witness);
-        g2_prepared_elems.push(vk.vk.prepared_h.clone());
+        g2_prepared_elems.push(&vk.vk.prepared_h);
 
         g1_projective_elems.push(-combined_witness);
-        g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+        g2_prepared_elems.push(&vk.vk.prepared_beta_h);
 
         let g1_prepared_elems_iter = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
             .into_iter()
@@ -670,7 +671,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
 
         ensure!(g1_prepared_elems_iter.len() == g2_prepared_elems.len());
-        let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems.iter());
+        let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems);
         let is_one: bool = E::product_of_pairings(g1_g2_prepared).is_one();
         end_timer!(check_time);
         Ok(is_one)
@@ -306,7 +306,7 @@ mod varuna_hiding {
             VarunaHidingMode,
             VarunaSNARK,
         },
-        traits::{AlgebraicSponge, SNARK},
+        traits::{AlgebraicSponge, SNARKExt, SNARK},
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_utilities::{
@@ -339,8 +339,26 @@ mod varuna_hiding {
             let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
             println!("Called prover");
 
-            assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+            assert!(
+                VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.clone(), &proof)
+                    .unwrap()
+            );
             println!("Called verifier");
+
+            // Check the specialized entry points agree with the default ones.
+            let proof =
+                VarunaInst::prove_with_hints(universal_prover, &fs_parameters, &index_pk, &circuit, &(), rng).unwrap();
+            let prepared_vk = VarunaInst::prepare_verifying_key(&index_vk);
+            assert!(
+                VarunaInst::verify_with_preprocessed(
+                    universal_verifier,
+                    &fs_parameters,
+                    &prepared_vk,
+                    public_inputs,
+                    &proof
+                )
+                .unwrap()
+            );
             eprintln!("\nShould not verify (i.e. verifier messages should print below):");
             assert!(
                 !VarunaInst::verify(
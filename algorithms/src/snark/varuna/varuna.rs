@@ -39,12 +39,13 @@ use crate::{
     srs::UniversalVerifier,
     AlgebraicSponge,
     SNARKError,
+    SNARKExt,
     SNARK,
 };
 use rand::RngCore;
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{end_memory_scope, start_memory_scope, to_bytes_le, ToBytes};
 
 use anyhow::{anyhow, bail, ensure, Result};
 use core::marker::PhantomData;
@@ -340,6 +341,7 @@ where
         zk_rng: &mut R,
     ) -> Result<Self::Proof> {
         let prover_time = start_timer!(|| "Varuna::Prover");
+        let prover_memory = start_memory_scope!(|| "Varuna::Prover");
         if keys_to_constraints.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -348,7 +350,9 @@ where
         for (pk, constraints) in keys_to_constraints {
             circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
         }
+        let synthesis_memory = start_memory_scope!(|| "Varuna::Prover circuit synthesis");
         let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        end_memory_scope!(synthesis_memory);
 
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
@@ -383,6 +387,7 @@ where
 
         // --------------------------------------------------------------------
         // First round
+        let round_memory = start_memory_scope!(|| "Varuna::Prover first round");
 
         let prover_state = AHPForR1CS::<_, SM>::prover_first_round(prover_state, zk_rng)?;
 
@@ -408,10 +413,12 @@ where
             prover_state.max_non_zero_domain,
             &mut sponge,
         )?;
+        end_memory_scope!(round_memory);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Second round
+        let round_memory = start_memory_scope!(|| "Varuna::Prover second round");
 
         let (second_oracles, prover_state) =
             AHPForR1CS::<_, SM>::prover_second_round(&verifier_first_message, prover_state, zk_rng)?;
@@ -429,10 +436,12 @@ where
 
         let (verifier_second_msg, verifier_state) =
             AHPForR1CS::<_, SM>::verifier_second_round(verifier_state, &mut sponge)?;
+        end_memory_scope!(round_memory);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Third round
+        let round_memory = start_memory_scope!(|| "Varuna::Prover third round");
 
         let (prover_third_message, third_oracles, prover_state) = AHPForR1CS::<_, SM>::prover_third_round(
             &verifier_first_message,
@@ -458,10 +467,12 @@ where
 
         let (verifier_third_msg, verifier_state) =
             AHPForR1CS::<_, SM>::verifier_third_round(verifier_state, &mut sponge)?;
+        end_memory_scope!(round_memory);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Fourth round
+        let round_memory = start_memory_scope!(|| "Varuna::Prover fourth round");
 
         let (prover_fourth_message, fourth_oracles, mut prover_state) =
             AHPForR1CS::<_, SM>::prover_fourth_round(&verifier_second_msg, &verifier_third_msg, prover_state, zk_rng)?;
@@ -479,6 +490,7 @@ where
 
         let (verifier_fourth_msg, verifier_state) =
             AHPForR1CS::<_, SM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        end_memory_scope!(round_memory);
         // --------------------------------------------------------------------
 
         // We take out values from state before they are consumed.
@@ -490,6 +502,7 @@ where
 
         // --------------------------------------------------------------------
         // Fifth round
+        let round_memory = start_memory_scope!(|| "Varuna::Prover fifth round");
         let fifth_oracles = AHPForR1CS::<_, SM>::prover_fifth_round(verifier_fourth_msg, prover_state, zk_rng)?;
 
         let fifth_round_comm_time = start_timer!(|| "Committing to fifth round polys");
@@ -504,6 +517,7 @@ where
         Self::absorb_labeled(&fifth_commitments, &mut sponge);
 
         let verifier_state = AHPForR1CS::<_, SM>::verifier_fifth_round(verifier_state, &mut sponge)?;
+        end_memory_scope!(round_memory);
         // --------------------------------------------------------------------
 
         // Gather prover polynomials in one vector.
@@ -579,6 +593,7 @@ where
             &verifier_state,
         )?;
 
+        let opening_memory = start_memory_scope!(|| "Varuna::Prover opening");
         let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
         let mut evaluations = std::collections::BTreeMap::new();
         for (label, (_, point)) in query_set.to_set() {
@@ -614,7 +629,9 @@ where
         )?;
         proof.check_batch_sizes()?;
         ensure!(proof.pc_proof.is_hiding() == SM::ZK);
+        end_memory_scope!(opening_memory);
 
+        end_memory_scope!(prover_memory);
         end_timer!(prover_time);
         Ok(proof)
     }
@@ -912,3 +929,14 @@ where
         Ok(evaluations_are_correct & proof_has_correct_zk_mode)
     }
 }
+
+impl<E: PairingEngine, FS, SM> SNARKExt for VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    type PreparedVerifyingKey = CircuitVerifyingKey<E>;
+    type ProverHints = ();
+}
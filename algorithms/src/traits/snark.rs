@@ -115,3 +115,45 @@ pub trait SNARK {
         proof: &Self::Proof,
     ) -> Result<bool>;
 }
+
+/// An extension of `SNARK` with specialized proving and verification paths.
+///
+/// These are kept out of `SNARK`, so that existing implementors are not required to define the new associated types.
+/// A backend opts in by implementing this trait, and the default methods fall back to the `SNARK` methods.
+pub trait SNARKExt: SNARK {
+    /// A verifying key with any verifier-side precomputation applied, for repeated verification.
+    type PreparedVerifyingKey: Clone + Send + Sync + From<Self::VerifyingKey> + Borrow<Self::VerifyingKey>;
+
+    /// Auxiliary data that a prover may use to take a specialized proving path.
+    type ProverHints;
+
+    /// Returns a proof for the given constraints, using the given hints where the backend supports them.
+    /// By default, the hints are ignored and this is equivalent to `prove`.
+    fn prove_with_hints<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        proving_key: &Self::ProvingKey,
+        constraints: &C,
+        _hints: &Self::ProverHints,
+        rng: &mut R,
+    ) -> Result<Self::Proof> {
+        Self::prove(universal_prover, fs_parameters, proving_key, constraints, rng)
+    }
+
+    /// Returns the prepared form of the given verifying key.
+    fn prepare_verifying_key(verifying_key: &Self::VerifyingKey) -> Self::PreparedVerifyingKey {
+        verifying_key.clone().into()
+    }
+
+    /// Verifies the given proof against a prepared verifying key.
+    /// By default, this is equivalent to `verify` on the underlying verifying key.
+    fn verify_with_preprocessed<B: Borrow<Self::VerifierInput>>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        prepared_verifying_key: &Self::PreparedVerifyingKey,
+        input: B,
+        proof: &Self::Proof,
+    ) -> Result<bool> {
+        Self::verify(universal_verifier, fs_parameters, prepared_verifying_key.borrow(), input, proof)
+    }
+}
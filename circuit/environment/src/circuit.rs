@@ -374,4 +374,29 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_enforce_product() {
+        Circuit::scope("test_enforce_product", || {
+            // Enforce `x^5 == y` as a single degree-5 constraint, as in a Poseidon S-box.
+            let value = <Circuit as Environment>::BaseField::from(3u64);
+            let x: LinearCombination<_> = Circuit::new_variable(Mode::Private, value).into();
+            let y: LinearCombination<_> = Circuit::new_variable(Mode::Public, value.pow([5])).into();
+            Circuit::enforce_product(vec![x.clone(); 5], y.clone());
+            assert_eq!(1 + 3, Circuit::num_private_in_scope());
+            assert_eq!(4, Circuit::num_constraints_in_scope());
+            assert!(Circuit::is_satisfied_in_scope());
+
+            // Ensure the (constant) empty product is checked directly, and the single factor is lowered to one constraint.
+            Circuit::enforce_product(vec![], Circuit::one());
+            Circuit::enforce_product(vec![x.clone()], x.clone());
+            assert_eq!(5, Circuit::num_constraints_in_scope());
+            assert!(Circuit::is_satisfied_in_scope());
+
+            // Ensure an incorrect product is not satisfied.
+            Circuit::enforce_product(vec![x; 4], y);
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
 }
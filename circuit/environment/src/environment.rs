@@ -103,6 +103,39 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         Self::enforce(|| (a_minus_b, multiplier, Self::one()));
     }
 
+    /// Adds constraints enforcing that the product of the given `factors` is equal to `product`.
+    ///
+    /// This expresses a degree-`n` custom constraint, which is lowered to `n - 1` R1CS constraints
+    /// by introducing a witness for each intermediate product. An empty product is equal to `1`.
+    fn enforce_product<P: Into<LinearCombination<Self::BaseField>>>(
+        factors: Vec<LinearCombination<Self::BaseField>>,
+        product: P,
+    ) {
+        let mut factors = factors.into_iter().peekable();
+
+        // Retrieve the first factor.
+        let mut accumulator = match factors.next() {
+            Some(factor) => factor,
+            None => return Self::assert_eq(Self::one(), product),
+        };
+
+        while let Some(factor) = factors.next() {
+            // If this is the last factor, enforce `accumulator * factor == product`.
+            if factors.peek().is_none() {
+                return Self::enforce(|| (accumulator, factor, product));
+            }
+            // Otherwise, compute and enforce the intermediate product.
+            let mode = witness_mode!(accumulator, factor);
+            let intermediate: LinearCombination<_> =
+                Self::new_variable(mode, accumulator.value() * factor.value()).into();
+            Self::enforce(|| (accumulator, factor, intermediate.clone()));
+            accumulator = intermediate;
+        }
+
+        // Enforce the single factor is equal to the product.
+        Self::assert_eq(accumulator, product)
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool;
 
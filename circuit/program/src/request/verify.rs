@@ -406,4 +406,81 @@ mod tests {
     fn test_sign_and_verify_private() -> Result<()> {
         check_verify(Mode::Private, 40018, 0, 26401, 26429)
     }
+
+    #[test]
+    fn test_verify_rejects_tampered_requests() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key and address.
+        let private_key = snarkvm_console_account::PrivateKey::new(rng)?;
+        let address = snarkvm_console_account::Address::try_from(&private_key)?;
+
+        // Construct a program ID and function name.
+        let program_id = console::ProgramID::from_str("token.aleo")?;
+        let function_name = console::Identifier::from_str("transfer")?;
+
+        // Construct the inputs and input types.
+        let record_string =
+            format!("{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 0group.public }}");
+        let inputs = [
+            console::Value::<<Circuit as Environment>::Network>::from_str("{ token_amount: 9876543210u128 }")?,
+            console::Value::<<Circuit as Environment>::Network>::from_str(&record_string)?,
+        ];
+        let input_types =
+            vec![console::ValueType::from_str("amount.public")?, console::ValueType::from_str("token.record")?];
+
+        // Compute the signed request.
+        let request =
+            console::Request::sign(&private_key, program_id, function_name, inputs.iter(), &input_types, rng)?;
+
+        // Returns the request, with the given (tampered) signer, input IDs, and inputs.
+        let tamper = |signer, input_ids, inputs| {
+            console::Request::from((
+                signer,
+                *request.network_id(),
+                *request.program_id(),
+                *request.function_name(),
+                input_ids,
+                inputs,
+                *request.signature(),
+                *request.sk_tag(),
+                *request.tvk(),
+                *request.tcm(),
+            ))
+        };
+
+        // Inflate the public input value, without updating its input ID.
+        let mut inflated_inputs = request.inputs().to_vec();
+        inflated_inputs[0] = console::Value::from_str("{ token_amount: 9876543211u128 }")?;
+        let inflated_value = tamper(*request.signer(), request.input_ids().to_vec(), inflated_inputs);
+
+        // Replace the serial number of the record input.
+        let mut mismatched_input_ids = request.input_ids().to_vec();
+        if let console::InputID::Record(commitment, gamma, serial_number, tag) = mismatched_input_ids[1] {
+            mismatched_input_ids[1] = console::InputID::Record(commitment, gamma, serial_number.double(), tag);
+        }
+        let mismatched_serial_number = tamper(*request.signer(), mismatched_input_ids, request.inputs().to_vec());
+
+        // Replace the signer with another address.
+        let other_address =
+            snarkvm_console_account::Address::try_from(&snarkvm_console_account::PrivateKey::new(rng)?)?;
+        let wrong_signer = tamper(other_address, request.input_ids().to_vec(), request.inputs().to_vec());
+
+        for (name, tampered) in
+            [("inflated value", inflated_value), ("serial number", mismatched_serial_number), ("signer", wrong_signer)]
+        {
+            // Ensure the tampered request is rejected natively.
+            assert!(!tampered.verify(&input_types), "The request with a tampered {name} was accepted");
+
+            // Ensure the tampered request is rejected in the circuit.
+            let tpk = Group::<Circuit>::new(Mode::Private, tampered.to_tpk());
+            let tampered = Request::<Circuit>::new(Mode::Private, tampered);
+            Circuit::scope(name, || {
+                let candidate = tampered.verify(&input_types, &tpk);
+                assert!(!candidate.eject_value(), "The circuit accepted the request with a tampered {name}");
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
 }
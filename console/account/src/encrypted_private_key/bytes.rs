@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The identifier of the scrypt key derivation function.
+const SCRYPT_ID: u8 = 0;
+
+impl FromBytes for KeyDerivation {
+    /// Reads the key derivation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            SCRYPT_ID => {
+                let log_n = u8::read_le(&mut reader)?;
+                let r = u32::read_le(&mut reader)?;
+                let p = u32::read_le(&mut reader)?;
+                Ok(Self::Scrypt { log_n, r, p })
+            }
+            _ => Err(error("Invalid key derivation function")),
+        }
+    }
+}
+
+impl ToBytes for KeyDerivation {
+    /// Writes the key derivation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Scrypt { log_n, r, p } => {
+                SCRYPT_ID.write_le(&mut writer)?;
+                log_n.write_le(&mut writer)?;
+                r.write_le(&mut writer)?;
+                p.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for EncryptedPrivateKey<N> {
+    /// Reads the encrypted private key from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != ENCRYPTED_PRIVATE_KEY_VERSION {
+            return Err(error("Invalid encrypted private key version"));
+        }
+        // Read the key derivation.
+        let key_derivation = KeyDerivation::read_le(&mut reader)?;
+        // Read the salt.
+        let mut salt = [0u8; SALT_SIZE_IN_BYTES];
+        reader.read_exact(&mut salt)?;
+        // Read the nonce, ciphertext, and checksum.
+        let nonce = Field::read_le(&mut reader)?;
+        let ciphertext = Field::read_le(&mut reader)?;
+        let checksum = Field::read_le(&mut reader)?;
+        Ok(Self { key_derivation, salt, nonce, ciphertext, checksum })
+    }
+}
+
+impl<N: Network> ToBytes for EncryptedPrivateKey<N> {
+    /// Writes the encrypted private key to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        ENCRYPTED_PRIVATE_KEY_VERSION.write_le(&mut writer)?;
+        // Write the key derivation.
+        self.key_derivation.write_le(&mut writer)?;
+        // Write the salt.
+        writer.write_all(&self.salt)?;
+        // Write the nonce, ciphertext, and checksum.
+        self.nonce.write_le(&mut writer)?;
+        self.ciphertext.write_le(&mut writer)?;
+        self.checksum.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample an encrypted private key.
+            let (private_key, expected) = test_helpers::sample_encrypted_private_key("password", &mut rng);
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            let candidate = EncryptedPrivateKey::read_le(&expected_bytes[..])?;
+            assert_eq!(expected, candidate);
+            assert_eq!(private_key, candidate.decrypt("password")?);
+            assert!(EncryptedPrivateKey::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+            // Check that an unknown version or key derivation is rejected.
+            let mut invalid_bytes = expected_bytes.clone();
+            invalid_bytes[0] = 0;
+            assert!(EncryptedPrivateKey::<CurrentNetwork>::read_le(&invalid_bytes[..]).is_err());
+            let mut invalid_bytes = expected_bytes;
+            invalid_bytes[1] = 1;
+            assert!(EncryptedPrivateKey::<CurrentNetwork>::read_le(&invalid_bytes[..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> EncryptedPrivateKey<N> {
+    /// Decrypts the private key with the given password.
+    pub fn decrypt(&self, password: &str) -> Result<PrivateKey<N>> {
+        // Derive the encryption key from the password.
+        let key = self.key_derivation.derive_key::<N>(password, &self.salt)?;
+        // Unblind the account seed.
+        let seed = self.ciphertext - Self::blinding(key, self.nonce)?;
+        // Ensure the checksum matches, which fails if the password is incorrect.
+        ensure!(
+            Self::checksum(key, self.nonce, seed)? == self.checksum,
+            "Failed to decrypt the private key: incorrect password"
+        );
+        // Output the private key.
+        PrivateKey::try_from(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample an encrypted private key.
+            let (private_key, encrypted) = test_helpers::sample_encrypted_private_key("password", &mut rng);
+            assert_eq!(encrypted.key_derivation(), test_helpers::TEST_KEY_DERIVATION);
+
+            // Check that the private key decrypts with the password.
+            assert_eq!(private_key, encrypted.decrypt("password")?);
+            // Check that the private key does not decrypt with an incorrect password.
+            assert!(encrypted.decrypt("passw0rd").is_err());
+            assert!(encrypted.decrypt("").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_is_randomized() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Check that encrypting the same private key twice yields different envelopes.
+        let (private_key, first) = test_helpers::sample_encrypted_private_key("password", &mut rng);
+        let second =
+            EncryptedPrivateKey::encrypt(&private_key, "password", test_helpers::TEST_KEY_DERIVATION, &mut rng)?;
+        assert_ne!(first, second);
+        assert_eq!(first.decrypt("password")?, second.decrypt("password")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_key_derivation() {
+        let mut rng = TestRng::default();
+
+        // Check that invalid scrypt parameters are rejected.
+        let (_, encrypted) = test_helpers::sample_encrypted_private_key("password", &mut rng);
+        let invalid =
+            EncryptedPrivateKey { key_derivation: KeyDerivation::Scrypt { log_n: 4, r: 0, p: 1 }, ..encrypted };
+        assert!(invalid.decrypt("password").is_err());
+        let invalid =
+            EncryptedPrivateKey { key_derivation: KeyDerivation::Scrypt { log_n: 32, r: 8, p: 1 }, ..encrypted };
+        assert!(invalid.decrypt("password").is_err());
+    }
+
+    #[test]
+    fn test_oversized_key_derivation() {
+        let mut rng = TestRng::default();
+
+        // Check that oversized scrypt parameters are rejected before any memory is allocated.
+        let (_, encrypted) = test_helpers::sample_encrypted_private_key("password", &mut rng);
+        for key_derivation in [
+            KeyDerivation::Scrypt { log_n: 4, r: u32::MAX, p: 1 },
+            KeyDerivation::Scrypt { log_n: 20, r: 9, p: 1 },
+            KeyDerivation::Scrypt { log_n: 4, r: 8, p: u32::MAX },
+            KeyDerivation::Scrypt { log_n: 4, r: 8, p: MAX_SCRYPT_P + 1 },
+        ] {
+            let invalid = EncryptedPrivateKey { key_derivation, ..encrypted };
+            let error = invalid.decrypt("password").unwrap_err();
+            assert!(error.to_string().contains("exceeds the maximum"), "{error}");
+        }
+        // Check that the recommended parameters are within the limits.
+        let KeyDerivation::Scrypt { log_n, r, p } = KeyDerivation::RECOMMENDED;
+        assert!((128 * u64::from(r)) << log_n <= MAX_SCRYPT_MEMORY_IN_BYTES && p <= MAX_SCRYPT_P);
+    }
+}
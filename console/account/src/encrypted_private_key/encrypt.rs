@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> EncryptedPrivateKey<N> {
+    /// Encrypts the given private key under the given password, with the given key derivation.
+    pub fn encrypt<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        password: &str,
+        key_derivation: KeyDerivation,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Sample a random salt and nonce.
+        let salt = rng.gen::<[u8; SALT_SIZE_IN_BYTES]>();
+        let nonce = Uniform::rand(rng);
+        // Derive the encryption key from the password.
+        let key = key_derivation.derive_key::<N>(password, &salt)?;
+        // Blind the account seed, and compute its checksum.
+        let ciphertext = private_key.seed() + Self::blinding(key, nonce)?;
+        let checksum = Self::checksum(key, nonce, private_key.seed())?;
+        Ok(Self { key_derivation, salt, nonce, ciphertext, checksum })
+    }
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Encrypts the private key under the given password, with the recommended key derivation.
+    pub fn encrypt<R: Rng + CryptoRng>(&self, password: &str, rng: &mut R) -> Result<EncryptedPrivateKey<N>> {
+        EncryptedPrivateKey::encrypt(self, password, KeyDerivation::RECOMMENDED, rng)
+    }
+}
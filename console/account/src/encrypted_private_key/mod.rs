@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod decrypt;
+mod encrypt;
+mod serialize;
+mod string;
+
+use crate::PrivateKey;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use zeroize::Zeroize;
+
+/// The domain for the blinding factor of the encrypted account seed.
+const ENCRYPTION_DOMAIN: &str = "AleoEncryptedPrivateKey0";
+/// The domain for the checksum of the encrypted account seed.
+const CHECKSUM_DOMAIN: &str = "AleoEncryptedPrivateKeyCheck0";
+/// The version of the encrypted private key envelope.
+const ENCRYPTED_PRIVATE_KEY_VERSION: u8 = 1;
+/// The maximum scrypt cost `log_n`, which bounds the time an untrusted envelope can make decryption take.
+const MAX_SCRYPT_LOG_N: u8 = 20;
+/// The maximum scrypt memory `128 * r * 2^log_n` in bytes, which bounds what an untrusted envelope can make
+/// decryption allocate.
+const MAX_SCRYPT_MEMORY_IN_BYTES: u64 = 1 << 30; // 1 GiB
+/// The maximum scrypt parallelism `p`, which bounds the time an untrusted envelope can make decryption take.
+const MAX_SCRYPT_P: u32 = 16;
+/// The number of bytes in the salt of the key derivation.
+const SALT_SIZE_IN_BYTES: usize = 16;
+
+/// The key derivation function, and its parameters, used to derive the encryption key from a password.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KeyDerivation {
+    /// The scrypt key derivation function, with CPU and memory cost `2^log_n`, block size `r`, and parallelism `p`.
+    Scrypt { log_n: u8, r: u32, p: u32 },
+}
+
+impl KeyDerivation {
+    /// The recommended key derivation for interactive use, which matches the scrypt defaults.
+    pub const RECOMMENDED: Self = Self::Scrypt { log_n: 17, r: 8, p: 1 };
+
+    /// Returns the encryption key derived from the given password and salt.
+    fn derive_key<N: Network>(&self, password: &str, salt: &[u8]) -> Result<Field<N>> {
+        match *self {
+            Self::Scrypt { log_n, r, p } => {
+                // Ensure the parameters are within the limits, as they are read from an untrusted envelope.
+                ensure!(log_n <= MAX_SCRYPT_LOG_N, "The scrypt cost exceeds the maximum of 2^{MAX_SCRYPT_LOG_N}");
+                ensure!(
+                    (128 * u64::from(r)) << log_n <= MAX_SCRYPT_MEMORY_IN_BYTES,
+                    "The scrypt memory exceeds the maximum of {MAX_SCRYPT_MEMORY_IN_BYTES} bytes"
+                );
+                ensure!(p <= MAX_SCRYPT_P, "The scrypt parallelism exceeds the maximum of {MAX_SCRYPT_P}");
+                let parameters = scrypt::Params::new(log_n, r, p, scrypt::Params::RECOMMENDED_LEN)
+                    .map_err(|error| anyhow!("Invalid scrypt parameters: {error}"))?;
+                // Derive the key bytes, and reduce them into a field element.
+                let mut key_bytes = [0u8; 64];
+                scrypt::scrypt(password.as_bytes(), salt, &parameters, &mut key_bytes)
+                    .map_err(|error| anyhow!("Failed to derive the encryption key: {error}"))?;
+                let key = Field::new(N::Field::from_bytes_le_mod_order(&key_bytes));
+                key_bytes.zeroize();
+                Ok(key)
+            }
+        }
+    }
+}
+
+/// An account private key, encrypted under a password for export to other wallets.
+///
+/// The envelope records its version and the key derivation parameters, so it can be decrypted by any tool
+/// given only the password. The account seed is blinded by `HashPSD2(domain, key, nonce)`, where the key
+/// is derived from the password and salt, and a checksum over the seed detects an incorrect password.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EncryptedPrivateKey<N: Network> {
+    /// The key derivation function, and its parameters.
+    key_derivation: KeyDerivation,
+    /// The salt of the key derivation.
+    salt: [u8; SALT_SIZE_IN_BYTES],
+    /// The nonce of the blinding factor.
+    nonce: Field<N>,
+    /// The blinded account seed.
+    ciphertext: Field<N>,
+    /// The checksum of the account seed.
+    checksum: Field<N>,
+}
+
+impl<N: Network> EncryptedPrivateKey<N> {
+    /// Returns the key derivation function, and its parameters.
+    pub const fn key_derivation(&self) -> KeyDerivation {
+        self.key_derivation
+    }
+
+    /// Returns the blinding factor of the account seed, for the given key and nonce.
+    fn blinding(key: Field<N>, nonce: Field<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[Field::new_domain_separator(ENCRYPTION_DOMAIN), key, nonce])
+    }
+
+    /// Returns the checksum of the given account seed, for the given key and nonce.
+    fn checksum(key: Field<N>, nonce: Field<N>, seed: Field<N>) -> Result<Field<N>> {
+        N::hash_psd4(&[Field::new_domain_separator(CHECKSUM_DOMAIN), key, nonce, seed])
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// The key derivation for tests, which is cheap to evaluate.
+    pub(super) const TEST_KEY_DERIVATION: KeyDerivation = KeyDerivation::Scrypt { log_n: 4, r: 8, p: 1 };
+
+    /// Samples a random private key, and encrypts it under the given password.
+    pub(super) fn sample_encrypted_private_key(
+        password: &str,
+        rng: &mut TestRng,
+    ) -> (PrivateKey<CurrentNetwork>, EncryptedPrivateKey<CurrentNetwork>) {
+        let private_key = PrivateKey::new(rng).unwrap();
+        let encrypted = EncryptedPrivateKey::encrypt(&private_key, password, TEST_KEY_DERIVATION, rng).unwrap();
+        (private_key, encrypted)
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static ENCRYPTED_PRIVATE_KEY_PREFIX: [u8; 11] = [41, 18, 94, 93, 181, 71, 85, 168, 108, 234, 18]; // AEncryptedKey1
+
+/// The number of bytes in an encrypted private key, without the prefix.
+const ENCRYPTED_PRIVATE_KEY_SIZE_IN_BYTES: usize = 123;
+
+impl<N: Network> FromStr for EncryptedPrivateKey<N> {
+    type Err = Error;
+
+    /// Reads in an encrypted private key from a base58 string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Decode the string from base58.
+        let data = bs58::decode(s).into_vec().map_err(|err| anyhow!("{:?}", err))?;
+        if data.len() != 11 + ENCRYPTED_PRIVATE_KEY_SIZE_IN_BYTES {
+            bail!(
+                "Invalid encrypted private key length: found {}, expected {}",
+                data.len(),
+                11 + ENCRYPTED_PRIVATE_KEY_SIZE_IN_BYTES
+            )
+        } else if data[0..11] != ENCRYPTED_PRIVATE_KEY_PREFIX {
+            bail!(
+                "Invalid encrypted private key prefix: found {:?}, expected {:?}",
+                &data[0..11],
+                ENCRYPTED_PRIVATE_KEY_PREFIX
+            )
+        }
+        // Output the encrypted private key.
+        Ok(Self::read_le(&data[11..])?)
+    }
+}
+
+impl<N: Network> fmt::Display for EncryptedPrivateKey<N> {
+    /// Writes the encrypted private key as a base58 string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Write the encrypted private key bytes.
+        let mut encrypted_private_key = ENCRYPTED_PRIVATE_KEY_PREFIX.to_vec();
+        self.write_le(&mut encrypted_private_key).map_err(|_| fmt::Error)?;
+        // Encode the encrypted private key into base58.
+        write!(f, "{}", bs58::encode(encrypted_private_key).into_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample an encrypted private key.
+            let (private_key, expected) = test_helpers::sample_encrypted_private_key("password", &mut rng);
+
+            // Check the string representation.
+            let candidate = format!("{expected}");
+            assert_eq!(expected, EncryptedPrivateKey::from_str(&candidate)?);
+            assert_eq!(private_key, EncryptedPrivateKey::<CurrentNetwork>::from_str(&candidate)?.decrypt("password")?);
+            assert!(candidate.starts_with("AEncryptedKey1"));
+            assert!(EncryptedPrivateKey::<CurrentNetwork>::from_str(&private_key.to_string()).is_err());
+        }
+        Ok(())
+    }
+}
@@ -26,6 +26,11 @@ pub mod compute_key;
 #[cfg(feature = "compute_key")]
 pub use compute_key::*;
 
+#[cfg(feature = "private_key")]
+pub mod encrypted_private_key;
+#[cfg(feature = "private_key")]
+pub use encrypted_private_key::*;
+
 #[cfg(feature = "graph_key")]
 pub mod graph_key;
 #[cfg(feature = "graph_key")]
@@ -41,6 +46,11 @@ pub mod signature;
 #[cfg(feature = "signature")]
 pub use signature::*;
 
+#[cfg(feature = "signature")]
+pub mod signed_message;
+#[cfg(feature = "signature")]
+pub use signed_message::*;
+
 #[cfg(feature = "view_key")]
 pub mod view_key;
 #[cfg(feature = "view_key")]
@@ -19,6 +19,16 @@ impl<N: Network> Signature<N> {
     ///     challenge := HashToScalar(nonce * G, pk_sig, pr_sig, address, message)
     ///     response := nonce - challenge * private_key.sk_sig()
     pub fn sign<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, message: &[Field<N>], rng: &mut R) -> Result<Self> {
+        Self::sign_with_hash(private_key, message, N::hash_to_scalar_psd8, rng)
+    }
+
+    /// Returns a signature for a given message and RNG, where the challenge is computed with the given hash.
+    pub(crate) fn sign_with_hash<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        message: &[Field<N>],
+        hash_to_scalar: fn(&[Field<N>]) -> Result<Scalar<N>>,
+        rng: &mut R,
+    ) -> Result<Self> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
             bail!("Cannot sign the message: the message exceeds maximum allowed size")
@@ -45,7 +55,7 @@ impl<N: Network> Signature<N> {
         preimage.extend(message);
 
         // Compute the verifier challenge.
-        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        let challenge = hash_to_scalar(&preimage)?;
         // Compute the prover response.
         let response = nonce - (challenge * private_key.sk_sig());
 
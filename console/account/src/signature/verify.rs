@@ -18,6 +18,16 @@ impl<N: Network> Signature<N> {
     /// Verifies (challenge == challenge') && (address == address') where:
     ///     challenge' := HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message)
     pub fn verify(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
+        self.verify_with_hash(address, message, N::hash_to_scalar_psd8)
+    }
+
+    /// Verifies the signature for the given address and message, where the challenge is computed with the given hash.
+    pub(crate) fn verify_with_hash(
+        &self,
+        address: &Address<N>,
+        message: &[Field<N>],
+        hash_to_scalar: fn(&[Field<N>]) -> Result<Scalar<N>>,
+    ) -> bool {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
             eprintln!("Cannot sign the signature: the signed message exceeds maximum allowed size");
@@ -38,7 +48,7 @@ impl<N: Network> Signature<N> {
         preimage.extend(message);
 
         // Hash to derive the verifier challenge, and return `false` if this operation fails.
-        let candidate_challenge = match N::hash_to_scalar_psd8(&preimage) {
+        let candidate_challenge = match hash_to_scalar(&preimage) {
             // Output the computed candidate challenge.
             Ok(candidate_challenge) => candidate_challenge,
             // Return `false` if the challenge errored.
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for SignedMessage<N> {
+    /// Reads the signed message from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != SIGNED_MESSAGE_VERSION {
+            return Err(error("Invalid signed message version"));
+        }
+        // Read the address.
+        let address = Address::read_le(&mut reader)?;
+        // Read the number of bytes in the message.
+        let num_bytes = usize::try_from(u64::from(VarInt::read_le(&mut reader)?)).map_err(error)?;
+        // Ensure the message does not exceed the maximum size.
+        if num_bytes > Self::max_message_size() {
+            return Err(error("The signed message exceeds the maximum allowed size"));
+        }
+        // Read the message.
+        let mut message = vec![0u8; num_bytes];
+        reader.read_exact(&mut message)?;
+        // Read the signature.
+        let signature = Signature::read_le(&mut reader)?;
+        Ok(Self { address, message, signature })
+    }
+}
+
+impl<N: Network> ToBytes for SignedMessage<N> {
+    /// Writes the signed message to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        SIGNED_MESSAGE_VERSION.write_le(&mut writer)?;
+        // Write the address.
+        self.address.write_le(&mut writer)?;
+        // Write the number of bytes in the message.
+        VarInt(self.message.len() as u64).write_le(&mut writer)?;
+        // Write the message.
+        writer.write_all(&self.message)?;
+        // Write the signature.
+        self.signature.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a signed message.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let message: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let expected = SignedMessage::sign(&private_key, message, rng)?;
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            let candidate = SignedMessage::read_le(&expected_bytes[..])?;
+            assert_eq!(expected, candidate);
+            assert!(candidate.verify());
+            assert!(SignedMessage::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+            assert!(SignedMessage::<CurrentNetwork>::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod sign;
+mod verify;
+
+use crate::{Address, PrivateKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+/// The domain for the preimage of a signed message.
+const SIGNED_MESSAGE_DOMAIN: &str = "AleoSignedMessage0";
+/// The version of the signed message envelope.
+const SIGNED_MESSAGE_VERSION: u8 = 1;
+
+/// A message signed by an account, for off-chain uses such as login-with-Aleo authentication.
+///
+/// The signature is over the preimage `(domain, length, message)`, and its challenge is computed with
+/// `HashToScalarPSD4`, whereas `Signature::sign` (and so `sign_bytes` and transaction requests) computes
+/// it with `HashToScalarPSD8`. As the hashes are distinct, a signed message cannot be replayed as any
+/// other signature, and vice versa, even if the other signature is over the same field elements.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignedMessage<N: Network> {
+    /// The address of the signer.
+    address: Address<N>,
+    /// The message.
+    message: Vec<u8>,
+    /// The signature of the message.
+    signature: Signature<N>,
+}
+
+impl<N: Network> SignedMessage<N> {
+    /// Initializes a signed message from its parts, without verifying the signature.
+    pub fn from_parts(address: Address<N>, message: Vec<u8>, signature: Signature<N>) -> Self {
+        Self { address, message, signature }
+    }
+
+    /// Returns the address of the signer.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns the message.
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    /// Returns the signature of the message.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns the maximum number of bytes in a signed message.
+    pub fn max_message_size() -> usize {
+        (N::MAX_DATA_SIZE_IN_FIELDS as usize - 2) * Field::<N>::size_in_data_bits() / 8
+    }
+
+    /// Returns the preimage `(domain, length, message)` of the signature for the given message.
+    fn preimage(message: &[u8]) -> Result<Vec<Field<N>>> {
+        // Ensure the message does not exceed the maximum size.
+        ensure!(message.len() <= Self::max_message_size(), "The message exceeds the maximum allowed size");
+
+        // Construct the preimage as (domain, length, message).
+        let mut preimage =
+            vec![Field::new_domain_separator(SIGNED_MESSAGE_DOMAIN), Field::from_u64(message.len() as u64)];
+        for chunk in message.to_bits_le().chunks(Field::<N>::size_in_data_bits()) {
+            preimage.push(Field::from_bits_le(chunk)?);
+        }
+        Ok(preimage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_sign_and_verify_message() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the signed message is valid.
+            let message: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signed_message = SignedMessage::sign(&private_key, message.clone(), rng)?;
+            assert!(signed_message.verify());
+            assert_eq!(signed_message.address(), &address);
+            assert_eq!(signed_message.message(), message);
+            assert!(signed_message.signature().verify_message(&address, &message));
+
+            // Check that the signature is not valid for the raw bytes.
+            assert!(!signed_message.signature().verify_bytes(&address, &message));
+
+            // Check that the signature is invalid for an incorrect message.
+            let mut failure_message = message.clone();
+            failure_message.push(0);
+            assert!(!signed_message.signature().verify_message(&address, &failure_message));
+            assert!(!SignedMessage::from_parts(address, failure_message, *signed_message.signature()).verify());
+
+            // Check that the signature is invalid for an incorrect address.
+            let failure_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!signed_message.signature().verify_message(&failure_address, &message));
+            assert!(!SignedMessage::from_parts(failure_address, message, *signed_message.signature()).verify());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_bytes_is_not_a_signed_message() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an address and a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Craft the bytes that `sign_bytes` packs into the same field elements as the signed message preimage.
+        let message = b"Sign in to example.com";
+        let size_in_data_bits = Field::<CurrentNetwork>::size_in_data_bits();
+        let mut bits = SIGNED_MESSAGE_DOMAIN.as_bytes().to_bits_le();
+        bits.resize(size_in_data_bits, false);
+        let mut length_bits = (message.len() as u64).to_bits_le();
+        length_bits.resize(size_in_data_bits, false);
+        bits.extend(length_bits);
+        bits.extend(message.to_bits_le());
+        let crafted = bits.chunks(8).map(u8::from_bits_le).collect::<Result<Vec<_>>>()?;
+        let crafted_fields = bits.chunks(size_in_data_bits).map(Field::from_bits_le).collect::<Result<Vec<_>>>()?;
+        assert_eq!(crafted_fields, SignedMessage::<CurrentNetwork>::preimage(message)?);
+
+        // Check that a signature of the crafted bytes does not verify as a signed message.
+        let signature = Signature::sign_bytes(&private_key, &crafted, rng)?;
+        assert!(signature.verify_bytes(&address, &crafted));
+        assert!(!signature.verify_message(&address, message));
+        assert!(!SignedMessage::from_parts(address, message.to_vec(), signature).verify());
+
+        // Check that a signed message does not verify as a signature of the crafted bytes, or its preimage.
+        let signature = Signature::sign_message(&private_key, message, rng)?;
+        assert!(signature.verify_message(&address, message));
+        assert!(!signature.verify_bytes(&address, &crafted));
+        assert!(!signature.verify(&address, &crafted_fields));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_message_from_private_key() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an address and a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Check that the signature is valid for the message.
+        let signature = private_key.sign_message(b"Sign in to example.com", rng)?;
+        assert!(signature.verify_message(&address, b"Sign in to example.com"));
+        assert!(!signature.verify_message(&address, b"Sign in to example.org"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_message_exceeds_maximum_size() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+        // Check that the maximum size message can be signed, but not a larger one.
+        let max_size = SignedMessage::<CurrentNetwork>::max_message_size();
+        assert!(SignedMessage::sign(&private_key, vec![7u8; max_size], rng)?.verify());
+        assert!(SignedMessage::sign(&private_key, vec![7u8; max_size + 1], rng).is_err());
+        Ok(())
+    }
+}
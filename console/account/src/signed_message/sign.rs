@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Signature<N> {
+    /// Returns a signature for the given message (as bytes), prefixed by the signed message domain.
+    pub fn sign_message<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, message: &[u8], rng: &mut R) -> Result<Self> {
+        Self::sign_with_hash(private_key, &SignedMessage::<N>::preimage(message)?, N::hash_to_scalar_psd4, rng)
+    }
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns a signature for the given message (as bytes), prefixed by the signed message domain.
+    pub fn sign_message<R: Rng + CryptoRng>(&self, message: &[u8], rng: &mut R) -> Result<Signature<N>> {
+        Signature::sign_message(self, message, rng)
+    }
+}
+
+impl<N: Network> SignedMessage<N> {
+    /// Signs the given message with the given private key.
+    pub fn sign<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, message: Vec<u8>, rng: &mut R) -> Result<Self> {
+        let signature = Signature::sign_message(private_key, &message, rng)?;
+        Ok(Self { address: Address::try_from(private_key)?, message, signature })
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Signature<N> {
+    /// Verifies a signature for the given address and message (as bytes), prefixed by the signed message domain.
+    pub fn verify_message(&self, address: &Address<N>, message: &[u8]) -> bool {
+        match SignedMessage::<N>::preimage(message) {
+            Ok(preimage) => self.verify_with_hash(address, &preimage, N::hash_to_scalar_psd4),
+            Err(error) => {
+                eprintln!("Failed to verify the signed message: {error}");
+                false
+            }
+        }
+    }
+}
+
+impl<N: Network> SignedMessage<N> {
+    /// Returns `true` if the signature is valid for the address and message.
+    pub fn verify(&self) -> bool {
+        self.signature.verify_message(&self.address, &self.message)
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+
+/// The error returned when a Merkle tree operation is out of bounds.
+///
+/// Merkle tree methods return this error wrapped in an `anyhow::Error`,
+/// so callers can recover it with `error.downcast_ref::<MerkleTreeError>()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MerkleTreeError {
+    /// The Merkle tree depth is zero, or exceeds 64.
+    InvalidDepth { depth: u8 },
+    /// The leaves do not fit in a tree of the given depth.
+    DepthOverflow { max_depth: u8, depth: u8 },
+    /// The number of leaves overflows the tree size, or exceeds the capacity of the tree.
+    LeafCountOverflow { number_of_leaves: usize },
+    /// The leaf index is not less than the number of leaves in the tree.
+    IndexOutOfBounds { index: usize, number_of_leaves: usize },
+    /// The leaf does not match the one at the given index in the tree.
+    LeafMismatch { index: usize },
+}
+
+impl fmt::Display for MerkleTreeError {
+    /// Prints the error.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidDepth { depth } => {
+                write!(f, "Merkle tree depth must be greater than 0 and at most 64, found {depth}")
+            }
+            Self::DepthOverflow { max_depth, depth } => {
+                write!(f, "Merkle tree cannot exceed depth {max_depth}: attempted to reach depth {depth}")
+            }
+            Self::LeafCountOverflow { number_of_leaves } => {
+                write!(f, "Merkle tree cannot contain {number_of_leaves} leaves")
+            }
+            Self::IndexOutOfBounds { index, number_of_leaves } => {
+                write!(f, "Merkle leaf index {index} is out of bounds for a tree with {number_of_leaves} leaves")
+            }
+            Self::LeafMismatch { index } => {
+                write!(f, "The given Merkle leaf does not match the one at index {index} in the Merkle tree")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MerkleTreeError {}
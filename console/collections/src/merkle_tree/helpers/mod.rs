@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod error;
+pub use error::*;
+
 mod leaf_hash;
 pub use leaf_hash::*;
 
@@ -30,6 +30,15 @@ use std::collections::BTreeMap;
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// A binary Merkle tree of depth `DEPTH`, which may be half-full.
+///
+/// The tree pads its leaves in two steps:
+/// 1. The leaf level is padded with the empty hash, up to the next power of two of the number of leaves.
+///    Padding is not hashed as a leaf, and cannot be proven.
+/// 2. The root of the resulting subtree is hashed with the empty hash, once per level, up to `DEPTH`.
+///
+/// As a result, the root depends only on the leaves, and two trees with the same leaves have the same root.
+/// To pad with leaves that can be proven, use [`MerkleTree::new_padded`].
 #[derive(Clone)]
 pub struct MerkleTree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8> {
     /// The leaf hasher for the Merkle tree.
@@ -54,16 +63,13 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf]) -> Result<Self> {
         let timer = timer!("MerkleTree::new");
 
-        // Ensure the Merkle tree depth is greater than 0.
-        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
-        // Ensure the Merkle tree depth is less than or equal to 64.
-        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the Merkle tree depth is greater than 0, and less than or equal to 64.
+        if DEPTH == 0 || DEPTH > 64u8 {
+            bail!(MerkleTreeError::InvalidDepth { depth: DEPTH })
+        }
 
         // Compute the maximum number of leaves.
-        let max_leaves = match leaves.len().checked_next_power_of_two() {
-            Some(num_leaves) => num_leaves,
-            None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
-        };
+        let max_leaves = max_leaves::<DEPTH>(leaves.len())?;
 
         // Compute the number of nodes.
         let num_nodes = max_leaves - 1;
@@ -119,16 +125,43 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         })
     }
 
+    #[inline]
+    /// Initializes a new Merkle tree with the given leaves, followed by copies of `padding_leaf` up to `number_of_leaves`.
+    /// Unlike the implicit padding of [`MerkleTree::new`], the padding leaves are hashed and can be proven.
+    pub fn new_padded(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaves: &[LH::Leaf],
+        padding_leaf: &LH::Leaf,
+        number_of_leaves: usize,
+    ) -> Result<Self> {
+        // Ensure the leaves fit in the padded tree.
+        if leaves.len() > number_of_leaves {
+            bail!(MerkleTreeError::LeafCountOverflow { number_of_leaves: leaves.len() })
+        }
+        // Ensure the padded leaves fit in a tree of depth `DEPTH`.
+        max_leaves::<DEPTH>(number_of_leaves)?;
+
+        // Pad the leaves.
+        let mut padded_leaves = Vec::with_capacity(number_of_leaves);
+        padded_leaves.extend_from_slice(leaves);
+        padded_leaves.resize(number_of_leaves, padding_leaf.clone());
+
+        Self::new(leaf_hasher, path_hasher, &padded_leaves)
+    }
+
     #[inline]
     /// Returns a new Merkle tree with the given new leaves appended to it.
     pub fn prepare_append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_append");
 
+        // Compute the updated number of leaves.
+        let updated_number_of_leaves = self
+            .number_of_leaves
+            .checked_add(new_leaves.len())
+            .ok_or(MerkleTreeError::LeafCountOverflow { number_of_leaves: usize::MAX })?;
         // Compute the maximum number of leaves.
-        let max_leaves = match (self.number_of_leaves + new_leaves.len()).checked_next_power_of_two() {
-            Some(num_leaves) => num_leaves,
-            None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
-        };
+        let max_leaves = max_leaves::<DEPTH>(updated_number_of_leaves)?;
         // Compute the number of nodes.
         let num_nodes = max_leaves - 1;
         // Compute the tree size as the maximum number of leaves plus the number of nodes.
@@ -155,7 +188,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Initialize a precompute index to track the starting index of each precomputed level.
         let start_precompute_index = match self.number_of_leaves.checked_next_power_of_two() {
             Some(num_leaves) => num_leaves - 1,
-            None => bail!("Integer overflow when computing the Merkle tree precompute index"),
+            None => bail!(MerkleTreeError::LeafCountOverflow { number_of_leaves: self.number_of_leaves }),
         };
         // Initialize a precompute index to track the middle index of each precomputed level.
         let middle_precompute_index = match num_nodes == start_precompute_index {
@@ -228,11 +261,9 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         let timer = timer!("MerkleTree::prepare_update");
 
         // Check that the leaf index is within the bounds of the Merkle tree.
-        ensure!(
-            leaf_index < self.number_of_leaves,
-            "Leaf index must be less than the number of leaves in the Merkle tree {leaf_index} , {}",
-            self.number_of_leaves
-        );
+        if leaf_index >= self.number_of_leaves {
+            bail!(MerkleTreeError::IndexOutOfBounds { index: leaf_index, number_of_leaves: self.number_of_leaves })
+        }
 
         // Allocate a vector to store the path hashes.
         let mut path_hashes = Vec::with_capacity(DEPTH as usize);
@@ -244,7 +275,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
         let start = match self.number_of_leaves.checked_next_power_of_two() {
             Some(num_leaves) => num_leaves - 1,
-            None => bail!("Integer overflow when computing the Merkle tree start index"),
+            None => bail!(MerkleTreeError::LeafCountOverflow { number_of_leaves: self.number_of_leaves }),
         };
 
         // Compute the new hashes for the path from the leaf to the root.
@@ -309,15 +340,15 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
 
         // Check that the latest leaf index is less than number of leaves in the Merkle tree.
         // Note: This unwrap is safe since updates is guaranteed to be non-empty.
-        ensure!(
-            *updates.last_key_value().unwrap().0 < self.number_of_leaves,
-            "Leaf index must be less than the number of leaves in the Merkle tree"
-        );
+        let last_index = *updates.last_key_value().unwrap().0;
+        if last_index >= self.number_of_leaves {
+            bail!(MerkleTreeError::IndexOutOfBounds { index: last_index, number_of_leaves: self.number_of_leaves })
+        }
 
         // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
         let start = match self.number_of_leaves.checked_next_power_of_two() {
             Some(num_leaves) => num_leaves - 1,
-            None => bail!("Integer overflow when computing the Merkle tree start index"),
+            None => bail!(MerkleTreeError::LeafCountOverflow { number_of_leaves: self.number_of_leaves }),
         };
 
         // A helper to compute the leaf hash.
@@ -440,10 +471,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         })?;
 
         // Compute the maximum number of leaves.
-        let max_leaves = match (updated_number_of_leaves).checked_next_power_of_two() {
-            Some(num_leaves) => num_leaves,
-            None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
-        };
+        let max_leaves = max_leaves::<DEPTH>(updated_number_of_leaves)?;
         // Compute the number of nodes.
         let num_nodes = max_leaves - 1;
         // Compute the tree size as the maximum number of leaves plus the number of nodes.
@@ -468,7 +496,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Initialize a precompute index to track the starting index of each precomputed level.
         let start_precompute_index = match self.number_of_leaves.checked_next_power_of_two() {
             Some(num_leaves) => num_leaves - 1,
-            None => bail!("Integer overflow when computing the Merkle tree precompute index"),
+            None => bail!(MerkleTreeError::LeafCountOverflow { number_of_leaves: self.number_of_leaves }),
         };
         // Initialize a precompute index to track the middle index of each precomputed level.
         let middle_precompute_index = match num_nodes == start_precompute_index {
@@ -526,7 +554,9 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     /// Returns the Merkle path for the given leaf index and leaf.
     pub fn prove(&self, leaf_index: usize, leaf: &LH::Leaf) -> Result<MerklePath<E, DEPTH>> {
         // Ensure the leaf index is valid.
-        ensure!(leaf_index < self.number_of_leaves, "The given Merkle leaf index is out of bounds");
+        if leaf_index >= self.number_of_leaves {
+            bail!(MerkleTreeError::IndexOutOfBounds { index: leaf_index, number_of_leaves: self.number_of_leaves })
+        }
 
         // Compute the leaf hash.
         let leaf_hash = self.leaf_hasher.hash_leaf(leaf)?;
@@ -534,15 +564,19 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
         let start = match self.number_of_leaves.checked_next_power_of_two() {
             Some(num_leaves) => num_leaves - 1,
-            None => bail!("Integer overflow when computing the Merkle tree start index"),
+            None => bail!(MerkleTreeError::LeafCountOverflow { number_of_leaves: self.number_of_leaves }),
         };
 
         // Compute the absolute index of the leaf in the Merkle tree.
         let mut index = start + leaf_index;
         // Ensure the leaf index is valid.
-        ensure!(index < self.tree.len(), "The given Merkle leaf index is out of bounds");
+        if index >= self.tree.len() {
+            bail!(MerkleTreeError::IndexOutOfBounds { index: leaf_index, number_of_leaves: self.number_of_leaves })
+        }
         // Ensure the leaf hash matches the one in the tree.
-        ensure!(self.tree[index] == leaf_hash, "The given Merkle leaf does not match the one in the Merkle tree");
+        if self.tree[index] != leaf_hash {
+            bail!(MerkleTreeError::LeafMismatch { index: leaf_index })
+        }
 
         // Initialize a vector for the Merkle path.
         let mut path = Vec::with_capacity(DEPTH as usize);
@@ -595,7 +629,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
         let start = match self.number_of_leaves.checked_next_power_of_two() {
             Some(num_leaves) => num_leaves - 1,
-            None => bail!("Integer overflow when computing the Merkle tree start index"),
+            None => bail!(MerkleTreeError::LeafCountOverflow { number_of_leaves: self.number_of_leaves }),
         };
         // Compute the end index (on the right) for the leaf hashes level in the Merkle tree.
         let end = start + self.number_of_leaves;
@@ -730,8 +764,22 @@ fn tree_depth<const DEPTH: u8>(tree_size: usize) -> Result<u8> {
     match tree_depth <= DEPTH {
         // Return the tree depth.
         true => Ok(tree_depth),
-        false => bail!("Merkle tree cannot exceed depth {DEPTH}: attempted to reach depth {tree_depth}"),
+        false => bail!(MerkleTreeError::DepthOverflow { max_depth: DEPTH, depth: tree_depth }),
+    }
+}
+
+/// Returns the number of leaf slots in the tree, given the number of leaves.
+/// This is the number of leaves, rounded up to the next power of two.
+#[inline]
+fn max_leaves<const DEPTH: u8>(number_of_leaves: usize) -> Result<usize> {
+    // Ensure the leaves fit in a tree of depth `DEPTH`.
+    if number_of_leaves as u128 > 1u128 << DEPTH {
+        bail!(MerkleTreeError::LeafCountOverflow { number_of_leaves })
     }
+    // Compute the maximum number of leaves.
+    number_of_leaves
+        .checked_next_power_of_two()
+        .ok_or_else(|| anyhow!(MerkleTreeError::LeafCountOverflow { number_of_leaves }))
 }
 
 /// Returns the index of the left child, given an index.
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+type LH = BHP512<CurrentEnvironment>;
+type PH = Poseidon<CurrentEnvironment, 2>;
+
+/// Returns the Merkle tree error for the given result.
+fn error_of<T>(result: Result<T>) -> MerkleTreeError {
+    match result {
+        Ok(_) => panic!("Expected a Merkle tree error"),
+        Err(error) => *error.downcast_ref::<MerkleTreeError>().expect("Expected a typed Merkle tree error"),
+    }
+}
+
+#[test]
+fn test_merkle_tree_errors() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..4).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+
+    // Ensure an invalid depth is rejected.
+    let result = MerkleTree::<CurrentEnvironment, LH, PH, 0>::new(&leaf_hasher, &path_hasher, &leaves);
+    assert_eq!(error_of(result), MerkleTreeError::InvalidDepth { depth: 0 });
+    let result = MerkleTree::<CurrentEnvironment, LH, PH, 65>::new(&leaf_hasher, &path_hasher, &leaves);
+    assert_eq!(error_of(result), MerkleTreeError::InvalidDepth { depth: 65 });
+
+    // Ensure the leaf count is bounded by the depth.
+    let result = MerkleTree::<CurrentEnvironment, LH, PH, 1>::new(&leaf_hasher, &path_hasher, &leaves);
+    assert_eq!(error_of(result), MerkleTreeError::LeafCountOverflow { number_of_leaves: 4 });
+    let mut merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 2>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    assert_eq!(error_of(merkle_tree.prepare_append(&leaves[..1])), MerkleTreeError::LeafCountOverflow {
+        number_of_leaves: 5
+    });
+
+    // Ensure out-of-range indices are rejected.
+    let expected = MerkleTreeError::IndexOutOfBounds { index: 4, number_of_leaves: 4 };
+    assert_eq!(error_of(merkle_tree.prove(4, &leaves[0])), expected);
+    assert_eq!(error_of(merkle_tree.update(4, &leaves[0])), expected);
+    assert_eq!(error_of(merkle_tree.update_many(&[(4, leaves[0].clone())].into())), expected);
+
+    // Ensure a mismatched leaf is rejected.
+    assert_eq!(error_of(merkle_tree.prove(1, &leaves[0])), MerkleTreeError::LeafMismatch { index: 1 });
+
+    // Ensure the depth overflow is reported by the helper.
+    assert_eq!(error_of(tree_depth::<2>(15)), MerkleTreeError::DepthOverflow { max_depth: 2, depth: 3 });
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_new_padded() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..3).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+    let padding_leaf = Field::<CurrentEnvironment>::zero().to_bits_le();
+
+    // Construct a half-full tree, padded with explicit leaves.
+    let merkle_tree =
+        MerkleTree::<CurrentEnvironment, LH, PH, 4>::new_padded(&leaf_hasher, &path_hasher, &leaves, &padding_leaf, 6)?;
+    assert_eq!(merkle_tree.number_of_leaves(), 6);
+
+    // Ensure the tree matches the tree of the padded leaves.
+    let mut padded_leaves = leaves.clone();
+    padded_leaves.extend(std::iter::repeat(padding_leaf.clone()).take(3));
+    let expected = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &padded_leaves)?;
+    assert_eq!(merkle_tree.root(), expected.root());
+
+    // Ensure the padding leaves can be proven.
+    let path = merkle_tree.prove(5, &padding_leaf)?;
+    assert!(merkle_tree.verify(&path, merkle_tree.root(), &padding_leaf));
+
+    // Ensure the explicit padding differs from the implicit padding.
+    let implicit = MerkleTree::<CurrentEnvironment, LH, PH, 4>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    assert_ne!(merkle_tree.root(), implicit.root());
+
+    // Ensure the leaves must fit in the padded tree.
+    let result =
+        MerkleTree::<CurrentEnvironment, LH, PH, 4>::new_padded(&leaf_hasher, &path_hasher, &leaves, &padding_leaf, 2);
+    assert_eq!(error_of(result), MerkleTreeError::LeafCountOverflow { number_of_leaves: 3 });
+    let result =
+        MerkleTree::<CurrentEnvironment, LH, PH, 4>::new_padded(&leaf_hasher, &path_hasher, &leaves, &padding_leaf, 17);
+    assert_eq!(error_of(result), MerkleTreeError::LeafCountOverflow { number_of_leaves: 17 });
+    Ok(())
+}
@@ -15,6 +15,7 @@
 use super::*;
 
 mod append;
+mod errors;
 mod remove;
 mod update;
 mod update_many;
@@ -71,6 +71,10 @@ pub mod prelude {
         error,
         has_duplicates,
         io::{Read, Result as IoResult, Write},
+        test_rng_for,
+        ByteError,
+        ByteReader,
+        ByteWriter,
         DeserializeExt,
         FromBits as _,
         FromBytes,
@@ -80,6 +84,7 @@ pub mod prelude {
         ToBytes,
         ToBytesSerializer,
         Uniform,
+        VarInt,
     };
 
     pub use core::{
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::environment::prelude::*;
+
+/// Declares a lazily-initialized instance of each given hash function for the given network.
+///
+/// Each instance is set up with the domain `Aleo{hasher}` (e.g. `AleoBHP256` for `BHP256`),
+/// so the domain of an instance cannot diverge from its parameters when a network is added.
+macro_rules! hash_instances {
+    ($network:ident => { $( $(#[$meta:meta])* $name:ident: $hasher:ident ),+ $(,)? }) => {
+        lazy_static! {
+            $(
+                $(#[$meta])*
+                pub static ref $name: $hasher<$network> = $crate::helpers::Setup::into_instance(
+                    $hasher::<$network>::setup(concat!("Aleo", stringify!($hasher))),
+                    stringify!($hasher),
+                );
+            )+
+        }
+    };
+}
+
+/// A helper trait to unify the (fallible and infallible) setup of hash functions.
+pub(crate) trait Setup<T> {
+    /// Returns the instance, panicking if its setup failed.
+    fn into_instance(self, hasher: &str) -> T;
+}
+
+impl<T> Setup<T> for T {
+    fn into_instance(self, _hasher: &str) -> T {
+        self
+    }
+}
+
+impl<T> Setup<T> for Result<T> {
+    fn into_instance(self, hasher: &str) -> T {
+        match self {
+            Ok(instance) => instance,
+            Err(error) => panic!("Failed to setup {hasher}: {error}"),
+        }
+    }
+}
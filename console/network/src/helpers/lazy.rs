@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Network, VarunaProvingKey};
+use snarkvm_console_network_environment::prelude::*;
+use snarkvm_parameters::errors::ParameterError;
+
+use indexmap::IndexMap;
+use once_cell::sync::OnceCell;
+use std::sync::Arc;
+
+/// A function that loads the bytes of a circuit key.
+pub type KeyLoader = fn() -> Result<Vec<u8>, ParameterError>;
+
+/// A proving key, which is initialized at most once.
+type LazyProvingKey<N> = OnceCell<Arc<VarunaProvingKey<N>>>;
+
+/// A set of proving keys, where each proving key is loaded on first use.
+///
+/// A node that only verifies never requests a proving key, and thus never downloads one.
+/// Each proving key is initialized at most once, even if it is requested from several threads at once.
+/// If loading a proving key fails, the error is returned, and the next request retries the load.
+pub struct LazyParameters<N: Network> {
+    /// The loader and the proving key, for each function name.
+    keys: IndexMap<String, (KeyLoader, LazyProvingKey<N>)>,
+}
+
+impl<N: Network> Default for LazyParameters<N> {
+    /// Initializes an empty set of proving keys.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> LazyParameters<N> {
+    /// Initializes an empty set of proving keys.
+    pub fn new() -> Self {
+        Self { keys: IndexMap::new() }
+    }
+
+    /// Registers the loader for the proving key of the given function name, without loading it.
+    pub fn insert(&mut self, function_name: String, loader: KeyLoader) {
+        self.keys.insert(function_name, (loader, OnceCell::new()));
+    }
+
+    /// Returns `true` if a proving key is registered for the given function name.
+    pub fn contains(&self, function_name: &str) -> bool {
+        self.keys.contains_key(function_name)
+    }
+
+    /// Returns `true` if the proving key for the given function name has been loaded.
+    pub fn is_loaded(&self, function_name: &str) -> bool {
+        self.keys.get(function_name).map_or(false, |(_, key)| key.get().is_some())
+    }
+
+    /// Returns the number of proving keys that have been loaded.
+    pub fn num_loaded(&self) -> usize {
+        self.keys.values().filter(|(_, key)| key.get().is_some()).count()
+    }
+
+    /// Returns the function names of the registered proving keys.
+    pub fn function_names(&self) -> impl '_ + Iterator<Item = &String> {
+        self.keys.keys()
+    }
+
+    /// Returns the proving key for the given function name, loading it if this is the first use.
+    pub fn get(&self, function_name: &str) -> Result<&Arc<VarunaProvingKey<N>>> {
+        // Retrieve the entry for the function name.
+        let (loader, key) =
+            self.keys.get(function_name).ok_or_else(|| anyhow!("Proving key for '{function_name}' not found"))?;
+        // Load the proving key, if it has not been loaded yet.
+        key.get_or_try_init(|| {
+            let bytes = loader().map_err(|error| anyhow!("Failed to load the '{function_name}' bytes: {error}"))?;
+            // Skipping the first byte, which is the encoded version.
+            let proving_key = VarunaProvingKey::<N>::from_bytes_le(bytes.get(1..).unwrap_or_default())
+                .map_err(|error| anyhow!("Failed to recover the '{function_name}' proving key: {error}"))?;
+            Ok(Arc::new(proving_key))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Testnet3;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type CurrentNetwork = Testnet3;
+
+    static NUM_LOADS: AtomicUsize = AtomicUsize::new(0);
+
+    fn failing_loader() -> Result<Vec<u8>, ParameterError> {
+        NUM_LOADS.fetch_add(1, Ordering::SeqCst);
+        Err(ParameterError::Message("unavailable".to_string()))
+    }
+
+    #[test]
+    fn test_lazy_parameters() {
+        let mut parameters = LazyParameters::<CurrentNetwork>::new();
+        parameters.insert("transfer_public".to_string(), failing_loader);
+
+        // Ensure registering a proving key does not load it.
+        assert!(parameters.contains("transfer_public"));
+        assert!(!parameters.is_loaded("transfer_public"));
+        assert_eq!(parameters.num_loaded(), 0);
+        assert_eq!(NUM_LOADS.load(Ordering::SeqCst), 0);
+
+        // Ensure a missing proving key is rejected, without loading any proving key.
+        assert!(parameters.get("join").is_err());
+        assert_eq!(NUM_LOADS.load(Ordering::SeqCst), 0);
+
+        // Ensure a failed load is not cached, and is retried on the next use.
+        assert!(parameters.get("transfer_public").is_err());
+        assert!(parameters.get("transfer_public").is_err());
+        assert!(!parameters.is_loaded("transfer_public"));
+        assert_eq!(NUM_LOADS.load(Ordering::SeqCst), 2);
+    }
+}
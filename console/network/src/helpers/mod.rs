@@ -15,5 +15,12 @@
 mod id;
 pub use id::*;
 
+#[macro_use]
+mod instances;
+pub(crate) use instances::*;
+
+mod lazy;
+pub use lazy::*;
+
 mod object;
 pub use object::*;
@@ -22,6 +22,7 @@ extern crate lazy_static;
 pub use snarkvm_console_network_environment as environment;
 pub use snarkvm_console_network_environment::*;
 
+#[macro_use]
 mod helpers;
 pub use helpers::*;
 
@@ -35,7 +36,7 @@ pub mod prelude {
 use crate::environment::prelude::*;
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
-    snark::varuna::{CircuitProvingKey, CircuitVerifyingKey, VarunaHidingMode},
+    snark::varuna::{CircuitProvingKey, CircuitVerifyingKey, SNARKMode},
     srs::{UniversalProver, UniversalVerifier},
     AlgebraicSponge,
 };
@@ -59,7 +60,7 @@ pub type FiatShamir<N> = PoseidonSponge<Fq<N>, 2, 1>;
 pub type FiatShamirParameters<N> = <FiatShamir<N> as AlgebraicSponge<Fq<N>, 2>>::Parameters;
 
 /// Helper types for the Varuna proving and verifying key.
-pub(crate) type VarunaProvingKey<N> = CircuitProvingKey<<N as Environment>::PairingCurve, VarunaHidingMode>;
+pub(crate) type VarunaProvingKey<N> = CircuitProvingKey<<N as Environment>::PairingCurve, <N as Network>::VarunaMode>;
 pub(crate) type VarunaVerifyingKey<N> = CircuitVerifyingKey<<N as Environment>::PairingCurve>;
 
 pub trait Network:
@@ -109,6 +110,15 @@ pub trait Network:
     const BLOCK_TIME: u16 = 10;
     /// The coinbase puzzle degree.
     const COINBASE_PUZZLE_DEGREE: u32 = (1 << 13) - 1; // 8,191
+    /// The coinbase puzzle degree schedule, as `(starting epoch number, degree)` pairs in increasing order of epoch.
+    /// Note: The schedule must start at epoch 0, and each degree applies until the next starting epoch.
+    const COINBASE_PUZZLE_SCHEDULE: &'static [(u32, u32)] = &[(0, Self::COINBASE_PUZZLE_DEGREE)];
+    /// The block height from which block headers must commit to the receipts root.
+    /// Note: This is `u32::MAX` until the receipts root extension is activated by a coordinated upgrade.
+    const RECEIPTS_ROOT_HEIGHT: u32 = u32::MAX;
+    /// The block height from which proofs must be written in the version 2 layout, with the proof system metadata.
+    /// Note: This is `u32::MAX` until the version 2 layout is activated by a coordinated upgrade.
+    const PROOF_VERSION_2_HEIGHT: u32 = u32::MAX;
     /// The maximum number of solutions that can be included per block.
     const MAX_SOLUTIONS: usize = 1 << 8; // 256 solutions
     /// The number of blocks per epoch.
@@ -156,6 +166,13 @@ pub trait Network:
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 16;
 
+    /// The maximum number of transitions per transaction (including the fee transition).
+    const MAX_TRANSITIONS: usize = 32;
+    /// The maximum number of bytes in a transaction.
+    /// Note: This is enforced when a transaction is deserialized, which also bounds the size of every ciphertext
+    /// in a transaction, below `MAX_DATA_SIZE_IN_FIELDS`.
+    const MAX_TRANSACTION_SIZE: usize = 128_000; // 128 kB
+
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
     /// The block hash type.
@@ -166,6 +183,9 @@ pub trait Network:
     type TransactionID: Bech32ID<Field<Self>>;
     /// The transition ID type.
     type TransitionID: Bech32ID<Field<Self>>;
+    /// The Varuna mode, which determines whether the proofs of this network are hiding (zero-knowledge).
+    /// Note: The non-hiding mode proves faster, and is only suitable for networks whose proofs are not privacy-critical.
+    type VarunaMode: SNARKMode;
 
     /// Returns the genesis block bytes.
     fn genesis_bytes() -> &'static [u8];
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use snarkvm_algorithms::snark::varuna::VarunaHidingMode;
 use snarkvm_console_algorithms::{
     Blake2Xs,
     Keccak256,
@@ -46,41 +47,54 @@ lazy_static! {
     /// The serial number domain as a constant field element.
     pub static ref SERIAL_NUMBER_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSerialNumber0");
 
+    /// The proving keys for `credits.aleo`, each of which is loaded on first use.
+    pub static ref CREDITS_PROVING_KEYS: LazyParameters<Testnet3> = {
+        let mut map = LazyParameters::new();
+        snarkvm_parameters::insert_credit_key_loaders!(map, Prover);
+        map
+    };
+    pub static ref CREDITS_VERIFYING_KEYS: IndexMap<String, Arc<VarunaVerifyingKey<Console>>> = {
+        let mut map = IndexMap::new();
+        snarkvm_parameters::insert_credit_keys!(map, VarunaVerifyingKey<Console>, Verifier);
+        map
+    };
+}
+
+hash_instances!(Testnet3 => {
     /// The BHP hash function, which can take an input of up to 256 bits.
-    pub static ref BHP_256: BHP256<Testnet3> = BHP256::<Testnet3>::setup("AleoBHP256").expect("Failed to setup BHP256");
+    BHP_256: BHP256,
     /// The BHP hash function, which can take an input of up to 512 bits.
-    pub static ref BHP_512: BHP512<Testnet3> = BHP512::<Testnet3>::setup("AleoBHP512").expect("Failed to setup BHP512");
+    BHP_512: BHP512,
     /// The BHP hash function, which can take an input of up to 768 bits.
-    pub static ref BHP_768: BHP768<Testnet3> = BHP768::<Testnet3>::setup("AleoBHP768").expect("Failed to setup BHP768");
+    BHP_768: BHP768,
     /// The BHP hash function, which can take an input of up to 1024 bits.
-    pub static ref BHP_1024: BHP1024<Testnet3> = BHP1024::<Testnet3>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
+    BHP_1024: BHP1024,
 
     /// The Pedersen hash function, which can take an input of up to 64 bits.
-    pub static ref PEDERSEN_64: Pedersen64<Testnet3> = Pedersen64::<Testnet3>::setup("AleoPedersen64");
+    PEDERSEN_64: Pedersen64,
     /// The Pedersen hash function, which can take an input of up to 128 bits.
-    pub static ref PEDERSEN_128: Pedersen128<Testnet3> = Pedersen128::<Testnet3>::setup("AleoPedersen128");
+    PEDERSEN_128: Pedersen128,
 
     /// The Poseidon hash function, using a rate of 2.
-    pub static ref POSEIDON_2: Poseidon2<Testnet3> = Poseidon2::<Testnet3>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
+    POSEIDON_2: Poseidon2,
     /// The Poseidon hash function, using a rate of 4.
-    pub static ref POSEIDON_4: Poseidon4<Testnet3> = Poseidon4::<Testnet3>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
+    POSEIDON_4: Poseidon4,
     /// The Poseidon hash function, using a rate of 8.
-    pub static ref POSEIDON_8: Poseidon8<Testnet3> = Poseidon8::<Testnet3>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
-
-    pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
-        let mut map = IndexMap::new();
-        snarkvm_parameters::insert_credit_keys!(map, VarunaProvingKey<Console>, Prover);
-        map
-    };
-    pub static ref CREDITS_VERIFYING_KEYS: IndexMap<String, Arc<VarunaVerifyingKey<Console>>> = {
-        let mut map = IndexMap::new();
-        snarkvm_parameters::insert_credit_keys!(map, VarunaVerifyingKey<Console>, Verifier);
-        map
-    };
-}
+    POSEIDON_8: Poseidon8,
+});
 
 pub const TRANSACTION_PREFIX: &str = "at";
 
+/// The window size (in bits) of the fixed-base table for the generator `G`.
+#[cfg(not(feature = "low-memory"))]
+const G_WINDOW_BITS: usize = 8;
+
+#[cfg(not(feature = "low-memory"))]
+lazy_static! {
+    /// The fixed-base window table for the generator `G`, where `table[i][j] = j * 2^(G_WINDOW_BITS * i) * G`.
+    pub static ref GENERATOR_G_WINDOW_TABLE: Vec<Vec<Group<Testnet3>>> = Testnet3::new_window_table(&GENERATOR_G);
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Testnet3;
 
@@ -99,6 +113,25 @@ impl Testnet3 {
         }
         g_bases
     }
+
+    /// Initializes the fixed-base window table for the given bases, where `bases[i] = 2^i * G`.
+    #[cfg(not(feature = "low-memory"))]
+    fn new_window_table(bases: &[Group<Self>]) -> Vec<Vec<Group<Self>>> {
+        bases
+            .iter()
+            .step_by(G_WINDOW_BITS)
+            .map(|base| {
+                // Compute the multiples `j * base`, for `j` in `[0, 2^G_WINDOW_BITS)`.
+                let mut window = Vec::with_capacity(1 << G_WINDOW_BITS);
+                let mut multiple = Group::<Self>::zero();
+                for _ in 0..(1 << G_WINDOW_BITS) {
+                    window.push(multiple);
+                    multiple += base;
+                }
+                window
+            })
+            .collect()
+    }
 }
 
 impl Environment for Testnet3 {
@@ -130,6 +163,8 @@ impl Network for Testnet3 {
     type TransactionID = AleoID<Field<Self>, { hrp2!(TRANSACTION_PREFIX) }>;
     /// The transition ID type.
     type TransitionID = AleoID<Field<Self>, { hrp2!("au") }>;
+    /// The Varuna mode.
+    type VarunaMode = VarunaHidingMode;
 
     /// The network edition.
     const EDITION: u16 = 0;
@@ -147,9 +182,7 @@ impl Network for Testnet3 {
 
     /// Returns the proving key for the given function name in `credits.aleo`.
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
-        CREDITS_PROVING_KEYS
-            .get(&function_name)
-            .ok_or_else(|| anyhow!("Proving key for credits.aleo/{function_name}' not found"))
+        CREDITS_PROVING_KEYS.get(&function_name)
     }
 
     /// Returns the verifying key for the given function name in `credits.aleo`.
@@ -161,7 +194,7 @@ impl Network for Testnet3 {
 
     /// Returns the `proving key` for the inclusion circuit.
     fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
-        static INSTANCE: OnceCell<Arc<VarunaProvingKey<Console>>> = OnceCell::new();
+        static INSTANCE: OnceCell<Arc<VarunaProvingKey<Testnet3>>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
             // Skipping the first byte, which is the encoded version.
             Arc::new(
@@ -190,14 +223,28 @@ impl Network for Testnet3 {
 
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
-        GENERATOR_G
-            .iter()
-            .zip_eq(&scalar.to_bits_le())
-            .filter_map(|(base, bit)| match bit {
-                true => Some(base),
-                false => None,
-            })
-            .sum()
+        // Sum the precomputed multiple of `G` for each window of the scalar.
+        #[cfg(not(feature = "low-memory"))]
+        {
+            GENERATOR_G_WINDOW_TABLE
+                .iter()
+                .zip_eq(scalar.to_bits_le().chunks(G_WINDOW_BITS))
+                .map(|(window, bits)| window[bits.iter().rev().fold(0, |index, bit| (index << 1) | *bit as usize)])
+                .sum()
+        }
+
+        // Otherwise, sum the power of `G` for each set bit of the scalar.
+        #[cfg(feature = "low-memory")]
+        {
+            GENERATOR_G
+                .iter()
+                .zip_eq(&scalar.to_bits_le())
+                .filter_map(|(base, bit)| match bit {
+                    true => Some(base),
+                    false => None,
+                })
+                .sum()
+        }
     }
 
     /// Returns the Varuna universal prover.
@@ -489,9 +536,31 @@ mod tests {
 
     #[test]
     fn test_g_scalar_multiply() {
-        // Compute G^r.
-        let scalar = Scalar::rand(&mut TestRng::default());
-        let group = CurrentNetwork::g_scalar_multiply(&scalar);
-        assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
+        let rng = &mut TestRng::default();
+
+        // Compute G^r, including for the edge cases.
+        for scalar in (0..100).map(|_| Scalar::rand(rng)).chain([Scalar::zero(), Scalar::one(), -Scalar::one()]) {
+            let group = CurrentNetwork::g_scalar_multiply(&scalar);
+            assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
+        }
+    }
+
+    #[test]
+    fn test_hash_instance_domains() -> Result<()> {
+        // Ensure each hash instance is set up with its expected domain.
+        let rng = &mut TestRng::default();
+        let input = (0..64).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+        assert_eq!(BHP_256.hash(&input)?, BHP256::<CurrentNetwork>::setup("AleoBHP256")?.hash(&input)?);
+        assert_eq!(BHP_512.hash(&input)?, BHP512::<CurrentNetwork>::setup("AleoBHP512")?.hash(&input)?);
+        assert_eq!(BHP_768.hash(&input)?, BHP768::<CurrentNetwork>::setup("AleoBHP768")?.hash(&input)?);
+        assert_eq!(BHP_1024.hash(&input)?, BHP1024::<CurrentNetwork>::setup("AleoBHP1024")?.hash(&input)?);
+        assert_eq!(PEDERSEN_64.hash(&input)?, Pedersen64::<CurrentNetwork>::setup("AleoPedersen64").hash(&input)?);
+        assert_eq!(PEDERSEN_128.hash(&input)?, Pedersen128::<CurrentNetwork>::setup("AleoPedersen128").hash(&input)?);
+
+        let input = [Field::rand(rng), Field::rand(rng)];
+        assert_eq!(POSEIDON_2.hash(&input)?, Poseidon2::<CurrentNetwork>::setup("AleoPoseidon2")?.hash(&input)?);
+        assert_eq!(POSEIDON_4.hash(&input)?, Poseidon4::<CurrentNetwork>::setup("AleoPoseidon4")?.hash(&input)?);
+        assert_eq!(POSEIDON_8.hash(&input)?, Poseidon8::<CurrentNetwork>::setup("AleoPoseidon8")?.hash(&input)?);
+        Ok(())
     }
 }
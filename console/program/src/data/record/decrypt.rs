@@ -14,6 +14,9 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network> Record<N, Ciphertext<N>> {
     /// Decrypts `self` into plaintext using the given view key and checks that the owner matches the view key.
     pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Record<N, Plaintext<N>>> {
@@ -28,6 +31,37 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         }
     }
 
+    /// Decrypts each of the given records into plaintext using the given view key,
+    /// and checks that the owner of each record matches the view key.
+    ///
+    /// This amortizes the work of repeated calls to `Self::decrypt`: the address of the view key is derived once,
+    /// the record view keys are normalized with a single batch inversion, and the records are decrypted in parallel.
+    pub fn decrypt_batch(view_key: &ViewKey<N>, ciphertexts: &[Self]) -> Vec<Result<Record<N, Plaintext<N>>>> {
+        // Compute the address of the view key.
+        let address = view_key.to_address();
+        // Compute the Diffie-Hellman shared secrets, and convert them to affine form in a single batch.
+        let shared_secrets = N::Projective::batch_normalization_into_affine(
+            cfg_iter!(ciphertexts).map(|ciphertext| *(ciphertext.nonce * **view_key)).collect(),
+        );
+
+        cfg_iter!(ciphertexts)
+            .zip_eq(cfg_iter!(shared_secrets))
+            .map(|(ciphertext, shared_secret)| {
+                // Compute the record view key.
+                let record_view_key = Field::new(shared_secret.to_x_coordinate());
+                // Decrypt the record.
+                let record = ciphertext.decrypt_symmetric_unchecked(&record_view_key)?;
+                // Ensure the record owner matches the view key.
+                match address == **record.owner() {
+                    true => Ok(record),
+                    false => bail!(
+                        "Illegal operation: Record::decrypt_batch() view key does not correspond to the record owner."
+                    ),
+                }
+            })
+            .collect()
+    }
+
     /// Decrypts `self` into plaintext using the given record view key.
     /// Note: This method does not check that the record view key corresponds to the record owner.
     /// Use `Self::decrypt` for the checked variant.
@@ -130,6 +164,12 @@ mod tests {
         // Ensure that decrypting with the incorrect view key fails.
         assert!(ciphertext.decrypt(&incorrect_view_key).is_err());
 
+        // Ensure the batch decryption matches the individual decryption.
+        let ciphertexts = vec![ciphertext.clone(), ciphertext];
+        let records = Record::decrypt_batch(&view_key, &ciphertexts).into_iter().collect::<Result<Vec<_>>>()?;
+        assert_eq!(vec![record.clone(), record], records);
+        assert!(Record::decrypt_batch(&incorrect_view_key, &ciphertexts).iter().all(|record| record.is_err()));
+
         Ok(())
     }
 
@@ -24,6 +24,7 @@ mod encrypt;
 mod equal;
 mod find;
 mod is_owner;
+mod nonce;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Private: Visibility> Record<N, Private> {
+    /// A helper method to derive the encryption randomizer of a record output, as `HashToScalar(tvk || index)`,
+    /// from the transition view key and the register index of the output.
+    ///
+    /// The transition view key is derived from fresh randomness for every request, and every output
+    /// of a function has a distinct register index, so each record output has a distinct randomizer.
+    /// Builders that construct outputs directly must use this derivation, as reusing a randomizer
+    /// reuses the encryption key of the record and links the records that share it.
+    pub fn randomizer(tvk: Field<N>, index: u64) -> Result<Scalar<N>> {
+        N::hash_to_scalar_psd2(&[tvk, Field::from_u64(index)])
+    }
+
+    /// A helper method to derive the nonce of a record output, as `G^randomizer`,
+    /// from the transition view key and the register index of the output.
+    ///
+    /// Note: The ledger rejects a transaction that contains a nonce twice, or a nonce that already exists on-chain.
+    pub fn nonce_from_tvk(tvk: Field<N>, index: u64) -> Result<Group<N>> {
+        Ok(N::g_scalar_multiply(&Self::randomizer(tvk, index)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use std::collections::HashSet;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentRecord = Record<CurrentNetwork, Plaintext<CurrentNetwork>>;
+
+    const ITERATIONS: usize = 100;
+    const NUM_OUTPUTS: u64 = 16;
+
+    #[test]
+    fn test_nonce_uniqueness() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let mut nonces = HashSet::new();
+        for _ in 0..ITERATIONS {
+            // Sample a transition view key.
+            let tvk = Field::rand(&mut rng);
+            for index in 0..NUM_OUTPUTS {
+                // Ensure the nonce matches its randomizer.
+                let nonce = CurrentRecord::nonce_from_tvk(tvk, index)?;
+                assert_eq!(nonce, CurrentNetwork::g_scalar_multiply(&CurrentRecord::randomizer(tvk, index)?));
+                // Ensure the nonce is distinct across outputs and transitions.
+                assert!(nonces.insert(nonce));
+            }
+        }
+        Ok(())
+    }
+}
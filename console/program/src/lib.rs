@@ -39,6 +39,9 @@ pub use locator::*;
 mod owner;
 pub use owner::*;
 
+mod private_key_ciphertext;
+pub use private_key_ciphertext::*;
+
 mod request;
 pub use request::*;
 
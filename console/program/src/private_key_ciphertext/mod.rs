@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod string;
+
+use crate::{Ciphertext, Identifier, Literal, Plaintext};
+use snarkvm_console_account::PrivateKey;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use core::ops::Deref;
+use indexmap::IndexMap;
+use once_cell::sync::OnceCell;
+
+/// The domain for the blinding factor of the private key.
+const PRIVATE_KEY_DOMAIN: &str = "private_key";
+
+/// An account private key, encrypted under a secret in the wallet `PrivateKeyCiphertext` format of the Aleo SDK.
+///
+/// The account seed is multiplied by the blinding factor `HashPSD2(domain, nonce, secret)`, and the product and
+/// nonce are encrypted as the plaintext `{ key, nonce }` under the secret. The string form is the ciphertext string,
+/// so the ciphertexts of existing wallets can be decrypted, and the ciphertexts produced here can be imported by them.
+///
+/// Note: The secret is used as a field element directly, without a key derivation function, so it should be a
+/// high-entropy secret. For a password-protected export, use `EncryptedPrivateKey` instead.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PrivateKeyCiphertext<N: Network>(Ciphertext<N>);
+
+impl<N: Network> PrivateKeyCiphertext<N> {
+    /// Encrypts the given private key under the given secret.
+    pub fn encrypt<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, secret: &str, rng: &mut R) -> Result<Self> {
+        // Derive the domain separator and the secret.
+        let domain = Field::new_domain_separator(PRIVATE_KEY_DOMAIN);
+        let secret = Field::new_domain_separator(secret);
+        // Sample a random nonce.
+        let nonce = Uniform::rand(rng);
+        // Blind the account seed.
+        let key = N::hash_psd2(&[domain, nonce, secret])? * private_key.seed();
+        // Encrypt the blinded account seed and the nonce under the secret.
+        let plaintext = Plaintext::Struct(
+            IndexMap::from_iter([
+                (Identifier::from_str("key")?, Plaintext::from(Literal::Field(key))),
+                (Identifier::from_str("nonce")?, Plaintext::from(Literal::Field(nonce))),
+            ]),
+            OnceCell::new(),
+        );
+        Ok(Self(plaintext.encrypt_symmetric(secret)?))
+    }
+
+    /// Decrypts the private key with the given secret.
+    pub fn decrypt(&self, secret: &str) -> Result<PrivateKey<N>> {
+        // Derive the domain separator and the secret.
+        let domain = Field::new_domain_separator(PRIVATE_KEY_DOMAIN);
+        let secret = Field::new_domain_separator(secret);
+        // Decrypt the blinded account seed and the nonce.
+        // Note: The format has no authentication tag, so an incorrect secret is only detected when it yields
+        // a malformed plaintext, as it does in almost all cases.
+        let plaintext = self.0.decrypt_symmetric(secret)?;
+        let field = |name: &str| match plaintext.find(&[Identifier::from_str(name)?])? {
+            Plaintext::Literal(Literal::Field(field), ..) => Ok(field),
+            _ => bail!("Failed to decrypt the private key: '{name}' is not a field element"),
+        };
+        let (key, nonce) = (field("key")?, field("nonce")?);
+        // Unblind the account seed.
+        let seed = key * N::hash_psd2(&[domain, nonce, secret])?.inverse()?;
+        // Output the private key.
+        PrivateKey::try_from(seed)
+    }
+}
+
+impl<N: Network> From<Ciphertext<N>> for PrivateKeyCiphertext<N> {
+    /// Initializes the private key ciphertext from a ciphertext.
+    fn from(ciphertext: Ciphertext<N>) -> Self {
+        Self(ciphertext)
+    }
+}
+
+impl<N: Network> Deref for PrivateKeyCiphertext<N> {
+    type Target = Ciphertext<N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a private key, and encrypt it.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let ciphertext = PrivateKeyCiphertext::encrypt(&private_key, "mypassword", &mut rng)?;
+
+            // Check that the private key decrypts with the secret.
+            assert_eq!(private_key, ciphertext.decrypt("mypassword")?);
+            // Check that the private key does not decrypt with an incorrect secret.
+            assert!(ciphertext.decrypt("badpassword").is_err());
+
+            // Check that encrypting the same private key twice yields different ciphertexts.
+            let other = PrivateKeyCiphertext::encrypt(&private_key, "mypassword", &mut rng)?;
+            assert_ne!(ciphertext, other);
+            assert_eq!(private_key, other.decrypt("mypassword")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_sdk_ciphertext() -> Result<()> {
+        // The test vector of the `PrivateKeyCiphertext` in the Aleo SDK.
+        let private_key =
+            PrivateKey::<CurrentNetwork>::from_str("APrivateKey1zkpAYS46Dq4rnt9wdohyWMwdmjmTeMJKPZdp5AhvjXZDsVG")?;
+        let ciphertext = PrivateKeyCiphertext::<CurrentNetwork>::from_str(
+            "ciphertext1qvqg7rgvam3xdcu55pwu6sl8rxwefxaj5gwthk0yzln6jv5fastzup0qn0qftqlqq7jcckyx03fzv9kke0z9puwd7cl7jzyhxfy2f2juplz39dkqs6p24urhxymhv364qm3z8mvyklv5gr52n4fxr2z59jgqytyddj8",
+        )?;
+
+        // Check that the private key decrypts with the secret, and not with an incorrect secret.
+        assert_eq!(private_key, ciphertext.decrypt("mypassword")?);
+        assert!(ciphertext.decrypt("badpassword").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromStr for PrivateKeyCiphertext<N> {
+    type Err = Error;
+
+    /// Reads in the private key ciphertext string.
+    fn from_str(ciphertext: &str) -> Result<Self, Self::Err> {
+        Ok(Self(Ciphertext::from_str(ciphertext)?))
+    }
+}
+
+impl<N: Network> Debug for PrivateKeyCiphertext<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for PrivateKeyCiphertext<N> {
+    /// Writes the private key ciphertext as a ciphertext string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a private key ciphertext.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let expected = PrivateKeyCiphertext::encrypt(&private_key, "mypassword", &mut rng)?;
+
+        // Check the string representation.
+        let candidate = format!("{expected}");
+        assert!(candidate.starts_with("ciphertext1"));
+        assert_eq!(expected, PrivateKeyCiphertext::from_str(&candidate)?);
+        assert_eq!(candidate, expected.to_string());
+
+        // Check that a corrupted string is rejected.
+        let corrupted = candidate.replacen('q', "p", 1);
+        assert!(PrivateKeyCiphertext::<CurrentNetwork>::from_str(&corrupted).is_err());
+        Ok(())
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Identifier, ProgramID, Register, Value, ValueType};
+use crate::{Identifier, Plaintext, ProgramID, Record, Register, Value, ValueType};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -155,10 +155,8 @@ impl<N: Network> Response<N> {
                         // Compute the record commitment.
                         let commitment = record.to_commitment(program_id, record_name)?;
 
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u64(output_register.locator());
                         // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
-                        let randomizer = N::hash_to_scalar_psd2(&[*tvk, index])?;
+                        let randomizer = Record::<N, Plaintext<N>>::randomizer(*tvk, output_register.locator())?;
 
                         // Encrypt the record, using the randomizer.
                         let encrypted_record = record.encrypt(randomizer)?;
@@ -27,6 +27,8 @@ pub const RATIFICATIONS_DEPTH: u8 = 16;
 pub const SUBDAG_CERTIFICATES_DEPTH: u8 = 16;
 /// The depth of the Merkle tree for transactions in a block.
 pub const TRANSACTIONS_DEPTH: u8 = 16;
+/// The depth of the Merkle tree for the execution receipts in a block.
+pub const RECEIPTS_DEPTH: u8 = TRANSACTIONS_DEPTH + TRANSACTION_DEPTH;
 /// The depth of the Merkle tree for the transaction.
 pub const TRANSACTION_DEPTH: u8 = 5;
 /// The depth of the Merkle tree for the transition.
@@ -52,6 +54,11 @@ pub type TransactionsTree<N> = BHPMerkleTree<N, TRANSACTIONS_DEPTH>;
 /// The Merkle path for a transaction in a block.
 pub type TransactionsPath<N> = MerklePath<N, TRANSACTIONS_DEPTH>;
 
+/// The Merkle tree for execution receipts in a block.
+pub type ReceiptsTree<N> = BHPMerkleTree<N, RECEIPTS_DEPTH>;
+/// The Merkle path for an execution receipt in a block.
+pub type ReceiptsPath<N> = MerklePath<N, RECEIPTS_DEPTH>;
+
 /// The Merkle tree for the transaction.
 pub type TransactionTree<N> = BHPMerkleTree<N, TRANSACTION_DEPTH>;
 /// The Merkle path for a function or transition in the transaction.
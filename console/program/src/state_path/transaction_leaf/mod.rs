@@ -47,6 +47,16 @@ impl<N: Network> TransactionLeaf<N> {
         Self { variant: 1, index, id }
     }
 
+    /// Initializes a new instance of `TransactionLeaf`, for the expiration height of an execution.
+    pub fn new_expiration(index: u16, expires_at: u32) -> Self {
+        Self { variant: 2, index, id: Field::from_u32(expires_at) }
+    }
+
+    /// Initializes a new instance of `TransactionLeaf`, for the version of the proof layout in a transaction.
+    pub fn new_proof_version(index: u16, version: u8) -> Self {
+        Self { variant: 3, index, id: Field::from_u8(version) }
+    }
+
     /// Initializes a new instance of `TransactionLeaf`.
     pub const fn from(variant: u8, index: u16, id: Field<N>) -> Self {
         Self { variant, index, id }
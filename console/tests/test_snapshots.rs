@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The snapshot tests for the canonical serializations of the console types.
+//!
+//! Each test samples a value from a fixed seed, serializes it with `to_bytes_le`, and compares the bytes to the
+//! snapshot file in `./tests/snapshots`. Any byte-level change to a serialization fails the corresponding test,
+//! as it would break consensus with the existing ledger. The snapshot must also deserialize to the sampled value.
+//!
+//! When a serialization is changed on purpose, rewrite the snapshot files by running:
+//! `REWRITE_EXPECTATIONS=1 cargo test -p snarkvm-console --test test_snapshots`.
+
+use snarkvm_console::{
+    account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey},
+    network::Testnet3,
+    prelude::*,
+    program::{Ciphertext, Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    types::{Boolean, Field, Group, Scalar, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8},
+};
+
+use std::{
+    fmt::Debug,
+    path::{Path, PathBuf},
+};
+
+type CurrentNetwork = Testnet3;
+
+/// The seed for the RNG of every snapshot test.
+const SEED: u64 = 1337;
+
+/// Returns the path to the snapshot file with the given name.
+fn snapshot_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("snapshots").join(format!("{name}.bin"))
+}
+
+/// Returns the offset of the first byte that differs between the given byte strings.
+fn first_difference(expected: &[u8], candidate: &[u8]) -> usize {
+    expected.iter().zip(candidate).position(|(a, b)| a != b).unwrap_or_else(|| expected.len().min(candidate.len()))
+}
+
+/// Checks the serialization of the given value against the snapshot file with the given name.
+fn check_snapshot<T: ToBytes + FromBytes + PartialEq + Debug>(name: &str, value: &T) {
+    let candidate = value.to_bytes_le().unwrap_or_else(|error| panic!("Failed to serialize '{name}': {error}"));
+    let path = snapshot_path(name);
+
+    // If the `REWRITE_EXPECTATIONS` environment variable is set, rewrite the snapshot file.
+    if std::env::var("REWRITE_EXPECTATIONS").is_ok() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &candidate).unwrap();
+    }
+
+    let expected = std::fs::read(&path).unwrap_or_else(|error| {
+        panic!("Missing the snapshot for '{name}' at {path:?} ({error}), set `REWRITE_EXPECTATIONS=1` to create it")
+    });
+    if expected != candidate {
+        let offset = first_difference(&expected, &candidate);
+        panic!(
+            "\n\nThe serialization of '{name}' changed, which breaks consensus.\n\
+             The snapshot has {} bytes, the serialization has {} bytes, and they first differ at byte {offset}.\n\
+             expected: {}\n\
+             actual:   {}\n\
+             Set `REWRITE_EXPECTATIONS=1` only if the change is intended.\n\n",
+            expected.len(),
+            candidate.len(),
+            hex_window(&expected, offset),
+            hex_window(&candidate, offset),
+        );
+    }
+
+    // Ensure the snapshot deserializes to the value.
+    let recovered =
+        T::from_bytes_le(&expected).unwrap_or_else(|error| panic!("Failed to deserialize '{name}': {error}"));
+    assert_eq!(value, &recovered, "The snapshot of '{name}' deserialized to a different value");
+}
+
+/// Returns the hex encoding of up to 16 bytes, starting at the given offset.
+fn hex_window(bytes: &[u8], offset: usize) -> String {
+    let window = bytes.get(offset..).unwrap_or_default();
+    window.iter().take(16).map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[test]
+fn test_snapshot_types() {
+    let mut rng = TestRng::fixed(SEED);
+
+    check_snapshot("address", &Address::<CurrentNetwork>::new(Uniform::rand(&mut rng)));
+    check_snapshot("boolean", &Boolean::<CurrentNetwork>::new(true));
+    check_snapshot("field", &Field::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("group", &Group::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("scalar", &Scalar::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("i8", &I8::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("i16", &I16::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("i32", &I32::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("i64", &I64::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("i128", &I128::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("u8", &U8::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("u16", &U16::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("u32", &U32::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("u64", &U64::<CurrentNetwork>::rand(&mut rng));
+    check_snapshot("u128", &U128::<CurrentNetwork>::rand(&mut rng));
+}
+
+#[test]
+fn test_snapshot_account() {
+    let mut rng = TestRng::fixed(SEED);
+
+    let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+    let view_key = ViewKey::try_from(&private_key).unwrap();
+    let message = (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Field<CurrentNetwork>>>();
+
+    check_snapshot("private_key", &private_key);
+    check_snapshot("compute_key", &ComputeKey::try_from(&private_key).unwrap());
+    check_snapshot("view_key", &view_key);
+    check_snapshot("graph_key", &GraphKey::try_from(&view_key).unwrap());
+    check_snapshot("signature", &Signature::sign(&private_key, &message, &mut rng).unwrap());
+}
+
+#[test]
+fn test_snapshot_program() {
+    let mut rng = TestRng::fixed(SEED);
+
+    let plaintext = Plaintext::<CurrentNetwork>::from_str(
+        "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amounts: [1u64, 2u64, 3u64], flag: true }",
+    )
+    .unwrap();
+    let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+        "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, _nonce: 0group.public }",
+    )
+    .unwrap();
+    let address = Address::<CurrentNetwork>::new(Uniform::rand(&mut rng));
+    let ciphertext: Ciphertext<CurrentNetwork> = plaintext.encrypt(&address, Uniform::rand(&mut rng)).unwrap();
+
+    check_snapshot("identifier", &Identifier::<CurrentNetwork>::from_str("transfer_public").unwrap());
+    check_snapshot("program_id", &ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap());
+    check_snapshot("literal", &Literal::<CurrentNetwork>::from_str("-12345i64").unwrap());
+    check_snapshot("plaintext", &plaintext);
+    check_snapshot("ciphertext", &ciphertext);
+    check_snapshot("record", &record);
+    check_snapshot("value", &Value::<CurrentNetwork>::Plaintext(plaintext));
+}
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    bls12_377::{G1Affine, G2Affine},
+    AffineCurve,
+    GroupError,
+};
+use snarkvm_fields::Zero;
+
+use ark_ec::AffineRepr;
+
+macro_rules! impl_arkworks_affine {
+    ($affine: ident, $ark_affine: ty) => {
+        impl TryFrom<$affine> for $ark_affine {
+            type Error = GroupError;
+
+            /// Converts a snarkVM affine point into the arkworks affine point,
+            /// ensuring it is on the curve and in the prime order subgroup.
+            fn try_from(point: $affine) -> Result<Self, Self::Error> {
+                if point.is_zero() {
+                    return Ok(Self::zero());
+                }
+                let point = Self::new_unchecked(point.x.try_into()?, point.y.try_into()?);
+                match point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve() {
+                    true => Ok(point),
+                    false => Err(GroupError::InvalidGroupElement),
+                }
+            }
+        }
+
+        impl TryFrom<$ark_affine> for $affine {
+            type Error = GroupError;
+
+            /// Converts an arkworks affine point into the snarkVM affine point,
+            /// ensuring it is on the curve and in the prime order subgroup.
+            fn try_from(point: $ark_affine) -> Result<Self, Self::Error> {
+                if point.infinity {
+                    return Ok(Self::zero());
+                }
+                Self::from_coordinates((point.x.try_into()?, point.y.try_into()?, false))
+                    .ok_or(GroupError::InvalidGroupElement)
+            }
+        }
+    };
+}
+
+impl_arkworks_affine!(G1Affine, ark_bls12_377::G1Affine);
+impl_arkworks_affine!(G2Affine, ark_bls12_377::G2Affine);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bls12_377::{Fq, Fq2, Fr, G1Projective, G2Projective},
+        edwards_bls12::Fr as EdwardsFr,
+        ProjectiveCurve,
+    };
+    use snarkvm_fields::Field;
+    use snarkvm_utilities::{
+        serialize::{CanonicalDeserialize, CanonicalSerialize},
+        TestRng,
+        Uniform,
+    };
+
+    use ark_serialize::CanonicalSerialize as ArkCanonicalSerialize;
+    use core::fmt::Debug;
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns the compressed and uncompressed encodings of the given snarkVM element.
+    fn encode<T: CanonicalSerialize>(element: &T) -> (Vec<u8>, Vec<u8>) {
+        let (mut compressed, mut uncompressed) = (Vec::new(), Vec::new());
+        element.serialize_compressed(&mut compressed).unwrap();
+        element.serialize_uncompressed(&mut uncompressed).unwrap();
+        (compressed, uncompressed)
+    }
+
+    /// Returns the compressed and uncompressed encodings of the given arkworks element.
+    fn ark_encode<T: ArkCanonicalSerialize>(element: &T) -> (Vec<u8>, Vec<u8>) {
+        let (mut compressed, mut uncompressed) = (Vec::new(), Vec::new());
+        element.serialize_compressed(&mut compressed).unwrap();
+        element.serialize_uncompressed(&mut uncompressed).unwrap();
+        (compressed, uncompressed)
+    }
+
+    /// Checks the encodings of a (non-infinity) snarkVM point against those of the equivalent arkworks point.
+    fn check_affine_encodings<
+        T: CanonicalSerialize + CanonicalDeserialize + PartialEq + Debug,
+        A: ArkCanonicalSerialize,
+    >(
+        point: &T,
+        ark_point: &A,
+    ) {
+        let ((compressed, mut uncompressed), (ark_compressed, ark_uncompressed)) =
+            (encode(point), ark_encode(ark_point));
+        // Ensure the compressed encodings match.
+        assert_eq!(compressed, ark_compressed);
+        // Ensure the uncompressed encodings match, except for the sign bit, which only arkworks sets.
+        let last = uncompressed.len() - 1;
+        uncompressed[last] |= ark_uncompressed[last] & (1 << 7);
+        assert_eq!(uncompressed, ark_uncompressed);
+        // Ensure the arkworks encodings decode to the snarkVM point.
+        assert_eq!(&T::deserialize_compressed(&*ark_compressed).unwrap(), point);
+        assert_eq!(&T::deserialize_uncompressed(&*ark_uncompressed).unwrap(), point);
+    }
+
+    #[test]
+    fn test_field_round_trip() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (a, b) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+            let (ark_a, ark_b) = (ark_bls12_377::Fr::try_from(a).unwrap(), ark_bls12_377::Fr::try_from(b).unwrap());
+            assert_eq!(Fr::try_from(ark_a).unwrap(), a);
+            assert_eq!(ark_bls12_377::Fr::try_from(a * b).unwrap(), ark_a * ark_b);
+            assert_eq!(encode(&a), ark_encode(&ark_a));
+
+            let (a, b) = (Fq::rand(&mut rng), Fq::rand(&mut rng));
+            let (ark_a, ark_b) = (ark_bls12_377::Fq::try_from(a).unwrap(), ark_bls12_377::Fq::try_from(b).unwrap());
+            assert_eq!(Fq::try_from(ark_a).unwrap(), a);
+            assert_eq!(ark_bls12_377::Fq::try_from(a * b).unwrap(), ark_a * ark_b);
+            assert_eq!(encode(&a), ark_encode(&ark_a));
+
+            let (a, b) = (Fq2::rand(&mut rng), Fq2::rand(&mut rng));
+            let (ark_a, ark_b) = (ark_bls12_377::Fq2::try_from(a).unwrap(), ark_bls12_377::Fq2::try_from(b).unwrap());
+            assert_eq!(Fq2::try_from(ark_a).unwrap(), a);
+            assert_eq!(ark_bls12_377::Fq2::try_from(a * b).unwrap(), ark_a * ark_b);
+            assert_eq!(
+                ark_bls12_377::Fq2::try_from(a.inverse().unwrap()).unwrap(),
+                ark_ff::Field::inverse(&ark_a).unwrap()
+            );
+            assert_eq!(encode(&a), ark_encode(&ark_a));
+        }
+    }
+
+    #[test]
+    fn test_field_mismatched_moduli() {
+        let mut rng = TestRng::default();
+
+        // Ensure elements of a different field with the same number of limbs are rejected.
+        assert!(ark_bls12_377::Fr::try_from(EdwardsFr::rand(&mut rng)).is_err());
+        assert!(EdwardsFr::try_from(ark_bls12_377::Fr::from(1u64)).is_err());
+    }
+
+    #[test]
+    fn test_affine_round_trip() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let point = G1Projective::rand(&mut rng).to_affine();
+            let ark_point = ark_bls12_377::G1Affine::try_from(point).unwrap();
+            assert_eq!(G1Affine::try_from(ark_point).unwrap(), point);
+            check_affine_encodings(&point, &ark_point);
+
+            let point = G2Projective::rand(&mut rng).to_affine();
+            let ark_point = ark_bls12_377::G2Affine::try_from(point).unwrap();
+            assert_eq!(G2Affine::try_from(ark_point).unwrap(), point);
+            check_affine_encodings(&point, &ark_point);
+        }
+
+        // Ensure the arkworks generators are valid points.
+        // Note: The G1 generators differ, so keys derived from a generator are not interchangeable.
+        assert!(G1Affine::try_from(ark_bls12_377::G1Affine::generator()).is_ok());
+        assert!(G2Affine::try_from(ark_bls12_377::G2Affine::generator()).is_ok());
+    }
+
+    #[test]
+    fn test_affine_infinity() {
+        let ark_zero = ark_bls12_377::G1Affine::try_from(G1Affine::zero()).unwrap();
+        assert!(ark_zero.infinity);
+        assert_eq!(G1Affine::try_from(ark_zero).unwrap(), G1Affine::zero());
+
+        // Ensure the compressed encodings of infinity match.
+        // Note: The uncompressed encodings differ, as snarkVM encodes infinity with `y = 1` and arkworks with `y = 0`.
+        assert_ne!(encode(&G1Affine::zero()).1, ark_encode(&ark_zero).1);
+        assert_eq!(encode(&G1Affine::zero()).0, ark_encode(&ark_zero).0);
+        assert_eq!(encode(&G2Affine::zero()).0, ark_encode(&ark_bls12_377::G2Affine::zero()).0);
+    }
+
+    #[test]
+    fn test_affine_invalid() {
+        let mut rng = TestRng::default();
+
+        // Ensure points that are not on the curve are rejected in both directions.
+        let point = G1Projective::rand(&mut rng).to_affine();
+        let invalid = G1Affine::new(point.x, point.y.double(), false);
+        assert!(ark_bls12_377::G1Affine::try_from(invalid).is_err());
+        let ark_invalid = ark_bls12_377::G1Affine::new_unchecked(
+            ark_bls12_377::Fq::try_from(invalid.x).unwrap(),
+            ark_bls12_377::Fq::try_from(invalid.y).unwrap(),
+        );
+        assert!(G1Affine::try_from(ark_invalid).is_err());
+    }
+}
@@ -42,6 +42,9 @@ pub mod g2;
 #[doc(inline)]
 pub use g2::*;
 
+#[cfg(feature = "arkworks-compat")]
+mod arkworks;
+
 pub mod parameters;
 #[doc(inline)]
 pub use parameters::*;
@@ -68,6 +68,7 @@ use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger256, BigInteger384},
     rand::{TestRng, Uniform},
     BitIteratorBE,
+    CanonicalSerialize,
 };
 
 use rand::Rng;
@@ -435,6 +436,19 @@ fn test_fr_sqrt_tonelli() {
     random_sqrt_tonelli_tests::<Fr>(&mut rng);
 }
 
+#[test]
+fn test_sqrt_root_choice() {
+    // Ensure `sqrt` returns the same one of the two roots as the table-less implementation.
+    let is_positive = |k: u64| Fr::from(k * k).sqrt().unwrap() == Fr::from(k);
+    assert_eq!((1..=16).map(is_positive).collect::<Vec<_>>(), [
+        true, false, false, false, true, false, false, false, false, true, true, false, false, false, true, false
+    ]);
+    let is_positive = |k: u64| Fq::from(k * k).sqrt().unwrap() == Fq::from(k);
+    assert_eq!((1..=16).map(is_positive).collect::<Vec<_>>(), [
+        true, true, true, false, true, true, true, false, true, false, true, false, true, true, false, true
+    ]);
+}
+
 #[test]
 fn test_fq_bench_sqrt() {
     let mut rng = TestRng::default();
@@ -702,3 +716,43 @@ fn test_bilinearity() {
     assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
     assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
 }
+
+#[test]
+fn test_serde_hex_and_decimal() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Encodings {
+        #[serde(with = "snarkvm_utilities::serialize::serde_hex")]
+        fq: Fq,
+        #[serde(with = "snarkvm_utilities::serialize::serde_decimal")]
+        fr: Fr,
+        #[serde(with = "snarkvm_utilities::serialize::serde_hex")]
+        g1: G1Affine,
+        #[serde(with = "snarkvm_utilities::serialize::serde_hex")]
+        g2: G2Affine,
+    }
+
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let expected = Encodings {
+            fq: rng.gen(),
+            fr: rng.gen(),
+            g1: rng.gen::<G1Projective>().to_affine(),
+            g2: rng.gen::<G2Projective>().to_affine(),
+        };
+
+        // Check the JSON representation.
+        let json = serde_json::to_value(&expected).unwrap();
+        assert_eq!(json["fr"].as_str().unwrap(), expected.fr.to_string());
+        assert_eq!(json["g1"].as_str().unwrap().len(), 2 * G1Affine::prime_subgroup_generator().compressed_size());
+        assert_eq!(expected, serde_json::from_value(json).unwrap());
+
+        // Check the binary representation.
+        let bytes = bincode::serialize(&expected).unwrap();
+        assert_eq!(expected, bincode::deserialize(&bytes).unwrap());
+    }
+
+    // Ensure non-canonical encodings are rejected.
+    let invalid = serde_json::json!({ "fq": "ff".repeat(48), "fr": "0", "g1": "00", "g2": "00" });
+    assert!(serde_json::from_value::<Encodings>(invalid).is_err());
+}
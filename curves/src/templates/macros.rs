@@ -83,13 +83,14 @@ macro_rules! impl_sw_curve_serializer {
                         }
                     }
                     Compress::No => {
-                        let flags = if self.is_zero() {
-                            snarkvm_utilities::serialize::SWFlags::infinity()
+                        // Note: The point at infinity is always serialized with its canonical coordinates.
+                        let (point, flags) = if self.is_zero() {
+                            (Self::zero(), snarkvm_utilities::serialize::SWFlags::infinity())
                         } else {
-                            snarkvm_utilities::serialize::SWFlags::default()
+                            (*self, snarkvm_utilities::serialize::SWFlags::default())
                         };
-                        self.x.serialize_uncompressed(&mut writer)?;
-                        self.y.serialize_with_flags(&mut writer, flags)?;
+                        point.x.serialize_uncompressed(&mut writer)?;
+                        point.y.serialize_with_flags(&mut writer, flags)?;
                         Ok(())
                     }
                 }
@@ -121,19 +122,32 @@ macro_rules! impl_sw_curve_serializer {
                 compress: Compress,
                 validate: Validate,
             ) -> Result<Self, snarkvm_utilities::serialize::SerializationError> {
-                use snarkvm_utilities::serialize::SWFlags;
+                use snarkvm_utilities::serialize::{SWFlags, SerializationError};
+                // Note: The encoding is specified in `SWFlags`. In strict mode (`Validate::Yes`),
+                // the point at infinity must be encoded with its canonical coordinates.
+                let strict = validate == Validate::Yes;
                 let point = if let Compress::Yes = compress {
                     let (x, flags) = P::BaseField::deserialize_with_flags::<_, SWFlags>(&mut reader)?;
                     if flags.is_infinity() {
+                        if strict && !x.is_zero() {
+                            return Err(SerializationError::InvalidData);
+                        }
                         Self::zero()
                     } else {
                         Affine::<P>::from_x_coordinate(x, flags.is_positive().unwrap())
-                            .ok_or(snarkvm_utilities::serialize::SerializationError::InvalidData)?
+                            .ok_or(SerializationError::InvalidData)?
                     }
                 } else {
                     let x = P::BaseField::deserialize_uncompressed(&mut reader)?;
                     let (y, flags) = P::BaseField::deserialize_with_flags::<_, SWFlags>(&mut reader)?;
-                    Affine::<P>::new(x, y, flags.is_infinity())
+                    if flags.is_infinity() {
+                        if strict && (x, y) != (Self::zero().x, Self::zero().y) {
+                            return Err(SerializationError::InvalidData);
+                        }
+                        Self::zero()
+                    } else {
+                        Affine::<P>::new(x, y, false)
+                    }
                 };
                 if validate == Validate::Yes {
                     point.check()?;
@@ -239,10 +253,15 @@ macro_rules! impl_edwards_curve_serializer {
                 validate: Validate,
             ) -> Result<Self, snarkvm_utilities::serialize::SerializationError> {
                 use snarkvm_utilities::serialize::{EdwardsFlags, SerializationError};
+                // Note: The encoding is specified in `EdwardsFlags`. In strict mode (`Validate::Yes`),
+                // the identity must be encoded with the sign bit unset.
                 let point = if let Compress::Yes = compress {
                     let (x, flags): (P::BaseField, EdwardsFlags) = P::BaseField::deserialize_with_flags(&mut reader)?;
 
                     if x == P::BaseField::zero() {
+                        if validate == Validate::Yes && flags.is_positive() {
+                            return Err(SerializationError::InvalidData);
+                        }
                         Self::zero()
                     } else {
                         Affine::<P>::from_x_coordinate(x, flags.is_positive()).ok_or(SerializationError::InvalidData)?
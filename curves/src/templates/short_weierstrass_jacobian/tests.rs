@@ -14,11 +14,11 @@
 
 use super::{Affine, Projective};
 use crate::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters};
-use snarkvm_fields::Zero;
+use snarkvm_fields::{Field, Zero};
 use snarkvm_utilities::{
     io::Cursor,
     rand::Uniform,
-    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, CanonicalSerializeWithFlags, SWFlags},
     Compress,
     TestRng,
     Validate,
@@ -30,6 +30,96 @@ pub fn sw_tests<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
     sw_curve_serialization_test::<P>(rng);
     sw_from_random_bytes::<P>(rng);
     sw_from_x_coordinate::<P>(rng);
+    sw_strict_parsing_test::<P>(rng);
+}
+
+pub fn sw_strict_parsing_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
+    let serialize = |point: &Affine<P>, compress| {
+        let mut bytes = Vec::new();
+        point.serialize_with_mode(&mut bytes, compress).unwrap();
+        bytes
+    };
+    let deserialize = |bytes: &[u8], compress, validate| Affine::<P>::deserialize_with_mode(bytes, compress, validate);
+
+    for compress in [Compress::Yes, Compress::No] {
+        // Ensure the point at infinity sets only the infinity bit, and decodes in both modes.
+        let infinity = serialize(&Affine::<P>::zero(), compress);
+        let last = infinity.len() - 1;
+        assert_eq!(infinity[last] >> 6, 0b01);
+        for validate in [Validate::Yes, Validate::No] {
+            assert_eq!(deserialize(&infinity, compress, validate).unwrap(), Affine::<P>::zero());
+        }
+
+        // Ensure setting both flag bits is rejected in both modes.
+        let mut bytes = infinity.clone();
+        bytes[last] |= 1 << 7;
+        for validate in [Validate::Yes, Validate::No] {
+            assert!(deserialize(&bytes, compress, validate).is_err());
+        }
+
+        for _ in 0..ITERATIONS {
+            let point = Projective::<P>::rand(rng).to_affine();
+            let bytes = serialize(&point, compress);
+
+            // Ensure the sign bit is set iff `y` is the largest of `y` and `-y` (and is unset when uncompressed).
+            let sign = compress == Compress::Yes && point.y > -point.y;
+            assert_eq!(bytes[last] >> 6, if sign { 0b10 } else { 0b00 });
+
+            // Ensure the infinity bit with non-canonical coordinates is rejected in strict mode only.
+            let mut infinity = bytes.clone();
+            infinity[last] = (infinity[last] & 0b0011_1111) | (1 << 6);
+            assert!(deserialize(&infinity, compress, Validate::Yes).is_err());
+            assert_eq!(deserialize(&infinity, compress, Validate::No).unwrap(), Affine::<P>::zero());
+
+            // Ensure truncated encodings are rejected in both modes.
+            for length in 0..bytes.len() {
+                for validate in [Validate::Yes, Validate::No] {
+                    assert!(deserialize(&bytes[..length], compress, validate).is_err());
+                }
+            }
+        }
+
+        // Ensure non-canonical field elements are rejected in both modes.
+        let mut bytes = vec![u8::MAX; infinity.len()];
+        bytes[last] &= 0b0011_1111;
+        for validate in [Validate::Yes, Validate::No] {
+            assert!(deserialize(&bytes, compress, validate).is_err());
+        }
+    }
+
+    for _ in 0..ITERATIONS {
+        // Ensure flipping the sign bit of a compressed point decodes its negation.
+        let point = Projective::<P>::rand(rng).to_affine();
+        let mut bytes = serialize(&point, Compress::Yes);
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1 << 7;
+        assert_eq!(deserialize(&bytes, Compress::Yes, Validate::Yes).unwrap(), -point);
+
+        // Ensure an uncompressed point that is not on the curve is rejected in strict mode.
+        let invalid = Affine::<P>::new(point.x, point.y.double(), false);
+        assert!(deserialize(&serialize(&invalid, Compress::No), Compress::No, Validate::Yes).is_err());
+
+        // Ensure an x-coordinate that is not on the curve is rejected in both modes.
+        let x = core::iter::repeat_with(|| P::BaseField::rand(rng))
+            .find(|x| Affine::<P>::from_x_coordinate(*x, true).is_none())
+            .unwrap();
+        let mut bytes = serialize(&point, Compress::Yes);
+        x.serialize_with_flags(&mut bytes[..], SWFlags::default()).unwrap();
+        for validate in [Validate::Yes, Validate::No] {
+            assert!(deserialize(&bytes, Compress::Yes, validate).is_err());
+        }
+
+        // Ensure a point outside of the prime order subgroup is rejected in strict mode only.
+        let point = core::iter::repeat_with(|| Affine::<P>::from_x_coordinate(P::BaseField::rand(rng), true))
+            .flatten()
+            .find(|point| !point.is_in_correct_subgroup_assuming_on_curve())
+            .unwrap();
+        for compress in [Compress::Yes, Compress::No] {
+            let bytes = serialize(&point, compress);
+            assert!(deserialize(&bytes, compress, Validate::Yes).is_err());
+            assert_eq!(deserialize(&bytes, compress, Validate::No).unwrap(), point);
+        }
+    }
 }
 
 pub fn sw_curve_serialization_test<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
@@ -49,6 +49,33 @@ impl<P: Parameters> Affine<P> {
     pub fn new(x: P::BaseField, y: P::BaseField, t: P::BaseField) -> Self {
         Self { x, y, t }
     }
+
+    /// Returns the Montgomery coordinates `(u, v)` of this point, under the birational map
+    /// `u = (1 + y) / (1 - y)` and `v = u / x`.
+    ///
+    /// Returns `None` if the point is exceptional under the map (i.e. `x = 0` or `y = 1`).
+    pub fn to_montgomery(&self) -> Option<(P::BaseField, P::BaseField)> {
+        let one = P::BaseField::one();
+        let u = (one + self.y) * (one - self.y).inverse()?;
+        let v = u * self.x.inverse()?;
+        Some((u, v))
+    }
+
+    /// Initializes a new affine group element from the given Montgomery coordinates `(u, v)`,
+    /// under the birational map `x = u / v` and `y = (u - 1) / (u + 1)`.
+    ///
+    /// Returns `None` if the point is exceptional under the map (i.e. `v = 0` or `u = -1`), or is not on the curve.
+    /// Note: The resulting point is **not** enforced to be in the correct subgroup.
+    pub fn from_montgomery(u: P::BaseField, v: P::BaseField) -> Option<Self> {
+        let one = P::BaseField::one();
+        let x = u * v.inverse()?;
+        let y = (u - one) * (u + one).inverse()?;
+        let point = Self::new(x, y, x * y);
+        match point.is_on_curve() {
+            true => Some(point),
+            false => None,
+        }
+    }
 }
 
 impl<P: Parameters> Zero for Affine<P> {
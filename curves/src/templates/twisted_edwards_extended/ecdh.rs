@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    templates::twisted_edwards_extended::Affine,
+    traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
+};
+use snarkvm_fields::Zero;
+
+/// Returns the Diffie-Hellman public key `private_key * G`, where `G` is the prime subgroup generator.
+pub fn ecdh_public_key<P: Parameters>(private_key: &P::ScalarField) -> Affine<P> {
+    (Affine::<P>::prime_subgroup_generator() * *private_key).to_affine()
+}
+
+/// Returns the X25519-style shared secret for the given private key and counterparty public key,
+/// which is the Montgomery `u`-coordinate of `private_key * public_key`.
+///
+/// Returns `None` if the private key is zero, or if the public key is the identity, is not on the curve,
+/// or is not in the prime order subgroup, so that a malicious public key can not force a low-order shared secret.
+pub fn ecdh_shared_secret<P: Parameters>(private_key: &P::ScalarField, public_key: &Affine<P>) -> Option<P::BaseField> {
+    // Ensure the private key is non-zero.
+    if private_key.is_zero() {
+        return None;
+    }
+    // Ensure the public key is a non-identity point in the prime order subgroup.
+    if public_key.is_zero() || !public_key.is_on_curve() || !public_key.is_in_correct_subgroup_assuming_on_curve() {
+        return None;
+    }
+    // Compute the shared point, which is a non-identity point in the prime order subgroup.
+    let shared_point = (*public_key * *private_key).to_affine();
+    // Return the Montgomery `u`-coordinate of the shared point.
+    shared_point.to_montgomery().map(|(u, _)| u)
+}
@@ -15,6 +15,9 @@
 pub mod affine;
 pub use affine::*;
 
+pub mod ecdh;
+pub use ecdh::*;
+
 pub mod projective;
 pub use projective::*;
 
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{Affine, Projective};
+use super::{ecdh_public_key, ecdh_shared_secret, Affine, Projective};
 
 use snarkvm_utilities::{
     io::Cursor,
@@ -51,6 +51,104 @@ where
     edwards_curve_serialization_test::<P>(rng);
     edwards_from_random_bytes::<P>(rng);
     edwards_from_x_and_y_coordinates::<P>(rng);
+    edwards_montgomery_map_test::<P>(rng);
+    edwards_ecdh_test::<P>(rng);
+    edwards_strict_parsing_test::<P>(rng);
+}
+
+pub fn edwards_strict_parsing_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    let serialize = |point: &Affine<P>| {
+        let mut bytes = Vec::new();
+        point.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    };
+    let deserialize = |bytes: &[u8], validate| Affine::<P>::deserialize_with_mode(bytes, Compress::Yes, validate);
+
+    // Ensure the identity is encoded with the sign bit unset, and decodes in both modes.
+    let identity = serialize(&Affine::<P>::zero());
+    let last = identity.len() - 1;
+    assert_eq!(identity[last] >> 7, 0);
+    for validate in [Validate::Yes, Validate::No] {
+        assert_eq!(deserialize(&identity, validate).unwrap(), Affine::<P>::zero());
+    }
+
+    // Ensure the identity with the sign bit set is rejected in strict mode only.
+    let mut bytes = identity.clone();
+    bytes[last] |= 1 << 7;
+    assert!(deserialize(&bytes, Validate::Yes).is_err());
+    assert_eq!(deserialize(&bytes, Validate::No).unwrap(), Affine::<P>::zero());
+
+    // Ensure non-canonical field elements are rejected in both modes.
+    let mut bytes = vec![u8::MAX; identity.len()];
+    bytes[last] &= 0b0111_1111;
+    for validate in [Validate::Yes, Validate::No] {
+        assert!(deserialize(&bytes, validate).is_err());
+    }
+
+    for _ in 0..ITERATIONS {
+        let point = Projective::<P>::rand(rng).to_affine();
+        let bytes = serialize(&point);
+
+        // Ensure the sign bit is set iff `y` is the largest of `y` and `-y`.
+        assert_eq!(bytes[last] >> 7 == 1, point.y > -point.y);
+
+        // Ensure flipping the sign bit decodes the point with the other `y`, which is outside of the prime order subgroup.
+        let mut flipped = bytes.clone();
+        flipped[last] ^= 1 << 7;
+        assert!(deserialize(&flipped, Validate::Yes).is_err());
+        let other = deserialize(&flipped, Validate::No).unwrap();
+        assert_eq!((other.x, other.y), (point.x, -point.y));
+
+        // Ensure truncated encodings are rejected in both modes.
+        for length in 0..bytes.len() {
+            for validate in [Validate::Yes, Validate::No] {
+                assert!(deserialize(&bytes[..length], validate).is_err());
+            }
+        }
+    }
+}
+
+pub fn edwards_montgomery_map_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    let a = P::MontgomeryParameters::MONTGOMERY_A;
+    let b = P::MontgomeryParameters::MONTGOMERY_B;
+
+    for _ in 0..ITERATIONS {
+        let point = Projective::<P>::rand(rng).to_affine();
+        let (u, v) = point.to_montgomery().unwrap();
+
+        // Ensure (u, v) satisfies B * v^2 == u^3 + A * u^2 + u.
+        assert_eq!(b * v.square(), u.square() * u + a * u.square() + u);
+        // Ensure the map round-trips.
+        assert_eq!(Affine::<P>::from_montgomery(u, v), Some(point));
+        // Ensure the inverse map rejects points that are off the curve.
+        assert!(Affine::<P>::from_montgomery(u, v + P::BaseField::one()).is_none());
+    }
+
+    // Ensure the exceptional points are rejected.
+    assert!(Affine::<P>::zero().to_montgomery().is_none());
+    assert!(Affine::<P>::from_montgomery(P::BaseField::one(), P::BaseField::zero()).is_none());
+    assert!(Affine::<P>::from_montgomery(-P::BaseField::one(), P::BaseField::one()).is_none());
+}
+
+pub fn edwards_ecdh_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let (alice, bob) = (P::ScalarField::rand(rng), P::ScalarField::rand(rng));
+        let (alice_public, bob_public) = (ecdh_public_key::<P>(&alice), ecdh_public_key::<P>(&bob));
+
+        // Ensure both parties derive the same shared secret.
+        let shared_secret = ecdh_shared_secret(&alice, &bob_public).unwrap();
+        assert_eq!(Some(shared_secret), ecdh_shared_secret(&bob, &alice_public));
+    }
+
+    // Ensure a zero private key and the identity public key are rejected.
+    let public_key = ecdh_public_key::<P>(&P::ScalarField::rand(rng));
+    assert!(ecdh_shared_secret(&P::ScalarField::zero(), &public_key).is_none());
+    assert!(ecdh_shared_secret(&P::ScalarField::rand(rng), &Affine::<P>::zero()).is_none());
+
+    // Ensure a public key outside of the prime order subgroup is rejected.
+    let low_order = Affine::<P>::new(P::BaseField::zero(), -P::BaseField::one(), P::BaseField::zero());
+    assert!(low_order.is_on_curve());
+    assert!(ecdh_shared_secret(&P::ScalarField::rand(rng), &low_order).is_none());
 }
 
 pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>(rng: &mut TestRng) {
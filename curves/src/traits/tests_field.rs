@@ -241,6 +241,22 @@ fn random_sqrt_tests<F: SquareRootField>(rng: &mut TestRng) {
     }
 }
 
+fn batch_sqrt_tests<F: SquareRootField>(rng: &mut TestRng) {
+    let mut elements = (0..ITERATIONS).map(|_| F::rand(rng)).collect::<Vec<_>>();
+    elements.push(F::zero());
+
+    // Ensure the batch operations match the individual operations.
+    let legendres = F::batch_legendre(&elements);
+    let sqrts = F::batch_sqrt(&elements);
+    assert_eq!(legendres.len(), elements.len());
+    assert_eq!(sqrts.len(), elements.len());
+    for ((element, legendre), sqrt) in elements.iter().zip(legendres).zip(sqrts) {
+        assert_eq!(legendre, element.legendre());
+        assert_eq!(sqrt, element.sqrt());
+        assert_eq!(sqrt.is_some(), !legendre.is_qnr());
+    }
+}
+
 pub fn random_sqrt_tonelli_tests<F: PrimeField + SquareRootField>(rng: &mut TestRng) {
     // Randomly check field elements on the `sqrt` operation.
     for _ in 0..10_000 {
@@ -579,6 +595,7 @@ pub fn sqrt_field_test<F: SquareRootField>(elem: F, rng: &mut TestRng) {
         assert!(sqrt.square() == elem);
     }
     random_sqrt_tests::<F>(rng);
+    batch_sqrt_tests::<F>(rng);
 }
 
 pub fn frobenius_test<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: usize, rng: &mut TestRng) {
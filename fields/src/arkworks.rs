@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    FieldError,
+    FieldParameters,
+    Fp2,
+    Fp256,
+    Fp256Parameters,
+    Fp2Parameters,
+    Fp384,
+    Fp384Parameters,
+    PrimeField,
+};
+
+use ark_ff::{BigInt, Fp2Config, MontBackend, MontConfig, PrimeField as _};
+
+macro_rules! impl_arkworks_prime_field {
+    ($field: ident, $params: ident, $limbs: expr) => {
+        impl<P: $params, C: MontConfig<$limbs>> TryFrom<$field<P>> for ark_ff::Fp<MontBackend<C, $limbs>, $limbs> {
+            type Error = FieldError;
+
+            /// Converts a snarkVM field element into the arkworks field element with the same modulus.
+            fn try_from(element: $field<P>) -> Result<Self, Self::Error> {
+                // Ensure the moduli match.
+                if <P as FieldParameters>::MODULUS.0 != C::MODULUS.0 {
+                    return Err(FieldError::Message("Mismatched moduli in the arkworks conversion".to_string()));
+                }
+                Self::from_bigint(BigInt(element.to_bigint().0)).ok_or(FieldError::InvalidFieldElement)
+            }
+        }
+
+        impl<P: $params, C: MontConfig<$limbs>> TryFrom<ark_ff::Fp<MontBackend<C, $limbs>, $limbs>> for $field<P> {
+            type Error = FieldError;
+
+            /// Converts an arkworks field element into the snarkVM field element with the same modulus.
+            fn try_from(element: ark_ff::Fp<MontBackend<C, $limbs>, $limbs>) -> Result<Self, Self::Error> {
+                // Ensure the moduli match.
+                if <P as FieldParameters>::MODULUS.0 != C::MODULUS.0 {
+                    return Err(FieldError::Message("Mismatched moduli in the arkworks conversion".to_string()));
+                }
+                Self::from_bigint(P::BigInteger::new(element.into_bigint().0)).ok_or(FieldError::InvalidFieldElement)
+            }
+        }
+    };
+}
+
+impl_arkworks_prime_field!(Fp256, Fp256Parameters, 4);
+impl_arkworks_prime_field!(Fp384, Fp384Parameters, 6);
+
+impl<P: Fp2Parameters, C: Fp2Config> TryFrom<Fp2<P>> for ark_ff::Fp2<C>
+where
+    C::Fp: TryFrom<P::Fp, Error = FieldError>,
+{
+    type Error = FieldError;
+
+    /// Converts a snarkVM quadratic extension field element into the arkworks one with the same non-residue.
+    fn try_from(element: Fp2<P>) -> Result<Self, Self::Error> {
+        // Ensure the quadratic non-residues match.
+        if C::Fp::try_from(P::NONRESIDUE)? != C::NONRESIDUE {
+            return Err(FieldError::Message("Mismatched non-residues in the arkworks conversion".to_string()));
+        }
+        Ok(Self::new(element.c0.try_into()?, element.c1.try_into()?))
+    }
+}
+
+impl<P: Fp2Parameters, C: Fp2Config> TryFrom<ark_ff::Fp2<C>> for Fp2<P>
+where
+    P::Fp: TryFrom<C::Fp, Error = FieldError>,
+{
+    type Error = FieldError;
+
+    /// Converts an arkworks quadratic extension field element into the snarkVM one with the same non-residue.
+    fn try_from(element: ark_ff::Fp2<C>) -> Result<Self, Self::Error> {
+        // Ensure the quadratic non-residues match.
+        if P::Fp::try_from(C::NONRESIDUE)? != P::NONRESIDUE {
+            return Err(FieldError::Message("Mismatched non-residues in the arkworks conversion".to_string()));
+        }
+        Ok(Self::new(element.c0.try_into()?, element.c1.try_into()?))
+    }
+}
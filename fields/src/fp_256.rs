@@ -532,7 +532,10 @@ impl<P: Fp256Parameters> SquareRootField for Fp256<P> {
 
     #[inline]
     fn sqrt(&self) -> Option<Self> {
-        sqrt_impl!(Self, P, self)
+        match P::TWO_ADICITY < 64 {
+            true => crate::sqrt_table::table_sqrt(self),
+            false => sqrt_impl!(Self, P, self),
+        }
     }
 
     fn sqrt_in_place(&mut self) -> Option<&mut Self> {
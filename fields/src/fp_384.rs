@@ -549,7 +549,10 @@ impl<P: Fp384Parameters> SquareRootField for Fp384<P> {
 
     #[inline]
     fn sqrt(&self) -> Option<Self> {
-        sqrt_impl!(Self, P, self)
+        match P::TWO_ADICITY < 64 {
+            true => crate::sqrt_table::table_sqrt(self),
+            false => sqrt_impl!(Self, P, self),
+        }
     }
 
     fn sqrt_in_place(&mut self) -> Option<&mut Self> {
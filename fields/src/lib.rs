@@ -21,6 +21,9 @@ extern crate thiserror;
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "arkworks-compat")]
+mod arkworks;
+
 pub mod errors;
 pub use errors::*;
 
@@ -41,6 +44,8 @@ pub use fp12_2over3over2::*;
 mod legendre;
 pub use legendre::*;
 
+mod sqrt_table;
+
 mod to_field_vec;
 pub use to_field_vec::*;
 
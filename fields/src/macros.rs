@@ -85,15 +85,27 @@ macro_rules! sqrt_impl {
     ($Self:ident, $P:tt, $self:expr) => {{
         use crate::LegendreSymbol::*;
         // https://eprint.iacr.org/2020/1407.pdf (page 4, algorithm 1)
-        match $self.legendre() {
+        let n = $P::TWO_ADICITY as u64;
+        // `T` is equivalent to `m` in the paper.
+        let v = $self.pow($P::T_MINUS_ONE_DIV_TWO);
+        let x = *$self * v.square();
+
+        // As `x = self^T` and `MODULUS - 1 = 2^n * T`, the Legendre symbol is `x^(2^(n - 1))`,
+        // which saves a full exponentiation over calling `legendre` first.
+        let legendre = if $self.is_zero() {
+            Zero
+        } else {
+            let mut s = x;
+            (1..n).for_each(|_| {
+                s.square_in_place();
+            });
+            if s.is_one() { QuadraticResidue } else { QuadraticNonResidue }
+        };
+
+        match legendre {
             Zero => Some(*$self),
             QuadraticNonResidue => None,
             QuadraticResidue => {
-                let n = $P::TWO_ADICITY as u64;
-                // `T` is equivalent to `m` in the paper.
-                let v = $self.pow($P::T_MINUS_ONE_DIV_TWO);
-                let x = *$self * v.square();
-
                 let k = ((n - 1) as f64).sqrt().floor() as u64;
                 // It's important that k_2 results in a number which makes `l_minus_one_times_k`
                 // divisible by `k`, because the native arithmetic will not match the field
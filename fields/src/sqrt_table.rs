@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FftParameters, PrimeField};
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
+
+/// The maximum width, in bits, of a window of the discrete logarithm.
+const MAX_WINDOW_BITS: u32 = 8;
+
+/// The precomputed tables of a table-based Tonelli-Shanks square root.
+///
+/// For `MODULUS - 1 = 2^n * T`, the square root of `a` is `a^((T + 1) / 2) * g^(-e / 2)`,
+/// where `g` is the 2-adic root of unity and `e` is the discrete logarithm of `a^T` to the base `g`.
+/// The tables find `e` in windows of `w` bits, with one lookup per window instead of a loop of
+/// squarings per bit (see Bernstein, "Faster square roots in annoying finite fields").
+struct SqrtTables<F: PrimeField> {
+    /// The 2-adicity `n`.
+    two_adicity: u32,
+    /// The window width `w`.
+    window: u32,
+    /// The number of windows `m = ceil(n / w)`.
+    num_windows: u32,
+    /// The map of `h^j` to `j` for `j < 2^w`, where `h = g^(2^(n - w))` has order `2^w`.
+    dlog: HashMap<F, u64>,
+    /// The powers `low[i][j] = g^(-j * 2^(i * w))`, for `i < m`.
+    low: Vec<Vec<F>>,
+    /// The powers `high[d - 1][j] = g^(-j * 2^(n - d * w))`, for `0 < d < m`.
+    high: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> SqrtTables<F> {
+    /// Initializes the tables for `F`.
+    fn new() -> Self {
+        let n = F::FftParameters::TWO_ADICITY;
+        let w = n.min(MAX_WINDOW_BITS);
+        let m = (n + w - 1) / w;
+
+        // Returns `[1, base, base^2, ..., base^(2^w - 1)]`.
+        let powers = |base: F| -> Vec<F> {
+            std::iter::successors(Some(F::one()), |power| Some(*power * base)).take(1 << w).collect()
+        };
+        // Returns `base^(2^k)`.
+        let square_times = |mut base: F, k: u32| -> F {
+            (0..k).for_each(|_| {
+                base.square_in_place();
+            });
+            base
+        };
+
+        let g = F::two_adic_root_of_unity();
+        // The 2-adic root of unity is never zero.
+        let g_inv = g.inverse().unwrap_or_else(F::zero);
+
+        let dlog = powers(square_times(g, n - w)).into_iter().zip(0u64..).collect();
+        let low = (0..m).map(|i| powers(square_times(g_inv, i * w))).collect();
+        let high = (1..m).map(|d| powers(square_times(g_inv, n - d * w))).collect();
+
+        Self { two_adicity: n, window: w, num_windows: m, dlog, low, high }
+    }
+
+    /// Returns the tables for `F`, initializing them on first use.
+    fn get() -> &'static Self {
+        type Cache = RwLock<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>;
+        static CACHE: OnceLock<Cache> = OnceLock::new();
+
+        let cache = CACHE.get_or_init(Default::default);
+        let tables = match cache.read().ok().and_then(|cache| cache.get(&TypeId::of::<F>()).copied()) {
+            Some(tables) => tables,
+            None => {
+                let mut cache = cache.write().unwrap_or_else(|error| error.into_inner());
+                *cache
+                    .entry(TypeId::of::<F>())
+                    .or_insert_with(|| -> &'static (dyn Any + Send + Sync) { Box::leak(Box::new(Self::new())) })
+            }
+        };
+        // The cache only holds tables of type `SqrtTables<F>` under the type ID of `F`.
+        tables.downcast_ref().expect("Mismatching square root tables")
+    }
+
+    /// Returns the square root of `a`, if it exists.
+    fn sqrt(&self, a: &F) -> Option<F> {
+        if a.is_zero() {
+            return Some(F::zero());
+        }
+        let (n, w, m) = (self.two_adicity, self.window, self.num_windows);
+
+        // Compute `x = a^((T - 1) / 2)`, so that `b = a * x^2 = a^T` lies in the subgroup of order `2^n`.
+        let x = a.pow(F::trace_minus_one_div_two());
+        let b = *a * x.square();
+
+        // Compute `b^(2^(n - (i + 1) * w))` for every window `i` but the last, with one chain of squarings.
+        let (mut power, mut exponent) = (b, 0);
+        let mut b_powers = (0..m - 1)
+            .rev()
+            .map(|i| {
+                while exponent < n - (i + 1) * w {
+                    power.square_in_place();
+                    exponent += 1;
+                }
+                power
+            })
+            .collect::<Vec<_>>();
+        b_powers.reverse();
+
+        // Find the discrete logarithm `e` of `b`, one window at a time from the least significant bits.
+        // Raising `b * g^(-e mod 2^(i * w))` to `2^(n - (i + 1) * w)` leaves `h^(e_i)`, which is looked up.
+        let mut digits = Vec::with_capacity(m as usize);
+        for (i, b_power) in b_powers.iter().enumerate() {
+            let c = digits.iter().enumerate().fold(*b_power, |c, (j, e_j)| c * self.high[i - j][*e_j]);
+            digits.push(*self.dlog.get(&c)? as usize);
+        }
+        // The last window holds the remaining `n - (m - 1) * w` bits.
+        let c = digits.iter().enumerate().fold(b, |c, (j, e_j)| c * self.low[j][*e_j]);
+        digits.push((*self.dlog.get(&c)? >> (m * w - n)) as usize);
+        let e = digits.iter().rev().fold(0u64, |e, digit| (e << w) | *digit as u64);
+
+        // The element is a square if and only if `e` is even.
+        if e & 1 == 1 {
+            return None;
+        }
+        // Compute `a^((T + 1) / 2) * g^(-e / 2)`.
+        let mask = (1u64 << w) - 1;
+        let root = (0..m).fold(*a * x, |root, i| root * self.low[i as usize][((e >> 1 >> (i * w)) & mask) as usize]);
+        // Return the same root as `sqrt_impl`, which computes `g^(t / 2)` for `t = -e mod 2^n`.
+        match e == 0 {
+            true => Some(root),
+            false => Some(-root),
+        }
+    }
+}
+
+/// Returns the square root of `a`, if it exists, using precomputed tables for `F`.
+///
+/// Note: The tables are computed on the first call for each field, and require a 2-adicity below 64.
+pub(crate) fn table_sqrt<F: PrimeField>(a: &F) -> Option<F> {
+    SqrtTables::<F>::get().sqrt(a)
+}
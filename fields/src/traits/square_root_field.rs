@@ -13,6 +13,10 @@
 // limitations under the License.
 
 use crate::{Field, LegendreSymbol};
+use snarkvm_utilities::cfg_iter;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 
 /// The interface for a field that supports an efficient square-root operation.
 pub trait SquareRootField: Field {
@@ -25,4 +29,14 @@ pub trait SquareRootField: Field {
 
     /// Sets `self` to be the square root of `self`, if it exists.
     fn sqrt_in_place(&mut self) -> Option<&mut Self>;
+
+    /// Returns the Legendre symbol of each of the given elements.
+    fn batch_legendre(elements: &[Self]) -> Vec<LegendreSymbol> {
+        cfg_iter!(elements).map(|element| element.legendre()).collect()
+    }
+
+    /// Returns the square root of each of the given elements, if it exists.
+    fn batch_sqrt(elements: &[Self]) -> Vec<Option<Self>> {
+        cfg_iter!(elements).map(|element| element.sqrt()).collect()
+    }
 }
@@ -88,7 +88,7 @@ fn block_serialization(c: &mut Criterion) {
 }
 
 fn block_header_serialization(c: &mut Criterion) {
-    let header = *load_genesis_block().header();
+    let header = load_genesis_block().header().clone();
     bench_serialization(c, "Header", header);
 }
 
@@ -17,48 +17,43 @@ use super::*;
 impl<N: Network> FromBytes for Block<N> {
     /// Reads the block from the buffer.
     #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let mut reader = ByteReader::new(reader);
+
         // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid block version"));
-        }
+        reader.read_version(1, "block")?;
 
         // Read the block hash.
-        let block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
+        let block_hash: N::BlockHash = reader.read()?;
         // Read the previous block hash.
-        let previous_hash = FromBytes::read_le(&mut reader)?;
+        let previous_hash = reader.read()?;
         // Read the header.
-        let header = FromBytes::read_le(&mut reader)?;
+        let header = reader.read()?;
 
         // Write the authority.
-        let authority = FromBytes::read_le(&mut reader)?;
+        let authority = reader.read()?;
 
         // Read the number of ratifications.
-        let ratifications = Ratifications::read_le(&mut reader)?;
+        let ratifications = reader.read::<Ratifications<N>>()?;
 
         // Read the solutions.
-        let solutions_variant = u8::read_le(&mut reader)?;
-        let solutions = match solutions_variant {
+        let solutions = match reader.read_u8()? {
             0 => None,
-            1 => Some(FromBytes::read_le(&mut reader)?),
-            _ => return Err(error("Invalid solutions variant in the block")),
+            1 => Some(reader.read()?),
+            _ => return Err(reader.error("Invalid solutions variant in the block")),
         };
 
         // Read the transactions.
-        let transactions = FromBytes::read_le(&mut reader)?;
+        let transactions = reader.read()?;
 
         // Read the number of aborted transaction IDs.
-        let num_aborted = u32::read_le(&mut reader)?;
-        // Ensure the number of aborted transaction IDs is within bounds (this is an early safety check).
-        if num_aborted as usize > Transactions::<N>::MAX_ABORTED_TRANSACTIONS {
-            return Err(error("Invalid number of aborted transaction IDs in the block"));
-        }
+        // Note: The number is bounded, as an early safety check.
+        let num_aborted =
+            reader.read_length_u32(Transactions::<N>::MAX_ABORTED_TRANSACTIONS, "aborted transaction IDs")?;
         // Read the aborted transaction IDs.
-        let mut aborted_transaction_ids = Vec::with_capacity(num_aborted as usize);
+        let mut aborted_transaction_ids = Vec::with_capacity(num_aborted);
         for _ in 0..num_aborted {
-            aborted_transaction_ids.push(FromBytes::read_le(&mut reader)?);
+            aborted_transaction_ids.push(reader.read()?);
         }
 
         // Construct the block.
@@ -71,12 +66,12 @@ impl<N: Network> FromBytes for Block<N> {
             transactions,
             aborted_transaction_ids,
         )
-        .map_err(error)?;
+        .map_err(|e| reader.error(e))?;
 
         // Ensure the block hash matches.
         match block_hash == block.hash() {
             true => Ok(block),
-            false => Err(error("Mismatching block hash, possible data corruption")),
+            false => Err(reader.error("Mismatching block hash, possible data corruption")),
         }
     }
 }
@@ -149,4 +144,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_error_position() -> Result<()> {
+        let block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+        let block_bytes = block.to_bytes_le()?;
+        let position = |bytes: &[u8]| {
+            let error = Block::<CurrentNetwork>::read_le(bytes).unwrap_err();
+            ByteError::from_io_error(&error).map(|error| error.position())
+        };
+
+        // Ensure an invalid version is reported at the start.
+        let mut bytes = block_bytes.clone();
+        bytes[0] = 2;
+        assert_eq!(position(&bytes), Some(0));
+
+        // Ensure a corrupt transactions version is reported at its position.
+        // Note: The block ends with its transactions, followed by the (empty) list of aborted transaction IDs.
+        let offset = block_bytes.len() - block.transactions().to_bytes_le()?.len() - 4;
+        let mut bytes = block_bytes.clone();
+        bytes[offset] = 2;
+        assert_eq!(position(&bytes), Some(offset as u64));
+
+        // Ensure a truncated block is reported at the start of the missing value.
+        assert_eq!(position(&block_bytes[..block_bytes.len() - 1]), Some(block_bytes.len() as u64 - 4));
+        Ok(())
+    }
 }
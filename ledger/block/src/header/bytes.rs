@@ -21,7 +21,7 @@ impl<N: Network> FromBytes for Header<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid header version"));
         }
 
@@ -34,8 +34,24 @@ impl<N: Network> FromBytes for Header<N> {
         let subdag_root = Field::<N>::read_le(&mut reader)?;
         let metadata = Metadata::read_le(&mut reader)?;
 
+        // Read the extensions, which are only present from version 2.
+        let extensions = match version {
+            1 => vec![],
+            _ => {
+                // Read the number of extensions.
+                let num_extensions = usize::try_from(u64::from(VarInt::read_le(&mut reader)?)).map_err(error)?;
+                // Ensure the number of extensions is within bounds. Note that version 2 requires at least one extension,
+                // so that a header without extensions has a single (version 1) byte representation.
+                if num_extensions == 0 || num_extensions > Self::MAX_EXTENSIONS {
+                    return Err(error(format!("Invalid number of header extensions ({num_extensions})")));
+                }
+                // Read the extensions. As each extension is length-prefixed, unknown extensions are read without being interpreted.
+                (0..num_extensions).map(|_| HeaderExtension::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?
+            }
+        };
+
         // Construct the block header.
-        Self::from(
+        Self::from_with_extensions(
             previous_state_root,
             transactions_root,
             finalize_root,
@@ -43,6 +59,7 @@ impl<N: Network> FromBytes for Header<N> {
             solutions_root,
             subdag_root,
             metadata,
+            extensions,
         )
         .map_err(|e| error(e.to_string()))
     }
@@ -52,8 +69,12 @@ impl<N: Network> ToBytes for Header<N> {
     /// Writes the block header to the buffer.
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Write the version. Note that headers without extensions retain the version 1 layout.
+        let version = match self.extensions.is_empty() {
+            true => 1u8,
+            false => 2u8,
+        };
+        version.write_le(&mut writer)?;
 
         // Write to the buffer.
         self.previous_state_root.write_le(&mut writer)?;
@@ -62,7 +83,14 @@ impl<N: Network> ToBytes for Header<N> {
         self.ratifications_root.write_le(&mut writer)?;
         self.solutions_root.write_le(&mut writer)?;
         self.subdag_root.write_le(&mut writer)?;
-        self.metadata.write_le(&mut writer)
+        self.metadata.write_le(&mut writer)?;
+
+        // Write the extensions, which are only present from version 2.
+        if version == 2 {
+            VarInt(self.extensions.len() as u64).write_le(&mut writer)?;
+            self.extensions.write_le(&mut writer)?;
+        }
+        Ok(())
     }
 }
 
@@ -77,7 +105,12 @@ mod tests {
     fn test_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
 
-        for expected in [crate::header::test_helpers::sample_block_header(rng)].into_iter() {
+        for expected in [
+            crate::header::test_helpers::sample_block_header(rng),
+            crate::header::test_helpers::sample_block_header_with_extensions(rng),
+        ]
+        .into_iter()
+        {
             // Check the byte representation.
             let expected_bytes = expected.to_bytes_le()?;
             assert_eq!(expected, Header::read_le(&expected_bytes[..])?);
@@ -85,4 +118,30 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_versions() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure a header without extensions is written in the version 1 layout.
+        let header = crate::header::test_helpers::sample_block_header(rng);
+        let bytes = header.to_bytes_le()?;
+        assert_eq!(bytes[0], 1);
+
+        // Ensure a version 2 header without extensions is rejected.
+        let mut bytes_v2 = bytes.clone();
+        bytes_v2[0] = 2;
+        bytes_v2.extend_from_slice(&VarInt(0).to_bytes_le()?);
+        assert!(Header::<CurrentNetwork>::read_le(&bytes_v2[..]).is_err());
+
+        // Ensure a version 2 header with an unknown extension is parsed, and the extension is retained.
+        let mut bytes_v2 = bytes;
+        bytes_v2[0] = 2;
+        bytes_v2.extend_from_slice(&VarInt(1).to_bytes_le()?);
+        bytes_v2.extend_from_slice(&HeaderExtension::new(u16::MAX, vec![1, 2, 3])?.to_bytes_le()?);
+        let candidate = Header::<CurrentNetwork>::read_le(&bytes_v2[..])?;
+        assert_eq!(candidate.extension(u16::MAX), Some(&[1u8, 2, 3][..]));
+        assert_eq!(candidate.to_bytes_le()?, bytes_v2);
+        Ok(())
+    }
 }
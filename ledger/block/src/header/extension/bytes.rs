@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl FromBytes for HeaderExtension {
+    /// Reads the header extension from the buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the ID.
+        let id = u16::read_le(&mut reader)?;
+        // Read the data length.
+        let data_length = usize::try_from(u64::from(VarInt::read_le(&mut reader)?)).map_err(error)?;
+        // Ensure the data length does not exceed the maximum size.
+        if data_length > Self::MAX_DATA_SIZE {
+            return Err(error(format!("Header extension {id} exceeds the maximum of {} bytes", Self::MAX_DATA_SIZE)));
+        }
+        // Read the data.
+        let mut data = vec![0u8; data_length];
+        reader.read_exact(&mut data)?;
+        // Return the extension.
+        Self::new(id, data).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl ToBytes for HeaderExtension {
+    /// Writes the header extension to the buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the ID.
+        self.id.write_le(&mut writer)?;
+        // Write the data length.
+        VarInt(self.data.len() as u64).write_le(&mut writer)?;
+        // Write the data.
+        writer.write_all(&self.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::header::extension::test_helpers::sample_header_extensions(rng) {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, HeaderExtension::read_le(&expected_bytes[..])?);
+            assert!(HeaderExtension::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::network::prelude::*;
+
+/// A header extension is an opaque, length-prefixed entry in the extension area of a block header.
+///
+/// Extensions are committed by the header root, but are otherwise uninterpreted by this version of the ledger,
+/// which allows future consensus features to attach data to a header without breaking its byte layout.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HeaderExtension {
+    /// The ID of the extension.
+    id: u16,
+    /// The data of the extension.
+    data: Vec<u8>,
+}
+
+impl HeaderExtension {
+    /// The maximum number of bytes in the data of an extension.
+    pub const MAX_DATA_SIZE: usize = 1024;
+
+    /// Initializes a new header extension with the given ID and data.
+    pub fn new(id: u16, data: Vec<u8>) -> Result<Self> {
+        // Ensure the data does not exceed the maximum size.
+        ensure!(
+            data.len() <= Self::MAX_DATA_SIZE,
+            "Header extension {id} contains {} bytes, exceeding the maximum of {} bytes",
+            data.len(),
+            Self::MAX_DATA_SIZE
+        );
+        // Return the extension.
+        Ok(Self { id, data })
+    }
+
+    /// Returns the ID of the extension.
+    pub const fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// Returns the data of the extension.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use super::*;
+
+    /// Samples a list of header extensions, sorted by ID.
+    pub(crate) fn sample_header_extensions(rng: &mut TestRng) -> Vec<HeaderExtension> {
+        (0..3u16)
+            .map(|index| {
+                let data = (0..rng.gen_range(0..64)).map(|_| rng.gen()).collect();
+                HeaderExtension::new(index * 10 + rng.gen_range(0..10), data).unwrap()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(HeaderExtension::new(0, vec![]).is_ok());
+        assert!(HeaderExtension::new(1, vec![0u8; HeaderExtension::MAX_DATA_SIZE]).is_ok());
+        assert!(HeaderExtension::new(2, vec![0u8; HeaderExtension::MAX_DATA_SIZE + 1]).is_err());
+    }
+}
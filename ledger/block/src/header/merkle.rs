@@ -20,6 +20,20 @@ impl<N: Network> Header<N> {
         Ok(*self.to_tree()?.root())
     }
 
+    /// Returns the extensions root, which is zero if the block header does not contain extensions.
+    pub fn to_extensions_root(&self) -> Result<Field<N>> {
+        // If there are no extensions, return zero, which preserves the reserved leaf of a header without extensions.
+        if self.extensions.is_empty() {
+            return Ok(Field::zero());
+        }
+        // Pack the extensions into field elements.
+        let bits = self.extensions.to_bytes_le()?.to_bits_le();
+        let fields =
+            bits.chunks(Field::<N>::size_in_data_bits()).map(Field::from_bits_le).collect::<Result<Vec<_>>>()?;
+        // Hash the extensions.
+        N::hash_psd8(&fields)
+    }
+
     /// Returns the Merkle path for the Merkle tree of the block header.
     pub fn to_path(&self, leaf: &HeaderLeaf<N>) -> Result<HeaderPath<N>> {
         // Compute the Merkle path.
@@ -52,6 +66,10 @@ impl<N: Network> Header<N> {
         else if id == &self.subdag_root {
             Ok(HeaderLeaf::<N>::new(5, self.subdag_root))
         }
+        // If the ID is the extensions root, then return the 6th leaf.
+        else if !self.extensions.is_empty() && id == &self.to_extensions_root()? {
+            Ok(HeaderLeaf::<N>::new(6, *id))
+        }
         // If the ID is the metadata hash, then return the 7th leaf.
         else if id == &self.metadata.to_hash()? {
             Ok(HeaderLeaf::<N>::new(7, *id))
@@ -75,7 +93,7 @@ impl<N: Network> Header<N> {
         leaves.push(HeaderLeaf::<N>::new(3, self.ratifications_root).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(4, self.solutions_root).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(5, self.subdag_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(6, Field::zero()).to_bits_le());
+        leaves.push(HeaderLeaf::<N>::new(6, self.to_extensions_root()?).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(7, self.metadata.to_hash()?).to_bits_le());
 
         // Ensure the correct number of leaves are allocated.
@@ -176,4 +194,62 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_merkle_with_extensions() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let coinbase_target = u64::rand(rng);
+            let proof_target = rng.gen_range(0..coinbase_target);
+
+            let metadata = Metadata::new(
+                CurrentNetwork::ID,
+                u64::rand(rng),
+                u32::rand(rng),
+                u128::rand(rng),
+                u128::rand(rng),
+                coinbase_target,
+                proof_target,
+                u64::rand(rng),
+                rng.gen_range(0..i64::MAX),
+                rng.gen_range(0..i64::MAX),
+            )?;
+            let roots: [Field<CurrentNetwork>; 6] = [(); 6].map(|_| Field::rand(rng));
+            let extensions = crate::header::extension::test_helpers::sample_header_extensions(rng);
+
+            let header = Header::<CurrentNetwork>::from(
+                roots[0].into(),
+                roots[1],
+                roots[2],
+                roots[3],
+                roots[4],
+                roots[5],
+                metadata,
+            )?;
+            let extended = Header::<CurrentNetwork>::from_with_extensions(
+                roots[0].into(),
+                roots[1],
+                roots[2],
+                roots[3],
+                roots[4],
+                roots[5],
+                metadata,
+                extensions,
+            )?;
+
+            // Ensure a header without extensions commits to zero in the reserved leaf.
+            assert_eq!(header.to_extensions_root()?, Field::zero());
+            // Ensure the extensions are committed by the header root.
+            assert_ne!(header.to_root()?, extended.to_root()?);
+
+            // Check the 6th leaf.
+            let root = extended.to_root()?;
+            let leaf = extended.to_leaf(&extended.to_extensions_root()?)?;
+            assert_eq!(leaf.index(), 6);
+            check_path(extended.to_path(&leaf)?, root, &leaf)?;
+        }
+
+        Ok(())
+    }
 }
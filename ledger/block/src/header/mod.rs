@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod extension;
+pub use extension::*;
+
 mod metadata;
 pub use metadata::*;
 
@@ -22,7 +25,7 @@ mod serialize;
 mod string;
 mod verify;
 
-use crate::{Ratifications, Transactions};
+use crate::{ChainWork, Ratifications, Transactions};
 use console::{
     network::prelude::*,
     program::{HeaderLeaf, HeaderPath, HeaderTree, HEADER_DEPTH},
@@ -31,7 +34,7 @@ use console::{
 use synthesizer_program::FinalizeOperation;
 
 /// The header for the block contains metadata that uniquely identifies the block.
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Header<N: Network> {
     /// The Merkle root representing the blocks in the ledger up to the previous block.
     previous_state_root: N::StateRoot,
@@ -47,9 +50,23 @@ pub struct Header<N: Network> {
     subdag_root: Field<N>,
     /// The metadata of the block.
     metadata: Metadata<N>,
+    /// The extensions of the block, sorted by ID.
+    extensions: Vec<HeaderExtension>,
 }
 
 impl<N: Network> Header<N> {
+    /// The maximum number of extensions in a block header.
+    pub const MAX_EXTENSIONS: usize = 16;
+    /// The ID of the header extension that commits to the receipts root.
+    pub const RECEIPTS_ROOT_EXTENSION_ID: u16 = 0;
+    /// The block height from which block headers must commit to the receipts root.
+    #[cfg(not(any(test, feature = "test")))]
+    pub const RECEIPTS_ROOT_HEIGHT: u32 = N::RECEIPTS_ROOT_HEIGHT;
+    /// The block height from which block headers must commit to the receipts root.
+    /// This is set to a deliberately low value (2) for testing purposes only.
+    #[cfg(any(test, feature = "test"))]
+    pub const RECEIPTS_ROOT_HEIGHT: u32 = 2;
+
     /// Initializes a new block header with the given inputs.
     pub fn from(
         previous_state_root: N::StateRoot,
@@ -59,6 +76,30 @@ impl<N: Network> Header<N> {
         solutions_root: Field<N>,
         subdag_root: Field<N>,
         metadata: Metadata<N>,
+    ) -> Result<Self> {
+        Self::from_with_extensions(
+            previous_state_root,
+            transactions_root,
+            finalize_root,
+            ratifications_root,
+            solutions_root,
+            subdag_root,
+            metadata,
+            vec![],
+        )
+    }
+
+    /// Initializes a new block header with the given inputs and extensions.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_with_extensions(
+        previous_state_root: N::StateRoot,
+        transactions_root: Field<N>,
+        finalize_root: Field<N>,
+        ratifications_root: Field<N>,
+        solutions_root: Field<N>,
+        subdag_root: Field<N>,
+        metadata: Metadata<N>,
+        extensions: Vec<HeaderExtension>,
     ) -> Result<Self> {
         // Construct a new block header.
         let header = Self {
@@ -69,6 +110,7 @@ impl<N: Network> Header<N> {
             solutions_root,
             subdag_root,
             metadata,
+            extensions,
         };
         // Ensure the header is valid.
         match header.is_valid() {
@@ -79,6 +121,14 @@ impl<N: Network> Header<N> {
 
     /// Returns `true` if the block header is well-formed.
     pub fn is_valid(&self) -> bool {
+        // Ensure the number of extensions is within bounds.
+        if self.extensions.len() > Self::MAX_EXTENSIONS {
+            return false;
+        }
+        // Ensure the extensions are sorted by ID, without duplicates.
+        if !self.extensions.windows(2).all(|pair| pair[0].id() < pair[1].id()) {
+            return false;
+        }
         match self.height() == 0u32 {
             true => self.is_genesis(),
             false => {
@@ -131,6 +181,26 @@ impl<N: Network> Header<N> {
         &self.metadata
     }
 
+    /// Returns the extensions in the block header.
+    pub fn extensions(&self) -> &[HeaderExtension] {
+        &self.extensions
+    }
+
+    /// Returns the data of the extension with the given ID, if it exists in the block header.
+    pub fn extension(&self, id: u16) -> Option<&[u8]> {
+        self.extensions.binary_search_by_key(&id, |extension| extension.id()).ok().map(|i| self.extensions[i].data())
+    }
+
+    /// Returns the receipts root in the block header, if the block header commits to one.
+    pub fn receipts_root(&self) -> Result<Option<Field<N>>> {
+        self.extension(Self::RECEIPTS_ROOT_EXTENSION_ID).map(Field::from_bytes_le).transpose()
+    }
+
+    /// Returns the header extension that commits to the given receipts root.
+    pub fn receipts_root_extension(receipts_root: Field<N>) -> Result<HeaderExtension> {
+        HeaderExtension::new(Self::RECEIPTS_ROOT_EXTENSION_ID, receipts_root.to_bytes_le()?)
+    }
+
     /// Returns the network ID of the block.
     pub const fn network(&self) -> u16 {
         self.metadata.network()
@@ -151,6 +221,11 @@ impl<N: Network> Header<N> {
         self.metadata.cumulative_weight()
     }
 
+    /// Returns the chain work for this block.
+    pub const fn chain_work(&self) -> ChainWork {
+        ChainWork::new(self.cumulative_weight())
+    }
+
     /// Returns the cumulative proof target for this block.
     pub const fn cumulative_proof_target(&self) -> u128 {
         self.metadata.cumulative_proof_target()
@@ -190,6 +265,22 @@ pub mod test_helpers {
 
     /// Samples a block header.
     pub(crate) fn sample_block_header(rng: &mut TestRng) -> Header<CurrentNetwork> {
-        *crate::test_helpers::sample_genesis_block(rng).header()
+        crate::test_helpers::sample_genesis_block(rng).header().clone()
+    }
+
+    /// Samples a block header with extensions.
+    pub(crate) fn sample_block_header_with_extensions(rng: &mut TestRng) -> Header<CurrentNetwork> {
+        let header = sample_block_header(rng);
+        Header::from_with_extensions(
+            header.previous_state_root,
+            header.transactions_root,
+            header.finalize_root,
+            header.ratifications_root,
+            header.solutions_root,
+            header.subdag_root,
+            header.metadata,
+            extension::test_helpers::sample_header_extensions(rng),
+        )
+        .unwrap()
     }
 }
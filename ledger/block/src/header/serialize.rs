@@ -19,7 +19,7 @@ impl<N: Network> Serialize for Header<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut header = serializer.serialize_struct("Header", 7)?;
+                let mut header = serializer.serialize_struct("Header", 7 + !self.extensions.is_empty() as usize)?;
                 header.serialize_field("previous_state_root", &self.previous_state_root)?;
                 header.serialize_field("transactions_root", &self.transactions_root)?;
                 header.serialize_field("finalize_root", &self.finalize_root)?;
@@ -27,6 +27,9 @@ impl<N: Network> Serialize for Header<N> {
                 header.serialize_field("solutions_root", &self.solutions_root)?;
                 header.serialize_field("subdag_root", &self.subdag_root)?;
                 header.serialize_field("metadata", &self.metadata)?;
+                if !self.extensions.is_empty() {
+                    header.serialize_field("extensions", &self.extensions)?;
+                }
                 header.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -40,7 +43,12 @@ impl<'de, N: Network> Deserialize<'de> for Header<N> {
         match deserializer.is_human_readable() {
             true => {
                 let mut header = serde_json::Value::deserialize(deserializer)?;
-                Ok(Self::from(
+                // Retrieve the extensions, which are omitted if the header does not contain any.
+                let extensions = match header.get("extensions").is_some() {
+                    true => DeserializeExt::take_from_value::<D>(&mut header, "extensions")?,
+                    false => vec![],
+                };
+                Ok(Self::from_with_extensions(
                     DeserializeExt::take_from_value::<D>(&mut header, "previous_state_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "transactions_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "finalize_root")?,
@@ -48,6 +56,7 @@ impl<'de, N: Network> Deserialize<'de> for Header<N> {
                     DeserializeExt::take_from_value::<D>(&mut header, "solutions_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "subdag_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "metadata")?,
+                    extensions,
                 )
                 .map_err(de::Error::custom)?)
             }
@@ -64,7 +73,12 @@ mod tests {
     fn test_serde_json() -> Result<()> {
         let rng = &mut TestRng::default();
 
-        for expected in [crate::header::test_helpers::sample_block_header(rng)].into_iter() {
+        for expected in [
+            crate::header::test_helpers::sample_block_header(rng),
+            crate::header::test_helpers::sample_block_header_with_extensions(rng),
+        ]
+        .into_iter()
+        {
             // Serialize
             let expected_string = &expected.to_string();
             let candidate_string = serde_json::to_string(&expected)?;
@@ -80,7 +94,12 @@ mod tests {
     fn test_bincode() -> Result<()> {
         let rng = &mut TestRng::default();
 
-        for expected in [crate::header::test_helpers::sample_block_header(rng)].into_iter() {
+        for expected in [
+            crate::header::test_helpers::sample_block_header(rng),
+            crate::header::test_helpers::sample_block_header_with_extensions(rng),
+        ]
+        .into_iter()
+        {
             // Serialize
             let expected_bytes = expected.to_bytes_le()?;
             let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{network::prelude::*, types::U64};
+
+/// The number of microcredits in one credit.
+pub const MICROCREDITS_PER_CREDIT: u64 = 1_000_000;
+
+/// An amount of Aleo credits, denominated in microcredits.
+///
+/// All arithmetic on an `Amount` is explicit: callers choose between checked, saturating,
+/// and overflowing operations, so that silent wrap-around cannot occur.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(u64);
+
+impl Amount {
+    /// The maximum amount.
+    pub const MAX: Self = Self(u64::MAX);
+    /// The zero amount.
+    pub const ZERO: Self = Self(0);
+
+    /// Initializes a new amount from the given number of microcredits.
+    pub const fn from_microcredits(microcredits: u64) -> Self {
+        Self(microcredits)
+    }
+
+    /// Initializes a new amount from the given number of credits, returning `None` on overflow.
+    pub const fn from_credits(credits: u64) -> Option<Self> {
+        match credits.checked_mul(MICROCREDITS_PER_CREDIT) {
+            Some(microcredits) => Some(Self(microcredits)),
+            None => None,
+        }
+    }
+
+    /// Returns the amount in microcredits.
+    pub const fn microcredits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the number of whole credits in the amount, discarding any fractional microcredits.
+    pub const fn credits(&self) -> u64 {
+        self.0 / MICROCREDITS_PER_CREDIT
+    }
+
+    /// Returns `true` if the amount is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Amount {
+    /// Returns the sum of `self` and `other`, or `None` on overflow.
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(microcredits) => Some(Self(microcredits)),
+            None => None,
+        }
+    }
+
+    /// Returns the difference of `self` and `other`, or `None` on underflow.
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(microcredits) => Some(Self(microcredits)),
+            None => None,
+        }
+    }
+
+    /// Returns the amount multiplied by `factor`, or `None` on overflow.
+    pub const fn checked_mul(self, factor: u64) -> Option<Self> {
+        match self.0.checked_mul(factor) {
+            Some(microcredits) => Some(Self(microcredits)),
+            None => None,
+        }
+    }
+
+    /// Returns the amount divided by `divisor`, or `None` if `divisor` is zero.
+    pub const fn checked_div(self, divisor: u64) -> Option<Self> {
+        match self.0.checked_div(divisor) {
+            Some(microcredits) => Some(Self(microcredits)),
+            None => None,
+        }
+    }
+
+    /// Returns the sum of `self` and `other`, saturating at `Amount::MAX`.
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Returns the difference of `self` and `other`, saturating at `Amount::ZERO`.
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// Returns the amount multiplied by `factor`, saturating at `Amount::MAX`.
+    pub const fn saturating_mul(self, factor: u64) -> Self {
+        Self(self.0.saturating_mul(factor))
+    }
+
+    /// Returns the sum of `self` and `other`, and a boolean indicating whether an overflow occurred.
+    pub const fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let (microcredits, overflow) = self.0.overflowing_add(other.0);
+        (Self(microcredits), overflow)
+    }
+
+    /// Returns the difference of `self` and `other`, and a boolean indicating whether an underflow occurred.
+    pub const fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let (microcredits, overflow) = self.0.overflowing_sub(other.0);
+        (Self(microcredits), overflow)
+    }
+}
+
+impl From<u64> for Amount {
+    /// Initializes a new amount from the given number of microcredits.
+    fn from(microcredits: u64) -> Self {
+        Self(microcredits)
+    }
+}
+
+impl<N: Network> From<U64<N>> for Amount {
+    /// Initializes a new amount from the given number of microcredits.
+    fn from(microcredits: U64<N>) -> Self {
+        Self(*microcredits)
+    }
+}
+
+impl TryFrom<i64> for Amount {
+    type Error = Error;
+
+    /// Initializes a new amount from the given number of microcredits, rejecting negative values.
+    fn try_from(microcredits: i64) -> Result<Self> {
+        match u64::try_from(microcredits) {
+            Ok(microcredits) => Ok(Self(microcredits)),
+            Err(_) => Err(anyhow!("Amount cannot be negative, found {microcredits} microcredits")),
+        }
+    }
+}
+
+impl TryFrom<u128> for Amount {
+    type Error = Error;
+
+    /// Initializes a new amount from the given number of microcredits, rejecting values above `u64::MAX`.
+    fn try_from(microcredits: u128) -> Result<Self> {
+        match u64::try_from(microcredits) {
+            Ok(microcredits) => Ok(Self(microcredits)),
+            Err(_) => Err(anyhow!("Amount exceeds the maximum, found {microcredits} microcredits")),
+        }
+    }
+}
+
+impl From<Amount> for u64 {
+    /// Returns the amount in microcredits.
+    fn from(amount: Amount) -> Self {
+        amount.0
+    }
+}
+
+impl<N: Network> From<Amount> for U64<N> {
+    /// Returns the amount in microcredits.
+    fn from(amount: Amount) -> Self {
+        U64::new(amount.0)
+    }
+}
+
+impl TryFrom<Amount> for i64 {
+    type Error = Error;
+
+    /// Returns the amount in microcredits, rejecting values above `i64::MAX`.
+    fn try_from(amount: Amount) -> Result<Self> {
+        match i64::try_from(amount.0) {
+            Ok(microcredits) => Ok(microcredits),
+            Err(_) => Err(anyhow!("Amount ({} microcredits) exceeds i64::MAX", amount.0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_from_credits() {
+        assert_eq!(Amount::from_credits(0), Some(Amount::ZERO));
+        assert_eq!(Amount::from_credits(1), Some(Amount::from_microcredits(MICROCREDITS_PER_CREDIT)));
+        assert_eq!(
+            Amount::from_credits(u64::MAX / MICROCREDITS_PER_CREDIT).map(|a| a.credits()),
+            Some(u64::MAX / MICROCREDITS_PER_CREDIT)
+        );
+        assert_eq!(Amount::from_credits(u64::MAX / MICROCREDITS_PER_CREDIT + 1), None);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let one = Amount::from_microcredits(1);
+        assert_eq!(Amount::MAX.checked_add(one), None);
+        assert_eq!(Amount::ZERO.checked_sub(one), None);
+        assert_eq!(Amount::MAX.checked_mul(2), None);
+        assert_eq!(one.checked_div(0), None);
+        assert_eq!(one.checked_add(one), Some(Amount::from_microcredits(2)));
+        assert_eq!(Amount::from_microcredits(7).checked_div(2), Some(Amount::from_microcredits(3)));
+    }
+
+    #[test]
+    fn test_saturating_and_overflowing_arithmetic() {
+        let one = Amount::from_microcredits(1);
+        assert_eq!(Amount::MAX.saturating_add(one), Amount::MAX);
+        assert_eq!(Amount::ZERO.saturating_sub(one), Amount::ZERO);
+        assert_eq!(Amount::MAX.saturating_mul(2), Amount::MAX);
+        assert_eq!(Amount::MAX.overflowing_add(one), (Amount::ZERO, true));
+        assert_eq!(Amount::ZERO.overflowing_sub(one), (Amount::MAX, true));
+        assert_eq!(one.overflowing_add(one), (Amount::from_microcredits(2), false));
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert!(Amount::try_from(-1i64).is_err());
+        assert_eq!(Amount::try_from(5i64).unwrap(), Amount::from_microcredits(5));
+        assert!(Amount::try_from(u64::MAX as u128 + 1).is_err());
+        assert!(i64::try_from(Amount::MAX).is_err());
+        assert_eq!(i64::try_from(Amount::from_microcredits(5)).unwrap(), 5);
+
+        let amount = Amount::from(U64::<CurrentNetwork>::new(42));
+        assert_eq!(u64::from(amount), 42);
+        assert_eq!(U64::<CurrentNetwork>::from(amount), U64::new(42));
+    }
+}
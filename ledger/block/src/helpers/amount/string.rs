@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of decimal places in one credit.
+const CREDITS_DECIMALS: usize = 6;
+
+impl Amount {
+    /// Returns the amount as a string in credits, with all six decimal places (e.g. `1.500000 credits`).
+    pub fn to_credits_string(&self) -> String {
+        self.to_credits_string_with_precision(CREDITS_DECIMALS)
+    }
+
+    /// Returns the amount as a string in credits, truncated to the given number of decimal places.
+    /// The precision is capped at six decimal places, which is the smallest unit (one microcredit).
+    ///
+    /// The formatting is locale-independent: a `.` is always used as the decimal separator,
+    /// and no digit grouping is applied (e.g. `1234.5 credits` for a precision of 1).
+    pub fn to_credits_string_with_precision(&self, precision: usize) -> String {
+        // Cap the precision at the number of decimal places in one credit.
+        let precision = precision.min(CREDITS_DECIMALS);
+        // Compute the whole credits and the fractional microcredits.
+        let whole = self.credits();
+        let fraction = format!("{:06}", self.0 % MICROCREDITS_PER_CREDIT);
+        // Return the formatted amount.
+        match precision {
+            0 => format!("{whole} credits"),
+            _ => format!("{whole}.{} credits", &fraction[..precision]),
+        }
+    }
+}
+
+impl FromStr for Amount {
+    type Err = Error;
+
+    /// Parses an amount from a string in credits or microcredits.
+    ///
+    /// The accepted formats are `<decimal> credits` (e.g. `1.5 credits`, with up to six decimal places),
+    /// `<integer> microcredits` (e.g. `1500000 microcredits`), and a bare `<integer>` in microcredits.
+    /// The unit may be singular, and whitespace between the number and the unit is optional.
+    fn from_str(string: &str) -> Result<Self> {
+        let string = string.trim();
+        // Split the string into the number and the unit.
+        let (number, unit) = match string.find(|c: char| !(c.is_ascii_digit() || c == '.')) {
+            Some(index) => (&string[..index], string[index..].trim_start()),
+            None => (string, ""),
+        };
+        // Ensure the number is not empty.
+        ensure!(!number.is_empty(), "Invalid amount '{string}' - expected a number");
+
+        match unit {
+            "credit" | "credits" => {
+                // Split the number into the whole and fractional parts.
+                let (whole, fraction) = match number.split_once('.') {
+                    Some((whole, fraction)) => (whole, fraction),
+                    None => (number, ""),
+                };
+                // Ensure the whole part is present, and that a decimal point is followed by digits.
+                ensure!(!whole.is_empty(), "Invalid amount '{string}' - missing whole credits");
+                ensure!(
+                    !number.ends_with('.') && !fraction.contains('.'),
+                    "Invalid amount '{string}' - malformed decimal number"
+                );
+                // Ensure the fractional part does not exceed the smallest unit.
+                ensure!(
+                    fraction.len() <= CREDITS_DECIMALS,
+                    "Invalid amount '{string}' - credits have at most {CREDITS_DECIMALS} decimal places"
+                );
+                // Compute the amount in microcredits.
+                let whole = Amount::from_credits(whole.parse::<u64>()?)
+                    .ok_or_else(|| anyhow!("Invalid amount '{string}' - exceeds the maximum amount"))?;
+                let fraction = match fraction.is_empty() {
+                    true => 0,
+                    false => format!("{fraction:0<CREDITS_DECIMALS$}").parse::<u64>()?,
+                };
+                whole
+                    .checked_add(Amount::from_microcredits(fraction))
+                    .ok_or_else(|| anyhow!("Invalid amount '{string}' - exceeds the maximum amount"))
+            }
+            "" | "microcredit" | "microcredits" => {
+                // Ensure the number is an integer.
+                ensure!(!number.contains('.'), "Invalid amount '{string}' - microcredits must be an integer");
+                Ok(Amount::from_microcredits(number.parse::<u64>()?))
+            }
+            _ => bail!("Invalid amount '{string}' - unknown unit '{unit}'"),
+        }
+    }
+}
+
+impl Display for Amount {
+    /// Prints the amount in microcredits (e.g. `1500000 microcredits`).
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} microcredits", self.0)
+    }
+}
+
+impl Debug for Amount {
+    /// Prints the amount in microcredits (e.g. `1500000 microcredits`).
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let amount = Amount::from_microcredits(1_500_000);
+        assert_eq!(amount.to_string(), "1500000 microcredits");
+        assert_eq!(amount.to_credits_string(), "1.500000 credits");
+        assert_eq!(Amount::from_microcredits(1).to_credits_string(), "0.000001 credits");
+    }
+
+    #[test]
+    fn test_precision() {
+        let amount = Amount::from_microcredits(1_234_567_891);
+        assert_eq!(amount.to_credits_string_with_precision(0), "1234 credits");
+        assert_eq!(amount.to_credits_string_with_precision(1), "1234.5 credits");
+        assert_eq!(amount.to_credits_string_with_precision(3), "1234.567 credits");
+        assert_eq!(amount.to_credits_string_with_precision(6), "1234.567891 credits");
+        assert_eq!(amount.to_credits_string_with_precision(9), "1234.567891 credits");
+    }
+
+    #[test]
+    fn test_from_str() -> Result<()> {
+        assert_eq!(Amount::from_str("1.5 credits")?, Amount::from_microcredits(1_500_000));
+        assert_eq!(Amount::from_str("1.5credits")?, Amount::from_microcredits(1_500_000));
+        assert_eq!(Amount::from_str("  2 credits ")?, Amount::from_microcredits(2_000_000));
+        assert_eq!(Amount::from_str("1 credit")?, Amount::from_microcredits(1_000_000));
+        assert_eq!(Amount::from_str("0.000001 credits")?, Amount::from_microcredits(1));
+        assert_eq!(Amount::from_str("1500000 microcredits")?, Amount::from_microcredits(1_500_000));
+        assert_eq!(Amount::from_str("1 microcredit")?, Amount::from_microcredits(1));
+        assert_eq!(Amount::from_str("42")?, Amount::from_microcredits(42));
+        assert_eq!(Amount::from_str("18446744073709.551615 credits")?, Amount::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_fails() {
+        assert!(Amount::from_str("").is_err());
+        assert!(Amount::from_str("credits").is_err());
+        assert!(Amount::from_str("-1 credits").is_err());
+        assert!(Amount::from_str(".5 credits").is_err());
+        assert!(Amount::from_str("1. credits").is_err());
+        assert!(Amount::from_str("1.2.3 credits").is_err());
+        assert!(Amount::from_str("0.0000001 credits").is_err());
+        assert!(Amount::from_str("1.5 microcredits").is_err());
+        assert!(Amount::from_str("1.5").is_err());
+        assert!(Amount::from_str("1 aleo").is_err());
+        assert!(Amount::from_str("1,5 credits").is_err());
+        assert!(Amount::from_str("18446744073709.551616 credits").is_err());
+        assert!(Amount::from_str("18446744073709551616").is_err());
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..1000 {
+            let expected = Amount::from_microcredits(rng.gen());
+            assert_eq!(expected, Amount::from_str(&expected.to_string())?);
+            assert_eq!(expected, Amount::from_str(&expected.to_credits_string())?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for BridgeMessage<N> {
+    /// Reads the bridge message from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid bridge message version"));
+        }
+
+        // Read the bridge message.
+        let destination_chain_id = u64::read_le(&mut reader)?;
+        let nonce = u64::read_le(&mut reader)?;
+        let sender = Address::<N>::read_le(&mut reader)?;
+        let recipient = <[u8; 20]>::read_le(&mut reader)?;
+        // Read the payload.
+        let payload_size = usize::try_from(u64::from(VarInt::read_le(&mut reader)?)).map_err(error)?;
+        if payload_size > Self::MAX_PAYLOAD_SIZE {
+            return Err(error(format!("Bridge message payload exceeds {} bytes", Self::MAX_PAYLOAD_SIZE)));
+        }
+        let mut payload = vec![0u8; payload_size];
+        reader.read_exact(&mut payload)?;
+
+        Self::new(destination_chain_id, nonce, sender, recipient, payload).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for BridgeMessage<N> {
+    /// Writes the bridge message to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the bridge message.
+        self.destination_chain_id.write_le(&mut writer)?;
+        self.nonce.write_le(&mut writer)?;
+        self.sender.write_le(&mut writer)?;
+        self.recipient.write_le(&mut writer)?;
+        // Write the payload.
+        VarInt(self.payload.len() as u64).write_le(&mut writer)?;
+        writer.write_all(&self.payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let expected = test_helpers::sample_bridge_message(rng);
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, BridgeMessage::read_le(&expected_bytes[..])?);
+            assert!(BridgeMessage::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
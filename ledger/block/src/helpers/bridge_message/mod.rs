@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    types::{Address, Field},
+};
+
+/// A message sent from this network to an external chain (e.g. an EVM chain), as the snarkVM-side half of a bridge.
+///
+/// A program emits the message commitment as a public transition output, and a relayer then proves its inclusion
+/// to the external verifier with an `InclusionProof` for the transaction, plus the chain of block headers.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BridgeMessage<N: Network> {
+    /// The chain ID of the destination chain.
+    destination_chain_id: u64,
+    /// The nonce of the message, which must be unique for each sender and destination chain.
+    nonce: u64,
+    /// The address of the sender on this network.
+    sender: Address<N>,
+    /// The 20-byte address of the recipient on the destination chain.
+    recipient: [u8; 20],
+    /// The payload of the message.
+    payload: Vec<u8>,
+}
+
+impl<N: Network> BridgeMessage<N> {
+    /// The maximum number of bytes in a message payload.
+    pub const MAX_PAYLOAD_SIZE: usize = 1024;
+
+    /// Initializes a new bridge message.
+    pub fn new(
+        destination_chain_id: u64,
+        nonce: u64,
+        sender: Address<N>,
+        recipient: [u8; 20],
+        payload: Vec<u8>,
+    ) -> Result<Self> {
+        // Ensure the payload is within the size limit.
+        ensure!(
+            payload.len() <= Self::MAX_PAYLOAD_SIZE,
+            "Bridge message payload exceeds {} bytes, found {}",
+            Self::MAX_PAYLOAD_SIZE,
+            payload.len()
+        );
+        Ok(Self { destination_chain_id, nonce, sender, recipient, payload })
+    }
+
+    /// Returns the chain ID of the destination chain.
+    pub const fn destination_chain_id(&self) -> u64 {
+        self.destination_chain_id
+    }
+
+    /// Returns the nonce of the message.
+    pub const fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Returns the address of the sender on this network.
+    pub const fn sender(&self) -> &Address<N> {
+        &self.sender
+    }
+
+    /// Returns the address of the recipient on the destination chain.
+    pub const fn recipient(&self) -> &[u8; 20] {
+        &self.recipient
+    }
+
+    /// Returns the payload of the message.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+}
+
+impl<N: Network> BridgeMessage<N> {
+    /// Returns the commitment to the message, as the BHP hash of its canonical (`ToBytes`) encoding.
+    pub fn to_commitment(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Returns the Solidity-compatible packed encoding of the message, which is equivalent to
+    /// `abi.encodePacked(uint16 network, uint64 destinationChainId, uint64 nonce, bytes32 sender, address recipient, bytes payload)`,
+    /// where `network` is the ID of this network, and `sender` is the big-endian encoding of the sender's x-coordinate.
+    pub fn to_solidity_packed(&self) -> Result<Vec<u8>> {
+        // Retrieve the big-endian encoding of the sender's x-coordinate.
+        let mut sender = self.sender.to_x_coordinate().to_bytes_le()?;
+        sender.reverse();
+        ensure!(sender.len() == 32, "Bridge message sender must be 32 bytes, found {}", sender.len());
+
+        // Construct the packed encoding.
+        let mut packed = Vec::with_capacity(2 + 8 + 8 + 32 + 20 + self.payload.len());
+        packed.extend_from_slice(&N::ID.to_be_bytes());
+        packed.extend_from_slice(&self.destination_chain_id.to_be_bytes());
+        packed.extend_from_slice(&self.nonce.to_be_bytes());
+        packed.extend_from_slice(&sender);
+        packed.extend_from_slice(&self.recipient);
+        packed.extend_from_slice(&self.payload);
+        Ok(packed)
+    }
+
+    /// Returns the Solidity-compatible hash of the message, which is equivalent to `keccak256(abi.encodePacked(...))`
+    /// over the encoding in `to_solidity_packed`.
+    pub fn to_solidity_hash(&self) -> Result<[u8; 32]> {
+        let hash = N::hash_keccak256(&self.to_solidity_packed()?.to_bits_le())?;
+        let hash = hash.chunks(8).map(u8::from_bits_le).collect::<Result<Vec<u8>>>()?;
+        hash.try_into().map_err(|hash: Vec<u8>| anyhow!("Expected a 32-byte Keccak hash, found {} bytes", hash.len()))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    /// Samples a random bridge message.
+    pub(crate) fn sample_bridge_message(rng: &mut TestRng) -> BridgeMessage<CurrentNetwork> {
+        let payload =
+            (0..rng.gen_range(0..=BridgeMessage::<CurrentNetwork>::MAX_PAYLOAD_SIZE)).map(|_| rng.gen()).collect();
+        BridgeMessage::new(rng.gen(), rng.gen(), Address::rand(rng), rng.gen(), payload).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_payload_size() {
+        let rng = &mut TestRng::default();
+
+        let payload = vec![0u8; BridgeMessage::<CurrentNetwork>::MAX_PAYLOAD_SIZE + 1];
+        assert!(BridgeMessage::<CurrentNetwork>::new(1, 0, Address::rand(rng), [0u8; 20], payload).is_err());
+    }
+
+    #[test]
+    fn test_solidity_packed() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let message = test_helpers::sample_bridge_message(rng);
+            let packed = message.to_solidity_packed()?;
+
+            // Ensure the fields are at their Solidity offsets.
+            assert_eq!(packed.len(), 70 + message.payload().len());
+            assert_eq!(packed[0..2], CurrentNetwork::ID.to_be_bytes());
+            assert_eq!(packed[2..10], message.destination_chain_id().to_be_bytes());
+            assert_eq!(packed[10..18], message.nonce().to_be_bytes());
+            let sender =
+                Field::<CurrentNetwork>::from_bytes_le(&packed[18..50].iter().rev().copied().collect::<Vec<_>>())?;
+            assert_eq!(sender, message.sender().to_x_coordinate());
+            assert_eq!(&packed[50..70], message.recipient());
+            assert_eq!(&packed[70..], message.payload());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_solidity_hash() -> Result<()> {
+        // Ensure the hash primitive matches Solidity's `keccak256`, using the known digest of the empty string.
+        let empty =
+            CurrentNetwork::hash_keccak256(&[])?.chunks(8).map(u8::from_bits_le).collect::<Result<Vec<u8>>>()?;
+        let expected = "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+        assert_eq!(empty.iter().map(|byte| format!("{byte:02x}")).collect::<String>(), expected);
+
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let message = test_helpers::sample_bridge_message(rng);
+            // Ensure the commitment and hash are deterministic.
+            assert_eq!(message.to_commitment()?, message.clone().to_commitment()?);
+            assert_eq!(message.to_solidity_hash()?, message.clone().to_solidity_hash()?);
+
+            // Ensure the commitment and hash bind the nonce.
+            let other = BridgeMessage::new(
+                message.destination_chain_id(),
+                message.nonce().wrapping_add(1),
+                *message.sender(),
+                *message.recipient(),
+                message.payload().to_vec(),
+            )?;
+            assert_ne!(message.to_commitment()?, other.to_commitment()?);
+            assert_ne!(message.to_solidity_hash()?, other.to_solidity_hash()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_solidity_hash_vector() -> Result<()> {
+        let sender =
+            Address::<CurrentNetwork>::from_str("aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf")?;
+        let recipient = core::array::from_fn(|i| i as u8 + 1);
+        let message = BridgeMessage::new(1, 7, sender, recipient, b"hello".to_vec())?;
+
+        let to_hex = |bytes: &[u8]| bytes.iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        // The packed encoding, as `abi.encodePacked(uint16(3), uint64(1), uint64(7), bytes32(sender), address(recipient), "hello")`.
+        let expected_packed = concat!(
+            "0003",
+            "0000000000000001",
+            "0000000000000007",
+            "0c868e13fa70bfdcb16b7d71c3fbb01f133124a8ed933b8b0780ae8481e91073",
+            "0102030405060708090a0b0c0d0e0f1011121314",
+            "68656c6c6f",
+        );
+        assert_eq!(to_hex(&message.to_solidity_packed()?), expected_packed);
+        // The Keccak-256 digest of the packed encoding, computed independently with `tiny-keccak`.
+        let expected_hash = "be997eaa862a29f7c81394efdfbee330353193f41ed27a553774ff217b4d4214";
+        assert_eq!(to_hex(&message.to_solidity_hash()?), expected_hash);
+        Ok(())
+    }
+}
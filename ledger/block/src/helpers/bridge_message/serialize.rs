@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for BridgeMessage<N> {
+    /// Serializes the bridge message to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut message = serializer.serialize_struct("BridgeMessage", 5)?;
+                message.serialize_field("destination_chain_id", &self.destination_chain_id)?;
+                message.serialize_field("nonce", &self.nonce)?;
+                message.serialize_field("sender", &self.sender)?;
+                message.serialize_field("recipient", &self.recipient)?;
+                message.serialize_field("payload", &self.payload)?;
+                message.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for BridgeMessage<N> {
+    /// Deserializes the bridge message from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut message = serde_json::Value::deserialize(deserializer)?;
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut message, "destination_chain_id")?,
+                    DeserializeExt::take_from_value::<D>(&mut message, "nonce")?,
+                    DeserializeExt::take_from_value::<D>(&mut message, "sender")?,
+                    DeserializeExt::take_from_value::<D>(&mut message, "recipient")?,
+                    DeserializeExt::take_from_value::<D>(&mut message, "payload")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "bridge message"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let expected = test_helpers::sample_bridge_message(rng);
+
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+
+            // Deserialize
+            assert_eq!(expected, BridgeMessage::from_str(expected_string)?);
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let expected = test_helpers::sample_bridge_message(rng);
+
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, BridgeMessage::read_le(&expected_bytes[..])?);
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl FromBytes for ChainWork {
+    /// Reads the chain work from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self(u128::read_le(&mut reader)?))
+    }
+}
+
+impl ToBytes for ChainWork {
+    /// Writes the chain work to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.0.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..1000 {
+            let expected = ChainWork::new(rng.gen());
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected_bytes, expected.cumulative_weight().to_le_bytes());
+            assert_eq!(expected, ChainWork::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+}
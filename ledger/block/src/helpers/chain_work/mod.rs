@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::network::prelude::*;
+
+/// The cumulative work of a chain, given by the cumulative weight of its tip.
+///
+/// Each block adds its combined proof target to the cumulative weight, so the chain work measures the total
+/// proving effort behind a chain. Fork choice prefers the chain with the most work, rather than the longest chain.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChainWork(u128);
+
+impl ChainWork {
+    /// The maximum chain work.
+    pub const MAX: Self = Self(u128::MAX);
+    /// The chain work of the genesis block.
+    pub const ZERO: Self = Self(0);
+
+    /// Initializes a new chain work from the given cumulative weight.
+    pub const fn new(cumulative_weight: u128) -> Self {
+        Self(cumulative_weight)
+    }
+
+    /// Returns the chain work as a cumulative weight.
+    pub const fn cumulative_weight(&self) -> u128 {
+        self.0
+    }
+
+    /// Returns the chain work after adding a block with the given weight (i.e. its combined proof target).
+    /// Note: The sum saturates, as the chain work must never wrap around to a smaller value.
+    pub const fn saturating_add(self, block_weight: u128) -> Self {
+        Self(self.0.saturating_add(block_weight))
+    }
+
+    /// Returns the chain work after adding a block with the given weight, or `None` on overflow.
+    pub const fn checked_add(self, block_weight: u128) -> Option<Self> {
+        match self.0.checked_add(block_weight) {
+            Some(cumulative_weight) => Some(Self(cumulative_weight)),
+            None => None,
+        }
+    }
+
+    /// Returns the work done between `ancestor` and `self`, or `None` if `ancestor` has more work.
+    pub const fn checked_sub(self, ancestor: Self) -> Option<u128> {
+        self.0.checked_sub(ancestor.0)
+    }
+
+    /// Returns `true` if a chain with `self` work at `height` is preferred over a chain with `other` work at `other_height`.
+    ///
+    /// The chain with more work is preferred. If both chains have the same work, the shorter chain is preferred,
+    /// as it achieved the same work in fewer blocks. Otherwise, the current chain (`other`) is kept.
+    pub fn is_preferred_over(&self, height: u32, other: &Self, other_height: u32) -> bool {
+        match self.cmp(other) {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal => height < other_height,
+        }
+    }
+}
+
+impl From<u128> for ChainWork {
+    /// Initializes a new chain work from the given cumulative weight.
+    fn from(cumulative_weight: u128) -> Self {
+        Self(cumulative_weight)
+    }
+}
+
+impl From<ChainWork> for u128 {
+    /// Returns the chain work as a cumulative weight.
+    fn from(chain_work: ChainWork) -> Self {
+        chain_work.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(ChainWork::ZERO.saturating_add(5), ChainWork::new(5));
+        assert_eq!(ChainWork::MAX.saturating_add(5), ChainWork::MAX);
+        assert_eq!(ChainWork::new(5).checked_add(7), Some(ChainWork::new(12)));
+        assert_eq!(ChainWork::MAX.checked_add(1), None);
+        assert_eq!(ChainWork::new(12).checked_sub(ChainWork::new(5)), Some(7));
+        assert_eq!(ChainWork::new(5).checked_sub(ChainWork::new(12)), None);
+    }
+
+    #[test]
+    fn test_is_preferred_over() {
+        let (light, heavy) = (ChainWork::new(100), ChainWork::new(101));
+        // Ensure the chain with more work is preferred, regardless of height.
+        assert!(heavy.is_preferred_over(10, &light, 1_000));
+        assert!(!light.is_preferred_over(1_000, &heavy, 10));
+        // Ensure ties in work are broken by the shorter chain.
+        assert!(light.is_preferred_over(9, &light, 10));
+        assert!(!light.is_preferred_over(11, &light, 10));
+        // Ensure an identical chain does not replace the current chain.
+        assert!(!light.is_preferred_over(10, &light, 10));
+    }
+}
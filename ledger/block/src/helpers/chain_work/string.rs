@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl FromStr for ChainWork {
+    type Err = Error;
+
+    /// Parses the chain work from its decimal cumulative weight.
+    fn from_str(string: &str) -> Result<Self> {
+        Ok(Self(string.parse::<u128>().map_err(|e| anyhow!("Invalid chain work '{string}' - {e}"))?))
+    }
+}
+
+impl Display for ChainWork {
+    /// Prints the chain work as its decimal cumulative weight.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Debug for ChainWork {
+    /// Prints the chain work as its decimal cumulative weight.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() -> Result<()> {
+        assert_eq!(ChainWork::from_str("0")?, ChainWork::ZERO);
+        assert_eq!(ChainWork::from_str(&u128::MAX.to_string())?, ChainWork::MAX);
+        assert!(ChainWork::from_str("").is_err());
+        assert!(ChainWork::from_str("-1").is_err());
+        assert!(ChainWork::from_str("1.5").is_err());
+        Ok(())
+    }
+}
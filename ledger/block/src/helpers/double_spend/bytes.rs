@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Spend<N> {
+    /// Reads the spend from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the variant.
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Execution(Execution::read_le(&mut reader)?)),
+            1 => Ok(Self::Fee(Fee::read_le(&mut reader)?)),
+            variant => Err(error(format!("Invalid spend variant '{variant}'"))),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for Spend<N> {
+    /// Writes the spend to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Execution(execution) => {
+                0u8.write_le(&mut writer)?;
+                execution.write_le(&mut writer)
+            }
+            Self::Fee(fee) => {
+                1u8.write_le(&mut writer)?;
+                fee.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for DoubleSpendProof<N> {
+    /// Reads the double-spend proof from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid double-spend proof version"));
+        }
+
+        // Read the double-spend proof.
+        let serial_number = Field::read_le(&mut reader)?;
+        let first = Spend::read_le(&mut reader)?;
+        let second = Spend::read_le(&mut reader)?;
+
+        Self::new(serial_number, first, second).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for DoubleSpendProof<N> {
+    /// Writes the double-spend proof to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the double-spend proof.
+        self.serial_number.write_le(&mut writer)?;
+        self.first.write_le(&mut writer)?;
+        self.second.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let expected = test_helpers::sample_double_spend_proof(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, DoubleSpendProof::read_le(&expected_bytes[..])?);
+        assert!(DoubleSpendProof::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{Execution, Fee, Transaction, Transition};
+use console::{network::prelude::*, types::Field};
+
+/// The part of a transaction that spends a record, along with the proof that authorizes it.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Spend<N: Network> {
+    /// An execution, whose transitions include the spend.
+    Execution(Execution<N>),
+    /// A fee, whose transition is the spend.
+    Fee(Fee<N>),
+}
+
+impl<N: Network> Spend<N> {
+    /// Returns the part of the given transaction that spends the given serial number.
+    pub fn from_transaction(transaction: &Transaction<N>, serial_number: &Field<N>) -> Result<Self> {
+        // Check the execution.
+        if let Some(execution) = transaction.execution() {
+            if execution.transitions().any(|transition| transition.contains_serial_number(serial_number)) {
+                return Ok(Self::Execution(execution.clone()));
+            }
+        }
+        // Check the fee.
+        match transaction.fee_transition() {
+            Some(fee) if fee.contains_serial_number(serial_number) => Ok(Self::Fee(fee)),
+            _ => bail!("Transaction '{}' does not spend serial number '{serial_number}'", transaction.id()),
+        }
+    }
+
+    /// Returns the transition that spends the given serial number, if any.
+    pub fn find_transition(&self, serial_number: &Field<N>) -> Option<&Transition<N>> {
+        match self {
+            Self::Execution(execution) => {
+                execution.transitions().find(|transition| transition.contains_serial_number(serial_number))
+            }
+            Self::Fee(fee) => {
+                Some(fee.transition()).filter(|transition| transition.contains_serial_number(serial_number))
+            }
+        }
+    }
+}
+
+/// Evidence that a record was spent twice: two distinct spends of the same serial number.
+///
+/// A double-spend proof is much smaller than the transactions it is taken from, as it omits any
+/// deployment and any unrelated fee. It is checked with `VM::check_double_spend_proof`, which
+/// verifies the proofs of both spends.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DoubleSpendProof<N: Network> {
+    /// The serial number that was spent twice.
+    serial_number: Field<N>,
+    /// The first spend.
+    first: Spend<N>,
+    /// The second spend.
+    second: Spend<N>,
+}
+
+impl<N: Network> DoubleSpendProof<N> {
+    /// Initializes a new double-spend proof.
+    pub fn new(serial_number: Field<N>, first: Spend<N>, second: Spend<N>) -> Result<Self> {
+        // Retrieve the transitions that spend the serial number.
+        let Some(first_transition) = first.find_transition(&serial_number) else {
+            bail!("The first spend does not spend serial number '{serial_number}'")
+        };
+        let Some(second_transition) = second.find_transition(&serial_number) else {
+            bail!("The second spend does not spend serial number '{serial_number}'")
+        };
+        // Ensure the spends are distinct.
+        ensure!(first_transition.id() != second_transition.id(), "The spends of '{serial_number}' are the same");
+        Ok(Self { serial_number, first, second })
+    }
+
+    /// Initializes a new double-spend proof from two transactions that spend the same serial number.
+    pub fn from_transactions(serial_number: Field<N>, first: &Transaction<N>, second: &Transaction<N>) -> Result<Self> {
+        Self::new(
+            serial_number,
+            Spend::from_transaction(first, &serial_number)?,
+            Spend::from_transaction(second, &serial_number)?,
+        )
+    }
+
+    /// Returns the serial number that was spent twice.
+    pub const fn serial_number(&self) -> &Field<N> {
+        &self.serial_number
+    }
+
+    /// Returns the first spend.
+    pub const fn first(&self) -> &Spend<N> {
+        &self.first
+    }
+
+    /// Returns the second spend.
+    pub const fn second(&self) -> &Spend<N> {
+        &self.second
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    /// Samples a double-spend proof, with two private fees that spend the same genesis record.
+    pub(crate) fn sample_double_spend_proof(rng: &mut TestRng) -> DoubleSpendProof<CurrentNetwork> {
+        // Sample a deployment and an execution, whose private fees spend the same record.
+        let first = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        let second = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        // Retrieve the serial number they both spend.
+        let serial_number = *first.fee_transition().unwrap().serial_numbers().next().unwrap();
+
+        DoubleSpendProof::from_transactions(serial_number, &first, &second).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let rng = &mut TestRng::default();
+
+        // Sample a double-spend proof.
+        let proof = test_helpers::sample_double_spend_proof(rng);
+        let serial_number = *proof.serial_number();
+        assert!(proof.first().find_transition(&serial_number).is_some());
+        assert!(proof.second().find_transition(&serial_number).is_some());
+
+        // Sample a transaction that spends the serial number in its fee.
+        let Spend::Fee(fee) = proof.first().clone() else { unreachable!() };
+        let transaction = Transaction::from_fee(fee).unwrap();
+        assert!(matches!(Spend::from_transaction(&transaction, &serial_number).unwrap(), Spend::Fee(..)));
+
+        // Ensure a transaction cannot double spend with itself.
+        assert!(DoubleSpendProof::from_transactions(serial_number, &transaction, &transaction).is_err());
+
+        // Ensure a transaction that does not spend the serial number is rejected.
+        let other = crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng);
+        assert!(Spend::from_transaction(&other, &serial_number).is_err());
+        assert!(DoubleSpendProof::from_transactions(serial_number, &transaction, &other).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for Spend<N> {
+    /// Serializes the spend to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => match self {
+                Self::Execution(execution) => {
+                    let mut spend = serializer.serialize_struct("Spend", 2)?;
+                    spend.serialize_field("type", "execution")?;
+                    spend.serialize_field("execution", execution)?;
+                    spend.end()
+                }
+                Self::Fee(fee) => {
+                    let mut spend = serializer.serialize_struct("Spend", 2)?;
+                    spend.serialize_field("type", "fee")?;
+                    spend.serialize_field("fee", fee)?;
+                    spend.end()
+                }
+            },
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Spend<N> {
+    /// Deserializes the spend from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut spend = serde_json::Value::deserialize(deserializer)?;
+                match spend.get("type").ok_or_else(|| de::Error::custom("The \"type\" field is missing"))?.as_str() {
+                    Some("execution") => {
+                        Ok(Self::Execution(DeserializeExt::take_from_value::<D>(&mut spend, "execution")?))
+                    }
+                    Some("fee") => Ok(Self::Fee(DeserializeExt::take_from_value::<D>(&mut spend, "fee")?)),
+                    _ => Err(de::Error::custom("Invalid spend type")),
+                }
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "spend"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for DoubleSpendProof<N> {
+    /// Serializes the double-spend proof to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut proof = serializer.serialize_struct("DoubleSpendProof", 3)?;
+                proof.serialize_field("serial_number", &self.serial_number)?;
+                proof.serialize_field("first", &self.first)?;
+                proof.serialize_field("second", &self.second)?;
+                proof.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for DoubleSpendProof<N> {
+    /// Deserializes the double-spend proof from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut proof = serde_json::Value::deserialize(deserializer)?;
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut proof, "serial_number")?,
+                    DeserializeExt::take_from_value::<D>(&mut proof, "first")?,
+                    DeserializeExt::take_from_value::<D>(&mut proof, "second")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "double-spend proof"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let expected = test_helpers::sample_double_spend_proof(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+
+        // Deserialize
+        assert_eq!(expected, DoubleSpendProof::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let expected = test_helpers::sample_double_spend_proof(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, DoubleSpendProof::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        Ok(())
+    }
+}
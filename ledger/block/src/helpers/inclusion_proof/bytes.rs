@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for InclusionProof<N> {
+    /// Reads the inclusion proof from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid inclusion proof version"));
+        }
+
+        // Read the inclusion proof.
+        let block_height = u32::read_le(&mut reader)?;
+        let transaction_id = N::TransactionID::read_le(&mut reader)?;
+        let transactions_path = TransactionsPath::<N>::read_le(&mut reader)?;
+
+        Ok(Self::new(block_height, transaction_id, transactions_path))
+    }
+}
+
+impl<N: Network> ToBytes for InclusionProof<N> {
+    /// Writes the inclusion proof to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the inclusion proof.
+        self.block_height.write_le(&mut writer)?;
+        self.transaction_id.write_le(&mut writer)?;
+        self.transactions_path.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..10 {
+            let (_, expected) = test_helpers::sample_inclusion_proof(rng);
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, InclusionProof::read_le(&expected_bytes[..])?);
+            assert!(InclusionProof::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::Header;
+use console::{
+    network::prelude::*,
+    program::{TransactionsPath, TRANSACTIONS_DEPTH},
+};
+
+/// A proof that a transaction is included in a block.
+///
+/// The proof is verified against the block header alone (see `verify_inclusion`), without access to
+/// the ledger state, so that external verifiers such as exchanges and bridges can confirm deposits.
+#[derive(Clone, PartialEq, Eq)]
+pub struct InclusionProof<N: Network> {
+    /// The height of the block containing the transaction.
+    block_height: u32,
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The Merkle path from the transaction ID to the transactions root of the block.
+    transactions_path: TransactionsPath<N>,
+}
+
+impl<N: Network> InclusionProof<N> {
+    /// Initializes a new inclusion proof.
+    pub const fn new(
+        block_height: u32,
+        transaction_id: N::TransactionID,
+        transactions_path: TransactionsPath<N>,
+    ) -> Self {
+        Self { block_height, transaction_id, transactions_path }
+    }
+
+    /// Returns the height of the block containing the transaction.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the Merkle path from the transaction ID to the transactions root of the block.
+    pub const fn transactions_path(&self) -> &TransactionsPath<N> {
+        &self.transactions_path
+    }
+}
+
+/// Checks that the given inclusion proof is valid for the given block header.
+///
+/// Note: The caller is responsible for ensuring the block header is canonical (e.g. by checking its block hash).
+pub fn verify_inclusion<N: Network>(header: &Header<N>, proof: &InclusionProof<N>) -> Result<()> {
+    // Ensure the proof is for the given block.
+    ensure!(
+        proof.block_height == header.height(),
+        "Inclusion proof is for block {}, not block {}",
+        proof.block_height,
+        header.height()
+    );
+    // Ensure the transaction ID is a leaf of the transactions root.
+    ensure!(
+        N::verify_merkle_path_bhp::<TRANSACTIONS_DEPTH>(
+            &proof.transactions_path,
+            &header.transactions_root(),
+            &proof.transaction_id.to_bits_le(),
+        ),
+        "Transaction '{}' is not in the transactions root of block {}",
+        proof.transaction_id,
+        header.height()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::header::Metadata;
+    use console::types::Field;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    /// Samples a block header for a block with random transaction IDs, and an inclusion proof for one of them.
+    pub(crate) fn sample_inclusion_proof(
+        rng: &mut TestRng,
+    ) -> (Header<CurrentNetwork>, InclusionProof<CurrentNetwork>) {
+        // Sample the transaction IDs.
+        let num_transactions = rng.gen_range(1..32);
+        let transaction_ids: Vec<<CurrentNetwork as Network>::TransactionID> =
+            (0..num_transactions).map(|_| Field::<CurrentNetwork>::rand(rng).into()).collect();
+        // Compute the transactions tree.
+        let leaves = transaction_ids.iter().map(|id| id.to_bits_le()).collect::<Vec<_>>();
+        let tree = CurrentNetwork::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&leaves).unwrap();
+
+        // Sample the block header.
+        let header = sample_header(*tree.root(), rng.gen_range(1..u32::MAX), rng);
+
+        // Prove the inclusion of one of the transaction IDs.
+        let index = rng.gen_range(0..num_transactions);
+        let path = tree.prove(index, &leaves[index]).unwrap();
+        let block_height = header.height();
+        (header, InclusionProof::new(block_height, transaction_ids[index], path))
+    }
+
+    /// Samples a block header with the given transactions root and height.
+    pub(crate) fn sample_header(
+        transactions_root: Field<CurrentNetwork>,
+        height: u32,
+        rng: &mut TestRng,
+    ) -> Header<CurrentNetwork> {
+        let coinbase_target = u64::rand(rng);
+        Header::<CurrentNetwork>::from(
+            Into::<<CurrentNetwork as Network>::StateRoot>::into(Field::rand(rng)),
+            transactions_root,
+            Field::rand(rng),
+            Field::rand(rng),
+            Field::rand(rng),
+            Field::rand(rng),
+            Metadata::new(
+                CurrentNetwork::ID,
+                u64::rand(rng),
+                height,
+                u128::rand(rng),
+                u128::rand(rng),
+                coinbase_target,
+                rng.gen_range(0..coinbase_target),
+                u64::rand(rng),
+                rng.gen_range(0..i64::MAX),
+                rng.gen_range(0..i64::MAX),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::types::Field;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_verify_inclusion() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (header, proof) = test_helpers::sample_inclusion_proof(rng);
+            // Ensure the proof is valid.
+            verify_inclusion(&header, &proof).unwrap();
+
+            // Ensure the proof is invalid for a different transaction ID.
+            let transaction_id = <CurrentNetwork as Network>::TransactionID::from(Field::rand(rng));
+            let invalid = InclusionProof::new(proof.block_height(), transaction_id, proof.transactions_path().clone());
+            assert!(verify_inclusion(&header, &invalid).is_err());
+
+            // Ensure the proof is invalid for a different block height.
+            let invalid = InclusionProof::new(
+                proof.block_height() - 1,
+                *proof.transaction_id(),
+                proof.transactions_path().clone(),
+            );
+            assert!(verify_inclusion(&header, &invalid).is_err());
+
+            // Ensure the proof is invalid for a different block header at the same height.
+            let other_header = test_helpers::sample_header(Field::rand(rng), header.height(), rng);
+            assert!(verify_inclusion(&other_header, &proof).is_err());
+        }
+    }
+}
@@ -12,5 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod amount;
+pub use amount::*;
+
+mod bridge_message;
+pub use bridge_message::*;
+
+mod chain_work;
+pub use chain_work::*;
+
+mod double_spend;
+pub use double_spend::*;
+
+mod inclusion_proof;
+pub use inclusion_proof::*;
+
+mod state_diff;
+pub use state_diff::*;
+
 mod target;
 pub use target::*;
+
+mod validation_report;
+pub use validation_report::*;
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for BlockDiff<N> {
+    /// Reads the block diff from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid block diff version"));
+        }
+
+        // Read the block diff.
+        let height = u32::read_le(&mut reader)?;
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        let previous_hash = N::BlockHash::read_le(&mut reader)?;
+        let state_root = N::StateRoot::read_le(&mut reader)?;
+        // Read the commitments.
+        let num_commitments = u64::from(VarInt::read_le(&mut reader)?);
+        let commitments = (0..num_commitments).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the serial numbers.
+        let num_serial_numbers = u64::from(VarInt::read_le(&mut reader)?);
+        let serial_numbers =
+            (0..num_serial_numbers).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        Ok(Self::new(height, block_hash, previous_hash, state_root, commitments, serial_numbers))
+    }
+}
+
+impl<N: Network> ToBytes for BlockDiff<N> {
+    /// Writes the block diff to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block diff.
+        self.height.write_le(&mut writer)?;
+        self.block_hash.write_le(&mut writer)?;
+        self.previous_hash.write_le(&mut writer)?;
+        self.state_root.write_le(&mut writer)?;
+        // Write the commitments.
+        VarInt(self.commitments.len() as u64).write_le(&mut writer)?;
+        self.commitments.write_le(&mut writer)?;
+        // Write the serial numbers.
+        VarInt(self.serial_numbers.len() as u64).write_le(&mut writer)?;
+        self.serial_numbers.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for StateDiff<N> {
+    /// Reads the state diff from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid state diff version"));
+        }
+
+        // Read the state diff.
+        let from_height = u32::read_le(&mut reader)?;
+        let from_hash = N::BlockHash::read_le(&mut reader)?;
+        // Read the block diffs.
+        let num_blocks = u64::from(VarInt::read_le(&mut reader)?);
+        let blocks = (0..num_blocks).map(|_| BlockDiff::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        Self::new(from_height, from_hash, blocks).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for StateDiff<N> {
+    /// Writes the state diff to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the state diff.
+        self.from_height.write_le(&mut writer)?;
+        self.from_hash.write_le(&mut writer)?;
+        // Write the block diffs.
+        VarInt(self.blocks.len() as u64).write_le(&mut writer)?;
+        self.blocks.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let expected = test_helpers::sample_state_diff(rng);
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, StateDiff::read_le(&expected_bytes[..])?);
+            assert!(StateDiff::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+/// A lightweight mirror of the ledger state, which is kept in sync by applying state diffs.
+///
+/// The mirror tracks the commitments and serial numbers introduced since its starting block,
+/// along with the latest block hash and state root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateMirror<N: Network> {
+    /// The latest block height.
+    latest_height: u32,
+    /// The latest block hash.
+    latest_hash: N::BlockHash,
+    /// The latest state root.
+    latest_state_root: N::StateRoot,
+    /// The commitments created since the starting block.
+    commitments: IndexSet<Field<N>>,
+    /// The serial numbers spent since the starting block.
+    serial_numbers: IndexSet<Field<N>>,
+}
+
+impl<N: Network> StateMirror<N> {
+    /// Initializes a new state mirror, starting from the given block.
+    pub fn new(height: u32, block_hash: N::BlockHash, state_root: N::StateRoot) -> Self {
+        Self {
+            latest_height: height,
+            latest_hash: block_hash,
+            latest_state_root: state_root,
+            commitments: Default::default(),
+            serial_numbers: Default::default(),
+        }
+    }
+
+    /// Returns the latest block height.
+    pub const fn latest_height(&self) -> u32 {
+        self.latest_height
+    }
+
+    /// Returns the latest block hash.
+    pub const fn latest_hash(&self) -> N::BlockHash {
+        self.latest_hash
+    }
+
+    /// Returns the latest state root.
+    pub const fn latest_state_root(&self) -> N::StateRoot {
+        self.latest_state_root
+    }
+
+    /// Returns the commitments created since the starting block.
+    pub const fn commitments(&self) -> &IndexSet<Field<N>> {
+        &self.commitments
+    }
+
+    /// Returns the serial numbers spent since the starting block.
+    pub const fn serial_numbers(&self) -> &IndexSet<Field<N>> {
+        &self.serial_numbers
+    }
+
+    /// Returns `true` if the given commitment was created since the starting block.
+    pub fn contains_commitment(&self, commitment: &Field<N>) -> bool {
+        self.commitments.contains(commitment)
+    }
+
+    /// Returns `true` if the given serial number was spent since the starting block.
+    pub fn contains_serial_number(&self, serial_number: &Field<N>) -> bool {
+        self.serial_numbers.contains(serial_number)
+    }
+
+    /// Applies the given state diff to the mirror.
+    /// If the diff is invalid, the mirror is left unchanged.
+    pub fn apply_diff(&mut self, diff: &StateDiff<N>) -> Result<()> {
+        // Ensure the diff builds on the latest block.
+        ensure!(
+            diff.from_height() == self.latest_height && diff.from_hash() == self.latest_hash,
+            "The state diff builds on block {} ('{}'), but the mirror is at block {} ('{}')",
+            diff.from_height(),
+            diff.from_hash(),
+            self.latest_height,
+            self.latest_hash
+        );
+
+        // Ensure the commitments are new.
+        let mut commitments = IndexSet::new();
+        for commitment in diff.commitments() {
+            ensure!(
+                !self.commitments.contains(commitment) && commitments.insert(*commitment),
+                "The state diff contains a duplicate commitment '{commitment}'"
+            );
+        }
+        // Ensure the serial numbers are new.
+        let mut serial_numbers = IndexSet::new();
+        for serial_number in diff.serial_numbers() {
+            ensure!(
+                !self.serial_numbers.contains(serial_number) && serial_numbers.insert(*serial_number),
+                "The state diff contains a duplicate serial number '{serial_number}'"
+            );
+        }
+
+        // Update the mirror.
+        if let Some(block) = diff.blocks().last() {
+            self.latest_height = block.height();
+            self.latest_hash = block.block_hash();
+            self.latest_state_root = block.state_root();
+        }
+        self.commitments.extend(commitments);
+        self.serial_numbers.extend(serial_numbers);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_apply_diff() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let first = test_helpers::sample_state_diff(rng);
+            let second = test_helpers::sample_state_diff_from(first.to_height(), first.to_hash(), rng);
+
+            // Initialize the mirror at the starting block.
+            let mut mirror = StateMirror::<CurrentNetwork>::new(
+                first.from_height(),
+                first.from_hash(),
+                Field::<CurrentNetwork>::rand(rng).into(),
+            );
+            // Ensure a diff that does not build on the mirror is rejected.
+            if !first.blocks().is_empty() {
+                assert!(mirror.apply_diff(&second).is_err());
+            }
+
+            // Apply the diffs.
+            mirror.apply_diff(&first)?;
+            mirror.apply_diff(&second)?;
+            assert_eq!(mirror.latest_height(), second.to_height());
+            assert_eq!(mirror.latest_hash(), second.to_hash());
+            if let Some(block) = second.blocks().last().or(first.blocks().last()) {
+                assert_eq!(mirror.latest_state_root(), block.state_root());
+            }
+            assert!(first.commitments().chain(second.commitments()).all(|c| mirror.contains_commitment(c)));
+            assert!(first.serial_numbers().chain(second.serial_numbers()).all(|s| mirror.contains_serial_number(s)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_diff_rejects_double_spend() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let from_hash = Field::<CurrentNetwork>::rand(rng).into();
+        let mut mirror = StateMirror::<CurrentNetwork>::new(0, from_hash, Field::<CurrentNetwork>::rand(rng).into());
+
+        // Apply a block that spends a serial number.
+        let serial_number = Field::rand(rng);
+        let block = BlockDiff::new(
+            1,
+            Field::<CurrentNetwork>::rand(rng).into(),
+            from_hash,
+            Field::<CurrentNetwork>::rand(rng).into(),
+            vec![],
+            vec![serial_number],
+        );
+        mirror.apply_diff(&StateDiff::new(0, from_hash, vec![block.clone()])?)?;
+
+        // Ensure a block that spends the same serial number is rejected, and the mirror is unchanged.
+        let expected = mirror.clone();
+        let double_spend = BlockDiff::new(
+            2,
+            Field::<CurrentNetwork>::rand(rng).into(),
+            block.block_hash(),
+            Field::<CurrentNetwork>::rand(rng).into(),
+            vec![Field::rand(rng)],
+            vec![serial_number],
+        );
+        assert!(mirror.apply_diff(&StateDiff::new(1, block.block_hash(), vec![double_spend])?).is_err());
+        assert_eq!(mirror, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,243 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod mirror;
+mod serialize;
+mod string;
+
+pub use mirror::*;
+
+use crate::Block;
+use console::{network::prelude::*, types::Field};
+
+/// The state changes introduced by a single block.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BlockDiff<N: Network> {
+    /// The height of the block.
+    height: u32,
+    /// The hash of the block.
+    block_hash: N::BlockHash,
+    /// The hash of the previous block.
+    previous_hash: N::BlockHash,
+    /// The state root after the block is added to the ledger.
+    state_root: N::StateRoot,
+    /// The commitments created in the block.
+    commitments: Vec<Field<N>>,
+    /// The serial numbers spent in the block.
+    serial_numbers: Vec<Field<N>>,
+}
+
+impl<N: Network> BlockDiff<N> {
+    /// Initializes a new block diff.
+    pub const fn new(
+        height: u32,
+        block_hash: N::BlockHash,
+        previous_hash: N::BlockHash,
+        state_root: N::StateRoot,
+        commitments: Vec<Field<N>>,
+        serial_numbers: Vec<Field<N>>,
+    ) -> Self {
+        Self { height, block_hash, previous_hash, state_root, commitments, serial_numbers }
+    }
+
+    /// Initializes a new block diff from the given block and the state root after it is added to the ledger.
+    pub fn from_block(block: &Block<N>, state_root: N::StateRoot) -> Self {
+        Self::new(
+            block.height(),
+            block.hash(),
+            block.previous_hash(),
+            state_root,
+            block.commitments().copied().collect(),
+            block.serial_numbers().copied().collect(),
+        )
+    }
+
+    /// Returns the height of the block.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the hash of the block.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the hash of the previous block.
+    pub const fn previous_hash(&self) -> N::BlockHash {
+        self.previous_hash
+    }
+
+    /// Returns the state root after the block is added to the ledger.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the commitments created in the block.
+    pub fn commitments(&self) -> &[Field<N>] {
+        &self.commitments
+    }
+
+    /// Returns the serial numbers spent in the block.
+    pub fn serial_numbers(&self) -> &[Field<N>] {
+        &self.serial_numbers
+    }
+}
+
+/// The state changes between two ledger heights, which allows mirrors (see `StateMirror`)
+/// to stay in sync with the ledger without processing the full blocks.
+///
+/// The diff covers the blocks in `(from_height, to_height]`, in order.
+#[derive(Clone, PartialEq, Eq)]
+pub struct StateDiff<N: Network> {
+    /// The height of the block the diff is applied on top of.
+    from_height: u32,
+    /// The hash of the block the diff is applied on top of.
+    from_hash: N::BlockHash,
+    /// The block diffs, in increasing order of height.
+    blocks: Vec<BlockDiff<N>>,
+}
+
+impl<N: Network> StateDiff<N> {
+    /// Initializes a new state diff, ensuring the block diffs form a contiguous chain on top of `from_hash`.
+    pub fn new(from_height: u32, from_hash: N::BlockHash, blocks: Vec<BlockDiff<N>>) -> Result<Self> {
+        // Ensure the block diffs are contiguous.
+        let mut expected = (from_height, from_hash);
+        for block in &blocks {
+            // Ensure the height is the next height.
+            let height = expected.0.checked_add(1).ok_or_else(|| anyhow!("The state diff overflows the height"))?;
+            ensure!(block.height == height, "Expected block {height} in the state diff, found block {}", block.height);
+            // Ensure the block builds on the previous block.
+            ensure!(
+                block.previous_hash == expected.1,
+                "Block {height} in the state diff does not build on block '{}'",
+                expected.1
+            );
+            expected = (block.height, block.block_hash);
+        }
+        Ok(Self { from_height, from_hash, blocks })
+    }
+
+    /// Returns the height of the block the diff is applied on top of.
+    pub const fn from_height(&self) -> u32 {
+        self.from_height
+    }
+
+    /// Returns the hash of the block the diff is applied on top of.
+    pub const fn from_hash(&self) -> N::BlockHash {
+        self.from_hash
+    }
+
+    /// Returns the height of the last block in the diff.
+    pub fn to_height(&self) -> u32 {
+        self.blocks.last().map_or(self.from_height, |block| block.height)
+    }
+
+    /// Returns the hash of the last block in the diff.
+    pub fn to_hash(&self) -> N::BlockHash {
+        self.blocks.last().map_or(self.from_hash, |block| block.block_hash)
+    }
+
+    /// Returns the block diffs, in increasing order of height.
+    pub fn blocks(&self) -> &[BlockDiff<N>] {
+        &self.blocks
+    }
+
+    /// Returns an iterator over the commitments created in the diff.
+    pub fn commitments(&self) -> impl '_ + Iterator<Item = &Field<N>> {
+        self.blocks.iter().flat_map(|block| block.commitments.iter())
+    }
+
+    /// Returns an iterator over the serial numbers spent in the diff.
+    pub fn serial_numbers(&self) -> impl '_ + Iterator<Item = &Field<N>> {
+        self.blocks.iter().flat_map(|block| block.serial_numbers.iter())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    /// Samples a random block diff, on top of the given height and hash.
+    pub(crate) fn sample_block_diff(
+        previous_height: u32,
+        previous_hash: <CurrentNetwork as Network>::BlockHash,
+        rng: &mut TestRng,
+    ) -> BlockDiff<CurrentNetwork> {
+        BlockDiff::new(
+            previous_height + 1,
+            Field::<CurrentNetwork>::rand(rng).into(),
+            previous_hash,
+            Field::<CurrentNetwork>::rand(rng).into(),
+            (0..rng.gen_range(0..8)).map(|_| Field::rand(rng)).collect(),
+            (0..rng.gen_range(0..8)).map(|_| Field::rand(rng)).collect(),
+        )
+    }
+
+    /// Samples a random state diff, on top of the given height and hash.
+    pub(crate) fn sample_state_diff_from(
+        from_height: u32,
+        from_hash: <CurrentNetwork as Network>::BlockHash,
+        rng: &mut TestRng,
+    ) -> StateDiff<CurrentNetwork> {
+        let mut blocks = Vec::<BlockDiff<CurrentNetwork>>::new();
+        for _ in 0..rng.gen_range(0..5) {
+            let (height, hash) =
+                blocks.last().map_or((from_height, from_hash), |block| (block.height, block.block_hash));
+            blocks.push(sample_block_diff(height, hash, rng));
+        }
+        StateDiff::new(from_height, from_hash, blocks).unwrap()
+    }
+
+    /// Samples a random state diff.
+    pub(crate) fn sample_state_diff(rng: &mut TestRng) -> StateDiff<CurrentNetwork> {
+        sample_state_diff_from(rng.gen_range(0..1_000_000), Field::<CurrentNetwork>::rand(rng).into(), rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_contiguity() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let diff = test_helpers::sample_state_diff(rng);
+            assert_eq!(diff.to_height() - diff.from_height(), diff.blocks().len() as u32);
+            assert_eq!(
+                diff.commitments().count(),
+                diff.blocks().iter().map(|block| block.commitments().len()).sum::<usize>()
+            );
+
+            // Ensure a diff that does not build on the starting block is rejected.
+            let block =
+                test_helpers::sample_block_diff(diff.to_height(), Field::<CurrentNetwork>::rand(rng).into(), rng);
+            let mut blocks = diff.blocks().to_vec();
+            blocks.push(block.clone());
+            assert!(StateDiff::new(diff.from_height(), diff.from_hash(), blocks).is_err());
+
+            // Ensure a diff that skips a height is rejected.
+            let block = test_helpers::sample_block_diff(diff.to_height() + 1, diff.to_hash(), rng);
+            let mut blocks = diff.blocks().to_vec();
+            blocks.push(block);
+            assert!(StateDiff::new(diff.from_height(), diff.from_hash(), blocks).is_err());
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for BlockDiff<N> {
+    /// Serializes the block diff to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut diff = serializer.serialize_struct("BlockDiff", 6)?;
+                diff.serialize_field("height", &self.height)?;
+                diff.serialize_field("block_hash", &self.block_hash)?;
+                diff.serialize_field("previous_hash", &self.previous_hash)?;
+                diff.serialize_field("state_root", &self.state_root)?;
+                diff.serialize_field("commitments", &self.commitments)?;
+                diff.serialize_field("serial_numbers", &self.serial_numbers)?;
+                diff.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for BlockDiff<N> {
+    /// Deserializes the block diff from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut diff = serde_json::Value::deserialize(deserializer)?;
+                Ok(Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut diff, "height")?,
+                    DeserializeExt::take_from_value::<D>(&mut diff, "block_hash")?,
+                    DeserializeExt::take_from_value::<D>(&mut diff, "previous_hash")?,
+                    DeserializeExt::take_from_value::<D>(&mut diff, "state_root")?,
+                    DeserializeExt::take_from_value::<D>(&mut diff, "commitments")?,
+                    DeserializeExt::take_from_value::<D>(&mut diff, "serial_numbers")?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "block diff"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for StateDiff<N> {
+    /// Serializes the state diff to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut diff = serializer.serialize_struct("StateDiff", 3)?;
+                diff.serialize_field("from_height", &self.from_height)?;
+                diff.serialize_field("from_hash", &self.from_hash)?;
+                diff.serialize_field("blocks", &self.blocks)?;
+                diff.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for StateDiff<N> {
+    /// Deserializes the state diff from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut diff = serde_json::Value::deserialize(deserializer)?;
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut diff, "from_height")?,
+                    DeserializeExt::take_from_value::<D>(&mut diff, "from_hash")?,
+                    DeserializeExt::take_from_value::<D>(&mut diff, "blocks")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "state diff"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let expected = test_helpers::sample_state_diff(rng);
+
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+
+            // Deserialize
+            assert_eq!(expected, StateDiff::from_str(expected_string)?);
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let expected = test_helpers::sample_state_diff(rng);
+
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, StateDiff::read_le(&expected_bytes[..])?);
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromStr for BlockDiff<N> {
+    type Err = Error;
+
+    /// Initializes the block diff from a JSON-string.
+    fn from_str(diff: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(diff)?)
+    }
+}
+
+impl<N: Network> Debug for BlockDiff<N> {
+    /// Prints the block diff as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for BlockDiff<N> {
+    /// Displays the block diff as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+impl<N: Network> FromStr for StateDiff<N> {
+    type Err = Error;
+
+    /// Initializes the state diff from a JSON-string.
+    fn from_str(diff: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(diff)?)
+    }
+}
+
+impl<N: Network> Debug for StateDiff<N> {
+    /// Prints the state diff as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for StateDiff<N> {
+    /// Displays the state diff as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
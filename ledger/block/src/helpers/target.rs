@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::Amount;
 use console::prelude::{ensure, Result};
 
 /// A safety bound (sanity-check) for the coinbase reward.
@@ -29,9 +30,13 @@ pub const fn block_reward(total_supply: u64, block_time: u16, coinbase_reward: u
     // Compute the annual reward: (0.05 * S).
     let annual_reward = total_supply / 20;
     // Compute the block reward: (0.05 * S) / H_Y1.
-    let block_reward = annual_reward / block_height_at_year_1 as u64;
+    let block_reward = Amount::from_microcredits(annual_reward / block_height_at_year_1 as u64);
     // Return the sum of the block reward, coinbase reward, and transaction fees.
-    block_reward + (coinbase_reward / 2) + transaction_fees
+    // Note: The sum saturates, as it must never wrap around to a smaller reward.
+    block_reward
+        .saturating_add(Amount::from_microcredits(puzzle_reward(coinbase_reward)))
+        .saturating_add(Amount::from_microcredits(transaction_fees))
+        .microcredits()
 }
 
 /// Calculate the puzzle reward, given the coinbase reward.
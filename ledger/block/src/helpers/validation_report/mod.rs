@@ -0,0 +1,263 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// A machine-readable reason code for a failed block validation check.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReasonCode {
+    /// The block hash or previous block hash is incorrect.
+    InvalidBlockHash,
+    /// The block authority is incorrect.
+    InvalidAuthority,
+    /// The block solutions are incorrect.
+    InvalidSolutions,
+    /// The block ratifications are incorrect.
+    InvalidRatifications,
+    /// The block does not contain any transactions.
+    MissingTransactions,
+    /// The block contains too many transactions.
+    TooManyTransactions,
+    /// The block contains too many aborted transaction IDs.
+    TooManyAbortedTransactions,
+    /// The block contains a duplicate transaction ID.
+    DuplicateTransactionID,
+    /// The block contains an expired execution.
+    ExpiredExecution,
+    /// The block contains a duplicate transition ID.
+    DuplicateTransitionID,
+    /// The block contains a duplicate program ID.
+    DuplicateProgramID,
+    /// The block contains a duplicate input ID.
+    DuplicateInputID,
+    /// The block contains a duplicate serial number.
+    DuplicateSerialNumber,
+    /// The block contains a duplicate tag.
+    DuplicateTag,
+    /// The block contains a duplicate output ID.
+    DuplicateOutputID,
+    /// The block contains a duplicate commitment.
+    DuplicateCommitment,
+    /// The block contains a duplicate nonce.
+    DuplicateNonce,
+    /// The block contains a duplicate transition public key.
+    DuplicateTransitionPublicKey,
+    /// The block contains a duplicate transition commitment.
+    DuplicateTransitionCommitment,
+    /// One of the block roots could not be computed.
+    RootComputationFailed,
+    /// The transactions root in the block header is incorrect.
+    TransactionsRootMismatch,
+    /// The finalize root in the block header is incorrect.
+    FinalizeRootMismatch,
+    /// The ratifications root in the block header is incorrect.
+    RatificationsRootMismatch,
+    /// The solutions root in the block header is incorrect.
+    SolutionsRootMismatch,
+    /// The subdag root in the block header is incorrect.
+    SubdagRootMismatch,
+    /// The receipts root in the block header is missing or incorrect.
+    ReceiptsRootMismatch,
+    /// The block header is otherwise incorrect.
+    InvalidHeader,
+}
+
+impl ReasonCode {
+    /// Returns the stable identifier of the reason code.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::InvalidBlockHash => "invalid_block_hash",
+            Self::InvalidAuthority => "invalid_authority",
+            Self::InvalidSolutions => "invalid_solutions",
+            Self::InvalidRatifications => "invalid_ratifications",
+            Self::MissingTransactions => "missing_transactions",
+            Self::TooManyTransactions => "too_many_transactions",
+            Self::TooManyAbortedTransactions => "too_many_aborted_transactions",
+            Self::DuplicateTransactionID => "duplicate_transaction_id",
+            Self::ExpiredExecution => "expired_execution",
+            Self::DuplicateTransitionID => "duplicate_transition_id",
+            Self::DuplicateProgramID => "duplicate_program_id",
+            Self::DuplicateInputID => "duplicate_input_id",
+            Self::DuplicateSerialNumber => "duplicate_serial_number",
+            Self::DuplicateTag => "duplicate_tag",
+            Self::DuplicateOutputID => "duplicate_output_id",
+            Self::DuplicateCommitment => "duplicate_commitment",
+            Self::DuplicateNonce => "duplicate_nonce",
+            Self::DuplicateTransitionPublicKey => "duplicate_transition_public_key",
+            Self::DuplicateTransitionCommitment => "duplicate_transition_commitment",
+            Self::RootComputationFailed => "root_computation_failed",
+            Self::TransactionsRootMismatch => "transactions_root_mismatch",
+            Self::FinalizeRootMismatch => "finalize_root_mismatch",
+            Self::RatificationsRootMismatch => "ratifications_root_mismatch",
+            Self::SolutionsRootMismatch => "solutions_root_mismatch",
+            Self::SubdagRootMismatch => "subdag_root_mismatch",
+            Self::ReceiptsRootMismatch => "receipts_root_mismatch",
+            Self::InvalidHeader => "invalid_header",
+        }
+    }
+}
+
+impl Display for ReasonCode {
+    /// Prints the reason code as its stable identifier.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A single failed check in a block validation report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationFailure {
+    /// The reason code of the failed check.
+    reason: ReasonCode,
+    /// The index of the offending transaction in the block, if the check applies to a single transaction.
+    index: Option<usize>,
+    /// The human-readable error message.
+    message: String,
+}
+
+impl ValidationFailure {
+    /// Returns the reason code of the failed check.
+    pub const fn reason(&self) -> ReasonCode {
+        self.reason
+    }
+
+    /// Returns the index of the offending transaction in the block, if any.
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Returns the human-readable error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ValidationFailure {
+    /// Prints the failure as `reason: message`, followed by the offending transaction index, if any.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.reason, self.message)?;
+        match self.index {
+            Some(index) => write!(f, " (transaction index {index})"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A report of every failed check from validating a block.
+///
+/// Unlike `Block::verify`, which returns the first error it encounters, building a report runs every check
+/// whose inputs are available, so a rejected block can be diagnosed in a single pass.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// The failed checks, in the order they were performed.
+    failures: Vec<ValidationFailure>,
+}
+
+impl ValidationReport {
+    /// Initializes a new, empty validation report.
+    pub const fn new() -> Self {
+        Self { failures: Vec::new() }
+    }
+
+    /// Records a failed check with the given reason code, offending transaction index, and message.
+    pub fn push(&mut self, reason: ReasonCode, index: Option<usize>, message: impl Into<String>) {
+        self.failures.push(ValidationFailure { reason, index, message: message.into() });
+    }
+
+    /// Returns the output of the given check, or records its error under the given reason code and returns `None`.
+    pub fn check<T>(&mut self, reason: ReasonCode, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(output) => Some(output),
+            Err(error) => {
+                self.push(reason, None, error.to_string());
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if every check passed.
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Returns the failed checks, in the order they were performed.
+    pub fn failures(&self) -> &[ValidationFailure] {
+        &self.failures
+    }
+
+    /// Returns `true` if a check with the given reason code failed.
+    pub fn contains(&self, reason: ReasonCode) -> bool {
+        self.failures.iter().any(|failure| failure.reason == reason)
+    }
+
+    /// Returns the error message of the first failed check, or `Ok(())` if every check passed.
+    pub fn ensure_valid(&self) -> Result<()> {
+        match self.failures.first() {
+            Some(failure) => bail!("{}", failure.message),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Display for ValidationReport {
+    /// Prints each failed check on its own line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.is_valid() {
+            true => write!(f, "The block is valid"),
+            false => {
+                for (i, failure) in self.failures.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{failure}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        let mut report = ValidationReport::new();
+        assert!(report.is_valid());
+        assert!(report.ensure_valid().is_ok());
+        assert_eq!(report.to_string(), "The block is valid");
+
+        // Record a failure with an index, and a failure from a result.
+        report.push(ReasonCode::DuplicateSerialNumber, Some(3), "Found a duplicate serial number in block 7");
+        assert_eq!(report.check(ReasonCode::InvalidAuthority, Err::<(), _>(anyhow!("Signature is invalid"))), None);
+        assert_eq!(report.check(ReasonCode::InvalidSolutions, Ok(5u8)), Some(5));
+
+        // Ensure every failure is retained in order.
+        assert!(!report.is_valid());
+        assert_eq!(report.failures().len(), 2);
+        assert_eq!(report.failures()[0].reason(), ReasonCode::DuplicateSerialNumber);
+        assert_eq!(report.failures()[0].index(), Some(3));
+        assert_eq!(report.failures()[1].index(), None);
+        assert!(report.contains(ReasonCode::InvalidAuthority));
+        assert!(!report.contains(ReasonCode::InvalidSolutions));
+
+        // Ensure the first failure is surfaced as the error.
+        assert_eq!(report.ensure_valid().unwrap_err().to_string(), "Found a duplicate serial number in block 7");
+        assert_eq!(
+            report.to_string(),
+            "duplicate_serial_number: Found a duplicate serial number in block 7 (transaction index 3)\ninvalid_authority: Signature is invalid"
+        );
+    }
+}
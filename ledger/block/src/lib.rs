@@ -316,6 +316,11 @@ impl<N: Network> Block<N> {
         self.header.cumulative_weight()
     }
 
+    /// Returns the chain work for this block.
+    pub const fn chain_work(&self) -> ChainWork {
+        self.header.chain_work()
+    }
+
     /// Returns the cumulative proof target for this block.
     pub const fn cumulative_proof_target(&self) -> u128 {
         self.header.cumulative_proof_target()
@@ -379,6 +384,14 @@ impl<N: Network> Block<N> {
     pub fn get_confirmed_transaction(&self, transaction_id: &N::TransactionID) -> Option<&ConfirmedTransaction<N>> {
         self.transactions.get(transaction_id)
     }
+
+    /// Returns the inclusion proof for the given transaction ID, which can be verified against the block header.
+    pub fn prove_transaction_inclusion(&self, transaction_id: &N::TransactionID) -> Result<InclusionProof<N>> {
+        // Compute the Merkle path for the transaction ID.
+        let transactions_path = self.transactions.to_path(*transaction_id)?;
+        // Return the inclusion proof.
+        Ok(InclusionProof::new(self.height(), *transaction_id, transactions_path))
+    }
 }
 
 impl<N: Network> Block<N> {
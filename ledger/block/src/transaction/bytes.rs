@@ -17,72 +17,83 @@ use super::*;
 impl<N: Network> FromBytes for Transaction<N> {
     /// Reads the transaction from the buffer.
     #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid transaction version"));
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        // Limit the reader to the maximum transaction size, so an oversized transaction is rejected
+        // as soon as it is read past the limit, before any of it is verified.
+        let mut reader = ByteReader::with_limit(reader, N::MAX_TRANSACTION_SIZE as u64);
+        match Self::read_bounded(&mut reader) {
+            Ok(transaction) => Ok(transaction),
+            // If the reader ran out of bytes at the limit, the transaction is too large.
+            Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof && reader.remaining() == Some(0) => {
+                Err(reader.error(format!("Transaction exceeds the maximum of {} bytes", N::MAX_TRANSACTION_SIZE)))
+            }
+            Err(error) => Err(error),
         }
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Reads the transaction from the given size-limited reader.
+    fn read_bounded<R: Read>(reader: &mut ByteReader<R>) -> IoResult<Self> {
+        // Read the version.
+        reader.read_version(1, "transaction")?;
 
         // Read the variant.
-        let variant = u8::read_le(&mut reader)?;
+        let variant = reader.read_u8()?;
         // Match the variant.
         let (id, transaction) = match variant {
             0 => {
                 // Read the ID.
-                let id = N::TransactionID::read_le(&mut reader)?;
+                let id: N::TransactionID = reader.read()?;
                 // Read the owner.
-                let owner = ProgramOwner::read_le(&mut reader)?;
+                let owner = reader.read::<ProgramOwner<N>>()?;
                 // Read the deployment.
-                let deployment = Deployment::read_le(&mut reader)?;
+                let deployment = reader.read::<Deployment<N>>()?;
                 // Read the fee.
-                let fee = Fee::read_le(&mut reader)?;
+                let fee = reader.read::<Fee<N>>()?;
 
                 // Initialize the transaction.
-                let transaction = Self::from_deployment(owner, deployment, fee).map_err(|e| error(e.to_string()))?;
+                let transaction = Self::from_deployment(owner, deployment, fee).map_err(|e| reader.error(e))?;
                 // Return the ID and the transaction.
                 (id, transaction)
             }
             1 => {
                 // Read the ID.
-                let id = N::TransactionID::read_le(&mut reader)?;
+                let id: N::TransactionID = reader.read()?;
                 // Read the execution.
-                let execution = Execution::read_le(&mut reader)?;
+                let execution = reader.read::<Execution<N>>()?;
 
-                // Read the fee variant.
-                let fee_variant = u8::read_le(&mut reader)?;
                 // Read the fee.
-                let fee = match fee_variant {
+                let fee = match reader.read_u8()? {
                     0u8 => None,
-                    1u8 => Some(Fee::read_le(&mut reader)?),
-                    _ => return Err(error("Invalid fee variant")),
+                    1u8 => Some(reader.read::<Fee<N>>()?),
+                    _ => return Err(reader.error("Invalid fee variant")),
                 };
 
                 // Initialize the transaction.
-                let transaction = Self::from_execution(execution, fee).map_err(|e| error(e.to_string()))?;
+                let transaction = Self::from_execution(execution, fee).map_err(|e| reader.error(e))?;
                 // Return the ID and the transaction.
                 (id, transaction)
             }
             2 => {
                 // Read the ID.
-                let id = N::TransactionID::read_le(&mut reader)?;
+                let id: N::TransactionID = reader.read()?;
                 // Read the fee.
-                let fee = Fee::read_le(&mut reader)?;
+                let fee = reader.read::<Fee<N>>()?;
 
                 // Initialize the transaction.
-                let transaction = Self::from_fee(fee).map_err(|e| error(e.to_string()))?;
+                let transaction = Self::from_fee(fee).map_err(|e| reader.error(e))?;
                 // Return the ID and the transaction.
                 (id, transaction)
             }
-            3.. => return Err(error("Invalid transaction variant")),
+            3.. => return Err(reader.error("Invalid transaction variant")),
         };
 
         // Ensure the transaction ID matches.
         match transaction.id() == id {
             // Return the transaction.
             true => Ok(transaction),
-            false => Err(error("Transaction ID mismatch")),
+            false => Err(reader.error("Transaction ID mismatch")),
         }
     }
 }
@@ -139,6 +150,7 @@ impl<N: Network> ToBytes for Transaction<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::program::{Identifier, ProgramID};
 
     #[test]
     fn test_bytes() -> Result<()> {
@@ -158,4 +170,40 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_exceeds_max_transaction_size() -> Result<()> {
+        type CurrentNetwork = console::network::Testnet3;
+
+        // Constructs the bytes of a fee transaction, up to a private input with a ciphertext of `num_fields` fields.
+        // Note: The bytes end inside the ciphertext, so they never form a complete transaction.
+        let fee_transaction_prefix = |num_fields: u16| -> Result<Vec<u8>> {
+            let zero = Field::<CurrentNetwork>::zero().to_bytes_le()?;
+            let mut bytes = vec![1u8, 2u8]; // The transaction version and the fee variant.
+            bytes.extend_from_slice(&zero); // The transaction ID.
+            bytes.extend_from_slice(&[1u8, 1u8]); // The fee version and the transition version.
+            bytes.extend_from_slice(&zero); // The transition ID.
+            bytes.extend(ProgramID::<CurrentNetwork>::from_str("credits.aleo")?.to_bytes_le()?);
+            bytes.extend(Identifier::<CurrentNetwork>::from_str("fee_public")?.to_bytes_le()?);
+            bytes.extend_from_slice(&[1u8, 2u8]); // The number of inputs and the private input variant.
+            bytes.extend_from_slice(&zero); // The ciphertext hash.
+            bytes.push(1u8); // The ciphertext is present.
+            bytes.extend(num_fields.to_bytes_le()?);
+            bytes.extend((0..num_fields).flat_map(|_| zero.clone()));
+            Ok(bytes)
+        };
+
+        // Ensure a transaction that is read past the maximum size is rejected for its size.
+        let num_fields = u16::try_from(CurrentNetwork::MAX_TRANSACTION_SIZE / 32)?;
+        let bytes = fee_transaction_prefix(num_fields)?;
+        assert!(bytes.len() > CurrentNetwork::MAX_TRANSACTION_SIZE);
+        let error = Transaction::<CurrentNetwork>::read_le(&bytes[..]).unwrap_err();
+        assert!(error.to_string().contains("exceeds the maximum"), "{error}");
+
+        // Ensure a truncated transaction within the maximum size is rejected for being truncated instead.
+        let bytes = fee_transaction_prefix(10)?;
+        let error = Transaction::<CurrentNetwork>::read_le(&bytes[..]).unwrap_err();
+        assert!(!error.to_string().contains("exceeds the maximum"), "{error}");
+        Ok(())
+    }
 }
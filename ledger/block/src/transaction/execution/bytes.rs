@@ -20,7 +20,7 @@ impl<N: Network> FromBytes for Execution<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid execution version"));
         }
         // Read the number of transitions.
@@ -29,6 +29,10 @@ impl<N: Network> FromBytes for Execution<N> {
         if num_transitions == 0 {
             return Err(error("Execution (from 'read_le') has no transitions"));
         }
+        // Ensure the number of transitions is within bounds (holding back 1 for the fee).
+        if num_transitions as usize >= N::MAX_TRANSITIONS {
+            return Err(error(format!("Execution (from 'read_le') exceeds {} transitions", N::MAX_TRANSITIONS - 1)));
+        }
         // Read the transitions.
         let transitions =
             (0..num_transitions).map(|_| Transition::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
@@ -42,16 +46,25 @@ impl<N: Network> FromBytes for Execution<N> {
             1 => Some(Proof::read_le(&mut reader)?),
             _ => return Err(error(format!("Invalid proof variant '{proof_variant}'"))),
         };
-        // Return the new `Execution` instance.
-        Self::from(transitions.into_iter(), global_state_root, proof).map_err(|e| error(e.to_string()))
+        // Construct the new `Execution` instance.
+        let execution =
+            Self::from(transitions.into_iter(), global_state_root, proof).map_err(|e| error(e.to_string()))?;
+        // Read the expiration height, which is only present from version 2.
+        match version {
+            1 => Ok(execution),
+            _ => Ok(execution.with_expiration(u32::read_le(&mut reader)?)),
+        }
     }
 }
 
 impl<N: Network> ToBytes for Execution<N> {
     /// Writes the execution to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Write the version. Note that executions without an expiration retain the version 1 layout.
+        match self.expires_at {
+            Some(_) => 2u8.write_le(&mut writer)?,
+            None => 1u8.write_le(&mut writer)?,
+        }
         // Write the number of transitions.
         (u8::try_from(self.transitions.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
         // Write the transitions.
@@ -68,6 +81,10 @@ impl<N: Network> ToBytes for Execution<N> {
                 proof.write_le(&mut writer)?;
             }
         }
+        // Write the expiration height, which is only present from version 2.
+        if let Some(expires_at) = self.expires_at {
+            expires_at.write_le(&mut writer)?;
+        }
         Ok(())
     }
 }
@@ -88,4 +105,44 @@ mod tests {
         assert_eq!(expected, Execution::read_le(&expected_bytes[..])?);
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_expiration() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new execution with an expiration.
+        let execution = crate::transaction::execution::test_helpers::sample_execution(rng);
+        let expected = execution.clone().with_expiration(10);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes[0], 2);
+        assert_eq!(expected, Execution::read_le(&expected_bytes[..])?);
+
+        // Ensure the expiration is committed in the execution ID.
+        assert_ne!(execution.to_execution_id()?, expected.to_execution_id()?);
+        assert_ne!(expected.to_execution_id()?, execution.with_expiration(11).to_execution_id()?);
+
+        // Ensure the expiration is enforced.
+        assert!(!expected.is_expired(10));
+        assert!(expected.is_expired(11));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_exceeds_max_transitions() -> Result<()> {
+        type CurrentNetwork = console::network::Testnet3;
+
+        // Construct the version and an out-of-bounds number of transitions.
+        // Note: The transitions are omitted, as the number of transitions is checked before they are read.
+        let bytes = [1u8, u8::try_from(CurrentNetwork::MAX_TRANSITIONS)?];
+        let error = Execution::<CurrentNetwork>::read_le(&bytes[..]).unwrap_err();
+        assert!(error.to_string().contains("exceeds"), "{error}");
+
+        // Ensure the maximum number of transitions passes the check, and fails on the missing transitions instead.
+        let bytes = [1u8, u8::try_from(CurrentNetwork::MAX_TRANSITIONS - 1)?];
+        let error = Execution::<CurrentNetwork>::read_le(&bytes[..]).unwrap_err();
+        assert!(!error.to_string().contains("exceeds"), "{error}");
+        Ok(())
+    }
 }
@@ -30,12 +30,14 @@ pub struct Execution<N: Network> {
     global_state_root: N::StateRoot,
     /// The proof.
     proof: Option<Proof<N>>,
+    /// The block height after which the execution may no longer be included in a block, if any.
+    expires_at: Option<u32>,
 }
 
 impl<N: Network> Execution<N> {
     /// Initialize a new `Execution` instance.
     pub fn new() -> Self {
-        Self { transitions: Default::default(), global_state_root: Default::default(), proof: None }
+        Self { transitions: Default::default(), global_state_root: Default::default(), proof: None, expires_at: None }
     }
 
     /// Initializes a new `Execution` instance with the given transitions.
@@ -45,13 +47,34 @@ impl<N: Network> Execution<N> {
         proof: Option<Proof<N>>,
     ) -> Result<Self> {
         // Construct the execution.
-        let execution = Self { transitions: transitions.map(|t| (*t.id(), t)).collect(), global_state_root, proof };
+        let execution = Self {
+            transitions: transitions.map(|t| (*t.id(), t)).collect(),
+            global_state_root,
+            proof,
+            expires_at: None,
+        };
         // Ensure the transitions are not empty.
         ensure!(!execution.transitions.is_empty(), "Execution cannot initialize from empty list of transitions");
         // Return the new `Execution` instance.
         Ok(execution)
     }
 
+    /// Returns the execution with the given expiration height, which is the last block height it may be included in.
+    ///
+    /// Note: The expiration is committed in the execution ID, so it must be set before the fee is authorized.
+    pub fn with_expiration(mut self, expires_at: u32) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Returns the execution with its proof written in the byte layout of the given version.
+    ///
+    /// Note: The proof version is not committed in the execution ID.
+    pub fn with_proof_version(mut self, version: u8) -> Result<Self> {
+        self.proof = self.proof.map(|proof| proof.with_version(version)).transpose()?;
+        Ok(self)
+    }
+
     /// Returns the size in bytes.
     pub fn size_in_bytes(&self) -> Result<u64> {
         Ok(u64::try_from(self.to_bytes_le()?.len())?)
@@ -67,9 +90,25 @@ impl<N: Network> Execution<N> {
         self.proof.as_ref()
     }
 
+    /// Returns the expiration height, if the execution has one.
+    pub const fn expires_at(&self) -> Option<u32> {
+        self.expires_at
+    }
+
+    /// Returns `true` if the execution may no longer be included in a block at the given height.
+    pub fn is_expired(&self, block_height: u32) -> bool {
+        self.expires_at.map_or(false, |expires_at| block_height > expires_at)
+    }
+
     /// Returns the execution ID.
     pub fn to_execution_id(&self) -> Result<Field<N>> {
-        Ok(*Transaction::execution_tree(self, &None)?.root())
+        // Compute the root of the execution tree.
+        let root = *Transaction::execution_tree(self, &None)?.root();
+        // If the execution has an expiration, commit to it in the execution ID.
+        match self.expires_at {
+            Some(expires_at) => N::hash_bhp512(&to_bits_le![root, expires_at]),
+            None => Ok(root),
+        }
     }
 }
 
@@ -19,13 +19,19 @@ impl<N: Network> Serialize for Execution<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut execution = serializer.serialize_struct("Execution", 2 + self.proof.is_some() as usize)?;
+                let mut execution = serializer.serialize_struct(
+                    "Execution",
+                    2 + self.proof.is_some() as usize + self.expires_at.is_some() as usize,
+                )?;
                 execution
                     .serialize_field("transitions", &self.transitions.values().collect::<Vec<&Transition<N>>>())?;
                 execution.serialize_field("global_state_root", &self.global_state_root)?;
                 if let Some(proof) = &self.proof {
                     execution.serialize_field("proof", proof)?;
                 }
+                if let Some(expires_at) = &self.expires_at {
+                    execution.serialize_field("expires_at", expires_at)?;
+                }
                 execution.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -48,8 +54,18 @@ impl<'de, N: Network> Deserialize<'de> for Execution<N> {
                 let proof =
                     serde_json::from_value(execution.get_mut("proof").unwrap_or(&mut serde_json::Value::Null).take())
                         .map_err(de::Error::custom)?;
+                // Retrieve the expiration height.
+                let expires_at: Option<u32> = serde_json::from_value(
+                    execution.get_mut("expires_at").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
                 // Recover the execution.
-                Self::from(transitions.into_iter(), global_state_root, proof).map_err(de::Error::custom)
+                let execution =
+                    Self::from(transitions.into_iter(), global_state_root, proof).map_err(de::Error::custom)?;
+                Ok(match expires_at {
+                    Some(expires_at) => execution.with_expiration(expires_at),
+                    None => execution,
+                })
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "execution"),
         }
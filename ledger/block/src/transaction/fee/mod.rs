@@ -16,7 +16,7 @@ mod bytes;
 mod serialize;
 mod string;
 
-use crate::{Input, Output, Transition};
+use crate::{Amount, Input, Output, Transition};
 use console::{
     network::prelude::*,
     program::{Argument, Literal, Plaintext},
@@ -52,6 +52,12 @@ impl<N: Network> Fee<N> {
     ) -> Self {
         Self { transition, global_state_root, proof }
     }
+
+    /// Returns the fee with its proof written in the byte layout of the given version.
+    pub fn with_proof_version(mut self, version: u8) -> Result<Self> {
+        self.proof = self.proof.map(|proof| proof.with_version(version)).transpose()?;
+        Ok(self)
+    }
 }
 
 impl<N: Network> Fee<N> {
@@ -93,8 +99,10 @@ impl<N: Network> Fee<N> {
         // Retrieve the priority fee amount.
         let priority_fee_amount = self.priority_amount()?;
         // Return the amount.
-        // Note: Use of saturating add is safe, as the sum cannot exceed a u64.
-        Ok(U64::new(base_fee_amount.saturating_add(*priority_fee_amount)))
+        match Amount::from(base_fee_amount).checked_add(Amount::from(priority_fee_amount)) {
+            Some(amount) => Ok(amount.into()),
+            None => bail!("The fee amount overflowed (base: {base_fee_amount}, priority: {priority_fee_amount})"),
+        }
     }
 
     /// Returns the base amount (in microcredits).
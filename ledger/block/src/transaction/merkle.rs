@@ -16,7 +16,7 @@ use super::*;
 
 impl<N: Network> Transaction<N> {
     /// The maximum number of transitions allowed in a transaction.
-    const MAX_TRANSITIONS: usize = usize::pow(2, TRANSACTION_DEPTH as u32);
+    const MAX_TRANSITIONS: usize = N::MAX_TRANSITIONS;
 
     /// Returns the transaction root, by computing the root for a Merkle tree of the transition IDs.
     pub fn to_root(&self) -> Result<Field<N>> {
@@ -91,11 +91,15 @@ impl<N: Network> Transaction<N> {
     pub fn to_tree(&self) -> Result<TransactionTree<N>> {
         match self {
             // Compute the deployment tree.
-            Transaction::Deploy(_, _, deployment, fee) => Self::deployment_tree(deployment, Some(fee)),
+            Transaction::Deploy(_, _, deployment, fee) => {
+                Self::with_proof_version(Self::deployment_tree(deployment, Some(fee))?, None, Some(fee))
+            }
             // Compute the execution tree.
-            Transaction::Execute(_, execution, fee) => Self::execution_tree(execution, fee),
+            Transaction::Execute(_, execution, fee) => {
+                Self::with_proof_version(Self::execution_tree(execution, fee)?, Some(execution), fee.as_ref())
+            }
             // Compute the fee tree.
-            Transaction::Fee(_, fee) => Self::fee_tree(fee),
+            Transaction::Fee(_, fee) => Self::with_proof_version(Self::fee_tree(fee)?, None, Some(fee)),
         }
     }
 }
@@ -135,38 +139,63 @@ impl<N: Network> Transaction<N> {
     }
 
     /// Returns the Merkle tree for the given execution.
+    ///
+    /// If the execution has an expiration, the expiration height is committed in a leaf after the fee,
+    /// so that executions which differ only in their expiration have distinct transaction IDs.
     pub fn execution_tree(execution: &Execution<N>, fee: &Option<Fee<N>>) -> Result<TransactionTree<N>> {
-        Self::transitions_tree(execution.transitions(), fee)
+        Self::transitions_tree_with_expiration(execution.transitions(), fee, execution.expires_at())
     }
 
     /// Returns the Merkle tree for the given transitions.
     pub fn transitions_tree<'a>(
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         fee: &Option<Fee<N>>,
+    ) -> Result<TransactionTree<N>> {
+        Self::transitions_tree_with_expiration(transitions, fee, None)
+    }
+
+    /// Returns the Merkle tree for the given transitions, and the given expiration height, if there is one.
+    fn transitions_tree_with_expiration<'a>(
+        transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
+        fee: &Option<Fee<N>>,
+        expires_at: Option<u32>,
     ) -> Result<TransactionTree<N>> {
         // Retrieve the number of transitions.
         let num_transitions = transitions.len();
         // Ensure the number of leaves is within the Merkle tree size.
         Self::check_execution_size(num_transitions)?;
         // Prepare the leaves.
-        let leaves = transitions.enumerate().map(|(index, transition)| {
-            // Construct the transaction leaf.
-            Ok::<_, Error>(TransactionLeaf::new_execution(u16::try_from(index)?, **transition.id()).to_bits_le())
-        });
-        // If the fee is present, add it to the leaves.
-        let leaves = match fee {
-            Some(fee) => {
+        let mut leaves = transitions
+            .enumerate()
+            .map(|(index, transition)| {
                 // Construct the transaction leaf.
-                let leaf = TransactionLeaf::new_fee(
-                    u16::try_from(num_transitions)?, // The last index.
-                    **fee.transition_id(),
-                )
-                .to_bits_le();
-                // Add the leaf to the leaves.
-                leaves.chain([Ok(leaf)].into_iter()).collect::<Result<Vec<_>, _>>()?
-            }
-            None => leaves.collect::<Result<Vec<_>, _>>()?,
-        };
+                Ok::<_, Error>(TransactionLeaf::new_execution(u16::try_from(index)?, **transition.id()).to_bits_le())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // If the fee is present, add it to the leaves.
+        if let Some(fee) = fee {
+            // Construct the transaction leaf.
+            let leaf = TransactionLeaf::new_fee(
+                u16::try_from(num_transitions)?, // The last index.
+                **fee.transition_id(),
+            )
+            .to_bits_le();
+            // Add the leaf to the leaves.
+            leaves.push(leaf);
+        }
+        // If the expiration is present, add it to the leaves.
+        if let Some(expires_at) = expires_at {
+            // Ensure there is space for the expiration leaf.
+            ensure!(
+                leaves.len() < Self::MAX_TRANSITIONS,
+                "Execution with an expiration must contain less than {} transitions, found {num_transitions}",
+                Self::MAX_TRANSITIONS - usize::from(fee.is_some()),
+            );
+            // Construct the transaction leaf.
+            let leaf = TransactionLeaf::<N>::new_expiration(u16::try_from(leaves.len())?, expires_at).to_bits_le();
+            // Add the leaf to the leaves.
+            leaves.push(leaf);
+        }
 
         // Compute the execution tree.
         N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&leaves)
@@ -180,6 +209,41 @@ impl<N: Network> Transaction<N> {
         N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&[leaf])
     }
 
+    /// Returns the given transaction tree, with a leaf for the version of the proof layout in the given execution and fee.
+    ///
+    /// The leaf is omitted for the version 1 layout, so that the IDs of existing transactions are unchanged.
+    /// Note: The version is not committed in the tree of an execution, so that the execution ID of an authorization
+    /// matches the execution ID of its proven execution.
+    pub fn with_proof_version(
+        mut tree: TransactionTree<N>,
+        execution: Option<&Execution<N>>,
+        fee: Option<&Fee<N>>,
+    ) -> Result<TransactionTree<N>> {
+        // Retrieve the versions of the proofs.
+        let proofs = execution.and_then(|execution| execution.proof()).into_iter();
+        let mut versions = proofs.chain(fee.and_then(|fee| fee.proof())).map(|proof| proof.version());
+        // Ensure the proofs are in the same version.
+        let version = versions.next().unwrap_or(1);
+        ensure!(versions.all(|candidate| candidate == version), "Transaction proofs must have the same version");
+
+        // If the proofs are not in the version 1 layout, add the version to the leaves.
+        if version != 1 {
+            // Retrieve the number of leaves.
+            let num_leaves = tree.number_of_leaves();
+            // Ensure there is space for the proof version leaf.
+            ensure!(
+                num_leaves < Self::MAX_TRANSITIONS,
+                "Transaction with a version {version} proof must contain less than {} leaves, found {num_leaves}",
+                Self::MAX_TRANSITIONS
+            );
+            // Construct the transaction leaf.
+            let leaf = TransactionLeaf::<N>::new_proof_version(u16::try_from(num_leaves)?, version).to_bits_le();
+            // Add the leaf to the tree.
+            tree.append(&[leaf])?;
+        }
+        Ok(tree)
+    }
+
     /// Returns `true` if the deployment is within the size bounds.
     pub fn check_deployment_size(deployment: &Deployment<N>) -> Result<()> {
         // Retrieve the program.
@@ -213,9 +277,59 @@ impl<N: Network> Transaction<N> {
         // Ensure the number of functions is within the allowed range.
         ensure!(
             num_transitions < Self::MAX_TRANSITIONS, // Note: Observe we hold back 1 for the fee.
-            "Execution must contain less than {num_transitions} transitions, found {}",
+            "Execution must contain less than {} transitions, found {num_transitions}",
             Self::MAX_TRANSITIONS,
         );
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_transaction_depth() {
+        // Ensure the log2 relationship between depth and the maximum number of transitions.
+        assert_eq!(2usize.pow(TRANSACTION_DEPTH as u32), Transaction::<CurrentNetwork>::MAX_TRANSITIONS);
+    }
+
+    #[test]
+    fn test_execution_tree_with_expiration() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an execution.
+        let execution = crate::transaction::execution::test_helpers::sample_execution(rng);
+
+        // Ensure the expiration is committed in the transaction ID.
+        let id = Transaction::from_execution(execution.clone(), None)?.id();
+        let id_10 = Transaction::from_execution(execution.clone().with_expiration(10), None)?.id();
+        let id_11 = Transaction::from_execution(execution.with_expiration(11), None)?.id();
+        assert_ne!(id, id_10);
+        assert_ne!(id_10, id_11);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transaction_tree_with_proof_version() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an execution.
+        let execution = crate::transaction::execution::test_helpers::sample_execution(rng);
+        assert_eq!(execution.proof().map(|proof| proof.version()), Some(1));
+
+        // Ensure the version 1 layout is not committed in the transaction ID.
+        let transaction = Transaction::from_execution(execution.clone(), None)?;
+        assert_eq!(*transaction.id(), *Transaction::execution_tree(&execution, &None)?.root());
+        // Ensure the version 2 layout is committed in the transaction ID.
+        let rewritten = Transaction::from_execution(execution.clone().with_proof_version(2)?, None)?;
+        assert_ne!(rewritten.id(), transaction.id());
+        assert_eq!(*rewritten.id(), rewritten.to_root()?);
+        // Ensure the execution ID does not commit to the proof version.
+        assert_eq!(rewritten.execution().unwrap().to_execution_id()?, execution.to_execution_id()?);
+        Ok(())
+    }
+}
@@ -49,7 +49,8 @@ impl<N: Network> Transaction<N> {
         // Ensure the transaction is not empty.
         ensure!(!deployment.program().functions().is_empty(), "Attempted to create an empty deployment transaction");
         // Compute the transaction ID.
-        let id = *Self::deployment_tree(&deployment, Some(&fee))?.root();
+        let tree = Self::deployment_tree(&deployment, Some(&fee))?;
+        let id = *Self::with_proof_version(tree, None, Some(&fee))?.root();
         // Compute the deployment ID.
         let deployment_id = deployment.to_deployment_id()?;
         // Ensure the owner signed the correct transaction ID.
@@ -63,7 +64,8 @@ impl<N: Network> Transaction<N> {
         // Ensure the transaction is not empty.
         ensure!(!execution.is_empty(), "Attempted to create an empty execution transaction");
         // Compute the transaction ID.
-        let id = *Self::execution_tree(&execution, &fee)?.root();
+        let tree = Self::execution_tree(&execution, &fee)?;
+        let id = *Self::with_proof_version(tree, Some(&execution), fee.as_ref())?.root();
         // Construct the execution transaction.
         Ok(Self::Execute(id.into(), execution, fee))
     }
@@ -73,7 +75,8 @@ impl<N: Network> Transaction<N> {
         // Ensure the fee is nonzero.
         ensure!(!fee.is_zero()?, "Attempted to create a zero fee transaction");
         // Compute the transaction ID.
-        let id = *Self::fee_tree(&fee)?.root();
+        let tree = Self::fee_tree(&fee)?;
+        let id = *Self::with_proof_version(tree, None, Some(&fee))?.root();
         // Construct the execution transaction.
         Ok(Self::Fee(id.into(), fee))
     }
@@ -97,9 +100,30 @@ impl<N: Network> Transaction<N> {
     pub const fn is_fee(&self) -> bool {
         matches!(self, Self::Fee(..))
     }
+
+    /// Returns the size in bytes.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        Ok(u64::try_from(self.to_bytes_le()?.len())?)
+    }
 }
 
 impl<N: Network> Transaction<N> {
+    /// Returns the expiration height of the transaction, if it has one.
+    pub fn expires_at(&self) -> Option<u32> {
+        match self {
+            Self::Execute(_, execution, _) => execution.expires_at(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the transaction may no longer be included in a block at the given height.
+    pub fn is_expired(&self, block_height: u32) -> bool {
+        match self {
+            Self::Execute(_, execution, _) => execution.is_expired(block_height),
+            _ => false,
+        }
+    }
+
     /// Returns `true` if this transaction contains a call to `credits.aleo/split`.
     #[inline]
     pub fn contains_split(&self) -> bool {
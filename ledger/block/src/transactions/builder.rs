@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::network::BHPMerkleTree;
+
+/// A builder that incrementally maintains the roots of the block transactions as confirmed transactions are added,
+/// so that a block template can be finalized without recomputing the Merkle trees from scratch.
+#[derive(Clone)]
+pub struct TransactionsBuilder<N: Network> {
+    /// The confirmed transactions.
+    transactions: IndexMap<N::TransactionID, ConfirmedTransaction<N>>,
+    /// The Merkle tree of the transaction IDs.
+    transactions_tree: TransactionsTree<N>,
+    /// The Merkle tree of the finalize operations, excluding the ratified finalize operations.
+    finalize_tree: BHPMerkleTree<N, FINALIZE_OPERATIONS_DEPTH>,
+    /// The Merkle tree of the execution receipt hashes.
+    receipts_tree: ReceiptsTree<N>,
+}
+
+impl<N: Network> TransactionsBuilder<N> {
+    /// Initializes a new, empty transactions builder.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            transactions: IndexMap::new(),
+            transactions_tree: N::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&[])?,
+            finalize_tree: N::merkle_tree_bhp::<FINALIZE_OPERATIONS_DEPTH>(&[])?,
+            receipts_tree: N::merkle_tree_bhp::<RECEIPTS_DEPTH>(&[])?,
+        })
+    }
+
+    /// Appends the given confirmed transaction, updating the roots.
+    pub fn push(&mut self, transaction: ConfirmedTransaction<N>) -> Result<()> {
+        let transaction_id = transaction.id();
+        // Ensure the transaction is not a duplicate.
+        ensure!(!self.transactions.contains_key(&transaction_id), "Duplicate transaction '{transaction_id}'");
+        // Ensure the number of transactions is within the allowed range.
+        ensure!(
+            self.transactions.len() < Transactions::<N>::MAX_TRANSACTIONS,
+            "Block cannot exceed {} transactions",
+            Transactions::<N>::MAX_TRANSACTIONS
+        );
+
+        // Prepare the updated transactions tree.
+        let transactions_tree = self.transactions_tree.prepare_append(&[transaction_id.to_bits_le()])?;
+        // Prepare the updated finalize tree.
+        let leaves = transaction.finalize_operations().iter().map(ToBits::to_bits_le).collect::<Vec<_>>();
+        let finalize_tree = match leaves.is_empty() {
+            true => self.finalize_tree.clone(),
+            false => self.finalize_tree.prepare_append(&leaves)?,
+        };
+        // Prepare the updated receipts tree.
+        let leaves = ExecutionReceipt::from_confirmed(&transaction)?
+            .iter()
+            .map(|receipt| Ok(receipt.to_hash()?.to_bits_le()))
+            .collect::<Result<Vec<_>>>()?;
+        let receipts_tree = match leaves.is_empty() {
+            true => self.receipts_tree.clone(),
+            false => self.receipts_tree.prepare_append(&leaves)?,
+        };
+
+        // Update the builder at the very end, so it is not altered in case of failure.
+        self.transactions.insert(transaction_id, transaction);
+        self.transactions_tree = transactions_tree;
+        self.finalize_tree = finalize_tree;
+        self.receipts_tree = receipts_tree;
+        Ok(())
+    }
+
+    /// Returns the number of confirmed transactions.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if there are no confirmed transactions.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Returns the transactions root.
+    pub fn transactions_root(&self) -> Field<N> {
+        *self.transactions_tree.root()
+    }
+
+    /// Returns the finalize root, given the ratified finalize operations of the block.
+    pub fn finalize_root(&self, ratified_finalize_operations: &[FinalizeOperation<N>]) -> Result<Field<N>> {
+        // Prepare the leaves.
+        let leaves = ratified_finalize_operations.iter().map(ToBits::to_bits_le).collect::<Vec<_>>();
+        // Append the ratified finalize operations, which come after the finalize operations of the transactions.
+        match leaves.is_empty() {
+            true => Ok(*self.finalize_tree.root()),
+            false => Ok(*self.finalize_tree.prepare_append(&leaves)?.root()),
+        }
+    }
+
+    /// Returns the receipts root.
+    pub fn receipts_root(&self) -> Field<N> {
+        *self.receipts_tree.root()
+    }
+
+    /// Returns the confirmed transactions.
+    pub fn finish(self) -> Transactions<N> {
+        Transactions { transactions: self.transactions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_builder_matches_transactions_roots() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        let expected = crate::transactions::test_helpers::sample_block_transactions(rng);
+
+        // Build the transactions incrementally, checking the roots after each transaction.
+        let mut builder = TransactionsBuilder::<CurrentNetwork>::new()?;
+        assert_eq!(builder.transactions_root(), Transactions::<CurrentNetwork>::from(&[]).to_transactions_root()?);
+        for (index, transaction) in expected.iter().enumerate() {
+            builder.push(transaction.clone())?;
+
+            let candidate = Transactions::from(&expected.iter().take(index + 1).cloned().collect::<Vec<_>>());
+            assert_eq!(builder.transactions_root(), candidate.to_transactions_root()?);
+            assert_eq!(builder.finalize_root(&[])?, candidate.to_finalize_root(vec![])?);
+            assert_eq!(builder.receipts_root(), candidate.to_receipts_root()?);
+        }
+
+        // Ensure duplicate transactions are rejected.
+        assert!(builder.push(expected.iter().next().unwrap().clone()).is_err());
+
+        // Ensure the builder finishes with the expected transactions.
+        assert_eq!(builder.len(), expected.len());
+        assert_eq!(builder.finish(), expected);
+        Ok(())
+    }
+}
@@ -17,21 +17,15 @@ use super::*;
 impl<N: Network> FromBytes for Transactions<N> {
     /// Reads the transactions from buffer.
     #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let mut reader = ByteReader::new(reader);
+
         // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid transactions version"));
-        }
+        reader.read_version(1, "transactions")?;
         // Read the number of transactions.
-        let num_txs: u32 = FromBytes::read_le(&mut reader)?;
-        // Ensure the number of transactions is within bounds.
-        if num_txs as usize > Self::MAX_TRANSACTIONS {
-            return Err(error("Failed to read transactions: too many transactions"));
-        }
+        let num_txs = reader.read_length_u32(Self::MAX_TRANSACTIONS, "transactions")?;
         // Read the transactions.
-        let transactions = (0..num_txs).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        let transactions = (0..num_txs).map(|_| reader.read()).collect::<Result<Vec<_>, _>>()?;
         // Return the transactions.
         Ok(Self::from(&transactions))
     }
@@ -0,0 +1,268 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+impl<N: Network> Transactions<N> {
+    /// Returns the graph of the dependencies between the transactions in `self`,
+    /// where a transaction depends on each transaction that produces a record it consumes.
+    ///
+    /// A serial number does not reveal the commitment of the record it consumes, without the view key of the
+    /// record owner. The given `commitment_of` returns the commitment of the record consumed by a serial number,
+    /// if it is known to the caller, such as the block builder for chained transactions.
+    pub fn dependency_graph<F: Fn(&Field<N>) -> Option<Field<N>>>(&self, commitment_of: F) -> DependencyGraph<N> {
+        DependencyGraph::new(
+            self.iter()
+                .map(|tx| (tx.id(), tx.serial_numbers().copied().collect(), tx.commitments().copied().collect())),
+            commitment_of,
+        )
+    }
+}
+
+/// The graph of the dependencies between the transactions in a block.
+///
+/// The transactions are indexed in block order.
+/// A transaction depends on another if it consumes a record that the other produces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyGraph<N: Network> {
+    /// The transaction IDs, in block order.
+    transaction_ids: Vec<N::TransactionID>,
+    /// The indices of the transactions that each transaction depends on.
+    dependencies: Vec<IndexSet<usize>>,
+    /// The indices of the transactions that depend on each transaction.
+    dependents: Vec<IndexSet<usize>>,
+}
+
+impl<N: Network> DependencyGraph<N> {
+    /// Initializes the dependency graph, from the transaction IDs, serial numbers, and commitments in block order,
+    /// and the commitment of the record consumed by each known serial number.
+    pub(crate) fn new<F: Fn(&Field<N>) -> Option<Field<N>>>(
+        transactions: impl Iterator<Item = (N::TransactionID, Vec<Field<N>>, Vec<Field<N>>)>,
+        commitment_of: F,
+    ) -> Self {
+        let mut transaction_ids = Vec::new();
+        let mut serial_numbers = Vec::new();
+        // Index the transactions by the commitments they produce.
+        let mut producers = IndexMap::new();
+        for (index, (transaction_id, tx_serial_numbers, tx_commitments)) in transactions.enumerate() {
+            transaction_ids.push(transaction_id);
+            serial_numbers.push(tx_serial_numbers);
+            for commitment in tx_commitments {
+                producers.entry(commitment).or_insert(index);
+            }
+        }
+
+        // Add an edge from the producer of each consumed record, to its consumer.
+        let mut dependencies = vec![IndexSet::new(); transaction_ids.len()];
+        let mut dependents = vec![IndexSet::new(); transaction_ids.len()];
+        for (consumer, tx_serial_numbers) in serial_numbers.iter().enumerate() {
+            for serial_number in tx_serial_numbers {
+                match commitment_of(serial_number).and_then(|commitment| producers.get(&commitment)) {
+                    // Note: A transaction that consumes its own record is not a dependency between transactions.
+                    Some(&producer) if producer != consumer => {
+                        dependencies[consumer].insert(producer);
+                        dependents[producer].insert(consumer);
+                    }
+                    _ => (),
+                }
+            }
+        }
+        Self { transaction_ids, dependencies, dependents }
+    }
+
+    /// Returns the number of transactions in the graph.
+    pub fn len(&self) -> usize {
+        self.transaction_ids.len()
+    }
+
+    /// Returns `true` if the graph contains no transactions.
+    pub fn is_empty(&self) -> bool {
+        self.transaction_ids.is_empty()
+    }
+
+    /// Returns the transaction IDs, in block order.
+    pub fn transaction_ids(&self) -> &[N::TransactionID] {
+        &self.transaction_ids
+    }
+
+    /// Returns the indices of the transactions that the given transaction depends on.
+    pub fn dependencies(&self, index: usize) -> Option<&IndexSet<usize>> {
+        self.dependencies.get(index)
+    }
+
+    /// Returns the indices of the transactions that depend on the given transaction.
+    pub fn dependents(&self, index: usize) -> Option<&IndexSet<usize>> {
+        self.dependents.get(index)
+    }
+
+    /// Returns the number of dependencies in the graph.
+    pub fn num_dependencies(&self) -> usize {
+        self.dependencies.iter().map(IndexSet::len).sum()
+    }
+
+    /// Returns `true` if every transaction comes after the transactions it depends on, in block order.
+    pub fn is_ordered(&self) -> bool {
+        self.dependencies.iter().enumerate().all(|(index, dependencies)| dependencies.iter().all(|dep| *dep < index))
+    }
+
+    /// Returns the indices of the transactions, grouped into levels that are executed in order, where the
+    /// transactions in a level only depend on the transactions in earlier levels, and may be executed in parallel.
+    /// Within a level, the transactions are in block order.
+    ///
+    /// Returns an error if the dependencies contain a cycle.
+    pub fn levels(&self) -> Result<Vec<Vec<usize>>> {
+        // Initialize the number of unresolved dependencies of each transaction.
+        let mut num_unresolved = self.dependencies.iter().map(IndexSet::len).collect::<Vec<_>>();
+        // Initialize the first level, with the transactions that have no dependencies.
+        let mut level = (0..self.len()).filter(|index| num_unresolved[*index] == 0).collect::<Vec<_>>();
+
+        let mut levels = Vec::new();
+        let mut num_resolved = 0;
+        while !level.is_empty() {
+            // Resolve the dependencies on the current level, and collect the transactions of the next level.
+            let mut next_level = Vec::new();
+            for index in &level {
+                for dependent in &self.dependents[*index] {
+                    num_unresolved[*dependent] -= 1;
+                    if num_unresolved[*dependent] == 0 {
+                        next_level.push(*dependent);
+                    }
+                }
+            }
+            next_level.sort_unstable();
+            num_resolved += level.len();
+            levels.push(core::mem::replace(&mut level, next_level));
+        }
+
+        // Ensure every transaction is resolved.
+        ensure!(num_resolved == self.len(), "The transaction dependencies contain a cycle");
+        Ok(levels)
+    }
+
+    /// Returns the transaction indices in a topological order, where every transaction comes after the
+    /// transactions it depends on. Transactions are kept in block order, wherever the dependencies allow.
+    ///
+    /// Returns an error if the dependencies contain a cycle.
+    pub fn topological_order(&self) -> Result<Vec<usize>> {
+        // Initialize the number of unresolved dependencies of each transaction.
+        let mut num_unresolved = self.dependencies.iter().map(IndexSet::len).collect::<Vec<_>>();
+        // Initialize the transactions that are ready, ordered by their index.
+        let mut ready = (0..self.len())
+            .filter(|index| num_unresolved[*index] == 0)
+            .map(core::cmp::Reverse)
+            .collect::<std::collections::BinaryHeap<_>>();
+
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(core::cmp::Reverse(index)) = ready.pop() {
+            order.push(index);
+            for dependent in &self.dependents[index] {
+                num_unresolved[*dependent] -= 1;
+                if num_unresolved[*dependent] == 0 {
+                    ready.push(core::cmp::Reverse(*dependent));
+                }
+            }
+        }
+
+        // Ensure every transaction is ordered.
+        ensure!(order.len() == self.len(), "The transaction dependencies contain a cycle");
+        Ok(order)
+    }
+
+    /// Returns the transaction IDs in a topological order, as given by [`DependencyGraph::topological_order`].
+    pub fn topological_transaction_ids(&self) -> Result<Vec<N::TransactionID>> {
+        Ok(self.topological_order()?.into_iter().map(|index| self.transaction_ids[index]).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    /// Returns the dependency graph, where the `i`-th transaction consumes the records of the given producers.
+    fn sample_graph(consumed: &[&[usize]], rng: &mut TestRng) -> DependencyGraph<CurrentNetwork> {
+        // Sample a commitment for each transaction, and a serial number for each consumed record.
+        let commitments = (0..consumed.len()).map(|_| Field::rand(rng)).collect::<Vec<_>>();
+        let mut links = IndexMap::new();
+        let transactions = consumed
+            .iter()
+            .zip(&commitments)
+            .map(|(producers, commitment)| {
+                let serial_numbers = producers
+                    .iter()
+                    .map(|producer| {
+                        let serial_number = Field::rand(rng);
+                        links.insert(serial_number, commitments[*producer]);
+                        serial_number
+                    })
+                    .collect::<Vec<_>>();
+                // Include a serial number of a record that is not produced in the block.
+                let unknown = vec![Field::rand(rng)];
+                (<CurrentNetwork as Network>::TransactionID::rand(rng), [serial_numbers, unknown].concat(), vec![
+                    *commitment,
+                ])
+            })
+            .collect::<Vec<_>>();
+        DependencyGraph::new(transactions.into_iter(), |serial_number| links.get(serial_number).copied())
+    }
+
+    #[test]
+    fn test_dependency_graph() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the graph: 0 <- 2, 1 <- 2, 2 <- 3, and 4 is independent.
+        let graph = sample_graph(&[&[], &[], &[0, 1], &[2], &[]], rng);
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.num_dependencies(), 3);
+        assert_eq!(graph.dependencies(2).unwrap(), &IndexSet::from([0, 1]));
+        assert_eq!(graph.dependents(2).unwrap(), &IndexSet::from([3]));
+        assert!(graph.dependencies(5).is_none());
+        assert!(graph.is_ordered());
+
+        assert_eq!(graph.topological_order().unwrap(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(graph.levels().unwrap(), vec![vec![0, 1, 4], vec![2], vec![3]]);
+        assert_eq!(graph.topological_transaction_ids().unwrap(), graph.transaction_ids());
+    }
+
+    #[test]
+    fn test_dependency_graph_reorders() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a graph, where 0 consumes the record of 2, and 1 consumes its own record.
+        let graph = sample_graph(&[&[2], &[1], &[]], rng);
+        assert_eq!(graph.num_dependencies(), 1);
+        assert!(!graph.is_ordered());
+        assert_eq!(graph.topological_order().unwrap(), vec![1, 2, 0]);
+        assert_eq!(graph.levels().unwrap(), vec![vec![1, 2], vec![0]]);
+    }
+
+    #[test]
+    fn test_dependency_graph_cycle() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a cycle is rejected.
+        let graph = sample_graph(&[&[1], &[2], &[0], &[]], rng);
+        assert!(graph.topological_order().is_err());
+        assert!(graph.levels().is_err());
+
+        // Ensure an empty graph is ordered.
+        let graph = sample_graph(&[], rng);
+        assert!(graph.is_empty());
+        assert!(graph.topological_order().unwrap().is_empty());
+        assert!(graph.levels().unwrap().is_empty());
+    }
+}
@@ -27,6 +27,42 @@ impl<N: Network> Transactions<N> {
     }
 }
 
+impl<N: Network> Transactions<N> {
+    /// The maximum number of execution receipts allowed in a block.
+    pub const MAX_RECEIPTS: usize = usize::pow(2, RECEIPTS_DEPTH as u32);
+
+    /// Returns the receipts root, by computing the root for a Merkle tree of the execution receipt hashes.
+    pub fn to_receipts_root(&self) -> Result<Field<N>> {
+        Ok(*Self::receipts_tree(&self.to_receipts()?)?.root())
+    }
+
+    /// Returns the Merkle path for the execution receipt of the given transition ID.
+    pub fn to_receipt_path(&self, transition_id: N::TransitionID) -> Result<ReceiptsPath<N>> {
+        // Compute the execution receipts.
+        let receipts = self.to_receipts()?;
+        // Find the execution receipt.
+        match receipts.iter().position(|receipt| receipt.transition_id() == transition_id) {
+            Some(index) => Self::receipts_tree(&receipts)?.prove(index, &receipts[index].to_hash()?.to_bits_le()),
+            None => bail!("The transition '{transition_id}' does not have an execution receipt in the block"),
+        }
+    }
+
+    /// Returns the Merkle tree for the given execution receipts.
+    fn receipts_tree(receipts: &[ExecutionReceipt<N>]) -> Result<ReceiptsTree<N>> {
+        // Ensure the number of receipts is within the allowed range.
+        ensure!(
+            receipts.len() <= Self::MAX_RECEIPTS,
+            "Block cannot exceed {} execution receipts, found {}",
+            Self::MAX_RECEIPTS,
+            receipts.len()
+        );
+        // Prepare the leaves.
+        let leaves = receipts.iter().map(|receipt| Ok(receipt.to_hash()?.to_bits_le())).collect::<Result<Vec<_>>>()?;
+        // Compute the receipts tree.
+        N::merkle_tree_bhp::<RECEIPTS_DEPTH>(&leaves)
+    }
+}
+
 impl<N: Network> Transactions<N> {
     /// Returns the transactions root, by computing the root for a Merkle tree of the transaction IDs.
     pub fn to_transactions_root(&self) -> Result<Field<N>> {
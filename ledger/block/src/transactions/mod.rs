@@ -12,9 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod builder;
+pub use builder::*;
+
 pub mod confirmed;
 pub use confirmed::*;
 
+pub mod dependency_graph;
+pub use dependency_graph::*;
+
+pub mod receipt;
+pub use receipt::*;
+
 pub mod rejected;
 pub use rejected::*;
 
@@ -29,10 +38,13 @@ use console::{
     program::{
         Ciphertext,
         ProgramOwner,
+        ReceiptsPath,
+        ReceiptsTree,
         Record,
         TransactionsPath,
         TransactionsTree,
         FINALIZE_OPERATIONS_DEPTH,
+        RECEIPTS_DEPTH,
         TRANSACTIONS_DEPTH,
     },
     types::{Field, Group, U64},
@@ -266,6 +278,11 @@ impl<N: Network> Transactions<N> {
     pub fn finalize_operations(&self) -> impl '_ + Iterator<Item = &FinalizeOperation<N>> {
         self.iter().flat_map(|tx| tx.finalize_operations())
     }
+
+    /// Returns the execution receipts, for the transitions of all accepted and rejected execute transactions.
+    pub fn to_receipts(&self) -> Result<Vec<ExecutionReceipt<N>>> {
+        Ok(self.iter().map(ExecutionReceipt::from_confirmed).collect::<Result<Vec<_>>>()?.concat())
+    }
 }
 
 impl<N: Network> IntoIterator for Transactions<N> {
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ExecutionReceipt<N> {
+    /// Reads the execution receipt from the buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid execution receipt version"));
+        }
+
+        // Read the transition ID.
+        let transition_id = N::TransitionID::read_le(&mut reader)?;
+        // Read the status.
+        let status = ReceiptStatus::from_code(u8::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?;
+        // Read the fee.
+        let fee = u64::read_le(&mut reader)?;
+        // Read the number of events.
+        let num_events = NumFinalizeSize::read_le(&mut reader)?;
+        // Read the events.
+        let events = (0..num_events).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Return the receipt.
+        Self::new(transition_id, status, fee, events).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ExecutionReceipt<N> {
+    /// Writes the execution receipt to the buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the transition ID.
+        self.transition_id.write_le(&mut writer)?;
+        // Write the status.
+        self.status.code().write_le(&mut writer)?;
+        // Write the fee.
+        self.fee.write_le(&mut writer)?;
+        // Write the number of events.
+        NumFinalizeSize::try_from(self.events.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the events.
+        self.events.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::transactions::receipt::test_helpers::sample_execution_receipts(rng) {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, ExecutionReceipt::read_le(&expected_bytes[..])?);
+            assert!(ExecutionReceipt::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use super::*;
+
+/// The deterministic status code of an execution receipt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReceiptStatus {
+    /// The execution was accepted, and its finalize operations were applied.
+    Success,
+    /// The execution was rejected, and only its fee was consumed.
+    Rejected,
+}
+
+impl ReceiptStatus {
+    /// Returns the status code.
+    pub const fn code(&self) -> u8 {
+        match self {
+            Self::Success => 0,
+            Self::Rejected => 1,
+        }
+    }
+
+    /// Returns the status for the given status code.
+    pub fn from_code(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(Self::Success),
+            1 => Ok(Self::Rejected),
+            _ => bail!("Invalid receipt status code '{code}'"),
+        }
+    }
+}
+
+/// An execution receipt records the outcome of a transition in a confirmed execution, so that applications
+/// can verify the outcome against the receipts root in the block header without re-executing.
+///
+/// The root transition of an execution finalizes its whole call graph and pays the fee of the execution,
+/// so its receipt records the consumed fee and all of the finalize operations of the confirmed transaction.
+/// The receipts of the other transitions only record the status of the execution.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExecutionReceipt<N: Network> {
+    /// The ID of the transition.
+    transition_id: N::TransitionID,
+    /// The status of the execution.
+    status: ReceiptStatus,
+    /// The fee consumed by the transition, in microcredits.
+    fee: u64,
+    /// The finalize operations emitted by the transition.
+    events: Vec<FinalizeOperation<N>>,
+}
+
+impl<N: Network> ExecutionReceipt<N> {
+    /// Initializes a new execution receipt.
+    pub fn new(
+        transition_id: N::TransitionID,
+        status: ReceiptStatus,
+        fee: u64,
+        events: Vec<FinalizeOperation<N>>,
+    ) -> Result<Self> {
+        // Ensure the number of events is within bounds.
+        ensure!(
+            events.len() <= NumFinalizeSize::MAX as usize,
+            "Execution receipt for '{transition_id}' contains too many events"
+        );
+        // Return the receipt.
+        Ok(Self { transition_id, status, fee, events })
+    }
+
+    /// Returns the execution receipts for the transitions of the given confirmed transaction,
+    /// in the order of the transitions, or an empty list if it is not an execution.
+    pub fn from_confirmed(confirmed: &ConfirmedTransaction<N>) -> Result<Vec<Self>> {
+        // Retrieve the status and the execution.
+        let (status, execution) = match confirmed {
+            ConfirmedTransaction::AcceptedExecute(_, Transaction::Execute(_, execution, _), _) => {
+                (ReceiptStatus::Success, execution)
+            }
+            ConfirmedTransaction::RejectedExecute(_, _, rejected, _) => match rejected.execution() {
+                Some(execution) => (ReceiptStatus::Rejected, execution),
+                None => bail!("Rejected execute transaction '{}' is missing an execution", confirmed.id()),
+            },
+            ConfirmedTransaction::AcceptedExecute(..) => {
+                bail!("Accepted execute transaction '{}' is missing an execution", confirmed.id())
+            }
+            ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::RejectedDeploy(..) => return Ok(vec![]),
+        };
+        // Retrieve the root transition, which is the last transition of the execution.
+        let root_transition_id = *execution.peek()?.id();
+        // Construct the receipts.
+        execution
+            .transitions()
+            .map(|transition| match *transition.id() == root_transition_id {
+                true => Self::new(
+                    root_transition_id,
+                    status,
+                    *confirmed.transaction().fee_amount()?,
+                    confirmed.finalize_operations().clone(),
+                ),
+                false => Self::new(*transition.id(), status, 0, vec![]),
+            })
+            .collect()
+    }
+
+    /// Returns the ID of the transition.
+    pub const fn transition_id(&self) -> N::TransitionID {
+        self.transition_id
+    }
+
+    /// Returns the status of the execution.
+    pub const fn status(&self) -> ReceiptStatus {
+        self.status
+    }
+
+    /// Returns the fee consumed by the transition, in microcredits.
+    pub const fn fee(&self) -> u64 {
+        self.fee
+    }
+
+    /// Returns the finalize operations emitted by the transition.
+    pub fn events(&self) -> &[FinalizeOperation<N>] {
+        &self.events
+    }
+
+    /// Returns the hash of the execution receipt.
+    pub fn to_hash(&self) -> Result<Field<N>> {
+        // Pack the receipt into field elements.
+        let bits = self.to_bytes_le()?.to_bits_le();
+        let fields =
+            bits.chunks(Field::<N>::size_in_data_bits()).map(Field::from_bits_le).collect::<Result<Vec<_>>>()?;
+        // Hash the receipt.
+        N::hash_psd8(&fields)
+    }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    /// Samples a list of execution receipts.
+    pub(crate) fn sample_execution_receipts(rng: &mut TestRng) -> Vec<ExecutionReceipt<CurrentNetwork>> {
+        [ReceiptStatus::Success, ReceiptStatus::Rejected]
+            .into_iter()
+            .map(|status| {
+                let events = (0..rng.gen_range(0..4))
+                    .map(|_| {
+                        FinalizeOperation::InsertKeyValue(
+                            Field::<CurrentNetwork>::rand(rng),
+                            Field::rand(rng),
+                            Field::rand(rng),
+                        )
+                    })
+                    .collect();
+                ExecutionReceipt::new(Field::<CurrentNetwork>::rand(rng).into(), status, rng.gen(), events).unwrap()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_code() {
+        for status in [ReceiptStatus::Success, ReceiptStatus::Rejected] {
+            assert_eq!(status, ReceiptStatus::from_code(status.code()).unwrap());
+        }
+        assert!(ReceiptStatus::from_code(2).is_err());
+    }
+
+    #[test]
+    fn test_from_confirmed() {
+        for confirmed in crate::transactions::confirmed::test_helpers::sample_confirmed_transactions() {
+            let receipts = ExecutionReceipt::from_confirmed(&confirmed).unwrap();
+            // Retrieve the expected status and execution.
+            let (status, execution) = match &confirmed {
+                ConfirmedTransaction::AcceptedExecute(_, transaction, _) => {
+                    (ReceiptStatus::Success, transaction.execution().unwrap())
+                }
+                ConfirmedTransaction::RejectedExecute(_, _, rejected, _) => {
+                    (ReceiptStatus::Rejected, rejected.execution().unwrap())
+                }
+                _ => {
+                    // Ensure deployments do not have execution receipts.
+                    assert!(receipts.is_empty());
+                    continue;
+                }
+            };
+            // Ensure there is one receipt per transition.
+            assert_eq!(receipts.len(), execution.len());
+            for (receipt, transition) in receipts.iter().zip_eq(execution.transitions()) {
+                assert_eq!(receipt.transition_id(), *transition.id());
+                assert_eq!(receipt.status(), status);
+            }
+            // Ensure the root transition records the fee and the finalize operations.
+            let root = receipts.last().unwrap();
+            assert_eq!(root.transition_id(), *execution.peek().unwrap().id());
+            assert_eq!(root.fee(), *confirmed.transaction().fee_amount().unwrap());
+            assert_eq!(root.events(), &confirmed.finalize_operations()[..]);
+        }
+    }
+
+    #[test]
+    fn test_to_hash() {
+        let rng = &mut TestRng::default();
+
+        let receipts = crate::transactions::receipt::test_helpers::sample_execution_receipts(rng);
+        // Ensure distinct receipts have distinct hashes.
+        assert_ne!(receipts[0].to_hash().unwrap(), receipts[1].to_hash().unwrap());
+        // Ensure the hash is deterministic.
+        assert_eq!(receipts[0].to_hash().unwrap(), receipts[0].clone().to_hash().unwrap());
+    }
+}
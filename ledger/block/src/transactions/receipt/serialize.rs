@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for ExecutionReceipt<N> {
+    /// Serializes the execution receipt to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut receipt = serializer.serialize_struct("ExecutionReceipt", 4)?;
+                receipt.serialize_field("transition_id", &self.transition_id)?;
+                receipt.serialize_field("status", &self.status.code())?;
+                receipt.serialize_field("fee", &self.fee)?;
+                receipt.serialize_field("events", &self.events)?;
+                receipt.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ExecutionReceipt<N> {
+    /// Deserializes the execution receipt from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut receipt = serde_json::Value::deserialize(deserializer)?;
+                let status = DeserializeExt::take_from_value::<D>(&mut receipt, "status")?;
+                Ok(Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut receipt, "transition_id")?,
+                    ReceiptStatus::from_code(status).map_err(de::Error::custom)?,
+                    DeserializeExt::take_from_value::<D>(&mut receipt, "fee")?,
+                    DeserializeExt::take_from_value::<D>(&mut receipt, "events")?,
+                )
+                .map_err(de::Error::custom)?)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "execution receipt"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::transactions::receipt::test_helpers::sample_execution_receipts(rng) {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+
+            // Deserialize
+            assert_eq!(expected, ExecutionReceipt::from_str(expected_string)?);
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::transactions::receipt::test_helpers::sample_execution_receipts(rng) {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, ExecutionReceipt::read_le(&expected_bytes[..])?);
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        }
+        Ok(())
+    }
+}
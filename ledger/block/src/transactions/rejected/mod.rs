@@ -84,10 +84,13 @@ impl<N: Network> Rejected<N> {
     /// When a transaction is rejected, its fee transition is used to construct the confirmed transaction ID,
     /// changing the original transaction ID.
     pub fn to_unconfirmed_id(&self, fee: &Option<Fee<N>>) -> Result<Field<N>> {
-        match self {
-            Self::Deployment(_, deployment) => Ok(*Transaction::deployment_tree(deployment, fee.as_ref())?.root()),
-            Self::Execution(execution) => Ok(*Transaction::execution_tree(execution, fee)?.root()),
-        }
+        // Compute the transaction tree.
+        let tree = match self {
+            Self::Deployment(_, deployment) => Transaction::deployment_tree(deployment, fee.as_ref())?,
+            Self::Execution(execution) => Transaction::execution_tree(execution, fee)?,
+        };
+        // Return the transaction ID, including the proof version.
+        Ok(*Transaction::with_proof_version(tree, self.execution(), fee.as_ref())?.root())
     }
 }
 
@@ -19,7 +19,7 @@ use super::*;
 use ledger_coinbase::{CoinbasePuzzle, EpochChallenge};
 use synthesizer_program::FinalizeOperation;
 
-use std::collections::HashSet;
+use std::{collections::HashSet, hash::Hash};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -94,9 +94,159 @@ impl<N: Network> Block<N> {
             current_timestamp,
         )?;
 
+        // Compute the expected receipts root.
+        let expected_receipts_root = self.compute_receipts_root()?;
+        // Ensure the receipts root is correct.
+        ensure!(
+            self.header.receipts_root()? == expected_receipts_root,
+            "Receipts root is incorrect in block {expected_height} (found '{:?}', expected '{expected_receipts_root:?}')",
+            self.header.receipts_root()?
+        );
+
         // Return the expected existing transaction ids.
         Ok(expected_existing_transaction_ids)
     }
+
+    /// Checks the block, returning a report of every failed check instead of stopping at the first error.
+    ///
+    /// Each check runs whenever its inputs are available, so a single report covers every independent problem
+    /// in the block. A block is valid if and only if the report is valid, in which case `Block::verify` succeeds.
+    pub fn verify_with_report(
+        &self,
+        previous_block: &Block<N>,
+        current_state_root: N::StateRoot,
+        current_committee: &Committee<N>,
+        current_puzzle: &CoinbasePuzzle<N>,
+        current_epoch_challenge: &EpochChallenge<N>,
+        current_timestamp: i64,
+        ratified_finalize_operations: Vec<FinalizeOperation<N>>,
+    ) -> ValidationReport {
+        let mut report = ValidationReport::new();
+
+        // Check the block hash.
+        report.check(ReasonCode::InvalidBlockHash, self.verify_hash(previous_block.height(), previous_block.hash()));
+
+        // Check the block authority.
+        let authority = report.check(
+            ReasonCode::InvalidAuthority,
+            self.verify_authority(previous_block.round(), previous_block.height(), current_committee),
+        );
+
+        // Check the block solutions.
+        let solutions = report.check(
+            ReasonCode::InvalidSolutions,
+            self.verify_solutions(previous_block, current_puzzle, current_epoch_challenge),
+        );
+
+        // Check the block ratifications, which requires the expected rewards.
+        if let Some((.., expected_block_reward, expected_puzzle_reward)) = solutions {
+            report.check(
+                ReasonCode::InvalidRatifications,
+                self.verify_ratifications(expected_block_reward, expected_puzzle_reward),
+            );
+        }
+
+        // Check the block transactions.
+        self.check_transactions(&mut report);
+
+        // Check each root in the block header against its expected value.
+        // Note: A root that fails to compute or match is replaced with the header value,
+        // so the remaining header checks below do not report it a second time.
+        let expected_height = previous_block.height().saturating_add(1);
+        let mut check_root = |reason: ReasonCode, name: &str, found: Field<N>, expected: Result<Field<N>>| {
+            match expected {
+                Ok(expected) if expected == found => {}
+                Ok(expected) => report.push(
+                    reason,
+                    None,
+                    format!(
+                        "{name} root is incorrect in block {expected_height} (found '{found}', expected '{expected}')"
+                    ),
+                ),
+                Err(error) => report.push(ReasonCode::RootComputationFailed, None, error.to_string()),
+            }
+            found
+        };
+        let header = &self.header;
+        let transactions_root = check_root(
+            ReasonCode::TransactionsRootMismatch,
+            "Transactions",
+            header.transactions_root(),
+            self.compute_transactions_root(),
+        );
+        let finalize_root = check_root(
+            ReasonCode::FinalizeRootMismatch,
+            "Finalize",
+            header.finalize_root(),
+            self.compute_finalize_root(ratified_finalize_operations),
+        );
+        let ratifications_root = check_root(
+            ReasonCode::RatificationsRootMismatch,
+            "Ratifications",
+            header.ratifications_root(),
+            self.compute_ratifications_root(),
+        );
+        let solutions_root = check_root(
+            ReasonCode::SolutionsRootMismatch,
+            "Solutions",
+            header.solutions_root(),
+            self.compute_solutions_root(),
+        );
+        let subdag_root =
+            check_root(ReasonCode::SubdagRootMismatch, "Subdag", header.subdag_root(), self.compute_subdag_root());
+
+        // Check the remaining fields of the block header, which requires the expected authority and solution values.
+        if let (Some((expected_round, expected_height, expected_timestamp, _)), Some(solutions)) =
+            (authority, solutions)
+        {
+            let (
+                expected_cumulative_weight,
+                expected_cumulative_proof_target,
+                expected_coinbase_target,
+                expected_proof_target,
+                expected_last_coinbase_target,
+                expected_last_coinbase_timestamp,
+                ..,
+            ) = solutions;
+            report.check(
+                ReasonCode::InvalidHeader,
+                header.verify(
+                    current_state_root,
+                    transactions_root,
+                    finalize_root,
+                    ratifications_root,
+                    solutions_root,
+                    subdag_root,
+                    expected_round,
+                    expected_height,
+                    expected_cumulative_weight,
+                    expected_cumulative_proof_target,
+                    expected_coinbase_target,
+                    expected_proof_target,
+                    expected_last_coinbase_target,
+                    expected_last_coinbase_timestamp,
+                    expected_timestamp,
+                    current_timestamp,
+                ),
+            );
+        }
+
+        // Check the receipts root.
+        match (header.receipts_root(), self.compute_receipts_root()) {
+            (Ok(found), Ok(expected)) if found == expected => {}
+            (Ok(found), Ok(expected)) => report.push(
+                ReasonCode::ReceiptsRootMismatch,
+                None,
+                format!(
+                    "Receipts root is incorrect in block {expected_height} (found '{found:?}', expected '{expected:?}')"
+                ),
+            ),
+            (Err(error), _) => report.push(ReasonCode::ReceiptsRootMismatch, None, error.to_string()),
+            (_, Err(error)) => report.push(ReasonCode::RootComputationFailed, None, error.to_string()),
+        }
+
+        report
+    }
 }
 
 impl<N: Network> Block<N> {
@@ -325,7 +475,8 @@ impl<N: Network> Block<N> {
         };
 
         // Compute the expected cumulative weight.
-        let expected_cumulative_weight = previous_block.cumulative_weight().saturating_add(combined_proof_target);
+        let expected_cumulative_weight =
+            previous_block.chain_work().saturating_add(combined_proof_target).cumulative_weight();
 
         // Construct the next coinbase target.
         let expected_coinbase_target = coinbase_target(
@@ -385,87 +536,166 @@ impl<N: Network> Block<N> {
 
     /// Ensures the block transactions are correct.
     fn verify_transactions(&self) -> Result<()> {
+        let mut report = ValidationReport::new();
+        self.check_transactions(&mut report);
+        report.ensure_valid()
+    }
+
+    /// Checks the block transactions, recording every failed check in the given report.
+    fn check_transactions(&self, report: &mut ValidationReport) {
         let height = self.height();
 
         // Ensure there are transactions.
-        ensure!(!self.transactions.is_empty(), "Block {height} must contain at least 1 transaction");
+        if self.transactions.is_empty() {
+            report.push(
+                ReasonCode::MissingTransactions,
+                None,
+                format!("Block {height} must contain at least 1 transaction"),
+            );
+        }
 
         // Ensure the number of transactions is within the allowed range.
         if self.transactions.len() > Transactions::<N>::MAX_TRANSACTIONS {
-            bail!(
-                "Cannot validate a block with more than {} confirmed transactions",
-                Transactions::<N>::MAX_TRANSACTIONS
+            report.push(
+                ReasonCode::TooManyTransactions,
+                None,
+                format!(
+                    "Cannot validate a block with more than {} confirmed transactions",
+                    Transactions::<N>::MAX_TRANSACTIONS
+                ),
             );
         }
 
         // Ensure the number of aborted transaction IDs is within the allowed range.
         if self.aborted_transaction_ids.len() > Transactions::<N>::MAX_ABORTED_TRANSACTIONS {
-            bail!(
-                "Cannot validate a block with more than {} aborted transaction IDs",
-                Transactions::<N>::MAX_ABORTED_TRANSACTIONS
+            report.push(
+                ReasonCode::TooManyAbortedTransactions,
+                None,
+                format!(
+                    "Cannot validate a block with more than {} aborted transaction IDs",
+                    Transactions::<N>::MAX_ABORTED_TRANSACTIONS
+                ),
             );
         }
 
         // Ensure there are no duplicate transaction IDs.
-        if has_duplicates(self.transaction_ids().chain(self.aborted_transaction_ids.iter())) {
-            bail!("Found a duplicate transaction in block {height}");
+        // Note: Duplicates among the aborted transaction IDs are reported without an index.
+        let mut seen_transaction_ids = HashSet::new();
+        for (index, transaction_id) in self.transaction_ids().enumerate() {
+            if !seen_transaction_ids.insert(transaction_id) {
+                let message = format!("Found a duplicate transaction in block {height}");
+                report.push(ReasonCode::DuplicateTransactionID, Some(index), message);
+            }
+        }
+        for transaction_id in self.aborted_transaction_ids.iter() {
+            if !seen_transaction_ids.insert(transaction_id) {
+                let message = format!("Found a duplicate transaction in block {height}");
+                report.push(ReasonCode::DuplicateTransactionID, None, message);
+            }
         }
 
-        // Ensure there are no duplicate transition IDs.
-        if has_duplicates(self.transition_ids()) {
-            bail!("Found a duplicate transition in block {height}");
+        // Ensure there are no expired executions, including rejected executions.
+        for (index, confirmed) in self.transactions().iter().enumerate() {
+            let execution = match confirmed.to_rejected() {
+                Some(rejected) => rejected.execution(),
+                None => confirmed.transaction().execution(),
+            };
+            if execution.map_or(false, |execution| execution.is_expired(height)) {
+                let message =
+                    format!("Found an expired execution in block {height} (transaction '{}')", confirmed.id());
+                report.push(ReasonCode::ExpiredExecution, Some(index), message);
+            }
         }
 
+        // Records a failure for each transaction containing an element that appeared earlier in the block.
+        let mut check_duplicates = |reason: ReasonCode, name: &str, indices: Vec<usize>| {
+            for index in indices {
+                report.push(reason, Some(index), format!("Found a duplicate {name} in block {height}"));
+            }
+        };
+
+        // Ensure there are no duplicate transition IDs.
+        check_duplicates(
+            ReasonCode::DuplicateTransitionID,
+            "transition",
+            duplicate_indices(&self.transactions, |tx| tx.transition_ids()),
+        );
+
         // Ensure there are no duplicate program IDs.
-        if has_duplicates(
-            self.transactions().iter().filter_map(|tx| tx.transaction().deployment().map(|d| d.program_id())),
-        ) {
-            bail!("Found a duplicate program ID in block {height}");
-        }
+        check_duplicates(
+            ReasonCode::DuplicateProgramID,
+            "program ID",
+            duplicate_indices(&self.transactions, |tx| tx.transaction().deployment().map(|d| d.program_id())),
+        );
 
         /* Input */
 
         // Ensure there are no duplicate input IDs.
-        if has_duplicates(self.input_ids()) {
-            bail!("Found a duplicate input ID in block {height}");
-        }
+        check_duplicates(
+            ReasonCode::DuplicateInputID,
+            "input ID",
+            duplicate_indices(&self.transactions, |tx| tx.input_ids()),
+        );
         // Ensure there are no duplicate serial numbers.
-        if has_duplicates(self.serial_numbers()) {
-            bail!("Found a duplicate serial number in block {height}");
-        }
+        check_duplicates(
+            ReasonCode::DuplicateSerialNumber,
+            "serial number",
+            duplicate_indices(&self.transactions, |tx| tx.serial_numbers()),
+        );
         // Ensure there are no duplicate tags.
-        if has_duplicates(self.tags()) {
-            bail!("Found a duplicate tag in block {height}");
-        }
+        check_duplicates(ReasonCode::DuplicateTag, "tag", duplicate_indices(&self.transactions, |tx| tx.tags()));
 
         /* Output */
 
         // Ensure there are no duplicate output IDs.
-        if has_duplicates(self.output_ids()) {
-            bail!("Found a duplicate output ID in block {height}");
-        }
+        check_duplicates(
+            ReasonCode::DuplicateOutputID,
+            "output ID",
+            duplicate_indices(&self.transactions, |tx| tx.output_ids()),
+        );
         // Ensure there are no duplicate commitments.
-        if has_duplicates(self.commitments()) {
-            bail!("Found a duplicate commitment in block {height}");
-        }
+        check_duplicates(
+            ReasonCode::DuplicateCommitment,
+            "commitment",
+            duplicate_indices(&self.transactions, |tx| tx.commitments()),
+        );
         // Ensure there are no duplicate nonces.
-        if has_duplicates(self.nonces()) {
-            bail!("Found a duplicate nonce in block {height}");
-        }
+        check_duplicates(ReasonCode::DuplicateNonce, "nonce", duplicate_indices(&self.transactions, |tx| tx.nonces()));
 
         /* Metadata */
 
         // Ensure there are no duplicate transition public keys.
-        if has_duplicates(self.transition_public_keys()) {
-            bail!("Found a duplicate transition public key in block {height}");
-        }
+        check_duplicates(
+            ReasonCode::DuplicateTransitionPublicKey,
+            "transition public key",
+            duplicate_indices(&self.transactions, |tx| tx.transition_public_keys()),
+        );
         // Ensure there are no duplicate transition commitments.
-        if has_duplicates(self.transition_commitments()) {
-            bail!("Found a duplicate transition commitment in block {height}");
+        check_duplicates(
+            ReasonCode::DuplicateTransitionCommitment,
+            "transition commitment",
+            duplicate_indices(&self.transactions, |tx| tx.transition_commitments()),
+        );
+    }
+}
+
+/// Returns the indices of the transactions containing an element that already appeared earlier in the block.
+/// Each index is returned at most once, even if the transaction contains several such elements.
+fn duplicate_indices<'a, N: Network, T: Eq + Hash, I: IntoIterator<Item = T>>(
+    transactions: &'a Transactions<N>,
+    elements: impl Fn(&'a ConfirmedTransaction<N>) -> I,
+) -> Vec<usize> {
+    let mut seen = HashSet::new();
+    let mut indices = Vec::new();
+    for (index, transaction) in transactions.iter().enumerate() {
+        if elements(transaction).into_iter().fold(false, |is_duplicate, element| !seen.insert(element) || is_duplicate)
+        {
+            indices.push(index);
         }
-        Ok(())
     }
+    indices
 }
+
 impl<N: Network> Block<N> {
     /// Computes the transactions root for the block.
     fn compute_transactions_root(&self) -> Result<Field<N>> {
@@ -475,6 +705,19 @@ impl<N: Network> Block<N> {
         }
     }
 
+    /// Computes the receipts root for the block, if the block must commit to one.
+    /// Note: Blocks below `Header::RECEIPTS_ROOT_HEIGHT` (e.g. the genesis block) must not commit to a receipts root.
+    fn compute_receipts_root(&self) -> Result<Option<Field<N>>> {
+        // Ensure the receipts root is active at this height.
+        if self.height() < Header::<N>::RECEIPTS_ROOT_HEIGHT {
+            return Ok(None);
+        }
+        match self.transactions.to_receipts_root() {
+            Ok(receipts_root) => Ok(Some(receipts_root)),
+            Err(error) => bail!("Failed to compute the receipts root for block {} - {error}", self.height()),
+        }
+    }
+
     /// Computes the finalize root for the block.
     fn compute_finalize_root(&self, ratified_finalize_operations: Vec<FinalizeOperation<N>>) -> Result<Field<N>> {
         match self.transactions.to_finalize_root(ratified_finalize_operations) {
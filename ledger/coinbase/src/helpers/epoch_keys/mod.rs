@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{degree_in_schedule, CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSolution, PuzzleConfig};
+use console::{account::Address, prelude::*};
+use snarkvm_algorithms::polycommit::kzg10::UniversalParams as SRS;
+use snarkvm_synthesizer_snark::UniversalSRS;
+
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    ops::Deref,
+};
+
+/// The coinbase puzzle keys for the current and next epochs of a degree schedule, rotated as the epochs advance.
+///
+/// The keys of the next epoch are trimmed from the SRS ahead of time, so a scheduled change in degree
+/// does not stall proving at the epoch boundary. Solutions are only checked against the keys of the
+/// degree scheduled for their epoch.
+#[derive(Clone)]
+pub struct EpochKeys<N: Network, S: Deref<Target = SRS<N::PairingCurve>> = UniversalSRS<N>> {
+    /// The universal SRS, from which the keys of each epoch are trimmed.
+    srs: S,
+    /// The `(starting epoch number, degree)` schedule.
+    schedule: &'static [(u32, u32)],
+    /// The current epoch number.
+    epoch_number: u32,
+    /// The coinbase puzzle of each loaded degree.
+    puzzles: BTreeMap<u32, CoinbasePuzzle<N>>,
+}
+
+impl<N: Network> EpochKeys<N> {
+    /// Loads the coinbase puzzle keys for the given epoch number and the next one, as scheduled by `N::COINBASE_PUZZLE_SCHEDULE`.
+    pub fn load(epoch_number: u32) -> Result<Self> {
+        Self::new(UniversalSRS::<N>::load()?, epoch_number)
+    }
+}
+
+impl<N: Network, S: Deref<Target = SRS<N::PairingCurve>>> EpochKeys<N, S> {
+    /// Initializes the coinbase puzzle keys for the given epoch number and the next one, as scheduled by `N::COINBASE_PUZZLE_SCHEDULE`.
+    pub fn new(srs: S, epoch_number: u32) -> Result<Self> {
+        Self::new_with_schedule(srs, N::COINBASE_PUZZLE_SCHEDULE, epoch_number)
+    }
+
+    /// Initializes the coinbase puzzle keys for the given epoch number and the next one, as scheduled by the given schedule.
+    pub(crate) fn new_with_schedule(srs: S, schedule: &'static [(u32, u32)], epoch_number: u32) -> Result<Self> {
+        let mut keys = Self { srs, schedule, epoch_number, puzzles: BTreeMap::new() };
+        keys.rotate(epoch_number)?;
+        Ok(keys)
+    }
+
+    /// Returns the current epoch number.
+    pub const fn epoch_number(&self) -> u32 {
+        self.epoch_number
+    }
+
+    /// Returns the degrees of the loaded keys.
+    pub fn degrees(&self) -> impl '_ + Iterator<Item = u32> {
+        self.puzzles.keys().copied()
+    }
+
+    /// Rotates the keys to the given epoch number, trimming the keys of the next epoch and dropping
+    /// the keys that neither the given epoch nor the next one uses.
+    pub fn rotate(&mut self, epoch_number: u32) -> Result<()> {
+        // Retrieve the degrees of the epoch and the next one.
+        let degrees = [
+            degree_in_schedule(self.schedule, epoch_number)?,
+            degree_in_schedule(self.schedule, epoch_number.saturating_add(1))?,
+        ];
+        // Drop the keys of the other degrees.
+        self.puzzles.retain(|degree, _| degrees.contains(degree));
+        // Trim the keys that are not yet loaded.
+        for degree in degrees {
+            if let Entry::Vacant(entry) = self.puzzles.entry(degree) {
+                entry.insert(CoinbasePuzzle::trim(&self.srs, PuzzleConfig { degree })?);
+            }
+        }
+        self.epoch_number = epoch_number;
+        Ok(())
+    }
+
+    /// Returns the coinbase puzzle for the given epoch challenge.
+    /// Note: This method ensures the epoch challenge has the degree scheduled for its epoch.
+    pub fn get(&self, epoch_challenge: &EpochChallenge<N>) -> Result<&CoinbasePuzzle<N>> {
+        // Ensure the epoch challenge has the degree scheduled for its epoch.
+        let degree = degree_in_schedule(self.schedule, epoch_challenge.epoch_number())?;
+        ensure!(
+            epoch_challenge.degree() == degree,
+            "Epoch {} is scheduled for degree {degree}, but the epoch challenge has degree {}",
+            epoch_challenge.epoch_number(),
+            epoch_challenge.degree()
+        );
+        // Retrieve the keys for the degree.
+        match self.puzzles.get(&degree) {
+            Some(puzzle) => Ok(puzzle),
+            None => bail!("The coinbase puzzle keys for epoch {} are not loaded", epoch_challenge.epoch_number()),
+        }
+    }
+
+    /// Returns a prover solution to the coinbase puzzle, using the keys of the epoch of the given challenge.
+    pub fn prove(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<ProverSolution<N>> {
+        self.get(epoch_challenge)?.prove(epoch_challenge, address, nonce, minimum_proof_target)
+    }
+
+    /// Returns `true` if the solutions are valid, using the keys of the epoch of the given challenge.
+    pub fn check_solutions(
+        &self,
+        solutions: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<()> {
+        self.get(epoch_challenge)?.check_solutions(solutions, epoch_challenge, proof_target)
+    }
+}
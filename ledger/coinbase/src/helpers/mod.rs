@@ -18,9 +18,15 @@ pub use coinbase_solution::*;
 mod epoch_challenge;
 pub use epoch_challenge::*;
 
+mod epoch_keys;
+pub use epoch_keys::*;
+
 mod partial_solution;
 pub use partial_solution::*;
 
+mod posw;
+pub use posw::*;
+
 mod prover_solution;
 pub use prover_solution::*;
 
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{CoinbasePuzzle, CoinbaseSolution, CoinbaseVerifyingKey, EpochChallenge, ProverSolution, PuzzleConfig};
+use console::{account::Address, prelude::*};
+use snarkvm_algorithms::polycommit::kzg10::UniversalParams as SRS;
+
+/// The coinbase puzzle of a node that never proves, which holds only the verifying key.
+///
+/// Unlike `CoinbasePuzzle`, which checks for the proving key at runtime, this type has no way to
+/// prove or to load the proving key, so a verifier-only node is distinguished at compile time.
+#[derive(Clone)]
+pub struct PoswVerifier<N: Network>(CoinbasePuzzle<N>);
+
+impl<N: Network> PoswVerifier<N> {
+    /// Loads the coinbase puzzle verifying key.
+    pub fn load() -> Result<Self> {
+        Ok(Self(CoinbasePuzzle::load_verifier()?))
+    }
+
+    /// Returns the coinbase puzzle verifier for the given SRS.
+    pub fn trim(srs: &SRS<N::PairingCurve>) -> Result<Self> {
+        Ok(Self(CoinbasePuzzle::trim_verifier(srs)?))
+    }
+
+    /// Returns the coinbase verifying key.
+    pub fn coinbase_verifying_key(&self) -> &CoinbaseVerifyingKey<N> {
+        self.0.coinbase_verifying_key()
+    }
+
+    /// Returns `true` if the solutions are valid.
+    pub fn check_solutions(
+        &self,
+        solutions: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<()> {
+        self.0.check_solutions(solutions, epoch_challenge, proof_target)
+    }
+
+    /// Returns the verifier as a coinbase puzzle, which is always the `Verifier` variant.
+    pub const fn as_puzzle(&self) -> &CoinbasePuzzle<N> {
+        &self.0
+    }
+}
+
+impl<N: Network> From<PoswProver<N>> for PoswVerifier<N> {
+    /// Returns the verifier of the given prover, dropping the proving key.
+    fn from(prover: PoswProver<N>) -> Self {
+        Self(prover.0.into_verifier())
+    }
+}
+
+/// The coinbase puzzle of a prover, which holds the proving key.
+#[derive(Clone)]
+pub struct PoswProver<N: Network>(CoinbasePuzzle<N>);
+
+impl<N: Network> PoswProver<N> {
+    /// Loads the coinbase puzzle proving and verifying keys.
+    pub fn load() -> Result<Self> {
+        Ok(Self(CoinbasePuzzle::load()?))
+    }
+
+    /// Returns the coinbase puzzle prover for the given SRS and configuration.
+    pub fn trim(srs: &SRS<N::PairingCurve>, config: PuzzleConfig) -> Result<Self> {
+        Ok(Self(CoinbasePuzzle::trim(srs, config)?))
+    }
+
+    /// Returns a prover solution to the coinbase puzzle.
+    pub fn prove(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<ProverSolution<N>> {
+        self.0.prove(epoch_challenge, address, nonce, minimum_proof_target)
+    }
+
+    /// Returns the coinbase verifying key.
+    pub fn coinbase_verifying_key(&self) -> &CoinbaseVerifyingKey<N> {
+        self.0.coinbase_verifying_key()
+    }
+
+    /// Returns `true` if the solutions are valid.
+    pub fn check_solutions(
+        &self,
+        solutions: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<()> {
+        self.0.check_solutions(solutions, epoch_challenge, proof_target)
+    }
+
+    /// Returns the prover as a coinbase puzzle, which is always the `Prover` variant.
+    pub const fn as_puzzle(&self) -> &CoinbasePuzzle<N> {
+        &self.0
+    }
+}
+
+impl<N: Network> TryFrom<CoinbasePuzzle<N>> for PoswProver<N> {
+    type Error = Error;
+
+    /// Returns the prover for the given coinbase puzzle, if it holds the proving key.
+    fn try_from(puzzle: CoinbasePuzzle<N>) -> Result<Self> {
+        ensure!(puzzle.is_prover(), "The coinbase puzzle does not hold the proving key");
+        Ok(Self(puzzle))
+    }
+}
@@ -71,6 +71,35 @@ impl<N: Network> CoinbasePuzzle<N> {
         Self::trim(&*universal_srs, PuzzleConfig { degree: max_degree })
     }
 
+    /// Load the coinbase puzzle verifying key, without the proving key.
+    /// This allows nodes that never prove to skip computing the Lagrange basis of the SRS.
+    pub fn load_verifier() -> Result<Self> {
+        // Load the universal SRS.
+        let universal_srs = UniversalSRS::<N>::load()?;
+        // Extract the verifying key.
+        Self::trim_verifier(&*universal_srs)
+    }
+
+    /// Returns the coinbase puzzle verifier for the given SRS.
+    /// Note: The verifying key is independent of the puzzle degree.
+    pub fn trim_verifier(srs: &SRS<N::PairingCurve>) -> Result<Self> {
+        Ok(Self::Verifier(Arc::new(Self::verifying_key_from_srs(srs)?)))
+    }
+
+    /// Load the coinbase puzzle proving and verifying keys for the given epoch number.
+    /// This allows provers to pre-load the keys for the next epoch, ahead of a scheduled change in degree.
+    pub fn load_for_epoch(epoch_number: u32) -> Result<Self> {
+        // Load the universal SRS.
+        let universal_srs = UniversalSRS::<N>::load()?;
+        // Trim the universal SRS to the degree of the epoch.
+        Self::trim(&*universal_srs, PuzzleConfig { degree: Self::degree_for_epoch(epoch_number)? })
+    }
+
+    /// Returns the coinbase puzzle degree for the given epoch number, as scheduled by `N::COINBASE_PUZZLE_SCHEDULE`.
+    pub fn degree_for_epoch(epoch_number: u32) -> Result<u32> {
+        degree_in_schedule(N::COINBASE_PUZZLE_SCHEDULE, epoch_number)
+    }
+
     pub fn trim(srs: &SRS<N::PairingCurve>, config: PuzzleConfig) -> Result<Self> {
         // As above, we must support committing to the product of two degree `n` polynomials.
         // Thus, the SRS must support committing to a polynomial of degree `2n - 1`.
@@ -84,14 +113,7 @@ impl<N: Network> CoinbasePuzzle<N> {
         let fft_precomputation = product_domain.precompute_fft();
         let product_domain_elements = product_domain.elements().collect();
 
-        let vk = CoinbaseVerifyingKey::<N> {
-            g: srs.power_of_beta_g(0)?,
-            gamma_g: <N::PairingCurve as PairingEngine>::G1Affine::zero(), // We don't use gamma_g later on since we are not hiding.
-            h: srs.h,
-            beta_h: srs.beta_h(),
-            prepared_h: srs.prepared_h.clone(),
-            prepared_beta_h: srs.prepared_beta_h.clone(),
-        };
+        let vk = Self::verifying_key_from_srs(srs)?;
 
         let pk = CoinbaseProvingKey {
             product_domain,
@@ -117,6 +139,13 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Prover(coinbase_proving_key) => coinbase_proving_key,
             Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
+        // Ensure the proving key supports the degree of the epoch challenge.
+        ensure!(
+            self.supports_epoch_challenge(epoch_challenge)?,
+            "The coinbase proving key does not support the degree ({}) of epoch {}",
+            epoch_challenge.degree(),
+            epoch_challenge.epoch_number()
+        );
 
         let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
 
@@ -210,9 +239,64 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(coinbase_verifying_key) => coinbase_verifying_key,
         }
     }
+
+    /// Returns `true` if the coinbase puzzle contains the proving key.
+    pub const fn is_prover(&self) -> bool {
+        matches!(self, Self::Prover(_))
+    }
+
+    /// Returns the coinbase puzzle verifier, dropping the proving key if it is present.
+    pub fn into_verifier(self) -> Self {
+        match self {
+            Self::Prover(coinbase_proving_key) => Self::Verifier(Arc::new(coinbase_proving_key.verifying_key.clone())),
+            Self::Verifier(coinbase_verifying_key) => Self::Verifier(coinbase_verifying_key),
+        }
+    }
+
+    /// Returns `true` if the coinbase puzzle keys support the degree of the given epoch challenge.
+    /// Note: The verifying key is independent of the degree, so a verifier supports every epoch challenge.
+    pub fn supports_epoch_challenge(&self, epoch_challenge: &EpochChallenge<N>) -> Result<bool> {
+        match self {
+            Self::Prover(coinbase_proving_key) => {
+                Ok(coinbase_proving_key.product_domain.size() == Self::product_domain(epoch_challenge.degree())?.size())
+            }
+            Self::Verifier(_) => Ok(true),
+        }
+    }
+}
+
+/// Returns the degree for the given epoch number in the given `(starting epoch number, degree)` schedule.
+pub(crate) fn degree_in_schedule(schedule: &[(u32, u32)], epoch_number: u32) -> Result<u32> {
+    // Ensure the schedule starts at epoch 0.
+    ensure!(matches!(schedule.first(), Some((0, _))), "The coinbase puzzle schedule must start at epoch 0");
+    // Ensure the schedule is in strictly increasing order of epoch.
+    ensure!(
+        schedule.windows(2).all(|window| window[0].0 < window[1].0),
+        "The coinbase puzzle schedule must be in strictly increasing order of epoch"
+    );
+    // Ensure the degrees are non-zero.
+    ensure!(schedule.iter().all(|(_, degree)| *degree != 0), "The coinbase puzzle schedule contains a zero degree");
+
+    // Return the degree of the last entry that starts at or before the epoch.
+    match schedule.iter().rev().find(|(starting_epoch, _)| *starting_epoch <= epoch_number) {
+        Some((_, degree)) => Ok(*degree),
+        None => bail!("The coinbase puzzle schedule does not cover epoch {epoch_number}"),
+    }
 }
 
 impl<N: Network> CoinbasePuzzle<N> {
+    /// Returns the coinbase verifying key for the given SRS.
+    fn verifying_key_from_srs(srs: &SRS<N::PairingCurve>) -> Result<CoinbaseVerifyingKey<N>> {
+        Ok(CoinbaseVerifyingKey::<N> {
+            g: srs.power_of_beta_g(0)?,
+            gamma_g: <N::PairingCurve as PairingEngine>::G1Affine::zero(), // We don't use gamma_g later on since we are not hiding.
+            h: srs.h,
+            beta_h: srs.beta_h(),
+            prepared_h: srs.prepared_h.clone(),
+            prepared_beta_h: srs.prepared_beta_h.clone(),
+        })
+    }
+
     /// Checks that the degree for the epoch and prover polynomial is within bounds,
     /// and returns the evaluation domain for the product polynomial.
     pub(crate) fn product_domain(degree: u32) -> Result<EvaluationDomain<N::Field>> {
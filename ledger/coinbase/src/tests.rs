@@ -107,6 +107,147 @@ fn test_edge_case_for_degree() {
     assert!(puzzle.check_solutions(&coinbase_solution, &epoch_challenge, 0u64).is_ok());
 }
 
+#[test]
+fn test_degree_schedule() {
+    // Ensure the network schedule starts with the genesis degree.
+    assert_eq!(CoinbasePuzzle::<Testnet3>::degree_for_epoch(0).unwrap(), Testnet3::COINBASE_PUZZLE_DEGREE);
+    assert_eq!(CoinbasePuzzle::<Testnet3>::degree_for_epoch(u32::MAX).unwrap(), Testnet3::COINBASE_PUZZLE_DEGREE);
+
+    // Ensure each degree applies until the next starting epoch.
+    let schedule = [(0, 31), (10, 63), (20, 127)];
+    assert_eq!(degree_in_schedule(&schedule, 0).unwrap(), 31);
+    assert_eq!(degree_in_schedule(&schedule, 9).unwrap(), 31);
+    assert_eq!(degree_in_schedule(&schedule, 10).unwrap(), 63);
+    assert_eq!(degree_in_schedule(&schedule, 19).unwrap(), 63);
+    assert_eq!(degree_in_schedule(&schedule, u32::MAX).unwrap(), 127);
+
+    // Ensure invalid schedules are rejected.
+    assert!(degree_in_schedule(&[], 0).is_err());
+    assert!(degree_in_schedule(&[(1, 31)], 1).is_err());
+    assert!(degree_in_schedule(&[(0, 31), (10, 63), (10, 127)], 0).is_err());
+    assert!(degree_in_schedule(&[(0, 31), (10, 0)], 0).is_err());
+}
+
+#[test]
+fn test_epoch_rotation() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    // Initialize the keys for the current and next epochs.
+    let (current_degree, next_degree) = ((1 << 5) - 1, (1 << 6) - 1);
+    let current_puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: current_degree }).unwrap();
+    let next_puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: next_degree }).unwrap();
+    let next_challenge = EpochChallenge::new(1, Default::default(), next_degree).unwrap();
+
+    // Ensure the current keys cannot prove for the next epoch.
+    let address = Address::try_from(PrivateKey::<Testnet3>::new(&mut rng).unwrap()).unwrap();
+    assert!(!current_puzzle.supports_epoch_challenge(&next_challenge).unwrap());
+    assert!(current_puzzle.prove(&next_challenge, address, rng.gen(), None).is_err());
+
+    // Ensure the pre-loaded keys prove for the next epoch, and the solution verifies under either key.
+    assert!(next_puzzle.supports_epoch_challenge(&next_challenge).unwrap());
+    let solution = next_puzzle.prove(&next_challenge, address, rng.gen(), None).unwrap();
+    let solutions = CoinbaseSolution::new(vec![solution]).unwrap();
+    assert!(next_puzzle.check_solutions(&solutions, &next_challenge, 0u64).is_ok());
+    assert!(current_puzzle.check_solutions(&solutions, &next_challenge, 0u64).is_ok());
+}
+
+#[test]
+fn test_epoch_keys() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = Arc::new(CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap());
+    let schedule = &[(0, (1 << 5) - 1), (2, (1 << 6) - 1)];
+    let address = Address::try_from(PrivateKey::<Testnet3>::new(&mut rng).unwrap()).unwrap();
+
+    // Ensure only the keys of the first degree are loaded, as the next epoch has the same degree.
+    let mut keys = EpochKeys::<Testnet3, _>::new_with_schedule(srs, schedule, 0).unwrap();
+    assert_eq!(keys.degrees().collect::<Vec<_>>(), [(1 << 5) - 1]);
+
+    // Ensure the keys of the next degree are pre-loaded in the epoch before it is scheduled.
+    keys.rotate(1).unwrap();
+    assert_eq!(keys.epoch_number(), 1);
+    assert_eq!(keys.degrees().collect::<Vec<_>>(), [(1 << 5) - 1, (1 << 6) - 1]);
+
+    // Ensure the keys of the next epoch prove and verify a solution ahead of the epoch.
+    let next_challenge = EpochChallenge::new(2, Default::default(), (1 << 6) - 1).unwrap();
+    let solution = keys.prove(&next_challenge, address, rng.gen(), None).unwrap();
+    let solutions = CoinbaseSolution::new(vec![solution]).unwrap();
+    keys.check_solutions(&solutions, &next_challenge, 0u64).unwrap();
+
+    // Ensure an epoch challenge with a degree other than the scheduled one is rejected.
+    let invalid_challenge = EpochChallenge::new(2, Default::default(), (1 << 5) - 1).unwrap();
+    assert!(keys.prove(&invalid_challenge, address, rng.gen(), None).is_err());
+    assert!(keys.check_solutions(&solutions, &invalid_challenge, 0u64).is_err());
+
+    // Ensure the keys of the previous degree are dropped once they are no longer scheduled.
+    keys.rotate(2).unwrap();
+    assert_eq!(keys.degrees().collect::<Vec<_>>(), [(1 << 6) - 1]);
+    keys.check_solutions(&solutions, &next_challenge, 0u64).unwrap();
+}
+
+#[test]
+fn test_verifier() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    // Initialize the prover, and the verifiers with and without the proving key.
+    let degree = (1 << 5) - 1;
+    let prover = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let verifier = CoinbasePuzzle::<Testnet3>::trim_verifier(&srs).unwrap();
+    assert!(prover.is_prover());
+    assert!(!verifier.is_prover());
+    assert!(!prover.clone().into_verifier().is_prover());
+    assert_eq!(prover.coinbase_verifying_key(), verifier.coinbase_verifying_key());
+
+    // Ensure the verifier cannot prove, but verifies the prover's solutions.
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let address = Address::try_from(PrivateKey::<Testnet3>::new(&mut rng).unwrap()).unwrap();
+    assert!(verifier.prove(&epoch_challenge, address, rng.gen(), None).is_err());
+    assert!(verifier.coinbase_proving_key().is_err());
+    let solution = prover.prove(&epoch_challenge, address, rng.gen(), None).unwrap();
+    let solutions = CoinbaseSolution::new(vec![solution]).unwrap();
+    assert!(verifier.check_solutions(&solutions, &epoch_challenge, 0u64).is_ok());
+    assert!(prover.into_verifier().check_solutions(&solutions, &epoch_challenge, 0u64).is_ok());
+}
+
+#[test]
+fn test_posw_prover_and_verifier() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 15 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    // Initialize the typed prover and verifier.
+    let degree = (1 << 5) - 1;
+    let prover = PoswProver::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let verifier = PoswVerifier::<Testnet3>::trim(&srs).unwrap();
+    assert!(prover.as_puzzle().is_prover());
+    assert!(!verifier.as_puzzle().is_prover());
+    assert_eq!(prover.coinbase_verifying_key(), verifier.coinbase_verifying_key());
+
+    // Ensure a prover is only constructed from a coinbase puzzle with the proving key.
+    assert!(PoswProver::try_from(verifier.as_puzzle().clone()).is_err());
+    assert!(PoswProver::try_from(prover.as_puzzle().clone()).is_ok());
+
+    // Ensure the verifier, and the verifier of the prover, verify the prover's solutions.
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let address = Address::try_from(PrivateKey::<Testnet3>::new(&mut rng).unwrap()).unwrap();
+    let solution = prover.prove(&epoch_challenge, address, rng.gen(), None).unwrap();
+    let solutions = CoinbaseSolution::new(vec![solution]).unwrap();
+    verifier.check_solutions(&solutions, &epoch_challenge, 0u64).unwrap();
+    let prover_verifier = PoswVerifier::from(prover);
+    assert!(!prover_verifier.as_puzzle().is_prover());
+    prover_verifier.check_solutions(&solutions, &epoch_challenge, 0u64).unwrap();
+}
+
 /// Use `cargo test profiler --features timer` to run this test.
 #[ignore]
 #[test]
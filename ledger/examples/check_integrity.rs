@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks the consistency of a ledger stored in RocksDB, and optionally repairs its secondary indexes.
+//!
+//! This is intended for node operators recovering from a crash. Stop the node before running it.
+//!
+//! ```text
+//! cargo run --release --features rocks --example check_integrity -- [--dev <id> | --path <path>] [--repair]
+//! ```
+
+use aleo_std::StorageMode;
+use anyhow::{bail, Result};
+use console::network::{prelude::*, Testnet3};
+use snarkvm_ledger::{store::helpers::rocksdb::ConsensusDB, Block, Ledger};
+use std::path::PathBuf;
+
+type CurrentNetwork = Testnet3;
+
+/// Parses the storage mode and the repair flag from the command-line arguments.
+fn parse_args() -> Result<(StorageMode, bool)> {
+    let mut storage_mode = StorageMode::Production;
+    let mut repair = false;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dev" => match args.next().map(|id| id.parse::<u16>()) {
+                Some(Ok(id)) => storage_mode = StorageMode::Development(id),
+                _ => bail!("'--dev' requires a numeric development ID"),
+            },
+            "--path" => match args.next() {
+                Some(path) => storage_mode = StorageMode::Custom(PathBuf::from(path)),
+                None => bail!("'--path' requires a ledger directory"),
+            },
+            "--repair" => repair = true,
+            _ => bail!("Unknown argument '{arg}' (usage: [--dev <id> | --path <path>] [--repair])"),
+        }
+    }
+    Ok((storage_mode, repair))
+}
+
+fn main() -> Result<()> {
+    let (storage_mode, repair) = parse_args()?;
+
+    // Load the ledger, without the spot checks performed by `Ledger::load`.
+    let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes())?;
+    let ledger = Ledger::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::load_unchecked(genesis, storage_mode)?;
+
+    // Check the ledger.
+    let report = ledger.check_integrity(repair)?;
+
+    // Print the issues.
+    println!("Checked {} blocks", report.num_blocks());
+    for issue in report.issues() {
+        println!("  {issue}");
+    }
+    if report.is_repaired() {
+        println!("Rebuilt the secondary indexes, {} issues remain", report.remaining_issues().len());
+        for issue in report.remaining_issues() {
+            println!("  {issue}");
+        }
+    }
+
+    // Exit with an error if the ledger is inconsistent.
+    match report.is_consistent() {
+        true => println!("The ledger is consistent"),
+        false => {
+            println!("The ledger is inconsistent");
+            std::process::exit(1);
+        }
+    }
+    Ok(())
+}
@@ -81,16 +81,47 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         )
     }
 
+    /// Returns a handle to a candidate for the next block in the ledger, which can be checked for invalidation.
+    pub fn prepare_beacon_block_template<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        candidate_ratifications: Vec<Ratify<N>>,
+        candidate_solutions: Vec<ProverSolution<N>>,
+        candidate_transactions: Vec<Transaction<N>>,
+        rng: &mut R,
+    ) -> Result<TemplateHandle<N>> {
+        self.prepare_advance_to_next_beacon_block(
+            private_key,
+            candidate_ratifications,
+            candidate_solutions,
+            candidate_transactions,
+            rng,
+        )
+        .map(TemplateHandle::new)
+    }
+
+    /// Returns the reason the given block template is invalid, given the transmissions removed from
+    /// the memory pool since it was built, or `None` if the template is still valid.
+    pub fn check_block_template<'a>(
+        &self,
+        template: &TemplateHandle<N>,
+        removed_transaction_ids: impl IntoIterator<Item = &'a N::TransactionID>,
+        removed_puzzle_commitments: impl IntoIterator<Item = &'a PuzzleCommitment<N>>,
+    ) -> Option<TemplateInvalidation<N>> {
+        template.check_invalidation(self.latest_hash(), removed_transaction_ids, removed_puzzle_commitments)
+    }
+
     /// Adds the given block as the next block in the ledger.
     pub fn advance_to_next_block(&self, block: &Block<N>) -> Result<()> {
-        // Acquire the write lock on the current block.
-        let mut current_block = self.current_block.write();
+        // Acquire the advance lock, to ensure blocks are added one at a time.
+        // Note: The current block is not locked while the VM is updated, so that readers are not blocked.
+        // Until the current block is updated below, readers see the previous block as the latest block,
+        // even though the block store may already contain the new block.
+        let _advance_lock = self.advance_lock.lock();
         // Update the VM.
         self.vm.add_next_block(block)?;
         // Update the current block.
-        *current_block = block.clone();
-        // Drop the write lock on the current block.
-        drop(current_block);
+        *self.current_block.write() = block.clone();
 
         // Update the cached committee from storage.
         if let Ok(current_committee) = self.vm.finalize_store().committee_store().current_committee() {
@@ -105,6 +136,83 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         Ok(())
     }
+
+    /// Checks the given block is a valid next block, and adds it to the ledger, along with any orphan blocks it is
+    /// the missing parent of. If the parent of the block is unknown, the block is held in the orphan pool instead.
+    ///
+    /// Returns the hashes of the blocks added to the ledger, in order of height.
+    pub fn add_next_block_or_orphan<R: CryptoRng + Rng>(
+        &self,
+        block: Block<N>,
+        rng: &mut R,
+    ) -> Result<Vec<N::BlockHash>> {
+        // Ensure the block does not already exist.
+        if self.contains_block_hash(&block.hash())? {
+            bail!("Block hash '{}' already exists in the ledger", block.hash())
+        }
+        // If the parent of the block is unknown, hold the block until its parent arrives.
+        if !self.contains_block_hash(&block.previous_hash())? {
+            let latest_height = self.latest_height();
+            let mut orphan_pool = self.orphan_pool.write();
+            orphan_pool.remove_stale(latest_height);
+            orphan_pool.insert(block, latest_height, OffsetDateTime::now_utc().unix_timestamp());
+            return Ok(vec![]);
+        }
+
+        // Add the block, and then each orphan block that extends the new latest block.
+        let mut added = Vec::new();
+        let mut candidates = vec![block];
+        while !candidates.is_empty() {
+            let mut next_block = None;
+            for candidate in candidates {
+                // Ensure the candidate is a valid next block.
+                match self.check_next_block(&candidate, rng) {
+                    Ok(()) => {
+                        next_block = Some(candidate);
+                        break;
+                    }
+                    // The first block is not an orphan, so its error is returned to the caller.
+                    Err(error) if added.is_empty() => return Err(error),
+                    Err(error) => warn!("Discarding invalid orphan block '{}' - {error}", candidate.hash()),
+                }
+            }
+            let Some(next_block) = next_block else {
+                break;
+            };
+            // Add the block to the ledger.
+            self.advance_to_next_block(&next_block)?;
+            added.push(next_block.hash());
+            // Retrieve the orphan blocks that extend the new latest block.
+            let mut orphan_pool = self.orphan_pool.write();
+            candidates = orphan_pool.remove_children(next_block.hash());
+            orphan_pool.remove_stale(next_block.height());
+        }
+        Ok(added)
+    }
+
+    /// Returns the number of blocks held in the orphan pool.
+    pub fn num_orphan_blocks(&self) -> usize {
+        self.orphan_pool.read().len()
+    }
+
+    /// Returns `true` if the given block hash is held in the orphan pool.
+    pub fn contains_orphan_block(&self, block_hash: &N::BlockHash) -> bool {
+        self.orphan_pool.read().contains(block_hash)
+    }
+
+    /// Checks the given block is a valid next block, and adds it to the ledger.
+    /// The verification and storage updates run on the blocking thread pool, so the async runtime is not blocked.
+    #[cfg(feature = "tokio-async")]
+    pub async fn add_next_block_async(&self, block: Block<N>) -> Result<()> {
+        let ledger = self.clone();
+        tokio::task::spawn_blocking(move || {
+            // Ensure the block is a valid next block.
+            ledger.check_next_block(&block, &mut OsRng)?;
+            // Add the block to the ledger.
+            ledger.advance_to_next_block(&block)
+        })
+        .await?
+    }
 }
 
 /// Splits candidate solutions into a collection of accepted ones and aborted ones.
@@ -228,7 +336,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             None => OffsetDateTime::now_utc().unix_timestamp(),
         };
         // Compute the next cumulative weight.
-        let next_cumulative_weight = previous_block.cumulative_weight().saturating_add(combined_proof_target);
+        let next_cumulative_weight =
+            previous_block.chain_work().saturating_add(combined_proof_target).cumulative_weight();
         // Compute the next cumulative proof target.
         let next_cumulative_proof_target = latest_cumulative_proof_target.saturating_add(combined_proof_target);
         // Determine if the coinbase target is reached.
@@ -307,8 +416,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             next_timestamp,
         )?;
 
+        // Construct the header extensions.
+        // Note: Until `Header::RECEIPTS_ROOT_HEIGHT`, the header carries no extensions, and is written as version 1.
+        let extensions = match next_height >= Header::<N>::RECEIPTS_ROOT_HEIGHT {
+            true => vec![Header::receipts_root_extension(transactions.to_receipts_root()?)?],
+            false => vec![],
+        };
+
         // Construct the header.
-        let header = Header::from(
+        let header = Header::from_with_extensions(
             latest_state_root,
             transactions.to_transactions_root()?,
             transactions.to_finalize_root(ratified_finalize_operations)?,
@@ -316,6 +432,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             solutions_root,
             subdag_root,
             metadata,
+            extensions,
         )?;
 
         // Return the block template.
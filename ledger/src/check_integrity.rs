@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::BlockTree;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Checks the consistency of the ledger storage, and optionally repairs its secondary indexes.
+    ///
+    /// For every stored block, this re-derives the block hash, the header roots, and the state root from the stored
+    /// contents, and cross-checks the block, state root, transaction, and puzzle commitment indexes against the blocks.
+    /// Note: The finalize root is not re-derived, as it requires re-executing the finalize operations of the block.
+    ///
+    /// If `repair` is `true` and a secondary index is inconsistent, the secondary indexes are rebuilt from the
+    /// stored blocks, and the ledger is checked again. The ledger does not advance while the check is in progress.
+    pub fn check_integrity(&self, repair: bool) -> Result<IntegrityReport> {
+        // Ensure the ledger does not advance during the check.
+        let _advance_lock = self.advance_lock.lock();
+
+        // Check the ledger.
+        let (num_blocks, issues) = self.collect_integrity_issues()?;
+
+        // If requested, and a secondary index is inconsistent, rebuild the secondary indexes and check the ledger again.
+        let remaining_issues = match repair && issues.iter().any(|issue| issue.kind().is_repairable()) {
+            true => {
+                info!("Rebuilding the secondary indexes of the ledger...");
+                self.vm.block_store().rebuild_secondary_indexes()?;
+                Some(self.collect_integrity_issues()?.1)
+            }
+            false => None,
+        };
+
+        Ok(IntegrityReport::new(num_blocks, issues, remaining_issues))
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the number of stored blocks, and the integrity issues found in the ledger.
+    fn collect_integrity_issues(&self) -> Result<(u32, Vec<IntegrityIssue>)> {
+        let block_store = self.vm.block_store();
+
+        // Initialize the list of issues.
+        let mut issues = Vec::new();
+
+        // Determine the number of stored blocks.
+        let num_blocks = match block_store.max_height() {
+            Some(max_height) => max_height.saturating_add(1),
+            None => {
+                issues.push(IntegrityIssue::new(
+                    IntegrityIssueKind::MissingBlock,
+                    None,
+                    "The ledger contains no blocks",
+                ));
+                return Ok((0, issues));
+            }
+        };
+
+        // Initialize the block tree, which is re-derived from the stored block hashes.
+        // Note: If a block hash is missing, the state roots of the subsequent blocks cannot be re-derived.
+        let mut block_tree: Option<BlockTree<N>> = Some(N::merkle_tree_bhp(&[])?);
+        // Initialize the expected previous block hash.
+        let mut expected_previous_hash = N::BlockHash::default();
+
+        for height in 0..num_blocks {
+            // Retrieve the block hash.
+            let block_hash = match block_store.get_block_hash(height) {
+                Ok(Some(block_hash)) => block_hash,
+                Ok(None) | Err(_) => {
+                    let message = "The block hash is missing from the block ID index";
+                    issues.push(IntegrityIssue::new(IntegrityIssueKind::MissingBlock, Some(height), message));
+                    block_tree = None;
+                    continue;
+                }
+            };
+
+            // Re-derive the state root.
+            if let Some(tree) = block_tree.take() {
+                let tree = tree.prepare_append(&[block_hash.to_bits_le()])?;
+                let state_root: N::StateRoot = (*tree.root()).into();
+                match block_store.get_state_root(height) {
+                    Ok(Some(stored)) if stored == state_root => {}
+                    Ok(stored) => issues.push(IntegrityIssue::new(
+                        IntegrityIssueKind::StateRootMismatch,
+                        Some(height),
+                        format!("The stored state root is '{stored:?}', expected '{state_root}'"),
+                    )),
+                    Err(error) => issues.push(IntegrityIssue::new(
+                        IntegrityIssueKind::StateRootMismatch,
+                        Some(height),
+                        format!("Failed to load the state root - {error}"),
+                    )),
+                }
+                // Ensure the state root is indexed to the block height.
+                if block_store.find_block_height_from_state_root(state_root).ok().flatten() != Some(height) {
+                    let message = format!("The state root '{state_root}' is not indexed to the block height");
+                    issues.push(IntegrityIssue::new(IntegrityIssueKind::SecondaryIndexMismatch, Some(height), message));
+                }
+                // If this is the latest block, ensure the state root matches the current state root.
+                if height == num_blocks - 1 && block_store.current_state_root() != state_root {
+                    let message = format!(
+                        "The current state root is '{}', expected '{state_root}'",
+                        block_store.current_state_root()
+                    );
+                    issues.push(IntegrityIssue::new(IntegrityIssueKind::StateRootMismatch, Some(height), message));
+                }
+                block_tree = Some(tree);
+            }
+
+            // Ensure the block hash is indexed to the block height.
+            if block_store.get_block_height(&block_hash).ok().flatten() != Some(height) {
+                let message = format!("The block hash '{block_hash}' is not indexed to the block height");
+                issues.push(IntegrityIssue::new(IntegrityIssueKind::SecondaryIndexMismatch, Some(height), message));
+            }
+
+            // Load the block.
+            let block = match block_store.get_block(&block_hash) {
+                Ok(Some(block)) => block,
+                Ok(None) => {
+                    let message = format!("The block '{block_hash}' is missing from storage");
+                    issues.push(IntegrityIssue::new(IntegrityIssueKind::MissingBlock, Some(height), message));
+                    expected_previous_hash = block_hash;
+                    continue;
+                }
+                Err(error) => {
+                    let message = format!("Failed to load the block '{block_hash}' - {error}");
+                    issues.push(IntegrityIssue::new(IntegrityIssueKind::CorruptBlock, Some(height), message));
+                    expected_previous_hash = block_hash;
+                    continue;
+                }
+            };
+
+            // Check the contents of the block.
+            self.check_block_integrity(&block, height, expected_previous_hash, &mut issues);
+            expected_previous_hash = block_hash;
+        }
+
+        // Ensure the secondary block indexes do not contain stale entries.
+        let num_hashes = block_store.hashes().count();
+        if num_hashes != num_blocks as usize {
+            let message = format!("The block hash index contains {num_hashes} entries, expected {num_blocks}");
+            issues.push(IntegrityIssue::new(IntegrityIssueKind::SecondaryIndexMismatch, None, message));
+        }
+        let num_state_roots = block_store.state_roots().count();
+        if num_state_roots != num_blocks as usize {
+            let message = format!("The state root index contains {num_state_roots} entries, expected {num_blocks}");
+            issues.push(IntegrityIssue::new(IntegrityIssueKind::SecondaryIndexMismatch, None, message));
+        }
+
+        Ok((num_blocks, issues))
+    }
+
+    /// Checks the contents of the given stored block, recording any issues found.
+    fn check_block_integrity(
+        &self,
+        block: &Block<N>,
+        height: u32,
+        expected_previous_hash: N::BlockHash,
+        issues: &mut Vec<IntegrityIssue>,
+    ) {
+        let block_store = self.vm.block_store();
+        let block_hash = block.hash();
+        let mut push =
+            |kind: IntegrityIssueKind, message: String| issues.push(IntegrityIssue::new(kind, Some(height), message));
+
+        // Ensure the block height matches.
+        if block.height() != height {
+            push(IntegrityIssueKind::CorruptBlock, format!("The block header has height {}", block.height()));
+        }
+
+        // Ensure the previous block hash matches.
+        if block.previous_hash() != expected_previous_hash {
+            push(
+                IntegrityIssueKind::BrokenChain,
+                format!("The previous block hash is '{}', expected '{expected_previous_hash}'", block.previous_hash()),
+            );
+        }
+
+        // Re-derive the block hash.
+        let candidate_hash = block
+            .header()
+            .to_root()
+            .and_then(|header_root| N::hash_bhp1024(&to_bits_le![block.previous_hash(), header_root]));
+        match candidate_hash {
+            Ok(candidate_hash) if *block_hash == candidate_hash => {}
+            Ok(candidate_hash) => push(
+                IntegrityIssueKind::InvalidBlockHash,
+                format!("The block hash is '{block_hash}', expected '{}'", N::BlockHash::from(candidate_hash)),
+            ),
+            Err(error) => {
+                push(IntegrityIssueKind::InvalidBlockHash, format!("Failed to compute the block hash - {error}"))
+            }
+        }
+
+        // Re-derive the header roots.
+        let header = block.header();
+        let mut check_root = |name: &str, found: Option<Field<N>>, expected: Result<Field<N>>| match (found, expected) {
+            (Some(found), Ok(expected)) if found == expected => {}
+            (found, Ok(expected)) => {
+                push(IntegrityIssueKind::RootMismatch, format!("The {name} root is '{found:?}', expected '{expected}'"))
+            }
+            (_, Err(error)) => {
+                push(IntegrityIssueKind::RootMismatch, format!("Failed to compute the {name} root - {error}"))
+            }
+        };
+        check_root("transactions", Some(header.transactions_root()), block.transactions().to_transactions_root());
+        check_root("ratifications", Some(header.ratifications_root()), block.ratifications().to_ratifications_root());
+        check_root(
+            "solutions",
+            Some(header.solutions_root()),
+            block.solutions().map_or(Ok(Field::zero()), |solutions| solutions.to_accumulator_point()),
+        );
+        check_root("subdag", Some(header.subdag_root()), match block.authority() {
+            Authority::Quorum(subdag) => subdag.to_subdag_root(),
+            Authority::Beacon(_) => Ok(Field::zero()),
+        });
+        // Note: Blocks from before the receipts root was introduced (e.g. the genesis block) do not commit to it.
+        match header.receipts_root() {
+            Ok(Some(receipts_root)) => {
+                check_root("receipts", Some(receipts_root), block.transactions().to_receipts_root())
+            }
+            Ok(None) => {}
+            Err(error) => check_root("receipts", None, Err(error)),
+        }
+
+        // Ensure the puzzle commitments are indexed to the block height.
+        for puzzle_commitment in block.solutions().into_iter().flat_map(|solutions| solutions.keys()) {
+            if block_store.find_block_height_from_puzzle_commitment(puzzle_commitment).ok().flatten() != Some(height) {
+                push(
+                    IntegrityIssueKind::SecondaryIndexMismatch,
+                    format!("The puzzle commitment '{puzzle_commitment}' is not indexed to the block height"),
+                );
+            }
+        }
+
+        // Ensure the confirmed transactions are indexed to the block.
+        for confirmed in block.transactions().iter() {
+            let transaction_id = confirmed.id();
+            if block_store.find_block_hash(&transaction_id).ok().flatten() != Some(block_hash) {
+                push(
+                    IntegrityIssueKind::TransactionIndexMismatch,
+                    format!("The transaction '{transaction_id}' is not indexed to the block"),
+                );
+            }
+        }
+
+        // Ensure the rejected and aborted transaction IDs are indexed to the block.
+        let rejected_transaction_ids = block
+            .transactions()
+            .iter()
+            .filter(|confirmed| confirmed.is_rejected())
+            .filter_map(|confirmed| confirmed.to_unconfirmed_transaction_id().ok());
+        for transaction_id in rejected_transaction_ids.chain(block.aborted_transaction_ids().iter().copied()) {
+            if block_store.find_block_hash(&transaction_id).ok().flatten() != Some(block_hash) {
+                push(
+                    IntegrityIssueKind::SecondaryIndexMismatch,
+                    format!("The rejected or aborted transaction '{transaction_id}' is not indexed to the block"),
+                );
+            }
+        }
+    }
+}
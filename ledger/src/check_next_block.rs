@@ -89,7 +89,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             &self.latest_block(),
             self.latest_state_root(),
             &self.latest_committee()?,
-            self.coinbase_puzzle(),
+            self.coinbase_puzzle().as_puzzle(),
             &self.latest_epoch_challenge()?,
             OffsetDateTime::now_utc().unix_timestamp(),
             ratified_finalize_operations,
@@ -20,6 +20,22 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().find_block_height_from_state_root(state_root)
     }
 
+    /// Returns the height of the latest block in the given block locator that is also in the ledger,
+    /// or `None` if the block locator shares no blocks with the ledger.
+    pub fn find_common_ancestor(&self, locator: &BlockLocator<N>) -> Result<Option<u32>> {
+        for (height, hash) in locator.iter() {
+            // Skip heights beyond the latest block.
+            if *height > self.latest_height() {
+                continue;
+            }
+            // Check if the block hash at the height matches.
+            if self.get_hash(*height)? == *hash {
+                return Ok(Some(*height));
+            }
+        }
+        Ok(None)
+    }
+
     /// Returns the block hash that contains the given `transaction ID`.
     pub fn find_block_hash(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>> {
         self.vm.block_store().find_block_hash(transaction_id)
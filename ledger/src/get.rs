@@ -35,6 +35,45 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().get_state_path_for_commitment(commitment)
     }
 
+    /// Returns the inclusion proof for the given transaction ID, which can be verified against the block header.
+    pub fn prove_transaction_inclusion(&self, transaction_id: &N::TransactionID) -> Result<InclusionProof<N>> {
+        // Retrieve the block hash for the transaction ID.
+        let Some(block_hash) = self.find_block_hash(transaction_id)? else {
+            bail!("Transaction '{transaction_id}' does not exist in storage");
+        };
+        // Retrieve the block and prove the inclusion of the transaction.
+        self.get_block_by_hash(&block_hash)?.prove_transaction_inclusion(transaction_id)
+    }
+
+    /// Returns the state diff for the blocks in `(from_height, to_height]`, which allows mirrors
+    /// to stay in sync with the ledger without processing the full blocks.
+    pub fn diff(&self, from_height: u32, to_height: u32) -> Result<StateDiff<N>> {
+        // Ensure the heights are in order.
+        ensure!(
+            from_height <= to_height,
+            "The diff starting height {from_height} exceeds the ending height {to_height}"
+        );
+        // Ensure the ending height exists.
+        let latest_height = self.latest_height();
+        ensure!(
+            to_height <= latest_height,
+            "The diff ending height {to_height} exceeds the latest height {latest_height}"
+        );
+
+        // Construct the block diffs.
+        let blocks = cfg_into_iter!((from_height + 1)..=to_height)
+            .map(|height| {
+                let block = self.get_block(height)?;
+                let Some(state_root) = self.get_state_root(height)? else {
+                    bail!("Missing state root for block {height}");
+                };
+                Ok(BlockDiff::from_block(&block, state_root))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Construct the state diff.
+        StateDiff::new(from_height, self.get_hash(from_height)?, blocks)
+    }
+
     /// Returns the epoch challenge for the given block height.
     pub fn get_epoch_challenge(&self, block_height: u32) -> Result<EpochChallenge<N>> {
         // Compute the epoch number from the current block height.
@@ -43,8 +82,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let epoch_starting_height = epoch_number * N::NUM_BLOCKS_PER_EPOCH;
         // Retrieve the epoch block hash, defined as the 'previous block hash' from the epoch starting height.
         let epoch_block_hash = self.get_previous_hash(epoch_starting_height)?;
-        // Construct the epoch challenge.
-        EpochChallenge::new(epoch_number, epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)
+        // Construct the epoch challenge, using the scheduled degree for the epoch.
+        EpochChallenge::new(epoch_number, epoch_block_hash, CoinbasePuzzle::<N>::degree_for_epoch(epoch_number)?)
     }
 
     /// Returns the block for the given block height.
@@ -65,6 +104,14 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the block for the given block height.
+    /// The storage lookup runs on the blocking thread pool, so the async runtime is not blocked.
+    #[cfg(feature = "tokio-async")]
+    pub async fn get_block_async(&self, height: u32) -> Result<Block<N>> {
+        let ledger = self.clone();
+        tokio::task::spawn_blocking(move || ledger.get_block(height)).await?
+    }
+
     /// Returns the blocks in the given block range.
     /// The range is inclusive of the start and exclusive of the end.
     pub fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>> {
@@ -116,7 +163,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     pub fn get_header(&self, height: u32) -> Result<Header<N>> {
         // If the height is 0, return the genesis block header.
         if height == 0 {
-            return Ok(*self.genesis_block.header());
+            return Ok(self.genesis_block.header().clone());
         }
         // Retrieve the block hash.
         let block_hash = match self.vm.block_store().get_block_hash(height)? {
@@ -241,6 +288,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     pub fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<Option<BatchCertificate<N>>> {
         self.vm.block_store().get_batch_certificate(certificate_id)
     }
+
+    /// Returns the block locator for the latest block.
+    pub fn get_block_locator(&self) -> Result<BlockLocator<N>> {
+        let locators = BlockLocator::<N>::heights(self.latest_height())
+            .into_iter()
+            .map(|height| Ok((height, self.get_hash(height)?)))
+            .collect::<Result<Vec<_>>>()?;
+        BlockLocator::new(locators)
+    }
 }
 
 #[cfg(test)]
@@ -251,6 +307,25 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_get_block_locator() {
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(genesis.clone(), StorageMode::Production).unwrap();
+        // Ensure the block locator contains only the genesis block.
+        let locator = ledger.get_block_locator().unwrap();
+        assert_eq!(locator.iter().collect::<Vec<_>>(), vec![&(0, genesis.hash())]);
+        // Ensure the common ancestor is the genesis block.
+        assert_eq!(ledger.find_common_ancestor(&locator).unwrap(), Some(0));
+
+        // Ensure a locator for a different chain has no common ancestor.
+        let hash = <CurrentNetwork as Network>::BlockHash::from(Field::from_u8(1));
+        let other = BlockLocator::new(vec![(1, hash), (0, hash)]).unwrap();
+        assert_eq!(ledger.find_common_ancestor(&other).unwrap(), None);
+    }
+
     #[test]
     fn test_get_block() {
         // Load the genesis block.
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, bail, ensure, Result};
+use parking_lot::{Condvar, Mutex};
+use std::{
+    collections::VecDeque,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{mpsc, Arc},
+    thread,
+};
+
+/// The priority of a verification job, from lowest to highest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VerificationPriority {
+    /// A job requested through an RPC endpoint.
+    Rpc,
+    /// A job for a transaction or solution entering the mempool.
+    Mempool,
+    /// A job for a block being added to the ledger.
+    Block,
+}
+
+impl VerificationPriority {
+    /// The number of priorities.
+    const NUM_PRIORITIES: usize = 3;
+
+    /// Returns the index of the queue for the priority.
+    const fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// A queued verification job.
+type Job = Box<dyn FnOnce() + Send>;
+
+/// The pending jobs, with one queue per priority.
+struct Queues {
+    /// The queued jobs, indexed by priority.
+    jobs: [VecDeque<Job>; VerificationPriority::NUM_PRIORITIES],
+    /// Whether the executor is shutting down.
+    is_shutdown: bool,
+}
+
+impl Queues {
+    /// Removes and returns the oldest job with the highest priority, if any.
+    fn pop(&mut self) -> Option<Job> {
+        self.jobs.iter_mut().rev().find_map(VecDeque::pop_front)
+    }
+}
+
+/// The state shared between the executor and its workers.
+struct Shared {
+    /// The pending jobs.
+    queues: Mutex<Queues>,
+    /// Signals the workers when a job is queued, or the executor shuts down.
+    condvar: Condvar,
+}
+
+/// An executor that runs proof-verification jobs on a bounded number of worker threads.
+///
+/// Jobs are started in order of priority, so blocks are verified before mempool entries,
+/// and mempool entries before RPC requests. Each priority has a bounded queue, and jobs
+/// submitted to a full queue are rejected, so a burst of low-priority work cannot grow without bound.
+pub struct VerificationExecutor {
+    /// The state shared with the workers.
+    shared: Arc<Shared>,
+    /// The maximum number of queued jobs, per priority.
+    max_queued: usize,
+    /// The worker threads.
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl VerificationExecutor {
+    /// Initializes a new executor, with `max_concurrency` workers and up to `max_queued` pending jobs per priority.
+    pub fn new(max_concurrency: usize, max_queued: usize) -> Result<Self> {
+        // Ensure the executor has at least one worker.
+        ensure!(max_concurrency > 0, "The verification executor requires at least one worker");
+        // Ensure the executor can queue at least one job.
+        ensure!(max_queued > 0, "The verification executor requires a non-empty queue");
+
+        // Initialize the shared state.
+        let shared = Arc::new(Shared {
+            queues: Mutex::new(Queues { jobs: Default::default(), is_shutdown: false }),
+            condvar: Condvar::new(),
+        });
+        // Spawn the workers.
+        let workers = (0..max_concurrency)
+            .map(|index| {
+                let shared = shared.clone();
+                thread::Builder::new()
+                    .name(format!("verification-worker-{index}"))
+                    .spawn(move || Self::run_worker(&shared))
+                    .map_err(|error| anyhow!("Failed to spawn a verification worker - {error}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { shared, max_queued, workers })
+    }
+
+    /// Returns the number of workers.
+    pub fn max_concurrency(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Returns the maximum number of queued jobs, per priority.
+    pub const fn max_queued(&self) -> usize {
+        self.max_queued
+    }
+
+    /// Returns the number of queued jobs with the given priority, excluding running jobs.
+    pub fn num_queued(&self, priority: VerificationPriority) -> usize {
+        self.shared.queues.lock().jobs[priority.index()].len()
+    }
+
+    /// Queues the given verification job, returning a handle to its result.
+    /// If the queue for the given priority is full, the job is rejected.
+    pub fn submit<F>(&self, priority: VerificationPriority, job: F) -> Result<VerificationHandle>
+    where
+        F: FnOnce() -> Result<()> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        // Wrap the job, so that a panic is reported as a failed verification.
+        let job: Job = Box::new(move || {
+            let result =
+                catch_unwind(AssertUnwindSafe(job)).unwrap_or_else(|_| Err(anyhow!("The verification job panicked")));
+            // Note: The receiver may have been dropped, in which case the result is discarded.
+            let _ = sender.send(result);
+        });
+
+        {
+            let mut queues = self.shared.queues.lock();
+            // Ensure the queue for the priority is not full.
+            let queue = &mut queues.jobs[priority.index()];
+            if queue.len() >= self.max_queued {
+                bail!("The verification queue for {priority:?} jobs is full ({} jobs)", self.max_queued)
+            }
+            // Queue the job.
+            queue.push_back(job);
+        }
+        // Wake a worker.
+        self.shared.condvar.notify_one();
+
+        Ok(VerificationHandle { receiver })
+    }
+
+    /// Runs queued jobs until the executor shuts down.
+    fn run_worker(shared: &Shared) {
+        loop {
+            let job = {
+                let mut queues = shared.queues.lock();
+                loop {
+                    if queues.is_shutdown {
+                        return;
+                    }
+                    if let Some(job) = queues.pop() {
+                        break job;
+                    }
+                    shared.condvar.wait(&mut queues);
+                }
+            };
+            job();
+        }
+    }
+}
+
+impl Drop for VerificationExecutor {
+    /// Stops the workers once their running jobs complete. Queued jobs are cancelled.
+    fn drop(&mut self) {
+        {
+            let mut queues = self.shared.queues.lock();
+            queues.is_shutdown = true;
+            queues.jobs.iter_mut().for_each(VecDeque::clear);
+        }
+        self.shared.condvar.notify_all();
+        for worker in self.workers.drain(..) {
+            // Note: Job panics are caught, so a worker can only fail if the executor itself is broken.
+            let _ = worker.join();
+        }
+    }
+}
+
+/// A handle to the result of a queued verification job.
+pub struct VerificationHandle {
+    /// The receiver for the result of the job.
+    receiver: mpsc::Receiver<Result<()>>,
+}
+
+impl VerificationHandle {
+    /// Blocks until the job completes, and returns its result.
+    /// If the job was cancelled before it started, an error is returned.
+    pub fn wait(self) -> Result<()> {
+        self.receiver.recv().unwrap_or_else(|_| Err(anyhow!("The verification job was cancelled")))
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+/// The kind of an inconsistency found by `Ledger::check_integrity`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IntegrityIssueKind {
+    /// A block is missing from storage.
+    MissingBlock,
+    /// A block could not be loaded from storage, or its contents do not match its height.
+    CorruptBlock,
+    /// The previous block hash of a block does not match the block before it.
+    BrokenChain,
+    /// The block hash does not match the hash re-derived from its header.
+    InvalidBlockHash,
+    /// A root in the block header does not match the root re-derived from the block contents.
+    RootMismatch,
+    /// A stored state root does not match the state root re-derived from the block hashes.
+    StateRootMismatch,
+    /// A confirmed transaction is not indexed to the block that contains it.
+    TransactionIndexMismatch,
+    /// A secondary index is missing an entry, points to the wrong block, or contains a stale entry.
+    SecondaryIndexMismatch,
+}
+
+impl IntegrityIssueKind {
+    /// Returns `true` if the issue is repaired by rebuilding the secondary indexes.
+    pub const fn is_repairable(&self) -> bool {
+        matches!(self, Self::SecondaryIndexMismatch)
+    }
+}
+
+/// An inconsistency found by `Ledger::check_integrity`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntegrityIssue {
+    /// The kind of the issue.
+    kind: IntegrityIssueKind,
+    /// The height of the affected block, if the issue applies to a single block.
+    height: Option<u32>,
+    /// The human-readable description of the issue.
+    message: String,
+}
+
+impl IntegrityIssue {
+    /// Initializes a new integrity issue.
+    pub fn new(kind: IntegrityIssueKind, height: Option<u32>, message: impl Into<String>) -> Self {
+        Self { kind, height, message: message.into() }
+    }
+
+    /// Returns the kind of the issue.
+    pub const fn kind(&self) -> IntegrityIssueKind {
+        self.kind
+    }
+
+    /// Returns the height of the affected block, if any.
+    pub const fn height(&self) -> Option<u32> {
+        self.height
+    }
+
+    /// Returns the human-readable description of the issue.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for IntegrityIssue {
+    /// Prints the issue, prefixed with the height of the affected block, if any.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.height {
+            Some(height) => write!(f, "[block {height}] {:?}: {}", self.kind, self.message),
+            None => write!(f, "{:?}: {}", self.kind, self.message),
+        }
+    }
+}
+
+/// The outcome of `Ledger::check_integrity`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// The number of blocks that were checked.
+    num_blocks: u32,
+    /// The issues found by the check.
+    issues: Vec<IntegrityIssue>,
+    /// The issues that remain after the repair, or `None` if no repair was performed.
+    remaining_issues: Option<Vec<IntegrityIssue>>,
+}
+
+impl IntegrityReport {
+    /// Initializes a new integrity report.
+    pub(crate) fn new(
+        num_blocks: u32,
+        issues: Vec<IntegrityIssue>,
+        remaining_issues: Option<Vec<IntegrityIssue>>,
+    ) -> Self {
+        Self { num_blocks, issues, remaining_issues }
+    }
+
+    /// Returns the number of blocks that were checked.
+    pub const fn num_blocks(&self) -> u32 {
+        self.num_blocks
+    }
+
+    /// Returns the issues found by the check, before any repair.
+    pub fn issues(&self) -> &[IntegrityIssue] {
+        &self.issues
+    }
+
+    /// Returns `true` if the secondary indexes were rebuilt.
+    pub const fn is_repaired(&self) -> bool {
+        self.remaining_issues.is_some()
+    }
+
+    /// Returns the issues that remain in the ledger, after any repair.
+    pub fn remaining_issues(&self) -> &[IntegrityIssue] {
+        self.remaining_issues.as_deref().unwrap_or(&self.issues)
+    }
+
+    /// Returns `true` if no issues remain in the ledger.
+    pub fn is_consistent(&self) -> bool {
+        self.remaining_issues().is_empty()
+    }
+}
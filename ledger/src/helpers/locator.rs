@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// The number of consecutive block hashes at the tip of a block locator.
+pub const NUM_RECENT_LOCATORS: u32 = 32;
+/// The maximum number of block hashes in a block locator.
+/// This covers the recent run, one hash per doubling of the spacing across the `u32` heights, and the genesis block.
+pub const MAX_LOCATORS: usize = NUM_RECENT_LOCATORS as usize + u32::BITS as usize + 1;
+
+/// A sparse summary of a chain, used to find the latest common block with a peer.
+///
+/// A block locator contains the hashes of the latest `NUM_RECENT_LOCATORS` blocks, followed by hashes at
+/// exponentially increasing spacing, down to the genesis block. Its size is logarithmic in the chain height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockLocator<N: Network> {
+    /// The block heights and hashes, in strictly decreasing order of height.
+    locators: Vec<(u32, N::BlockHash)>,
+}
+
+impl<N: Network> BlockLocator<N> {
+    /// Initializes a new block locator from the given block heights and hashes.
+    pub fn new(locators: Vec<(u32, N::BlockHash)>) -> Result<Self> {
+        // Ensure the locators are not empty.
+        ensure!(!locators.is_empty(), "A block locator must contain at least one block");
+        // Ensure the locators do not exceed the maximum.
+        ensure!(locators.len() <= MAX_LOCATORS, "A block locator must contain at most {MAX_LOCATORS} blocks");
+        // Ensure the heights are strictly decreasing.
+        ensure!(
+            locators.windows(2).all(|pair| pair[0].0 > pair[1].0),
+            "The block locator heights must be strictly decreasing"
+        );
+        // Ensure the locators end at the genesis block.
+        ensure!(locators.last().map(|(height, _)| *height) == Some(0), "A block locator must end at the genesis block");
+        Ok(Self { locators })
+    }
+
+    /// Returns the block heights to include in a block locator for a chain with the given latest height.
+    pub fn heights(latest_height: u32) -> Vec<u32> {
+        let mut heights = Vec::with_capacity(MAX_LOCATORS);
+        // Add the recent run of heights.
+        let mut height = latest_height;
+        for _ in 0..NUM_RECENT_LOCATORS {
+            heights.push(height);
+            match height.checked_sub(1) {
+                Some(next) => height = next,
+                None => return heights,
+            }
+        }
+        // Add the exponentially spaced heights.
+        let mut step = 2u32;
+        while height > 0 {
+            heights.push(height);
+            height = height.saturating_sub(step);
+            step = step.saturating_mul(2);
+        }
+        // Add the genesis height.
+        heights.push(0);
+        heights
+    }
+
+    /// Returns the latest block height in the block locator.
+    pub fn latest_height(&self) -> u32 {
+        self.locators[0].0
+    }
+
+    /// Returns the block heights and hashes, in decreasing order of height.
+    pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = &(u32, N::BlockHash)> {
+        self.locators.iter()
+    }
+}
+
+impl<N: Network> FromBytes for BlockLocator<N> {
+    /// Reads the block locator from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid block locator version"));
+        }
+        // Read the number of locators.
+        let num_locators = usize::try_from(u64::from(VarInt::read_le(&mut reader)?)).map_err(error)?;
+        // Ensure the number of locators is within bounds.
+        if num_locators > MAX_LOCATORS {
+            return Err(error(format!("A block locator must contain at most {MAX_LOCATORS} blocks")));
+        }
+        // Read the locators.
+        let locators = (0..num_locators)
+            .map(|_| Ok((u32::read_le(&mut reader)?, N::BlockHash::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        Self::new(locators).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for BlockLocator<N> {
+    /// Writes the block locator to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of locators.
+        VarInt(self.locators.len() as u64).write_le(&mut writer)?;
+        // Write the locators.
+        for (height, hash) in &self.locators {
+            height.write_le(&mut writer)?;
+            hash.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, types::Field};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_heights() {
+        // Check a short chain.
+        assert_eq!(BlockLocator::<CurrentNetwork>::heights(0), vec![0]);
+        assert_eq!(BlockLocator::<CurrentNetwork>::heights(3), vec![3, 2, 1, 0]);
+
+        // Check a long chain.
+        let heights = BlockLocator::<CurrentNetwork>::heights(1000);
+        assert_eq!(heights[..NUM_RECENT_LOCATORS as usize], (969..=1000).rev().collect::<Vec<_>>());
+        assert_eq!(heights[NUM_RECENT_LOCATORS as usize..], [968, 966, 962, 954, 938, 906, 842, 714, 458, 0]);
+
+        // Ensure the heights always form a valid block locator.
+        for latest_height in [0, 1, 31, 32, 33, 1 << 20, u32::MAX] {
+            let heights = BlockLocator::<CurrentNetwork>::heights(latest_height);
+            let locators =
+                heights.into_iter().map(|height| (height, Field::<CurrentNetwork>::from_u32(height).into())).collect();
+            assert_eq!(BlockLocator::<CurrentNetwork>::new(locators).unwrap().latest_height(), latest_height);
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let hash = <CurrentNetwork as Network>::BlockHash::default();
+        // Ensure invalid block locators are rejected.
+        assert!(BlockLocator::<CurrentNetwork>::new(vec![]).is_err());
+        assert!(BlockLocator::<CurrentNetwork>::new(vec![(1, hash)]).is_err());
+        assert!(BlockLocator::<CurrentNetwork>::new(vec![(0, hash), (1, hash)]).is_err());
+        assert!(BlockLocator::<CurrentNetwork>::new(vec![(1, hash), (1, hash), (0, hash)]).is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let locators = BlockLocator::<CurrentNetwork>::heights(u32::MAX)
+            .into_iter()
+            .map(|height| (height, Field::<CurrentNetwork>::from_u32(height).into()))
+            .collect::<Vec<_>>();
+        let expected = BlockLocator::<CurrentNetwork>::new(locators).unwrap();
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, BlockLocator::read_le(&expected_bytes[..]).unwrap());
+        assert!(BlockLocator::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Ensure the number of locators is written as a variable-length integer.
+        let num_locators = expected.locators.len();
+        assert_eq!(expected_bytes.len(), 1 + VarInt(num_locators as u64).encoded_len() + num_locators * (4 + 32));
+    }
+}
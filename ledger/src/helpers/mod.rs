@@ -15,5 +15,26 @@
 mod bft;
 pub use bft::*;
 
+mod executor;
+pub use executor::*;
+
+mod integrity;
+pub use integrity::*;
+
+mod locator;
+pub use locator::*;
+
+mod orphan;
+pub use orphan::*;
+
+mod pending_header;
+pub use pending_header::*;
+
+mod relay;
+pub use relay::*;
+
 mod supply;
 pub use supply::*;
+
+mod template;
+pub use template::*;
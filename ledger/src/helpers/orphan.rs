@@ -0,0 +1,323 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::{prelude::ToBytes, Network};
+use ledger_block::Block;
+
+use indexmap::IndexMap;
+
+/// The maximum number of orphan blocks held in the pool.
+pub const MAX_ORPHAN_BLOCKS: usize = 256;
+/// The maximum number of orphan blocks held in the pool at any one height.
+pub const MAX_ORPHAN_BLOCKS_PER_HEIGHT: usize = 4;
+/// The maximum total size of the orphan blocks held in the pool, in bytes.
+pub const MAX_ORPHAN_BYTES: usize = 256 * 1024 * 1024; // 256 MiB
+/// The maximum age of an orphan block in the pool, in seconds.
+pub const MAX_ORPHAN_AGE_IN_SECS: i64 = 600;
+/// The maximum number of blocks above the latest height that an orphan block may be at.
+pub const MAX_ORPHAN_LOOKAHEAD: u32 = 64;
+
+/// A pool of blocks whose parents are not yet known, held until their parents arrive.
+///
+/// The pool is bounded by the number of blocks, the number of blocks at each height, the total size of the blocks,
+/// and their age. When a bound is reached, the oldest blocks are evicted first. The ledger does not know which peer
+/// sent a block, so the per-height bound is what stops a single sender from filling the pool with forks of one height.
+/// Blocks more than `MAX_ORPHAN_LOOKAHEAD` above the latest height are rejected outright, so that blocks at
+/// far-future heights cannot evict the orphan blocks near the tip.
+#[derive(Clone, Debug)]
+pub struct OrphanPool<N: Network> {
+    /// The orphan blocks, along with the UNIX timestamp they were received at and their size in bytes,
+    /// in order of arrival.
+    orphans: IndexMap<N::BlockHash, (Block<N>, i64, usize)>,
+    /// The total size of the orphan blocks, in bytes.
+    num_bytes: usize,
+    /// The maximum number of orphan blocks.
+    max_orphans: usize,
+    /// The maximum number of orphan blocks at any one height.
+    max_orphans_per_height: usize,
+    /// The maximum total size of the orphan blocks, in bytes.
+    max_bytes: usize,
+    /// The maximum age of an orphan block, in seconds.
+    max_age_in_secs: i64,
+}
+
+impl<N: Network> Default for OrphanPool<N> {
+    /// Initializes a new orphan pool with the default bounds.
+    fn default() -> Self {
+        Self::new(MAX_ORPHAN_BLOCKS, MAX_ORPHAN_BLOCKS_PER_HEIGHT, MAX_ORPHAN_BYTES, MAX_ORPHAN_AGE_IN_SECS)
+    }
+}
+
+impl<N: Network> OrphanPool<N> {
+    /// Initializes a new orphan pool, bounded by the given number of blocks, number of blocks per height,
+    /// total size in bytes, and age in seconds.
+    pub fn new(max_orphans: usize, max_orphans_per_height: usize, max_bytes: usize, max_age_in_secs: i64) -> Self {
+        Self { orphans: IndexMap::new(), num_bytes: 0, max_orphans, max_orphans_per_height, max_bytes, max_age_in_secs }
+    }
+
+    /// Returns the number of orphan blocks.
+    pub fn len(&self) -> usize {
+        self.orphans.len()
+    }
+
+    /// Returns `true` if there are no orphan blocks.
+    pub fn is_empty(&self) -> bool {
+        self.orphans.is_empty()
+    }
+
+    /// Returns the total size of the orphan blocks, in bytes.
+    pub const fn num_bytes(&self) -> usize {
+        self.num_bytes
+    }
+
+    /// Returns `true` if the given block hash is an orphan block.
+    pub fn contains(&self, block_hash: &N::BlockHash) -> bool {
+        self.orphans.contains_key(block_hash)
+    }
+
+    /// Inserts the given block, received at the given UNIX timestamp, evicting the oldest blocks if the pool is full.
+    /// Returns `false` if the block is already in the pool, if it is more than `MAX_ORPHAN_LOOKAHEAD` above
+    /// the given latest height, or if the pool cannot hold it.
+    pub fn insert(&mut self, block: Block<N>, latest_height: u32, timestamp: i64) -> bool {
+        // Ensure the block is within the lookahead of the latest height.
+        if block.height() > latest_height.saturating_add(MAX_ORPHAN_LOOKAHEAD) {
+            return false;
+        }
+        // Remove the expired orphan blocks.
+        self.remove_expired(timestamp);
+        // Ensure the block is new, and that the pool can hold a block.
+        if self.max_orphans == 0 || self.max_orphans_per_height == 0 || self.orphans.contains_key(&block.hash()) {
+            return false;
+        }
+        // Ensure the block fits in the pool on its own.
+        let size = match block.to_bytes_le() {
+            Ok(bytes) if bytes.len() <= self.max_bytes => bytes.len(),
+            _ => return false,
+        };
+        // Evict the oldest orphan blocks at the same height, until there is space at the height.
+        let height = block.height();
+        while self.orphans.values().filter(|(orphan, ..)| orphan.height() == height).count()
+            >= self.max_orphans_per_height
+        {
+            match self.orphans.values().position(|(orphan, ..)| orphan.height() == height) {
+                Some(index) => self.remove_index(index),
+                None => break,
+            }
+        }
+        // Evict the oldest orphan blocks, until there is space for the block.
+        while self.orphans.len() >= self.max_orphans || self.num_bytes.saturating_add(size) > self.max_bytes {
+            self.remove_index(0);
+        }
+        self.num_bytes = self.num_bytes.saturating_add(size);
+        self.orphans.insert(block.hash(), (block, timestamp, size));
+        true
+    }
+
+    /// Removes and returns the orphan blocks whose parent is the given block hash, in order of arrival.
+    pub fn remove_children(&mut self, parent_hash: N::BlockHash) -> Vec<Block<N>> {
+        let children = self
+            .orphans
+            .iter()
+            .filter(|(_, (block, ..))| block.previous_hash() == parent_hash)
+            .map(|(hash, _)| *hash)
+            .collect::<Vec<_>>();
+        children.into_iter().filter_map(|hash| self.remove(&hash)).collect()
+    }
+
+    /// Removes the orphan blocks that are older than the maximum age at the given UNIX timestamp.
+    pub fn remove_expired(&mut self, timestamp: i64) {
+        let max_age_in_secs = self.max_age_in_secs;
+        self.retain(|_, received_at| timestamp.saturating_sub(received_at) <= max_age_in_secs);
+    }
+
+    /// Removes the orphan blocks at or below the given height, as they can no longer extend the ledger.
+    pub fn remove_stale(&mut self, latest_height: u32) {
+        self.retain(|block, _| block.height() > latest_height);
+    }
+}
+
+impl<N: Network> OrphanPool<N> {
+    /// Removes and returns the orphan block with the given hash, if it exists.
+    fn remove(&mut self, block_hash: &N::BlockHash) -> Option<Block<N>> {
+        let (block, _, size) = self.orphans.shift_remove(block_hash)?;
+        self.num_bytes = self.num_bytes.saturating_sub(size);
+        Some(block)
+    }
+
+    /// Removes the orphan block at the given index, in order of arrival.
+    fn remove_index(&mut self, index: usize) {
+        if let Some((_, (_, _, size))) = self.orphans.shift_remove_index(index) {
+            self.num_bytes = self.num_bytes.saturating_sub(size);
+        }
+    }
+
+    /// Retains only the orphan blocks for which the given predicate, on the block and the timestamp it was
+    /// received at, returns `true`.
+    fn retain(&mut self, mut keep: impl FnMut(&Block<N>, i64) -> bool) {
+        let mut num_removed_bytes = 0usize;
+        self.orphans.retain(|_, (block, received_at, size)| match keep(block, *received_at) {
+            true => true,
+            false => {
+                num_removed_bytes = num_removed_bytes.saturating_add(*size);
+                false
+            }
+        });
+        self.num_bytes = self.num_bytes.saturating_sub(num_removed_bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{sample_genesis_block, CurrentNetwork};
+    use console::{prelude::*, types::Field};
+    use ledger_block::{Header, Metadata};
+
+    #[test]
+    fn test_orphan_pool() {
+        let block = sample_genesis_block();
+        let mut pool = OrphanPool::<CurrentNetwork>::new(1, 1, MAX_ORPHAN_BYTES, 10);
+        assert!(pool.is_empty());
+
+        // Ensure a block is inserted once.
+        assert!(pool.insert(block.clone(), 0, 100));
+        assert!(!pool.insert(block.clone(), 0, 100));
+        assert!(pool.contains(&block.hash()));
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool.num_bytes(), block.to_bytes_le().unwrap().len());
+
+        // Ensure the block is only returned for its parent.
+        assert!(pool.remove_children(block.hash()).is_empty());
+        assert_eq!(pool.remove_children(block.previous_hash()), vec![block.clone()]);
+        assert!(pool.is_empty());
+        assert_eq!(pool.num_bytes(), 0);
+
+        // Ensure expired blocks are removed.
+        assert!(pool.insert(block.clone(), 0, 100));
+        pool.remove_expired(110);
+        assert_eq!(pool.len(), 1);
+        pool.remove_expired(111);
+        assert!(pool.is_empty());
+        assert_eq!(pool.num_bytes(), 0);
+
+        // Ensure stale blocks are removed.
+        assert!(pool.insert(block.clone(), 0, 100));
+        pool.remove_stale(0);
+        assert!(pool.is_empty());
+        assert_eq!(pool.num_bytes(), 0);
+
+        // Ensure a pool without capacity holds no blocks.
+        assert!(!OrphanPool::<CurrentNetwork>::new(0, 1, MAX_ORPHAN_BYTES, 10).insert(block.clone(), 0, 100));
+        assert!(!OrphanPool::<CurrentNetwork>::new(1, 0, MAX_ORPHAN_BYTES, 10).insert(block, 0, 100));
+    }
+
+    /// Returns a copy of the genesis block with the given block hash, to sample distinct blocks at the same height.
+    fn sample_block_with_hash(hash: u8) -> Block<CurrentNetwork> {
+        let block = sample_genesis_block();
+        Block::from_unchecked(
+            Field::<CurrentNetwork>::from_u8(hash).into(),
+            block.previous_hash(),
+            block.header().clone(),
+            block.authority().clone(),
+            block.ratifications().clone(),
+            block.solutions().cloned(),
+            block.transactions().clone(),
+            block.aborted_transaction_ids().clone(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_orphan_pool_bounds() {
+        // Sample two distinct blocks at the same height.
+        let (first, second) = (sample_block_with_hash(1), sample_block_with_hash(2));
+        assert_ne!(first.hash(), second.hash());
+        assert_eq!(first.height(), second.height());
+        let size = first.to_bytes_le().unwrap().len();
+
+        // Ensure a block larger than the byte bound is rejected.
+        let mut pool = OrphanPool::<CurrentNetwork>::new(8, 8, size - 1, 10);
+        assert!(!pool.insert(first.clone(), 0, 100));
+        assert!(pool.is_empty());
+
+        // Ensure the oldest block is evicted to stay within the byte bound.
+        let mut pool = OrphanPool::<CurrentNetwork>::new(8, 8, size + size / 2, 10);
+        assert!(pool.insert(first.clone(), 0, 100));
+        assert!(pool.insert(second.clone(), 0, 100));
+        assert!(!pool.contains(&first.hash()));
+        assert!(pool.contains(&second.hash()));
+        assert!(pool.num_bytes() <= size + size / 2);
+
+        // Ensure the oldest block at a height is evicted to stay within the per-height bound.
+        let mut pool = OrphanPool::<CurrentNetwork>::new(8, 1, MAX_ORPHAN_BYTES, 10);
+        assert!(pool.insert(first.clone(), 0, 100));
+        assert!(pool.insert(second.clone(), 0, 100));
+        assert_eq!(pool.len(), 1);
+        assert!(!pool.contains(&first.hash()));
+        assert!(pool.contains(&second.hash()));
+    }
+
+    /// Returns a copy of the genesis block with the given block hash, at the given (nonzero) height.
+    fn sample_block_at_height(hash: u8, height: u32) -> Block<CurrentNetwork> {
+        let block = sample_block_with_hash(hash);
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            height as u64,
+            height,
+            0,
+            0,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            CurrentNetwork::GENESIS_TIMESTAMP + 1,
+        )
+        .unwrap();
+        let (zero, one) = (Field::<CurrentNetwork>::zero(), Field::from_u8(1));
+        let previous_state_root = <CurrentNetwork as Network>::StateRoot::from(one);
+        let header = Header::from(previous_state_root, one, one, one, zero, zero, metadata).unwrap();
+        Block::from_unchecked(
+            block.hash(),
+            block.previous_hash(),
+            header,
+            block.authority().clone(),
+            block.ratifications().clone(),
+            block.solutions().cloned(),
+            block.transactions().clone(),
+            block.aborted_transaction_ids().clone(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_orphan_pool_lookahead() {
+        // Sample a block near the tip, and blocks at and beyond the edge of the lookahead.
+        let near = sample_block_at_height(1, 2);
+        let edge = sample_block_at_height(2, 1 + MAX_ORPHAN_LOOKAHEAD);
+        let far = sample_block_at_height(3, 2 + MAX_ORPHAN_LOOKAHEAD);
+
+        // Ensure a block at the edge of the lookahead is accepted.
+        let mut pool = OrphanPool::<CurrentNetwork>::new(8, 8, MAX_ORPHAN_BYTES, 10);
+        assert!(pool.insert(edge.clone(), 1, 100));
+        assert!(pool.contains(&edge.hash()));
+
+        // Ensure a far-future block is rejected, and cannot evict a near-tip orphan from a full pool.
+        let mut pool = OrphanPool::<CurrentNetwork>::new(1, 1, MAX_ORPHAN_BYTES, 10);
+        assert!(pool.insert(near.clone(), 1, 100));
+        assert!(!pool.insert(far.clone(), 1, 100));
+        assert!(pool.contains(&near.hash()));
+        assert!(!pool.contains(&far.hash()));
+        assert_eq!(pool.len(), 1);
+    }
+}
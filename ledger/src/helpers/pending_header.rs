@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Field};
+use ledger_block::{coinbase_target, proof_target, Block, Header};
+
+/// The maximum number of headers held ahead of their block bodies.
+#[cfg(not(any(test, feature = "test")))]
+pub const MAX_PENDING_HEADERS: usize = 10_000;
+/// The maximum number of headers held ahead of their block bodies.
+/// This is set to a deliberately low value (4) for testing purposes only.
+#[cfg(any(test, feature = "test"))]
+pub const MAX_PENDING_HEADERS: usize = 4;
+
+/// A block header that is accepted ahead of its block body, during header-first sync.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingHeader<N: Network> {
+    /// The hash of the block.
+    block_hash: N::BlockHash,
+    /// The hash of the previous block.
+    previous_hash: N::BlockHash,
+    /// The header of the block.
+    header: Header<N>,
+}
+
+impl<N: Network> PendingHeader<N> {
+    /// Initializes a new pending header, computing the block hash from the previous block hash and the header.
+    pub fn new(previous_hash: N::BlockHash, header: Header<N>) -> Result<Self> {
+        // Compute the block hash.
+        let block_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?;
+        // Return the pending header.
+        Ok(Self { block_hash: block_hash.into(), previous_hash, header })
+    }
+
+    /// Initializes a new pending header from the given block.
+    pub fn from_block(block: &Block<N>) -> Self {
+        Self { block_hash: block.hash(), previous_hash: block.previous_hash(), header: block.header().clone() }
+    }
+
+    /// Returns the hash of the block.
+    pub const fn hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the hash of the previous block.
+    pub const fn previous_hash(&self) -> N::BlockHash {
+        self.previous_hash
+    }
+
+    /// Returns the header of the block.
+    pub const fn header(&self) -> &Header<N> {
+        &self.header
+    }
+
+    /// Returns the height of the block.
+    pub const fn height(&self) -> u32 {
+        self.header.height()
+    }
+
+    /// Returns `true` if the given block has this hash, previous hash, and header.
+    pub fn matches_block(&self, block: &Block<N>) -> bool {
+        self.block_hash == block.hash() && self.previous_hash == block.previous_hash() && self.header == *block.header()
+    }
+
+    /// Ensures the given header is a valid successor of this header, without its block body.
+    ///
+    /// This checks the hash chain, the difficulty retargeting, and that the combined proof target recorded
+    /// in the cumulative weight is consistent with the coinbase accounting.
+    ///
+    /// Note: The proof-of-succinct-work is not verified here. The combined proof target is taken from the header,
+    /// as the solutions it is computed from are part of the block body. A peer can therefore claim any chain work
+    /// in a pending header. The solutions, authority, and roots are only checked once the block body is attached.
+    pub fn check_next(&self, next: &PendingHeader<N>, current_timestamp: i64) -> Result<()> {
+        let previous = &self.header;
+        let header = &next.header;
+        let height = self.height().saturating_add(1);
+
+        // Ensure the previous block hash matches.
+        ensure!(
+            next.previous_hash == self.block_hash,
+            "Previous block hash is incorrect in header {height} (found '{}', expected '{}')",
+            next.previous_hash,
+            self.block_hash
+        );
+        // Ensure the header is well-formed.
+        ensure!(header.is_valid(), "Header {height} is malformed");
+        // Ensure the height is correct.
+        ensure!(
+            header.height() == height,
+            "Height is incorrect in header {height} (found '{}', expected '{height}')",
+            header.height()
+        );
+        // Ensure the round is after the previous round.
+        ensure!(
+            header.round() > previous.round(),
+            "Round is not after the previous round in header {height} (found '{}', expected after '{}')",
+            header.round(),
+            previous.round()
+        );
+        // Ensure the timestamp is not in the future.
+        ensure!(
+            header.timestamp() <= current_timestamp,
+            "Timestamp is in the future in header {height} (found '{}', expected before '{current_timestamp}')",
+            header.timestamp()
+        );
+
+        // Ensure the coinbase target is correct.
+        let expected_coinbase_target = coinbase_target(
+            previous.last_coinbase_target(),
+            previous.last_coinbase_timestamp(),
+            header.timestamp(),
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )?;
+        ensure!(
+            header.coinbase_target() == expected_coinbase_target,
+            "Coinbase target is incorrect in header {height} (found '{}', expected '{expected_coinbase_target}')",
+            header.coinbase_target()
+        );
+        // Ensure the proof target is correct.
+        let expected_proof_target = proof_target(expected_coinbase_target, N::GENESIS_PROOF_TARGET);
+        ensure!(
+            header.proof_target() == expected_proof_target,
+            "Proof target is incorrect in header {height} (found '{}', expected '{expected_proof_target}')",
+            header.proof_target()
+        );
+
+        // Recover the combined proof target of the block from the chain work.
+        let Some(combined_proof_target) = header.chain_work().checked_sub(previous.chain_work()) else {
+            bail!("Cumulative weight decreased in header {height}")
+        };
+        // Ensure the solutions root is set if and only if the block contains solutions.
+        ensure!(
+            (combined_proof_target == 0) == (header.solutions_root() == Field::zero()),
+            "Solutions root is inconsistent with the cumulative weight in header {height}"
+        );
+
+        // Determine the expected coinbase accounting.
+        let (expected_cumulative_proof_target, is_coinbase_target_reached) = match combined_proof_target {
+            0 => (previous.cumulative_proof_target(), false),
+            _ => {
+                // Ensure the combined proof target is at least the proof target of a single solution.
+                ensure!(
+                    combined_proof_target >= previous.proof_target() as u128,
+                    "Combined proof target is below the previous proof target in header {height}"
+                );
+                // Compute the actual cumulative proof target (which can exceed the coinbase target).
+                let cumulative_proof_target = previous.cumulative_proof_target().saturating_add(combined_proof_target);
+                // Determine if the coinbase target is reached.
+                match cumulative_proof_target >= previous.coinbase_target() as u128 {
+                    true => (0u128, true),
+                    false => (cumulative_proof_target, false),
+                }
+            }
+        };
+        // Ensure the cumulative proof target is correct.
+        ensure!(
+            header.cumulative_proof_target() == expected_cumulative_proof_target,
+            "Cumulative proof target is incorrect in header {height} (found '{}', expected '{expected_cumulative_proof_target}')",
+            header.cumulative_proof_target()
+        );
+        // Ensure the last coinbase target and timestamp are correct.
+        let (expected_last_coinbase_target, expected_last_coinbase_timestamp) = match is_coinbase_target_reached {
+            true => (expected_coinbase_target, header.timestamp()),
+            false => (previous.last_coinbase_target(), previous.last_coinbase_timestamp()),
+        };
+        ensure!(
+            header.last_coinbase_target() == expected_last_coinbase_target,
+            "Last coinbase target is incorrect in header {height} (found '{}', expected '{expected_last_coinbase_target}')",
+            header.last_coinbase_target()
+        );
+        ensure!(
+            header.last_coinbase_timestamp() == expected_last_coinbase_timestamp,
+            "Last coinbase timestamp is incorrect in header {height} (found '{}', expected '{expected_last_coinbase_timestamp}')",
+            header.last_coinbase_timestamp()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{sample_next_header, CurrentNetwork};
+
+    #[test]
+    fn test_check_next() {
+        // Load the genesis block.
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let genesis = PendingHeader::from_block(&genesis);
+        let timestamp = genesis.header().timestamp() + 10;
+
+        // Ensure the block hash is recomputed correctly.
+        assert_eq!(PendingHeader::new(genesis.previous_hash(), genesis.header().clone()).unwrap(), genesis);
+
+        // Ensure a valid successor is accepted, with and without solutions.
+        let next = sample_next_header(&genesis, 0, timestamp, 0);
+        genesis.check_next(&next, timestamp).unwrap();
+        let proof_target = genesis.header().proof_target() as u128;
+        genesis.check_next(&sample_next_header(&genesis, proof_target, timestamp, 0), timestamp).unwrap();
+        next.check_next(&sample_next_header(&next, 0, timestamp + 10, 0), timestamp + 10).unwrap();
+
+        // Ensure a header that does not build on the previous header is rejected.
+        assert!(next.check_next(&next, timestamp).is_err());
+        assert!(genesis.check_next(&genesis, timestamp).is_err());
+        // Ensure a header from the future is rejected.
+        assert!(genesis.check_next(&next, timestamp - 1).is_err());
+        // Ensure a header with solutions below the proof target is rejected.
+        let header = sample_next_header(&genesis, proof_target - 1, timestamp, 0);
+        assert!(genesis.check_next(&header, timestamp).is_err());
+        // Ensure a header with an incorrect coinbase target is rejected.
+        let header = sample_next_header(&genesis, 0, timestamp, 1);
+        assert!(genesis.check_next(&header, timestamp).is_err());
+    }
+}
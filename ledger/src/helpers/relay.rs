@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::Network;
+use ledger_block::Transaction;
+
+use anyhow::{ensure, Result};
+use std::time::Duration;
+
+/// The properties of a transaction that determine its relay score.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RelayMetrics {
+    /// The total fee, in microcredits.
+    pub fee_in_microcredits: u64,
+    /// The size of the transaction, in bytes.
+    pub size_in_bytes: u64,
+    /// The number of circuits a validator verifies for the transaction.
+    pub num_verified_circuits: u64,
+    /// The time since the transaction was first seen.
+    pub age: Duration,
+}
+
+impl RelayMetrics {
+    /// Returns the relay metrics for the given transaction, first seen `age` ago.
+    pub fn from_transaction<N: Network>(transaction: &Transaction<N>, age: Duration) -> Result<Self> {
+        // Count the transitions, each of which is verified against its own circuit.
+        let num_transitions = transaction.transitions().count() as u64;
+        // Count the deployed functions, each of which is synthesized to check its certificate.
+        let num_deployed_functions =
+            transaction.deployment().map_or(0, |deployment| deployment.verifying_keys().len() as u64);
+
+        Ok(Self {
+            fee_in_microcredits: *transaction.fee_amount()?,
+            size_in_bytes: transaction.size_in_bytes()?,
+            num_verified_circuits: num_transitions + num_deployed_functions,
+            age,
+        })
+    }
+
+    /// Returns the fee paid per byte, in microcredits.
+    pub fn fee_per_byte(&self) -> f64 {
+        self.fee_in_microcredits as f64 / self.size_in_bytes.max(1) as f64
+    }
+}
+
+/// The weights used to combine relay metrics into a relay score.
+///
+/// A higher score indicates a transaction that should be relayed sooner, and evicted later.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RelayWeights {
+    /// The score per microcredit of fee paid per byte.
+    pub fee_per_byte: f64,
+    /// The score per kilobyte of transaction size, which is subtracted.
+    pub size_per_kilobyte: f64,
+    /// The score per verified circuit, which is subtracted.
+    pub verified_circuit: f64,
+    /// The score per second since the transaction was first seen.
+    pub age_per_second: f64,
+}
+
+impl Default for RelayWeights {
+    /// Returns the default weights, which rank transactions primarily by fee per byte.
+    fn default() -> Self {
+        Self { fee_per_byte: 1.0, size_per_kilobyte: 0.1, verified_circuit: 0.5, age_per_second: 0.01 }
+    }
+}
+
+impl RelayWeights {
+    /// Ensures the weights are finite and non-negative.
+    pub fn check(&self) -> Result<()> {
+        for (name, weight) in [
+            ("fee per byte", self.fee_per_byte),
+            ("size per kilobyte", self.size_per_kilobyte),
+            ("verified circuit", self.verified_circuit),
+            ("age per second", self.age_per_second),
+        ] {
+            ensure!(
+                weight.is_finite() && weight >= 0.0,
+                "The relay weight for '{name}' must be finite and non-negative"
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the relay score for the given metrics.
+    pub fn score(&self, metrics: &RelayMetrics) -> f64 {
+        self.fee_per_byte * metrics.fee_per_byte() + self.age_per_second * metrics.age.as_secs_f64()
+            - self.size_per_kilobyte * (metrics.size_in_bytes as f64 / 1024.0)
+            - self.verified_circuit * metrics.num_verified_circuits as f64
+    }
+}
+
+/// Returns the relay score for the given transaction, first seen `age` ago, using the given weights.
+///
+/// The score rewards fee per byte and age, and penalizes size and verification cost.
+pub fn relay_score<N: Network>(transaction: &Transaction<N>, age: Duration, weights: &RelayWeights) -> Result<f64> {
+    // Ensure the weights are well-formed.
+    weights.check()?;
+    // Compute the score.
+    Ok(weights.score(&RelayMetrics::from_transaction(transaction, age)?))
+}
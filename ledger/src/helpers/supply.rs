@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use console::network::Network;
-use ledger_block::Transactions;
+use ledger_block::{Amount, Transactions};
 
 use anyhow::{anyhow, Result};
 
@@ -25,17 +25,21 @@ pub fn update_total_supply<N: Network>(
     transactions: &Transactions<N>,
 ) -> Result<u64> {
     // Initialize the next total supply of microcredits.
-    let mut next_total_supply = starting_total_supply_in_microcredits;
+    let mut next_total_supply = Amount::from_microcredits(starting_total_supply_in_microcredits);
     // Add the block reward to the total supply.
-    next_total_supply = next_total_supply.saturating_add(block_reward);
+    next_total_supply = next_total_supply
+        .checked_add(Amount::from_microcredits(block_reward))
+        .ok_or_else(|| anyhow!("The block reward overflows the total supply of microcredits"))?;
     // Add the puzzle reward to the total supply.
-    next_total_supply = next_total_supply.saturating_add(puzzle_reward);
+    next_total_supply = next_total_supply
+        .checked_add(Amount::from_microcredits(puzzle_reward))
+        .ok_or_else(|| anyhow!("The puzzle reward overflows the total supply of microcredits"))?;
 
     // Iterate through the transactions to calculate the next total supply of microcredits.
     for confirmed in transactions.iter() {
         // Subtract the fee from the total supply.
         next_total_supply = next_total_supply
-            .checked_sub(*confirmed.fee_amount()?)
+            .checked_sub(Amount::from(confirmed.fee_amount()?))
             .ok_or_else(|| anyhow!("The proposed fee underflows the total supply of microcredits"))?;
 
         // Iterate over the transitions in the transaction.
@@ -45,11 +49,11 @@ pub fn update_total_supply<N: Network>(
                 // TODO (howardwu): Add a test that calls `split`, checks the output records - input records == 10_000u64.
                 // Subtract the amount split from the total supply.
                 next_total_supply = next_total_supply
-                    .checked_sub(10_000u64)
+                    .checked_sub(Amount::from_microcredits(10_000u64))
                     .ok_or_else(|| anyhow!("The proposed split underflows the total supply of microcredits"))?;
             }
         }
     }
     // Return the final total supply in microcredits.
-    Ok(next_total_supply)
+    Ok(next_total_supply.microcredits())
 }
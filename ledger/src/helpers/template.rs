@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::Network;
+use ledger_block::Block;
+use ledger_coinbase::PuzzleCommitment;
+
+use indexmap::IndexSet;
+
+/// The reason a block template is no longer valid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateInvalidation<N: Network> {
+    /// The template no longer builds on the latest block.
+    NewTip(N::BlockHash),
+    /// A transaction in the template is no longer available.
+    TransactionRemoved(N::TransactionID),
+    /// A solution in the template is no longer available.
+    SolutionRemoved(PuzzleCommitment<N>),
+}
+
+/// A block template, along with what it depends on, so it can be checked for invalidation.
+///
+/// Note: A template cannot be partially updated. Swapping a transaction changes the speculated finalize
+/// state of every later transaction, so an invalid template is rebuilt with `prepare_beacon_block_template`.
+#[derive(Clone, Debug)]
+pub struct TemplateHandle<N: Network> {
+    /// The candidate block.
+    block: Block<N>,
+    /// The IDs of the transactions in the template, including the aborted ones.
+    transaction_ids: IndexSet<N::TransactionID>,
+    /// The puzzle commitments of the solutions in the template.
+    puzzle_commitments: IndexSet<PuzzleCommitment<N>>,
+}
+
+impl<N: Network> TemplateHandle<N> {
+    /// Initializes a new template handle for the given candidate block.
+    pub fn new(block: Block<N>) -> Self {
+        let transaction_ids =
+            block.transaction_ids().chain(block.aborted_transaction_ids()).copied().collect::<IndexSet<_>>();
+        let puzzle_commitments = block.puzzle_commitments().into_iter().flatten().copied().collect::<IndexSet<_>>();
+        Self { block, transaction_ids, puzzle_commitments }
+    }
+
+    /// Returns the candidate block.
+    pub const fn block(&self) -> &Block<N> {
+        &self.block
+    }
+
+    /// Returns the candidate block, consuming the handle.
+    pub fn into_block(self) -> Block<N> {
+        self.block
+    }
+
+    /// Returns `true` if the template includes the given transaction, including as an aborted transaction.
+    pub fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> bool {
+        self.transaction_ids.contains(transaction_id)
+    }
+
+    /// Returns `true` if the template includes the given solution.
+    pub fn contains_puzzle_commitment(&self, puzzle_commitment: &PuzzleCommitment<N>) -> bool {
+        self.puzzle_commitments.contains(puzzle_commitment)
+    }
+
+    /// Returns the reason the template is invalid, given the latest block hash and the transmissions
+    /// removed from the memory pool since the template was built, or `None` if the template is still valid.
+    ///
+    /// Note: Newly-added transmissions do not invalidate a template.
+    pub fn check_invalidation<'a>(
+        &self,
+        latest_hash: N::BlockHash,
+        removed_transaction_ids: impl IntoIterator<Item = &'a N::TransactionID>,
+        removed_puzzle_commitments: impl IntoIterator<Item = &'a PuzzleCommitment<N>>,
+    ) -> Option<TemplateInvalidation<N>> {
+        // Ensure the template builds on the latest block.
+        if self.block.previous_hash() != latest_hash {
+            return Some(TemplateInvalidation::NewTip(latest_hash));
+        }
+        // Ensure no transaction in the template was removed.
+        if let Some(id) = removed_transaction_ids.into_iter().find(|id| self.contains_transaction_id(id)) {
+            return Some(TemplateInvalidation::TransactionRemoved(*id));
+        }
+        // Ensure no solution in the template was removed.
+        if let Some(commitment) = removed_puzzle_commitments.into_iter().find(|c| self.contains_puzzle_commitment(c)) {
+            return Some(TemplateInvalidation::SolutionRemoved(*commitment));
+        }
+        None
+    }
+}
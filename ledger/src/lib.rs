@@ -35,12 +35,14 @@ mod helpers;
 pub use helpers::*;
 
 mod advance;
+mod check_integrity;
 mod check_next_block;
 mod check_transaction_basic;
 mod contains;
 mod find;
 mod get;
 mod iterators;
+mod sync;
 
 #[cfg(test)]
 mod tests;
@@ -52,7 +54,14 @@ use console::{
     types::{Field, Group},
 };
 use ledger_authority::Authority;
-use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSolution, PuzzleCommitment};
+use ledger_coinbase::{
+    CoinbasePuzzle,
+    CoinbaseSolution,
+    EpochChallenge,
+    PoswVerifier,
+    ProverSolution,
+    PuzzleCommitment,
+};
 use ledger_committee::Committee;
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_query::Query;
@@ -69,9 +78,9 @@ use aleo_std::{
 use anyhow::Result;
 use core::ops::Range;
 use indexmap::IndexMap;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use rand::{prelude::IteratorRandom, rngs::OsRng};
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, collections::VecDeque, sync::Arc};
 use time::OffsetDateTime;
 
 #[cfg(not(feature = "serial"))]
@@ -99,14 +108,29 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     vm: VM<N, C>,
     /// The genesis block.
     genesis_block: Block<N>,
-    /// The coinbase puzzle.
-    coinbase_puzzle: CoinbasePuzzle<N>,
+    /// The coinbase puzzle, which holds only the verifying key.
+    coinbase_puzzle: PoswVerifier<N>,
     /// The current epoch challenge.
     current_epoch_challenge: Arc<RwLock<Option<EpochChallenge<N>>>>,
     /// The current committee.
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
+    ///
+    /// Note: This is updated only after the VM has stored the block, so it may lag the block store by one block.
+    /// While a block is being added, the `latest_*` getters return the previous block, while the storage getters
+    /// (e.g. `get_block`, `contains_block_hash`) may already return the new block. Callers that need several
+    /// fields of the same block should read them from a single `latest_block` instead of several `latest_*` calls.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The lock for ensuring there is no concurrency when advancing blocks.
+    ///
+    /// Note: This is a single lock over the whole advance, rather than a lock per storage map, because blocks are
+    /// added strictly in order. It serializes writers only; readers never take it, so they are not blocked on the
+    /// VM update, at the cost of observing the current block one block behind the block store (see `current_block`).
+    advance_lock: Arc<Mutex<()>>,
+    /// The headers accepted ahead of their block bodies, in order of height.
+    pending_headers: Arc<RwLock<VecDeque<PendingHeader<N>>>>,
+    /// The blocks received before their parents, to be added once their parents arrive.
+    orphan_pool: Arc<RwLock<OrphanPool<N>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -165,10 +189,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let mut ledger = Self {
             vm,
             genesis_block: genesis_block.clone(),
-            coinbase_puzzle: CoinbasePuzzle::<N>::load()?,
+            coinbase_puzzle: PoswVerifier::<N>::load()?,
             current_epoch_challenge: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            advance_lock: Arc::new(Mutex::new(())),
+            pending_headers: Default::default(),
+            orphan_pool: Default::default(),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -202,8 +229,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         &self.vm
     }
 
-    /// Returns the coinbase puzzle.
-    pub const fn coinbase_puzzle(&self) -> &CoinbasePuzzle<N> {
+    /// Returns the coinbase puzzle verifier, which holds only the verifying key.
+    pub const fn coinbase_puzzle(&self) -> &PoswVerifier<N> {
         &self.coinbase_puzzle
     }
 
@@ -255,7 +282,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
     /// Returns the latest block header.
     pub fn latest_header(&self) -> Header<N> {
-        *self.current_block.read().header()
+        self.current_block.read().header().clone()
     }
 
     /// Returns the latest block cumulative weight.
@@ -263,6 +290,18 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.current_block.read().cumulative_weight()
     }
 
+    /// Returns the latest chain work.
+    pub fn latest_chain_work(&self) -> ChainWork {
+        self.current_block.read().chain_work()
+    }
+
+    /// Returns `true` if a chain with the given work and height is preferred over the current chain.
+    /// This allows a node to compare the best chain advertised by a peer against its own chain.
+    pub fn is_preferred_chain(&self, chain_work: ChainWork, height: u32) -> bool {
+        let block = self.current_block.read();
+        chain_work.is_preferred_over(height, &block.chain_work(), block.height())
+    }
+
     /// Returns the latest block cumulative proof target.
     pub fn latest_cumulative_proof_target(&self) -> u128 {
         self.current_block.read().cumulative_proof_target()
@@ -385,14 +424,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
 #[cfg(test)]
 pub(crate) mod test_helpers {
-    use crate::Ledger;
+    use crate::{Ledger, PendingHeader};
     use aleo_std::StorageMode;
     use console::{
         account::{Address, PrivateKey, ViewKey},
         network::Testnet3,
         prelude::*,
+        types::Field,
     };
-    use ledger_block::Block;
+    use ledger_block::{Block, Header, Metadata};
     use ledger_store::ConsensusStore;
     use synthesizer::vm::VM;
 
@@ -430,6 +470,43 @@ pub(crate) mod test_helpers {
         TestEnv { ledger, private_key, view_key, address }
     }
 
+    /// Returns a header that builds on the given header, with the given combined proof target and timestamp,
+    /// and with its coinbase target increased by the given offset.
+    pub(crate) fn sample_next_header(
+        previous: &PendingHeader<CurrentNetwork>,
+        combined_proof_target: u128,
+        timestamp: i64,
+        coinbase_target_offset: u64,
+    ) -> PendingHeader<CurrentNetwork> {
+        let header = previous.header();
+        let coinbase_target = ledger_block::coinbase_target(
+            header.last_coinbase_target(),
+            header.last_coinbase_timestamp(),
+            timestamp,
+            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+        )
+        .unwrap();
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            header.round() + 1,
+            header.height() + 1,
+            header.cumulative_weight() + combined_proof_target,
+            header.cumulative_proof_target() + combined_proof_target,
+            coinbase_target + coinbase_target_offset,
+            ledger_block::proof_target(coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET),
+            header.last_coinbase_target(),
+            header.last_coinbase_timestamp(),
+            timestamp,
+        )
+        .unwrap();
+        let (zero, one) = (Field::<CurrentNetwork>::zero(), Field::from_u8(1));
+        let solutions_root = if combined_proof_target == 0 { zero } else { one };
+        let header = Header::from(one.into(), one, one, one, solutions_root, zero, metadata).unwrap();
+        PendingHeader::new(previous.hash(), header).unwrap()
+    }
+
     pub(crate) fn sample_genesis_block() -> Block<CurrentNetwork> {
         Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap()
     }
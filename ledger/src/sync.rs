@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the range of heights that have a validated header, but no block body yet.
+    pub fn headers_only_heights(&self) -> Range<u32> {
+        let pending_headers = self.pending_headers.read();
+        match (pending_headers.front(), pending_headers.back()) {
+            (Some(first), Some(last)) => first.height()..last.height().saturating_add(1),
+            _ => {
+                let next_height = self.latest_height().saturating_add(1);
+                next_height..next_height
+            }
+        }
+    }
+
+    /// Returns the pending header at the given height, if its block body has not been attached yet.
+    pub fn get_pending_header(&self, height: u32) -> Option<PendingHeader<N>> {
+        let pending_headers = self.pending_headers.read();
+        let first_height = pending_headers.front()?.height();
+        pending_headers.get(height.checked_sub(first_height)? as usize).cloned()
+    }
+
+    /// Returns the latest header, which is either the latest pending header or the header of the latest block.
+    pub fn latest_pending_header(&self) -> PendingHeader<N> {
+        match self.pending_headers.read().back() {
+            Some(header) => header.clone(),
+            None => PendingHeader::from_block(&self.current_block.read()),
+        }
+    }
+
+    /// Validates the given header ahead of its block body, and appends it to the pending headers.
+    /// The header must build on the latest pending header (or the latest block, if there are none).
+    pub fn add_pending_header(&self, previous_hash: N::BlockHash, header: Header<N>) -> Result<N::BlockHash> {
+        // Initialize the pending header.
+        let pending_header = PendingHeader::new(previous_hash, header)?;
+
+        // Acquire the write lock, so the pending headers cannot change during validation.
+        let mut pending_headers = self.pending_headers.write();
+        // Remove the pending headers whose blocks were already added to the ledger.
+        self.prune_pending_headers(&mut pending_headers);
+        // Ensure the pending headers are not full.
+        ensure!(
+            pending_headers.len() < MAX_PENDING_HEADERS,
+            "Cannot add header {} - the ledger already has {MAX_PENDING_HEADERS} pending headers",
+            pending_header.height()
+        );
+
+        // Retrieve the latest header.
+        let latest_header = match pending_headers.back() {
+            Some(header) => header.clone(),
+            None => PendingHeader::from_block(&self.current_block.read()),
+        };
+        // Ensure the header is a valid successor of the latest header.
+        latest_header.check_next(&pending_header, OffsetDateTime::now_utc().unix_timestamp())?;
+
+        // Append the pending header.
+        let block_hash = pending_header.hash();
+        pending_headers.push_back(pending_header);
+        Ok(block_hash)
+    }
+
+    /// Ensures the given block matches its pending header.
+    ///
+    /// This check does not depend on the ledger state, so bodies can be downloaded
+    /// and checked in any order, before they are attached with `attach_block_body`.
+    pub fn check_block_body(&self, block: &Block<N>) -> Result<()> {
+        let height = block.height();
+        // Retrieve the pending header.
+        let Some(pending_header) = self.get_pending_header(height) else {
+            bail!("Block {height} does not have a pending header")
+        };
+        // Ensure the block matches the pending header.
+        ensure!(pending_header.matches_block(block), "Block {height} does not match its pending header");
+        Ok(())
+    }
+
+    /// Attaches the body of the next pending header, fully validating the block and adding it to the ledger.
+    ///
+    /// Pending headers are not authenticated, so the block is validated before it is compared to its pending header.
+    /// If a valid block conflicts with its pending header, the pending header and all of its successors are removed,
+    /// and the block is added, so that a forged header chain cannot stall the ledger.
+    pub fn attach_block_body(&self, block: &Block<N>) -> Result<()> {
+        // Remove the pending headers whose blocks were already added to the ledger.
+        self.prune_pending_headers(&mut self.pending_headers.write());
+
+        // Ensure the block is the next block to attach.
+        let next_height = self.latest_height().saturating_add(1);
+        ensure!(
+            block.height() == next_height,
+            "Block {} is not the next block to attach (expected block {next_height})",
+            block.height()
+        );
+
+        // Ensure the block is a valid next block.
+        self.check_next_block(block, &mut OsRng)?;
+
+        // If the block conflicts with its pending header, remove the pending header and its successors.
+        {
+            let mut pending_headers = self.pending_headers.write();
+            if let Some(pending_header) = pending_headers.front() {
+                if pending_header.height() == next_height && !pending_header.matches_block(block) {
+                    warn!(
+                        "Discarding {} pending headers that conflict with block {next_height}",
+                        pending_headers.len()
+                    );
+                    pending_headers.clear();
+                }
+            }
+        }
+
+        // Add the block to the ledger.
+        self.advance_to_next_block(block)?;
+
+        // Remove the pending header of the added block.
+        self.prune_pending_headers(&mut self.pending_headers.write());
+        Ok(())
+    }
+
+    /// Removes all pending headers.
+    pub fn clear_pending_headers(&self) {
+        self.pending_headers.write().clear();
+    }
+
+    /// Removes the pending headers at or below the latest height.
+    /// If a removed header does not match the block in the ledger, all pending headers are removed,
+    /// as they build on a different chain.
+    fn prune_pending_headers(&self, pending_headers: &mut VecDeque<PendingHeader<N>>) {
+        let latest_height = self.latest_height();
+        while let Some(header) = pending_headers.front() {
+            if header.height() > latest_height {
+                break;
+            }
+            if self.get_hash(header.height()).ok() != Some(header.hash()) {
+                pending_headers.clear();
+                break;
+            }
+            pending_headers.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{sample_next_header, sample_test_env, CurrentLedger, CurrentNetwork};
+
+    #[test]
+    fn test_pending_headers() {
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(genesis.clone(), StorageMode::Production).unwrap();
+        assert_eq!(ledger.headers_only_heights(), 1..1);
+        assert_eq!(ledger.latest_pending_header(), PendingHeader::from_block(&genesis));
+
+        // Add two headers ahead of their block bodies.
+        let timestamp = genesis.timestamp() + 10;
+        let first = sample_next_header(&PendingHeader::from_block(&genesis), 0, timestamp, 0);
+        let second = sample_next_header(&first, 0, timestamp + 10, 0);
+        assert_eq!(ledger.add_pending_header(first.previous_hash(), first.header().clone()).unwrap(), first.hash());
+        assert_eq!(ledger.add_pending_header(second.previous_hash(), second.header().clone()).unwrap(), second.hash());
+        assert_eq!(ledger.headers_only_heights(), 1..3);
+        assert_eq!(ledger.get_pending_header(1), Some(first.clone()));
+        assert_eq!(ledger.get_pending_header(2), Some(second.clone()));
+        assert_eq!(ledger.get_pending_header(3), None);
+        assert_eq!(ledger.latest_pending_header(), second);
+
+        // Ensure a header that does not build on the latest pending header is rejected.
+        assert!(ledger.add_pending_header(first.previous_hash(), first.header().clone()).is_err());
+
+        // Ensure a block body that does not match its pending header is rejected.
+        assert!(ledger.check_block_body(&genesis).is_err());
+        assert!(ledger.attach_block_body(&genesis).is_err());
+        assert_eq!(ledger.headers_only_heights(), 1..3);
+
+        // Ensure the pending headers can be cleared.
+        ledger.clear_pending_headers();
+        assert_eq!(ledger.headers_only_heights(), 1..1);
+        assert_eq!(ledger.get_pending_header(1), None);
+    }
+
+    #[test]
+    fn test_pending_headers_limit() {
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(genesis.clone(), StorageMode::Production).unwrap();
+
+        // Add the maximum number of headers ahead of their block bodies.
+        let mut latest = PendingHeader::from_block(&genesis);
+        for i in 1..=MAX_PENDING_HEADERS as i64 {
+            latest = sample_next_header(&latest, 0, genesis.timestamp() + 10 * i, 0);
+            ledger.add_pending_header(latest.previous_hash(), latest.header().clone()).unwrap();
+        }
+        assert_eq!(ledger.headers_only_heights().len(), MAX_PENDING_HEADERS);
+
+        // Ensure the next header is rejected.
+        let next = sample_next_header(&latest, 0, latest.header().timestamp() + 10, 0);
+        assert!(ledger.add_pending_header(next.previous_hash(), next.header().clone()).is_err());
+        assert_eq!(ledger.latest_pending_header(), latest);
+
+        // Ensure headers are accepted again once the pending headers are cleared.
+        ledger.clear_pending_headers();
+        let first = sample_next_header(&PendingHeader::from_block(&genesis), 0, genesis.timestamp() + 10, 0);
+        ledger.add_pending_header(first.previous_hash(), first.header().clone()).unwrap();
+        assert_eq!(ledger.headers_only_heights(), 1..2);
+    }
+
+    #[test]
+    fn test_attach_block_body_with_forged_headers() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger.
+        let crate::test_helpers::TestEnv { ledger, private_key, .. } = sample_test_env(rng);
+        let latest = PendingHeader::from_block(&ledger.latest_block());
+
+        // Add a forged chain of headers ahead of their block bodies.
+        let mut forged = latest.clone();
+        for i in 1..=3 {
+            forged = sample_next_header(&forged, 0, latest.header().timestamp() + 10 * i, 0);
+            ledger.add_pending_header(forged.previous_hash(), forged.header().clone()).unwrap();
+        }
+        assert_eq!(ledger.headers_only_heights(), 1..4);
+
+        // Ensure an invalid block is still rejected.
+        assert!(ledger.attach_block_body(&ledger.get_block(0).unwrap()).is_err());
+        assert_eq!(ledger.headers_only_heights(), 1..4);
+
+        // Create the genuine next block, which conflicts with the forged pending header.
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        assert!(ledger.check_block_body(&block).is_err());
+
+        // Ensure the genuine block is added, and the forged headers are removed.
+        ledger.attach_block_body(&block).unwrap();
+        assert_eq!(ledger.latest_hash(), block.hash());
+        assert_eq!(ledger.headers_only_heights(), 2..2);
+        assert_eq!(ledger.latest_pending_header(), PendingHeader::from_block(&block));
+    }
+}
@@ -16,6 +16,7 @@ use crate::{
     advance::split_candidate_solutions,
     test_helpers::{CurrentLedger, CurrentNetwork},
     RecordsFilter,
+    TemplateInvalidation,
 };
 use aleo_std::StorageMode;
 use console::{
@@ -23,9 +24,21 @@ use console::{
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
 };
-use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
+use ledger_block::{
+    Block,
+    ConfirmedTransaction,
+    Header,
+    ReasonCode,
+    ReceiptStatus,
+    Rejected,
+    StateMirror,
+    Transaction,
+};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
-use synthesizer::{program::Program, vm::VM};
+use synthesizer::{
+    program::{FinalizeGlobalState, Program},
+    vm::VM,
+};
 
 #[test]
 fn test_load() {
@@ -66,6 +79,27 @@ fn test_load_unchecked() {
     assert_eq!(ledger.latest_block(), genesis);
 }
 
+#[test]
+fn test_check_integrity() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load_unchecked(genesis, StorageMode::Production).unwrap();
+
+    // Ensure the ledger is consistent.
+    let report = ledger.check_integrity(false).unwrap();
+    assert_eq!(report.num_blocks(), 1);
+    assert!(report.issues().is_empty());
+    assert!(report.is_consistent());
+    assert!(!report.is_repaired());
+
+    // Ensure a consistent ledger is not repaired.
+    let report = ledger.check_integrity(true).unwrap();
+    assert!(report.is_consistent());
+    assert!(!report.is_repaired());
+}
+
 #[test]
 fn test_state_path() {
     let rng = &mut TestRng::default();
@@ -82,6 +116,309 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_diff() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Initialize a mirror at the genesis block.
+    let genesis = ledger.get_block(0).unwrap();
+    let mut mirror = StateMirror::new(0, genesis.hash(), ledger.get_state_root(0).unwrap().unwrap());
+
+    // Construct and add the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Apply the diff to the mirror.
+    let diff = ledger.diff(0, 1).unwrap();
+    assert_eq!(diff.blocks().len(), 1);
+    mirror.apply_diff(&diff).unwrap();
+    assert_eq!(mirror.latest_height(), ledger.latest_height());
+    assert_eq!(mirror.latest_hash(), ledger.latest_hash());
+    assert_eq!(mirror.latest_state_root(), ledger.latest_state_root());
+
+    // Ensure the diff is empty for equal heights, and invalid beyond the latest height.
+    assert!(ledger.diff(1, 1).unwrap().blocks().is_empty());
+    assert!(ledger.diff(1, 0).is_err());
+    assert!(ledger.diff(1, 2).is_err());
+}
+
+#[test]
+fn test_concurrent_reads_during_advance() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Construct the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+
+    // Add the block in a separate thread, while reading the latest block.
+    let writer = {
+        let (ledger, block) = (ledger.clone(), block.clone());
+        std::thread::spawn(move || ledger.advance_to_next_block(&block).unwrap())
+    };
+    while !writer.is_finished() {
+        // Ensure readers only ever observe a complete block.
+        let latest = ledger.latest_block();
+        assert!(latest.height() <= 1);
+        assert_eq!(latest.hash(), ledger.get_hash(latest.height()).unwrap());
+    }
+    writer.join().unwrap();
+    assert_eq!(ledger.latest_block(), block);
+}
+
+#[test]
+fn test_block_template_invalidation() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Construct a template with a transfer.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    let template =
+        ledger.prepare_beacon_block_template(&private_key, vec![], vec![], vec![transaction.clone()], rng).unwrap();
+    assert!(template.contains_transaction_id(&transaction.id()));
+    assert_eq!(ledger.check_block_template(&template, [], []), None);
+
+    // Ensure removing the transfer invalidates the template.
+    assert_eq!(
+        ledger.check_block_template(&template, [&transaction.id()], []),
+        Some(TemplateInvalidation::TransactionRemoved(transaction.id()))
+    );
+
+    // Ensure a new tip invalidates the template.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.check_block_template(&template, [], []), Some(TemplateInvalidation::NewTip(block.hash())));
+}
+
+#[cfg(feature = "tokio-async")]
+#[test]
+fn test_async_add_and_get_block() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Construct the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+
+    // Initialize a single-threaded runtime, so the blocking work must be offloaded.
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    runtime.block_on(async {
+        // Add the block.
+        ledger.add_next_block_async(block.clone()).await.unwrap();
+        assert_eq!(ledger.get_block_async(1).await.unwrap(), block);
+        // Ensure the same block cannot be added twice.
+        assert!(ledger.add_next_block_async(block.clone()).await.is_err());
+        // Ensure a missing block is an error.
+        assert!(ledger.get_block_async(2).await.is_err());
+    });
+}
+
+#[test]
+fn test_verify_with_report() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Construct the next block, which contains no transactions.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    let state = FinalizeGlobalState::new::<CurrentNetwork>(
+        block.round(),
+        block.height(),
+        block.cumulative_weight(),
+        block.cumulative_proof_target(),
+        block.previous_hash(),
+    )
+    .unwrap();
+    let ratified_finalize_operations =
+        ledger.vm.check_speculate(state, block.ratifications(), block.solutions(), block.transactions()).unwrap();
+
+    // A helper function to verify the block against the given previous block.
+    let verify = |previous_block: &Block<CurrentNetwork>| {
+        let arguments = (
+            ledger.latest_state_root(),
+            ledger.latest_committee().unwrap(),
+            ledger.latest_epoch_challenge().unwrap(),
+            block.timestamp(),
+        );
+        let result = block.verify(
+            previous_block,
+            arguments.0,
+            &arguments.1,
+            ledger.coinbase_puzzle().as_puzzle(),
+            &arguments.2,
+            arguments.3,
+            ratified_finalize_operations.clone(),
+        );
+        let report = block.verify_with_report(
+            previous_block,
+            arguments.0,
+            &arguments.1,
+            ledger.coinbase_puzzle().as_puzzle(),
+            &arguments.2,
+            arguments.3,
+            ratified_finalize_operations.clone(),
+        );
+        (result, report)
+    };
+
+    // Ensure the only failure is the missing transactions, and it matches the error from `verify`.
+    let (result, report) = verify(&ledger.latest_block());
+    let reasons = report.failures().iter().map(|failure| failure.reason()).collect::<Vec<_>>();
+    assert_eq!(reasons, vec![ReasonCode::MissingTransactions]);
+    assert_eq!(result.unwrap_err().to_string(), report.ensure_valid().unwrap_err().to_string());
+
+    // Ensure verifying against the wrong previous block reports every failed check, rather than the first one.
+    let (result, report) = verify(&block);
+    assert!(result.is_err());
+    assert_eq!(report.failures()[0].reason(), ReasonCode::InvalidBlockHash);
+    assert!(report.contains(ReasonCode::MissingTransactions));
+    assert!(report.failures().len() > 2);
+}
+
+#[test]
+fn test_expired_execution() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // A helper function to construct a public transfer that expires at the given height.
+    let transfer_expiring_at = |expires_at: u32, rng: &mut TestRng| {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+        let execution = ledger
+            .vm
+            .execute_unsponsored(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, rng)
+            .unwrap()
+            .with_expiration(expires_at);
+        ledger.vm.sponsor_execution(&private_key, execution, None, 0, None, rng).unwrap()
+    };
+
+    // Ensure a transaction that expired before the next block is rejected.
+    let expired_transaction = transfer_expiring_at(0, rng);
+    assert_eq!(expired_transaction.expires_at(), Some(0));
+    assert!(ledger.check_transaction_basic(&expired_transaction, None, rng).is_err());
+
+    // Ensure a transaction that expires at the next block is accepted.
+    let transaction = transfer_expiring_at(1, rng);
+    let transaction_id = transaction.id();
+    ledger.check_transaction_basic(&transaction, None, rng).unwrap();
+
+    // Ensure the expired transaction is aborted, while the unexpired transaction is included.
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(
+            &private_key,
+            vec![],
+            vec![],
+            vec![expired_transaction.clone(), transaction],
+            rng,
+        )
+        .unwrap();
+    assert_eq!(block.aborted_transaction_ids(), &vec![expired_transaction.id()]);
+    assert!(block.transactions().get(&transaction_id).is_some());
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the expiration height survives a round trip through storage.
+    let stored_block = ledger.get_block(block.height()).unwrap();
+    assert_eq!(stored_block, block);
+    assert_eq!(ledger.get_transaction(transaction_id).unwrap().expires_at(), Some(1));
+
+    // Ensure a node syncing from the stored block accepts it.
+    let other_ledger = CurrentLedger::load(ledger.get_block(0).unwrap(), StorageMode::Production).unwrap();
+    other_ledger.check_next_block(&stored_block, rng).unwrap();
+    other_ledger.advance_to_next_block(&stored_block).unwrap();
+}
+
+#[test]
+fn test_receipts_root_activation() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+    assert_eq!(Header::<CurrentNetwork>::RECEIPTS_ROOT_HEIGHT, 2);
+
+    // A helper function to construct the next block, containing a public transfer.
+    let next_block = |rng: &mut TestRng| {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+        let transaction = ledger
+            .vm
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap()
+    };
+    // A helper function to re-sign the given block with the given header extensions.
+    let with_extensions = |block: &Block<CurrentNetwork>, extensions, rng: &mut TestRng| {
+        let header = block.header();
+        let header = Header::from_with_extensions(
+            header.previous_state_root(),
+            header.transactions_root(),
+            header.finalize_root(),
+            header.ratifications_root(),
+            header.solutions_root(),
+            header.subdag_root(),
+            *header.metadata(),
+            extensions,
+        )
+        .unwrap();
+        Block::new_beacon(
+            &private_key,
+            block.previous_hash(),
+            header,
+            block.ratifications().clone(),
+            block.solutions().cloned(),
+            block.transactions().clone(),
+            block.aborted_transaction_ids().clone(),
+            rng,
+        )
+        .unwrap()
+    };
+
+    // Ensure a block below the activation height does not commit to the receipts root, and keeps the version 1 layout.
+    let block = next_block(rng);
+    assert_eq!(block.height(), 1);
+    assert_eq!(block.header().receipts_root().unwrap(), None);
+    assert_eq!(block.header().to_bytes_le().unwrap()[0], 1);
+    // Ensure a block below the activation height that commits to the receipts root is rejected.
+    let receipts_root_extension = Header::receipts_root_extension(block.transactions().to_receipts_root().unwrap());
+    let invalid_block = with_extensions(&block, vec![receipts_root_extension.unwrap()], rng);
+    assert!(ledger.check_next_block(&invalid_block, rng).is_err());
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure a block at the activation height commits to the receipts root of its transitions.
+    let block = next_block(rng);
+    assert_eq!(block.height(), Header::<CurrentNetwork>::RECEIPTS_ROOT_HEIGHT);
+    assert_eq!(block.header().receipts_root().unwrap(), Some(block.transactions().to_receipts_root().unwrap()));
+    assert_eq!(block.header().to_bytes_le().unwrap()[0], 2);
+    let transaction = block.transactions().iter().next().unwrap().transaction();
+    let transition_id = *transaction.execution().unwrap().peek().unwrap().id();
+    let receipt = &block.transactions().to_receipts().unwrap()[0];
+    assert_eq!(receipt.transition_id(), transition_id);
+    assert_eq!(receipt.status(), ReceiptStatus::Success);
+    // Ensure the receipt can be proven against the receipts root in the block header.
+    let path = block.transactions().to_receipt_path(transition_id).unwrap();
+    let receipts_root = block.header().receipts_root().unwrap().unwrap();
+    assert!(CurrentNetwork::verify_merkle_path_bhp(&path, &receipts_root, &receipt.to_hash().unwrap().to_bits_le()));
+    // Ensure a block at the activation height that does not commit to the receipts root is rejected.
+    let invalid_block = with_extensions(&block, vec![], rng);
+    assert!(ledger.check_next_block(&invalid_block, rng).is_err());
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+}
+
 #[test]
 fn test_insufficient_private_fees() {
     let rng = &mut TestRng::default();
@@ -410,6 +747,7 @@ finalize failed_assert:
         )
         .unwrap();
     let failed_assert_transaction_id = failed_assert_transaction.id();
+    let execution_transition_id = *failed_assert_transaction.execution().unwrap().peek().unwrap().id();
 
     // Construct the next block containing the new transaction.
     let next_block = ledger
@@ -437,6 +775,18 @@ finalize failed_assert:
     // Check that the unconfirmed transaction ID of the rejected execution is correct.
     assert_eq!(confirmed_transaction.to_unconfirmed_transaction_id().unwrap(), failed_assert_transaction_id);
 
+    // Check that the execution receipt records the rejection, and is committed by the block header.
+    let receipts = next_block.transactions().to_receipts().unwrap();
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(receipts[0].transition_id(), execution_transition_id);
+    assert_eq!(receipts[0].status(), ReceiptStatus::Rejected);
+    assert_eq!(receipts[0].fee(), *confirmed_transaction.transaction().fee_amount().unwrap());
+    assert!(next_block.height() >= Header::<CurrentNetwork>::RECEIPTS_ROOT_HEIGHT);
+    assert_eq!(
+        next_block.header().receipts_root().unwrap(),
+        Some(next_block.transactions().to_receipts_root().unwrap())
+    );
+
     // Check that the next block is valid.
     ledger.check_next_block(&next_block, rng).unwrap();
 
@@ -799,7 +1149,7 @@ function create_duplicate_record:
     // Create a transaction with different transition ids, but with a fixed output record (output ID).
     let mut create_transaction_with_duplicate_output_id = |x: u64| -> Transaction<CurrentNetwork> {
         // Use a fixed seed RNG.
-        let fixed_rng = &mut TestRng::from_seed(1);
+        let fixed_rng = &mut test_rng_for("ledger::tests::test_execute_duplicate_output_ids");
 
         // Create a transaction with a fixed rng.
         let inputs = [Value::from_str(&format!("{x}u64")).unwrap()];
@@ -929,7 +1279,7 @@ function empty_function:
     // Create a transaction with different transaction ids, but with a fixed transition id.
     let mut create_transaction_with_duplicate_transition_id = || -> Transaction<CurrentNetwork> {
         // Use a fixed seed RNG.
-        let fixed_rng = &mut TestRng::from_seed(1);
+        let fixed_rng = &mut test_rng_for("ledger::tests::test_execute_duplicate_transition_ids");
 
         // Create a transaction with a fixed rng.
         let inputs: [Value<_>; 0] = [];
@@ -1068,7 +1418,7 @@ function simple_output:
     // Create a transaction with different transaction ids, but with a TPK.
     let mut create_transaction_with_duplicate_tpk = |function: &str| -> Transaction<CurrentNetwork> {
         // Use a fixed seed RNG.
-        let fixed_rng = &mut TestRng::from_seed(1);
+        let fixed_rng = &mut test_rng_for("ledger::tests::test_execute_duplicate_transition_public_keys");
 
         // Create a transaction with a fixed rng.
         let inputs: [Value<_>; 0] = [];
@@ -1254,3 +1604,118 @@ fn test_split_candidate_solutions() {
             split_candidate_solutions(candidate_solutions, max_solutions, |candidate| candidate % 2 == 0);
     }
 }
+
+#[test]
+fn test_verification_executor_priorities() {
+    use crate::{VerificationExecutor, VerificationPriority};
+    use std::sync::{mpsc, Arc};
+
+    // Initialize an executor with a single worker.
+    let executor = VerificationExecutor::new(1, 2).unwrap();
+
+    // Occupy the worker, until the gate is opened.
+    let (gate, gate_receiver) = mpsc::channel::<()>();
+    let blocker = executor.submit(VerificationPriority::Rpc, move || Ok(gate_receiver.recv()?)).unwrap();
+    while executor.num_queued(VerificationPriority::Rpc) > 0 {
+        std::thread::yield_now();
+    }
+
+    // Queue one job per priority, in order of increasing priority.
+    let order = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    let handles =
+        [VerificationPriority::Rpc, VerificationPriority::Mempool, VerificationPriority::Block].map(|priority| {
+            let order = order.clone();
+            executor
+                .submit(priority, move || {
+                    order.lock().push(priority);
+                    Ok(())
+                })
+                .unwrap()
+        });
+    // Ensure a full queue rejects new jobs.
+    executor.submit(VerificationPriority::Rpc, || Ok(())).unwrap();
+    assert!(executor.submit(VerificationPriority::Rpc, || Ok(())).is_err());
+    assert_eq!(executor.num_queued(VerificationPriority::Rpc), 2);
+
+    // Open the gate, and wait for the jobs.
+    gate.send(()).unwrap();
+    blocker.wait().unwrap();
+    handles.into_iter().for_each(|handle| handle.wait().unwrap());
+
+    // Ensure the jobs ran in order of decreasing priority.
+    assert_eq!(*order.lock(), [VerificationPriority::Block, VerificationPriority::Mempool, VerificationPriority::Rpc]);
+}
+
+#[test]
+fn test_verification_executor_results() {
+    use crate::{VerificationExecutor, VerificationPriority};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    // Ensure invalid configurations are rejected.
+    assert!(VerificationExecutor::new(0, 1).is_err());
+    assert!(VerificationExecutor::new(1, 0).is_err());
+
+    let executor = VerificationExecutor::new(2, 16).unwrap();
+    assert_eq!(executor.max_concurrency(), 2);
+
+    // Ensure errors and panics are reported to the caller.
+    assert!(executor.submit(VerificationPriority::Block, || bail!("Invalid proof")).unwrap().wait().is_err());
+    assert!(executor.submit(VerificationPriority::Block, || panic!("Invalid proof")).unwrap().wait().is_err());
+
+    // Ensure no more than the maximum number of jobs run at once.
+    let (running, max_running) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+    let handles = (0..16)
+        .map(|_| {
+            let (running, max_running) = (running.clone(), max_running.clone());
+            executor
+                .submit(VerificationPriority::Mempool, move || {
+                    let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(current, Ordering::SeqCst);
+                    std::thread::sleep(std::time::Duration::from_millis(5));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    Ok(())
+                })
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    handles.into_iter().for_each(|handle| handle.wait().unwrap());
+    assert!(max_running.load(Ordering::SeqCst) <= 2);
+}
+
+#[test]
+fn test_relay_score() {
+    use crate::{RelayMetrics, RelayWeights};
+    use std::time::Duration;
+
+    let weights = RelayWeights::default();
+    weights.check().unwrap();
+
+    let metrics = RelayMetrics {
+        fee_in_microcredits: 10_000,
+        size_in_bytes: 1024,
+        num_verified_circuits: 2,
+        age: Duration::ZERO,
+    };
+    let score = weights.score(&metrics);
+
+    // Ensure a higher fee increases the score.
+    assert!(weights.score(&RelayMetrics { fee_in_microcredits: 20_000, ..metrics }) > score);
+    // Ensure a larger size decreases the score.
+    assert!(weights.score(&RelayMetrics { size_in_bytes: 2048, ..metrics }) < score);
+    // Ensure a higher verification cost decreases the score.
+    assert!(weights.score(&RelayMetrics { num_verified_circuits: 4, ..metrics }) < score);
+    // Ensure an older transaction increases the score.
+    assert!(weights.score(&RelayMetrics { age: Duration::from_secs(60), ..metrics }) > score);
+
+    // Ensure the weights are configurable.
+    let fee_only =
+        RelayWeights { fee_per_byte: 1.0, size_per_kilobyte: 0.0, verified_circuit: 0.0, age_per_second: 0.0 };
+    assert_eq!(fee_only.score(&metrics), metrics.fee_per_byte());
+
+    // Ensure malformed weights are rejected.
+    assert!(RelayWeights { fee_per_byte: -1.0, ..weights }.check().is_err());
+    assert!(RelayWeights { age_per_second: f64::NAN, ..weights }.check().is_err());
+}
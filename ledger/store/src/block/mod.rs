@@ -430,7 +430,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             // Store the block height.
             self.reverse_id_map().insert(block.hash(), block.height())?;
             // Store the block header.
-            self.header_map().insert(block.hash(), *block.header())?;
+            self.header_map().insert(block.hash(), block.header().clone())?;
 
             // Store the block authority.
             self.authority_map().insert(block.hash(), block.authority().clone())?;
@@ -1170,12 +1170,103 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     }
 }
 
+impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
+    /// Rebuilds the secondary indexes of the block store from its primary maps.
+    ///
+    /// The secondary indexes are the reverse state root map, the reverse ID map, the puzzle commitments map,
+    /// and the rejected or aborted transaction ID map. Every existing entry is removed, and the entries are
+    /// re-derived from the stored blocks, so both stale and missing entries are repaired.
+    pub fn rebuild_secondary_indexes(&self) -> Result<()> {
+        let storage = &self.storage;
+
+        // Collect the existing entries of the secondary indexes.
+        let state_roots: Vec<_> =
+            storage.reverse_state_root_map().keys_confirmed().map(|key| cow_to_copied!(key)).collect();
+        let hashes: Vec<_> = storage.reverse_id_map().keys_confirmed().map(|key| cow_to_copied!(key)).collect();
+        let puzzle_commitments: Vec<_> =
+            storage.puzzle_commitments_map().keys_confirmed().map(|key| cow_to_copied!(key)).collect();
+        let rejected_or_aborted_transaction_ids: Vec<_> =
+            storage.rejected_or_aborted_transaction_id_map().keys_confirmed().map(|key| cow_to_copied!(key)).collect();
+
+        // Derive the expected entries of the secondary indexes from the primary maps.
+        let mut expected_state_roots = Vec::new();
+        let mut expected_hashes = Vec::new();
+        let mut expected_puzzle_commitments = Vec::new();
+        let mut expected_rejected_or_aborted_transaction_ids = Vec::new();
+        for height in storage.id_map().keys_confirmed() {
+            let height = cow_to_copied!(height);
+            // Retrieve the block hash.
+            let Some(block_hash) = storage.get_block_hash(height)? else {
+                bail!("Missing block hash for block {height}");
+            };
+            expected_hashes.push((block_hash, height));
+            // Retrieve the state root.
+            if let Some(state_root) = storage.get_state_root(height)? {
+                expected_state_roots.push((state_root, height));
+            }
+            // Retrieve the puzzle commitments.
+            if let Some(solutions) = storage.get_block_solutions(&block_hash)? {
+                expected_puzzle_commitments.extend(solutions.keys().map(|commitment| (*commitment, height)));
+            }
+            // Retrieve the aborted transaction IDs.
+            if let Some(aborted_transaction_ids) = storage.get_block_aborted_transaction_ids(&block_hash)? {
+                expected_rejected_or_aborted_transaction_ids
+                    .extend(aborted_transaction_ids.into_iter().map(|id| (id, block_hash)));
+            }
+            // Retrieve the rejected transaction IDs.
+            if let Some(transactions) = storage.get_block_transactions(&block_hash)? {
+                for confirmed in transactions.iter().filter(|tx| tx.is_rejected()) {
+                    expected_rejected_or_aborted_transaction_ids
+                        .push((confirmed.to_unconfirmed_transaction_id()?, block_hash));
+                }
+            }
+        }
+
+        atomic_batch_scope!(self, {
+            // Remove the existing entries.
+            for state_root in &state_roots {
+                storage.reverse_state_root_map().remove(state_root)?;
+            }
+            for block_hash in &hashes {
+                storage.reverse_id_map().remove(block_hash)?;
+            }
+            for puzzle_commitment in &puzzle_commitments {
+                storage.puzzle_commitments_map().remove(puzzle_commitment)?;
+            }
+            for transaction_id in &rejected_or_aborted_transaction_ids {
+                storage.rejected_or_aborted_transaction_id_map().remove(transaction_id)?;
+            }
+
+            // Store the expected entries.
+            for (state_root, height) in expected_state_roots {
+                storage.reverse_state_root_map().insert(state_root, height)?;
+            }
+            for (block_hash, height) in expected_hashes {
+                storage.reverse_id_map().insert(block_hash, height)?;
+            }
+            for (puzzle_commitment, height) in expected_puzzle_commitments {
+                storage.puzzle_commitments_map().insert(puzzle_commitment, height)?;
+            }
+            for (transaction_id, block_hash) in expected_rejected_or_aborted_transaction_ids {
+                storage.rejected_or_aborted_transaction_id_map().insert(transaction_id, block_hash)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     /// Returns the current state root.
     pub fn current_state_root(&self) -> N::StateRoot {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the latest block height, or `None` if the block store is empty.
+    pub fn max_height(&self) -> Option<u32> {
+        u32::try_from(self.tree.read().number_of_leaves()).ok()?.checked_sub(1)
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
@@ -1374,6 +1465,36 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_rebuild_secondary_indexes() {
+        let rng = &mut TestRng::default();
+
+        // Load the genesis block.
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let block_hash = block.hash();
+
+        // Initialize a new block store, and insert the block.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&block).unwrap();
+        let state_root = block_store.current_state_root();
+
+        // Corrupt the secondary indexes, by removing the block entries and adding a stale entry.
+        block_store.storage.reverse_id_map().remove(&block_hash).unwrap();
+        block_store.storage.reverse_state_root_map().remove(&state_root).unwrap();
+        block_store.storage.reverse_id_map().insert(rng.gen(), 5).unwrap();
+        assert_eq!(block_store.get_block_height(&block_hash).unwrap(), None);
+        assert_eq!(block_store.find_block_height_from_state_root(state_root).unwrap(), None);
+
+        // Rebuild the secondary indexes.
+        block_store.rebuild_secondary_indexes().unwrap();
+
+        // Ensure the missing entries are restored, and the stale entry is removed.
+        assert_eq!(block_store.get_block_height(&block_hash).unwrap(), Some(0));
+        assert_eq!(block_store.find_block_height_from_state_root(state_root).unwrap(), Some(0));
+        assert_eq!(block_store.hashes().collect::<Vec<_>>(), vec![Cow::Owned(block_hash)]);
+        assert_eq!(block_store.state_roots().count(), 1);
+    }
+
     #[test]
     fn test_find_block_hash() {
         let rng = &mut TestRng::default();
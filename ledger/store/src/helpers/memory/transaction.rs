@@ -183,6 +183,8 @@ pub struct ExecutionMemory<N: Network> {
     reverse_id_map: MemoryMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
     inclusion_map: MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The expiration map.
+    expiration_map: MemoryMap<N::TransactionID, u32>,
     /// The fee store.
     fee_store: FeeStore<N, FeeMemory<N>>,
 }
@@ -192,6 +194,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
     type ReverseIDMap = MemoryMap<N::TransitionID, N::TransactionID>;
     type InclusionMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type ExpirationMap = MemoryMap<N::TransactionID, u32>;
     type FeeStorage = FeeMemory<N>;
 
     /// Initializes the execution storage.
@@ -200,6 +203,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
             id_map: MemoryMap::default(),
             reverse_id_map: MemoryMap::default(),
             inclusion_map: MemoryMap::default(),
+            expiration_map: MemoryMap::default(),
             fee_store
         })
     }
@@ -219,6 +223,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
         &self.inclusion_map
     }
 
+    /// Returns the expiration map.
+    fn expiration_map(&self) -> &Self::ExpirationMap {
+        &self.expiration_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
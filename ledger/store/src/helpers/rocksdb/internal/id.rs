@@ -119,6 +119,7 @@ pub enum ExecutionMap {
     ID = DataID::ExecutionIDMap as u16,
     ReverseID = DataID::ExecutionReverseIDMap as u16,
     Inclusion = DataID::ExecutionInclusionMap as u16,
+    Expiration = DataID::ExecutionExpirationMap as u16,
 }
 
 /// The RocksDB map prefix for fee-related entries.
@@ -285,6 +286,7 @@ enum DataID {
     // TODO (howardwu): For mainnet - Reorder this up above.
     BlockRejectedDeploymentOrExecutionMap,
     BFTTransmissionsMap,
+    ExecutionExpirationMap,
 
     // Testing
     #[cfg(test)]
@@ -195,6 +195,8 @@ pub struct ExecutionDB<N: Network> {
     reverse_id_map: DataMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
     inclusion_map: DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The expiration map.
+    expiration_map: DataMap<N::TransactionID, u32>,
     /// The fee store.
     fee_store: FeeStore<N, FeeDB<N>>,
 }
@@ -204,6 +206,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
     type IDMap = DataMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
     type ReverseIDMap = DataMap<N::TransitionID, N::TransactionID>;
     type InclusionMap = DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type ExpirationMap = DataMap<N::TransactionID, u32>;
     type FeeStorage = FeeDB<N>;
 
     /// Initializes the execution storage.
@@ -214,6 +217,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
             id_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::ID))?,
             reverse_id_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::ReverseID))?,
             inclusion_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::Inclusion))?,
+            expiration_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::Expiration))?,
             fee_store,
         })
     }
@@ -233,6 +237,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
         &self.inclusion_map
     }
 
+    /// Returns the expiration map.
+    fn expiration_map(&self) -> &Self::ExpirationMap {
+        &self.expiration_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
@@ -38,6 +38,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     type ReverseIDMap: for<'a> Map<'a, N::TransitionID, N::TransactionID>;
     /// The mapping of `transaction ID` to `(global state root, (optional) proof)`.
     type InclusionMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    /// The mapping of `transaction ID` to `expiration height`, for executions that expire.
+    type ExpirationMap: for<'a> Map<'a, N::TransactionID, u32>;
     /// The fee storage.
     type FeeStorage: FeeStorage<N>;
 
@@ -50,6 +52,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_id_map(&self) -> &Self::ReverseIDMap;
     /// Returns the inclusion map.
     fn inclusion_map(&self) -> &Self::InclusionMap;
+    /// Returns the expiration map.
+    fn expiration_map(&self) -> &Self::ExpirationMap;
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
     /// Returns the transition store.
@@ -67,6 +71,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().start_atomic();
         self.reverse_id_map().start_atomic();
         self.inclusion_map().start_atomic();
+        self.expiration_map().start_atomic();
         self.fee_store().start_atomic();
     }
 
@@ -75,6 +80,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().is_atomic_in_progress()
             || self.reverse_id_map().is_atomic_in_progress()
             || self.inclusion_map().is_atomic_in_progress()
+            || self.expiration_map().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
     }
 
@@ -83,6 +89,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().atomic_checkpoint();
         self.reverse_id_map().atomic_checkpoint();
         self.inclusion_map().atomic_checkpoint();
+        self.expiration_map().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
     }
 
@@ -91,6 +98,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().clear_latest_checkpoint();
         self.reverse_id_map().clear_latest_checkpoint();
         self.inclusion_map().clear_latest_checkpoint();
+        self.expiration_map().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
     }
 
@@ -99,6 +107,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().atomic_rewind();
         self.reverse_id_map().atomic_rewind();
         self.inclusion_map().atomic_rewind();
+        self.expiration_map().atomic_rewind();
         self.fee_store().atomic_rewind();
     }
 
@@ -107,6 +116,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().abort_atomic();
         self.reverse_id_map().abort_atomic();
         self.inclusion_map().abort_atomic();
+        self.expiration_map().abort_atomic();
         self.fee_store().abort_atomic();
     }
 
@@ -115,6 +125,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().finish_atomic()?;
         self.reverse_id_map().finish_atomic()?;
         self.inclusion_map().finish_atomic()?;
+        self.expiration_map().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
@@ -135,6 +146,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         let global_state_root = execution.global_state_root();
         // Retrieve the proof.
         let proof = execution.proof().cloned();
+        // Retrieve the expiration height.
+        let expires_at = execution.expires_at();
 
         atomic_batch_scope!(self, {
             // Store the transition IDs.
@@ -151,6 +164,11 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             // Store the global state root and proof.
             self.inclusion_map().insert(*transaction_id, (global_state_root, proof))?;
 
+            // Store the expiration height, which is part of the execution ID.
+            if let Some(expires_at) = expires_at {
+                self.expiration_map().insert(*transaction_id, expires_at)?;
+            }
+
             // Store the fee.
             if let Some(fee) = fee {
                 // Store the fee.
@@ -183,6 +201,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
 
             // Remove the global state root and proof.
             self.inclusion_map().remove(transaction_id)?;
+            // Remove the expiration height.
+            self.expiration_map().remove(transaction_id)?;
 
             // Remove the fee.
             if has_fee {
@@ -235,8 +255,14 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             };
         }
 
-        // Return the execution.
-        Ok(Some(Execution::from(transitions.into_iter(), global_state_root, proof)?))
+        // Construct the execution.
+        let execution = Execution::from(transitions.into_iter(), global_state_root, proof)?;
+
+        // Return the execution, restoring its expiration height.
+        match self.expiration_map().get_confirmed(transaction_id)? {
+            Some(expires_at) => Ok(Some(execution.with_expiration(cow_to_copied!(expires_at)))),
+            None => Ok(Some(execution)),
+        }
     }
 
     /// Returns the transaction for the given `transaction ID`.
@@ -266,6 +292,11 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
 
         // Construct the execution.
         let execution = Execution::from(transitions.into_iter(), global_state_root, proof)?;
+        // Restore the expiration height, as it is part of the execution ID.
+        let execution = match self.expiration_map().get_confirmed(transaction_id)? {
+            Some(expires_at) => execution.with_expiration(cow_to_copied!(expires_at)),
+            None => execution,
+        };
 
         // Construct the transaction.
         let transaction = match has_fee {
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::CurrentNetwork;
+
+use console::{
+    account::{Address, PrivateKey},
+    prelude::*,
+    program::{Entry, Identifier, Literal, Owner, Plaintext, ProgramID, Record, StatePath},
+    types::{Field, Group, U16},
+};
+use ledger_block::{Header, Input, Metadata, Output, Transition};
+
+/******************************************** Record **********************************************/
+
+/// Samples a structurally valid record with a private owner and a random private `amount`.
+/// Note: The record is not the output of any transition, so it cannot be spent.
+pub fn sample_dummy_record(rng: &mut TestRng) -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+    // Sample the owner.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+    let owner = Owner::Private(Plaintext::from(Literal::Address(address)));
+    // Sample the data.
+    let amount = Plaintext::from(Literal::U64(Uniform::rand(rng)));
+    let data = [(Identifier::from_str("amount").unwrap(), Entry::Private(amount))].into_iter().collect();
+    // Sample the nonce.
+    let nonce = CurrentNetwork::g_scalar_multiply(&Uniform::rand(rng));
+    // Return the record.
+    Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(owner, data, nonce).unwrap()
+}
+
+/****************************************** Transition ********************************************/
+
+/// Samples a structurally valid transition of `dummy.aleo/dummy`, with one public input and one public output.
+/// Note: The transition is constructed without a prover, so it is not accompanied by a valid proof.
+pub fn sample_dummy_transition(rng: &mut TestRng) -> Transition<CurrentNetwork> {
+    // Prepare the program ID and function name.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy.aleo").unwrap();
+    let function_name = Identifier::<CurrentNetwork>::from_str("dummy").unwrap();
+    // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+    let network_id = U16::<CurrentNetwork>::new(CurrentNetwork::ID);
+    let function_id = CurrentNetwork::hash_bhp1024(
+        &(network_id, program_id.name(), program_id.network(), function_name).to_bits_le(),
+    )
+    .unwrap();
+
+    // Sample the transition commitment.
+    let tcm = Field::<CurrentNetwork>::rand(rng);
+    // Compute the hash of a random public value, at the given index, as `Hash(function ID || value || tcm || index)`.
+    let mut sample_public = |index: u16| {
+        let value = Plaintext::from(Literal::Field(Uniform::rand(rng)));
+        let mut preimage = vec![function_id];
+        preimage.extend(value.to_fields().unwrap());
+        preimage.extend([tcm, Field::from_u16(index)]);
+        (CurrentNetwork::hash_psd8(&preimage).unwrap(), value)
+    };
+    let (input_hash, input) = sample_public(0);
+    let (output_hash, output) = sample_public(1);
+
+    // Sample the transition public key.
+    let tpk = Group::<CurrentNetwork>::rand(rng);
+    // Return the transition.
+    Transition::new(
+        program_id,
+        function_name,
+        vec![Input::Public(input_hash, Some(input))],
+        vec![Output::Public(output_hash, Some(output))],
+        tpk,
+        tcm,
+    )
+    .unwrap()
+}
+
+/****************************************** Ledger Proof ******************************************/
+
+/// Samples a structurally valid ledger proof, i.e. a state path from a record commitment to a global state root.
+/// If a `commitment` is given, it is used. Otherwise, a `commitment` is randomly sampled.
+/// Note: The global state root is not the root of any real ledger.
+pub fn sample_dummy_ledger_proof(
+    commitment: Option<Field<CurrentNetwork>>,
+    rng: &mut TestRng,
+) -> StatePath<CurrentNetwork> {
+    console::program::state_path::test_helpers::sample_global_state_path(commitment, rng).unwrap()
+}
+
+/********************************************* Header *********************************************/
+
+/// Samples a structurally valid, non-genesis block header with random roots.
+/// Note: The header does not commit to any real block contents.
+pub fn sample_dummy_header(rng: &mut TestRng) -> Header<CurrentNetwork> {
+    // Sample the height and round.
+    let height = rng.gen_range(1..u32::MAX / 2);
+    let round = height as u64 + rng.gen_range(0..1_000);
+    // Sample a timestamp after genesis.
+    let timestamp = CurrentNetwork::GENESIS_TIMESTAMP + rng.gen_range(1..1_000_000);
+    // Prepare the metadata, with the genesis targets.
+    let metadata = Metadata::new(
+        CurrentNetwork::ID,
+        round,
+        height,
+        rng.gen(),
+        rng.gen(),
+        CurrentNetwork::GENESIS_COINBASE_TARGET,
+        CurrentNetwork::GENESIS_PROOF_TARGET,
+        CurrentNetwork::GENESIS_COINBASE_TARGET,
+        CurrentNetwork::GENESIS_TIMESTAMP,
+        timestamp,
+    )
+    .unwrap();
+    // Return the header.
+    Header::from(
+        Field::<CurrentNetwork>::rand(rng).into(),
+        Field::rand(rng),
+        Field::rand(rng),
+        Field::rand(rng),
+        Field::rand(rng),
+        Field::rand(rng),
+        metadata,
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_dummy_record() {
+        let rng = &mut TestRng::default();
+        let record = sample_dummy_record(rng);
+        // Ensure the record is owned by a private owner.
+        assert!(record.owner().is_private());
+    }
+
+    #[test]
+    fn test_sample_dummy_transition() {
+        let rng = &mut TestRng::default();
+        let transition = sample_dummy_transition(rng);
+        // Ensure the transition has one input and one output.
+        assert_eq!(transition.inputs().len(), 1);
+        assert_eq!(transition.outputs().len(), 1);
+    }
+
+    #[test]
+    fn test_sample_dummy_ledger_proof() {
+        let rng = &mut TestRng::default();
+        let commitment = Field::rand(rng);
+        let ledger_proof = sample_dummy_ledger_proof(Some(commitment), rng);
+        // Ensure the ledger proof is for the given commitment, and verifies against its global state root.
+        assert_eq!(ledger_proof.transition_leaf().id(), commitment);
+        assert!(ledger_proof.verify(true, Field::zero()).is_ok());
+    }
+
+    #[test]
+    fn test_sample_dummy_header() {
+        let rng = &mut TestRng::default();
+        let header = sample_dummy_header(rng);
+        // Ensure the header is valid, and not a genesis header.
+        assert!(header.is_valid());
+        assert!(!header.is_genesis());
+    }
+}
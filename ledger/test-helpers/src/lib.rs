@@ -39,6 +39,11 @@ use synthesizer_program::Program;
 
 use once_cell::sync::OnceCell;
 
+#[cfg(any(test, feature = "testing"))]
+mod dummy;
+#[cfg(any(test, feature = "testing"))]
+pub use dummy::*;
+
 type CurrentNetwork = console::network::Testnet3;
 type CurrentAleo = circuit::network::AleoV0;
 
@@ -2,7 +2,7 @@
 
 # Inputs: program name
 
-cargo run --release --example setup credits -- --nocapture || exit
+cargo run --release --manifest-path ../../../Cargo.toml --features setup --bin snarkvm-setup -- credits || exit
 
 mv *.metadata ../../src/testnet3/resources || exit
 mv *.prover.* ~/.aleo/resources || exit
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The maximum number of powers in an accumulator, which matches the maximum degree of the universal SRS.
+const MAX_NUM_POWERS: usize = 1 << 28;
+
+/// The accumulated powers `beta^i G`, for `i` from `0` to `num_powers - 1`, and `beta H`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Accumulator<E: PairingEngine> {
+    /// The powers of beta G.
+    powers_of_beta_g: Vec<E::G1Affine>,
+    /// beta * H
+    beta_h: E::G2Affine,
+}
+
+impl<E: PairingEngine> Accumulator<E> {
+    /// Initializes the accumulator for the given number of powers, with `beta = 1`.
+    pub fn new(num_powers: usize) -> Result<Self> {
+        ensure!(num_powers >= 2, "The ceremony requires at least 2 powers");
+        ensure!(num_powers <= MAX_NUM_POWERS, "The ceremony supports at most {MAX_NUM_POWERS} powers");
+        Ok(Self {
+            powers_of_beta_g: vec![E::G1Affine::prime_subgroup_generator(); num_powers],
+            beta_h: E::G2Affine::prime_subgroup_generator(),
+        })
+    }
+
+    /// Returns the number of powers.
+    pub fn num_powers(&self) -> usize {
+        self.powers_of_beta_g.len()
+    }
+
+    /// Returns the powers of beta G.
+    pub fn powers_of_beta_g(&self) -> &[E::G1Affine] {
+        &self.powers_of_beta_g
+    }
+
+    /// Returns beta * H.
+    pub const fn beta_h(&self) -> E::G2Affine {
+        self.beta_h
+    }
+
+    /// Returns the accumulator with `beta` multiplied by the given `tau`.
+    pub(super) fn multiply(&self, tau: E::Fr) -> Self {
+        let mut power_of_tau = E::Fr::one();
+        let powers_of_beta_g = self
+            .powers_of_beta_g
+            .iter()
+            .map(|power| {
+                let power = *power * power_of_tau;
+                power_of_tau *= tau;
+                power
+            })
+            .collect();
+        Self {
+            powers_of_beta_g: E::G1Projective::batch_normalization_into_affine(powers_of_beta_g),
+            beta_h: (self.beta_h * tau).into(),
+        }
+    }
+
+    /// Ensures the accumulator holds successive powers of the `beta` in `beta H`.
+    ///
+    /// This checks a random linear combination of the ratios `beta^{i+1} G / beta^i G` against `beta H / H`,
+    /// with 128-bit coefficients derived from the given seed, which must commit to the accumulator.
+    pub(super) fn verify(&self, seed: &[u8]) -> Result<()> {
+        // Ensure the powers start from the generator.
+        ensure!(
+            self.powers_of_beta_g.first() == Some(&E::G1Affine::prime_subgroup_generator()),
+            "The powers of beta G must start from the generator"
+        );
+        // Ensure beta is nonzero.
+        ensure!(!self.beta_h.is_zero(), "beta H must be nonzero");
+
+        // Compute the linear combinations of the powers, and of the powers shifted by one.
+        let mut lower = E::G1Projective::zero();
+        let mut upper = E::G1Projective::zero();
+        for (i, powers) in self.powers_of_beta_g.windows(2).enumerate() {
+            let coefficient = Sha256::new()
+                .chain_update(CONSISTENCY_DOMAIN)
+                .chain_update(seed)
+                .chain_update((i as u64).to_le_bytes())
+                .finalize();
+            let coefficient = E::Fr::from_bytes_le_mod_order(&coefficient[..16]);
+            lower += powers[0] * coefficient;
+            upper += powers[1] * coefficient;
+        }
+
+        // Ensure the combinations have the same ratio as beta H.
+        ensure!(
+            same_ratio::<E>((lower.into(), upper.into()), (E::G2Affine::prime_subgroup_generator(), self.beta_h)),
+            "The accumulator does not hold successive powers of beta"
+        );
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Accumulator<E> {
+    /// Reads the accumulator from a buffer, and ensures every point is in the prime-order subgroup.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of powers.
+        let num_powers = u32::read_le(&mut reader)? as usize;
+        if !(2..=MAX_NUM_POWERS).contains(&num_powers) {
+            return Err(error("Invalid number of powers in the accumulator"));
+        }
+        // Read the powers of beta G.
+        let mut powers_of_beta_g = Vec::with_capacity(num_powers.min(1 << 16));
+        for _ in 0..num_powers {
+            powers_of_beta_g.push(E::G1Affine::deserialize_with_mode(&mut reader, Compress::Yes, Validate::Yes)?);
+        }
+        // Read beta H.
+        let beta_h = E::G2Affine::deserialize_with_mode(&mut reader, Compress::Yes, Validate::Yes)?;
+        Ok(Self { powers_of_beta_g, beta_h })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Accumulator<E> {
+    /// Writes the accumulator to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the number of powers.
+        u32::try_from(self.powers_of_beta_g.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the powers of beta G.
+        for power in &self.powers_of_beta_g {
+            power.serialize_with_mode(&mut writer, Compress::Yes)?;
+        }
+        // Write beta H.
+        Ok(self.beta_h.serialize_with_mode(&mut writer, Compress::Yes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Bls12_377;
+    use snarkvm_utilities::TestRng;
+
+    type E = Bls12_377;
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Check that multiplying the accumulator preserves its consistency.
+        let accumulator = Accumulator::<E>::new(8)?;
+        assert!(accumulator.verify(b"seed").is_ok());
+        let accumulator = accumulator.multiply(Uniform::rand(rng));
+        assert!(accumulator.verify(b"seed").is_ok());
+
+        // Check that an out-of-order power is rejected.
+        let mut invalid = accumulator.clone();
+        invalid.powers_of_beta_g.swap(2, 3);
+        assert!(invalid.verify(b"seed").is_err());
+
+        // Check that a mismatched beta H is rejected.
+        let mut invalid = accumulator.clone();
+        invalid.beta_h = accumulator.multiply(Uniform::rand(rng)).beta_h;
+        assert!(invalid.verify(b"seed").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Check the byte representation.
+        let expected = Accumulator::<E>::new(8)?.multiply(Uniform::rand(rng));
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Accumulator::read_le(&expected_bytes[..])?);
+        assert!(Accumulator::<E>::read_le(&expected_bytes[1..]).is_err());
+        assert!(Accumulator::<E>::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A contribution to the ceremony, which multiplies the accumulated `beta` by a secret `tau`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Contribution<E: PairingEngine> {
+    /// The accumulated powers, after the contribution.
+    accumulator: Accumulator<E>,
+    /// tau * H
+    tau_h: E::G2Affine,
+    /// tau * R, where `R` is hashed from the transcript digest and `tau H`, which proves knowledge of `tau`.
+    tau_r: E::G1Affine,
+}
+
+impl<E: PairingEngine> Contribution<E> {
+    /// Returns a contribution on top of the given accumulator and transcript digest,
+    /// with a fresh secret sampled from the given RNG.
+    pub fn new<R: Rng + CryptoRng>(previous: &Accumulator<E>, digest: &[u8; 32], rng: &mut R) -> Result<Self> {
+        // Sample a nonzero secret.
+        let tau = loop {
+            let tau = E::Fr::rand(rng);
+            if !tau.is_zero() {
+                break tau;
+            }
+        };
+        // Compute tau H, and the proof of knowledge of tau.
+        let tau_h = (E::G2Affine::prime_subgroup_generator() * tau).into();
+        let tau_r = (proof_of_knowledge_base::<E>(digest, &tau_h)? * tau).into();
+        // Multiply the accumulated powers by tau.
+        Ok(Self { accumulator: previous.multiply(tau), tau_h, tau_r })
+    }
+
+    /// Returns the accumulated powers, after the contribution.
+    pub const fn accumulator(&self) -> &Accumulator<E> {
+        &self.accumulator
+    }
+
+    /// Returns the accumulated powers, after the contribution.
+    pub fn into_accumulator(self) -> Accumulator<E> {
+        self.accumulator
+    }
+
+    /// Verifies the contribution against the given accumulator and transcript digest.
+    pub fn verify(&self, previous: &Accumulator<E>, digest: &[u8; 32]) -> Result<()> {
+        // Ensure the number of powers is unchanged.
+        ensure!(
+            previous.num_powers() == self.accumulator.num_powers(),
+            "The contribution changed the number of powers"
+        );
+        // Ensure tau is nonzero.
+        ensure!(!self.tau_h.is_zero(), "The contribution must have a nonzero tau H");
+
+        // Ensure the contributor knows tau.
+        let h = E::G2Affine::prime_subgroup_generator();
+        let r = proof_of_knowledge_base::<E>(digest, &self.tau_h)?;
+        ensure!(same_ratio::<E>((r, self.tau_r), (h, self.tau_h)), "The proof of knowledge of tau is invalid");
+
+        // Ensure the accumulated beta was multiplied by tau.
+        ensure!(
+            same_ratio::<E>((previous.powers_of_beta_g()[1], self.accumulator.powers_of_beta_g()[1]), (h, self.tau_h)),
+            "The contribution is not built on the previous powers"
+        );
+        ensure!(
+            same_ratio::<E>(
+                (E::G1Affine::prime_subgroup_generator(), previous.powers_of_beta_g()[1]),
+                (self.tau_h, self.accumulator.beta_h())
+            ),
+            "The contribution is not built on the previous beta H"
+        );
+
+        // Ensure the accumulated powers are consistent, with coefficients derived from the contribution.
+        let seed = Sha256::new().chain_update(digest).chain_update(self.to_bytes_le()?).finalize();
+        self.accumulator.verify(&seed)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Contribution<E> {
+    /// Reads the contribution from a buffer, and ensures every point is in the prime-order subgroup.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let accumulator = Accumulator::read_le(&mut reader)?;
+        let tau_h = E::G2Affine::deserialize_with_mode(&mut reader, Compress::Yes, Validate::Yes)?;
+        let tau_r = E::G1Affine::deserialize_with_mode(&mut reader, Compress::Yes, Validate::Yes)?;
+        Ok(Self { accumulator, tau_h, tau_r })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Contribution<E> {
+    /// Writes the contribution to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.accumulator.write_le(&mut writer)?;
+        self.tau_h.serialize_with_mode(&mut writer, Compress::Yes)?;
+        Ok(self.tau_r.serialize_with_mode(&mut writer, Compress::Yes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Bls12_377;
+    use snarkvm_utilities::TestRng;
+
+    type E = Bls12_377;
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let previous = Accumulator::<E>::new(8)?;
+        let contribution = Contribution::new(&previous, &[1u8; 32], rng)?;
+        assert!(contribution.verify(&previous, &[1u8; 32]).is_ok());
+
+        // Check that a contribution built on other powers is rejected.
+        let other = Contribution::new(&previous, &[1u8; 32], rng)?;
+        assert!(contribution.verify(other.accumulator(), &[1u8; 32]).is_err());
+
+        // Check that replacing tau H is rejected.
+        let invalid = Contribution { tau_h: other.tau_h, ..contribution.clone() };
+        assert!(invalid.verify(&previous, &[1u8; 32]).is_err());
+
+        // Check that replacing the powers is rejected.
+        let invalid = Contribution { accumulator: other.accumulator.clone(), ..contribution.clone() };
+        assert!(invalid.verify(&previous, &[1u8; 32]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Check the byte representation.
+        let expected = Contribution::new(&Accumulator::<E>::new(8)?, &[0u8; 32], rng)?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Contribution::read_le(&expected_bytes[..])?);
+        assert!(Contribution::<E>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
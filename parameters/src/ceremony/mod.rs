@@ -0,0 +1,236 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A multi-party computation ceremony for the universal SRS.
+//!
+//! Varuna derives the circuit proving and verifying keys deterministically from the universal SRS,
+//! so the SRS is the only trusted setup: the powers `beta^i G` and `beta H` of a secret `beta`.
+//! In the ceremony, each participant multiplies the accumulated powers by a fresh secret `tau`, so
+//! the final `beta` is the product of every participant's `tau`, and remains unknown unless every
+//! participant colludes.
+//!
+//! Each contribution carries `tau H` and a proof of knowledge of `tau`, over a base point hashed
+//! from the transcript digest. The transcript digest chains every accepted contribution, so the
+//! final powers commit to the full sequence of contributions.
+//!
+//! Note: There is no circuit-specific (phase-2) ceremony and no key extraction step, as Varuna has
+//! no circuit-specific trusted setup. The circuit keys are derived from the finalized powers.
+
+mod accumulator;
+pub use accumulator::*;
+
+mod contribution;
+pub use contribution::*;
+
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::{
+    error,
+    CanonicalDeserialize,
+    CanonicalSerialize,
+    Compress,
+    FromBytes,
+    Read,
+    ToBytes,
+    Uniform,
+    Validate,
+    Write,
+};
+
+use anyhow::{bail, ensure, Result};
+use rand::{CryptoRng, Rng};
+use sha2::{Digest, Sha256};
+use std::io::Result as IoResult;
+
+/// The domain separator for the initial transcript digest.
+const CEREMONY_DOMAIN: &[u8] = b"snarkVM.Ceremony";
+/// The domain separator for the base point of the proof of knowledge.
+const PROOF_OF_KNOWLEDGE_DOMAIN: &[u8] = b"snarkVM.Ceremony.ProofOfKnowledge";
+/// The domain separator for the coefficients of the batched consistency check.
+const CONSISTENCY_DOMAIN: &[u8] = b"snarkVM.Ceremony.Consistency";
+
+/// A ceremony for the universal SRS, which tracks the accumulated powers and the transcript digest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ceremony<E: PairingEngine> {
+    /// The accumulated powers.
+    accumulator: Accumulator<E>,
+    /// The transcript digest, which chains every accepted contribution.
+    digest: [u8; 32],
+    /// The number of accepted contributions.
+    num_contributions: usize,
+}
+
+impl<E: PairingEngine> Ceremony<E> {
+    /// Initializes a new ceremony for the given number of powers.
+    pub fn new(num_powers: usize) -> Result<Self> {
+        let accumulator = Accumulator::new(num_powers)?;
+        let digest = Sha256::new().chain_update(CEREMONY_DOMAIN).chain_update((num_powers as u64).to_le_bytes());
+        Ok(Self { accumulator, digest: digest.finalize().into(), num_contributions: 0 })
+    }
+
+    /// Replays the given contributions, in order, and returns the resulting ceremony.
+    pub fn from_contributions(num_powers: usize, contributions: &[Contribution<E>]) -> Result<Self> {
+        let mut ceremony = Self::new(num_powers)?;
+        for contribution in contributions {
+            ceremony.apply(contribution.clone())?;
+        }
+        Ok(ceremony)
+    }
+
+    /// Returns the accumulated powers.
+    pub const fn accumulator(&self) -> &Accumulator<E> {
+        &self.accumulator
+    }
+
+    /// Returns the transcript digest.
+    pub const fn digest(&self) -> [u8; 32] {
+        self.digest
+    }
+
+    /// Returns the number of accepted contributions.
+    pub const fn num_contributions(&self) -> usize {
+        self.num_contributions
+    }
+
+    /// Returns a new contribution on top of the accumulated powers, with a fresh secret sampled from the given RNG.
+    /// The secret is dropped once the contribution is computed.
+    pub fn contribute<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Contribution<E>> {
+        Contribution::new(&self.accumulator, &self.digest, rng)
+    }
+
+    /// Verifies the given contribution against the accumulated powers and the transcript digest.
+    pub fn verify(&self, contribution: &Contribution<E>) -> Result<()> {
+        contribution.verify(&self.accumulator, &self.digest)
+    }
+
+    /// Verifies and accepts the given contribution, and chains it into the transcript digest.
+    pub fn apply(&mut self, contribution: Contribution<E>) -> Result<()> {
+        // Verify the contribution.
+        self.verify(&contribution)?;
+        // Chain the contribution into the transcript digest.
+        self.digest =
+            Sha256::new().chain_update(self.digest).chain_update(contribution.to_bytes_le()?).finalize().into();
+        // Accept the accumulated powers.
+        self.accumulator = contribution.into_accumulator();
+        self.num_contributions += 1;
+        Ok(())
+    }
+
+    /// Returns the final powers, once at least one contribution has been accepted.
+    pub fn finalize(self) -> Result<Accumulator<E>> {
+        ensure!(self.num_contributions > 0, "The ceremony has no contributions");
+        Ok(self.accumulator)
+    }
+}
+
+/// Returns `true` if `e(a, b_tau) == e(a_tau, b)`, that is, if `a_tau / a` and `b_tau / b` have the same discrete log.
+fn same_ratio<E: PairingEngine>(
+    (a, a_tau): (E::G1Affine, E::G1Affine),
+    (b, b_tau): (E::G2Affine, E::G2Affine),
+) -> bool {
+    let pairs = [(a.prepare(), b_tau.prepare()), ((-a_tau).prepare(), b.prepare())];
+    E::product_of_pairings(pairs.iter().map(|(a, b)| (a, b))).is_one()
+}
+
+/// Returns the base point of the proof of knowledge, hashed from the transcript digest and `tau H`.
+fn proof_of_knowledge_base<E: PairingEngine>(digest: &[u8; 32], tau_h: &E::G2Affine) -> Result<E::G1Affine> {
+    let mut tau_h_bytes = vec![];
+    tau_h.serialize_compressed(&mut tau_h_bytes)?;
+    // Hash to the curve with try-and-increment, and clear the cofactor.
+    for counter in 0..=u32::MAX {
+        let seed = Sha256::new()
+            .chain_update(PROOF_OF_KNOWLEDGE_DOMAIN)
+            .chain_update(digest)
+            .chain_update(&tau_h_bytes)
+            .chain_update(counter.to_le_bytes())
+            .finalize();
+        let bytes = [Sha256::digest([&seed[..], &[0]].concat()), Sha256::digest([&seed[..], &[1]].concat())].concat();
+        if let Some(point) = E::G1Affine::from_random_bytes(&bytes) {
+            let point = point.mul_by_cofactor();
+            if !point.is_zero() {
+                return Ok(point);
+            }
+        }
+    }
+    bail!("Failed to hash to the curve")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Bls12_377;
+    use snarkvm_utilities::TestRng;
+
+    type E = Bls12_377;
+
+    const NUM_POWERS: usize = 16;
+
+    #[test]
+    fn test_ceremony() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Run a ceremony with three participants.
+        let mut ceremony = Ceremony::<E>::new(NUM_POWERS)?;
+        let mut contributions = vec![];
+        for _ in 0..3 {
+            let contribution = ceremony.contribute(rng)?;
+            let digest = ceremony.digest();
+            ceremony.apply(contribution.clone())?;
+            assert_ne!(digest, ceremony.digest());
+            contributions.push(contribution);
+        }
+        assert_eq!(ceremony.num_contributions(), 3);
+
+        // Check that the transcript replays to the same ceremony.
+        assert_eq!(ceremony, Ceremony::from_contributions(NUM_POWERS, &contributions)?);
+
+        // Check that the contributions cannot be reordered or skipped.
+        assert!(Ceremony::<E>::from_contributions(NUM_POWERS, &contributions[1..]).is_err());
+        contributions.swap(1, 2);
+        assert!(Ceremony::<E>::from_contributions(NUM_POWERS, &contributions).is_err());
+
+        // Check that the final powers are successive powers of a nontrivial beta.
+        let accumulator = ceremony.finalize()?;
+        let powers = accumulator.powers_of_beta_g();
+        assert_eq!(powers.len(), NUM_POWERS);
+        assert_eq!(powers[0], <E as PairingEngine>::G1Affine::prime_subgroup_generator());
+        assert_ne!(powers[1], powers[0]);
+        for i in 1..NUM_POWERS {
+            assert!(same_ratio::<E>(
+                (powers[i - 1], powers[i]),
+                (<E as PairingEngine>::G2Affine::prime_subgroup_generator(), accumulator.beta_h())
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ceremony_requires_a_contribution() -> Result<()> {
+        assert!(Ceremony::<E>::new(NUM_POWERS)?.finalize().is_err());
+        assert!(Ceremony::<E>::new(1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_contribution_must_match_digest() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Check that a contribution is only valid against the transcript digest it was made for.
+        let ceremony = Ceremony::<E>::new(NUM_POWERS)?;
+        let contribution = ceremony.contribute(rng)?;
+        assert!(contribution.verify(ceremony.accumulator(), &ceremony.digest()).is_ok());
+        assert!(contribution.verify(ceremony.accumulator(), &[0u8; 32]).is_err());
+        Ok(())
+    }
+}
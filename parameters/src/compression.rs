@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Zstandard compression of the parameter files.
+//!
+//! With the `zstd` feature, a parameter file may be stored and served as a compressed `<filename>.zst` file.
+//! The compressed file is decompressed on load, and the size and checksum are always checked against the
+//! decompressed bytes, so a compressed file is accepted wherever the uncompressed file is.
+
+use crate::errors::ParameterError;
+
+use std::path::{Path, PathBuf};
+
+/// The extension of a compressed parameter file.
+pub const COMPRESSED_EXTENSION: &str = "zst";
+
+/// The default compression level for the parameter files.
+pub const DEFAULT_COMPRESSION_LEVEL: i32 = 19;
+
+/// Returns the filename of the compressed parameter file, for the given filename.
+pub fn compressed_filename(filename: &str) -> String {
+    format!("{filename}.{COMPRESSED_EXTENSION}")
+}
+
+/// Returns the path of the compressed parameter file, for the given path.
+pub fn compressed_path(file_path: &Path) -> PathBuf {
+    let mut path = file_path.as_os_str().to_owned();
+    path.push(".");
+    path.push(COMPRESSED_EXTENSION);
+    PathBuf::from(path)
+}
+
+/// Compresses the given bytes, at the given compression level.
+pub fn compress(bytes: &[u8], level: i32) -> Result<Vec<u8>, ParameterError> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "zstd")] {
+            zstd::bulk::compress(bytes, level).map_err(|error| ParameterError::Crate("zstd", format!("{error:?}")))
+        } else {
+            let _ = (bytes, level);
+            Err(ParameterError::CompressionDisabled)
+        }
+    }
+}
+
+/// Decompresses the given bytes, which must decompress to exactly the expected size.
+pub fn decompress(bytes: &[u8], expected_size: usize) -> Result<Vec<u8>, ParameterError> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "zstd")] {
+            // Note: The capacity bounds the decompressed size, so a malicious file cannot exhaust the memory.
+            let buffer = zstd::bulk::decompress(bytes, expected_size)
+                .map_err(|error| ParameterError::Crate("zstd", format!("{error:?}")))?;
+            // Ensure the size matches.
+            match buffer.len() == expected_size {
+                true => Ok(buffer),
+                false => Err(ParameterError::SizeMismatch(expected_size, buffer.len())),
+            }
+        } else {
+            let _ = (bytes, expected_size);
+            Err(ParameterError::CompressionDisabled)
+        }
+    }
+}
+
+/// Loads and decompresses the compressed parameter file next to the given path, if it exists.
+/// Returns `None` if there is no compressed file, or if the `zstd` feature is disabled.
+pub fn load_compressed(file_path: &Path, expected_size: usize) -> Result<Option<Vec<u8>>, ParameterError> {
+    let compressed_path = compressed_path(file_path);
+    match cfg!(feature = "zstd") && compressed_path.exists() {
+        true => decompress(&std::fs::read(compressed_path)?, expected_size).map(Some),
+        false => Ok(None),
+    }
+}
+
+/// Returns `true` if downloaded parameter files are fetched and stored compressed.
+/// This requires the `zstd` feature, and is enabled with [`ParameterStore::with_compression`](crate::store::ParameterStore::with_compression).
+pub fn is_enabled() -> bool {
+    cfg!(feature = "zstd") && crate::store::parameter_store().compression()
+}
+
+#[cfg(all(test, feature = "zstd"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compress_and_decompress() {
+        let bytes = (0..100_000u32).flat_map(|i| (i % 251).to_le_bytes()).collect::<Vec<_>>();
+        let compressed = compress(&bytes, DEFAULT_COMPRESSION_LEVEL).unwrap();
+        assert!(compressed.len() < bytes.len() / 2);
+        assert_eq!(decompress(&compressed, bytes.len()).unwrap(), bytes);
+        // Ensure the decompressed size is bounded by the expected size.
+        assert!(decompress(&compressed, bytes.len() - 1).is_err());
+        assert!(matches!(decompress(&compressed, bytes.len() + 1), Err(ParameterError::SizeMismatch(..))));
+    }
+
+    #[test]
+    fn test_load_compressed() {
+        let directory = std::env::temp_dir().join(format!("snarkvm-compression-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_path = directory.join("test.prover.a1b2c3d");
+        assert_eq!(compressed_path(&file_path), directory.join(compressed_filename("test.prover.a1b2c3d")));
+
+        // Ensure a missing compressed file is not loaded.
+        assert!(load_compressed(&file_path, 10).unwrap().is_none());
+
+        // Ensure the compressed file is decompressed on load.
+        std::fs::write(compressed_path(&file_path), compress(b"parameters", 3).unwrap()).unwrap();
+        assert_eq!(load_compressed(&file_path, 10).unwrap().unwrap(), b"parameters");
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
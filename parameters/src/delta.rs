@@ -0,0 +1,432 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Binary deltas between versions of a parameter file.
+//!
+//! When a circuit changes slightly across releases, most of its proving key is unchanged. A delta encodes the
+//! new version of a parameter file as a sequence of ranges copied from a previous version, and bytes inserted
+//! between them. Deltas are keyed by the checksums of both versions, and applying a delta verifies both.
+//!
+//! A delta from the version with checksum `<base>` is served as `<versioned filename>.<base>.delta`, next to the
+//! parameter file. The metadata of a parameter file lists its previous versions under `"bases"`, each with its
+//! `"checksum"` and the `"delta_size"` of the delta from it, which bounds the download of the delta.
+//! If a previous version is stored locally, the loader fetches and applies the delta, and otherwise falls back
+//! to downloading the whole parameter file.
+
+use crate::errors::ParameterError;
+
+use snarkvm_utilities::{error, FromBytes, Read, ToBytes, Write};
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// The magic bytes at the start of a delta, which contain the version of the delta format.
+const DELTA_MAGIC: &[u8; 8] = b"svmdlt01";
+
+/// The extension of a delta file.
+pub const DELTA_EXTENSION: &str = "delta";
+
+/// The size of the blocks of the previous version that are matched in the new version.
+const BLOCK_SIZE: usize = 64;
+
+/// The base of the rolling hash over a block.
+const HASH_BASE: u64 = 0x0100_0000_01b3;
+
+/// An operation of a delta.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeltaOperation {
+    /// Copies the given number of bytes from the given offset in the previous version.
+    Copy { offset: u64, length: u64 },
+    /// Inserts the given bytes.
+    Insert(Vec<u8>),
+}
+
+/// A binary delta from a previous version of a parameter file to a new version.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Delta {
+    /// The sha256 checksum of the previous version.
+    base_checksum: [u8; 32],
+    /// The sha256 checksum of the new version.
+    target_checksum: [u8; 32],
+    /// The size of the new version, in bytes.
+    target_size: u64,
+    /// The operations that construct the new version.
+    operations: Vec<DeltaOperation>,
+}
+
+impl Delta {
+    /// Computes the delta from the given previous version to the given new version.
+    pub fn new(base: &[u8], target: &[u8]) -> Self {
+        Self {
+            base_checksum: sha256(base),
+            target_checksum: sha256(target),
+            target_size: target.len() as u64,
+            operations: diff(base, target),
+        }
+    }
+
+    /// Returns the checksum of the previous version, in hex.
+    pub fn base_checksum(&self) -> String {
+        hex::encode(self.base_checksum)
+    }
+
+    /// Returns the checksum of the new version, in hex.
+    pub fn target_checksum(&self) -> String {
+        hex::encode(self.target_checksum)
+    }
+
+    /// Returns the size of the new version, in bytes.
+    pub const fn target_size(&self) -> u64 {
+        self.target_size
+    }
+
+    /// Returns the operations that construct the new version.
+    pub fn operations(&self) -> &[DeltaOperation] {
+        &self.operations
+    }
+
+    /// Applies the delta to the given previous version, and returns the new version.
+    /// The checksums of the previous version and of the new version must both match.
+    pub fn apply(&self, base: &[u8]) -> Result<Vec<u8>, ParameterError> {
+        // Ensure the checksum of the previous version matches.
+        let base_checksum = hex::encode(sha256(base));
+        if base_checksum != self.base_checksum() {
+            return Err(ParameterError::ChecksumMismatch(self.base_checksum(), base_checksum));
+        }
+
+        let target_size = usize::try_from(self.target_size).map_err(|_| {
+            ParameterError::Message(format!("The delta target of {} bytes is too large", self.target_size))
+        })?;
+        let mut target = Vec::with_capacity(target_size);
+        for operation in &self.operations {
+            let bytes = match operation {
+                DeltaOperation::Copy { offset, length } => {
+                    // Ensure the range is within the previous version.
+                    let start = usize::try_from(*offset).unwrap_or(usize::MAX);
+                    let end = usize::try_from(*length).ok().and_then(|length| start.checked_add(length));
+                    match end.and_then(|end| base.get(start..end)) {
+                        Some(bytes) => bytes,
+                        None => {
+                            return Err(ParameterError::Message(format!(
+                                "The delta copies {length} bytes at offset {offset}, beyond the {} bytes of the base",
+                                base.len()
+                            )));
+                        }
+                    }
+                }
+                DeltaOperation::Insert(bytes) => bytes.as_slice(),
+            };
+            // Ensure the new version does not exceed its size.
+            if target.len() + bytes.len() > target_size {
+                return Err(ParameterError::SizeMismatch(target_size, target.len() + bytes.len()));
+            }
+            target.extend_from_slice(bytes);
+        }
+
+        // Ensure the size matches.
+        if target.len() != target_size {
+            return Err(ParameterError::SizeMismatch(target_size, target.len()));
+        }
+        // Ensure the checksum of the new version matches.
+        let target_checksum = hex::encode(sha256(&target));
+        if target_checksum != self.target_checksum() {
+            return Err(ParameterError::ChecksumMismatch(self.target_checksum(), target_checksum));
+        }
+        Ok(target)
+    }
+}
+
+impl FromBytes for Delta {
+    /// Reads the delta from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> std::io::Result<Self> {
+        // Ensure the magic bytes match.
+        let magic = <[u8; 8]>::read_le(&mut reader)?;
+        if &magic != DELTA_MAGIC {
+            return Err(error("Invalid delta magic bytes"));
+        }
+        let base_checksum = <[u8; 32]>::read_le(&mut reader)?;
+        let target_checksum = <[u8; 32]>::read_le(&mut reader)?;
+        let target_size = u64::read_le(&mut reader)?;
+
+        let num_operations = u64::read_le(&mut reader)?;
+        // Note: The operations are not preallocated, as the number of operations is untrusted.
+        let mut operations = Vec::new();
+        for _ in 0..num_operations {
+            let operation = match u8::read_le(&mut reader)? {
+                0 => DeltaOperation::Copy { offset: u64::read_le(&mut reader)?, length: u64::read_le(&mut reader)? },
+                1 => {
+                    let length = u64::read_le(&mut reader)?;
+                    // Ensure the inserted bytes do not exceed the new version.
+                    if length > target_size {
+                        return Err(error(format!("Invalid delta insertion of {length} bytes")));
+                    }
+                    let mut bytes = Vec::new();
+                    (&mut reader).take(length).read_to_end(&mut bytes)?;
+                    if bytes.len() as u64 != length {
+                        return Err(error("Truncated delta insertion"));
+                    }
+                    DeltaOperation::Insert(bytes)
+                }
+                variant => return Err(error(format!("Invalid delta operation variant '{variant}'"))),
+            };
+            operations.push(operation);
+        }
+        Ok(Self { base_checksum, target_checksum, target_size, operations })
+    }
+}
+
+impl ToBytes for Delta {
+    /// Writes the delta to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        DELTA_MAGIC.write_le(&mut writer)?;
+        self.base_checksum.write_le(&mut writer)?;
+        self.target_checksum.write_le(&mut writer)?;
+        self.target_size.write_le(&mut writer)?;
+        (self.operations.len() as u64).write_le(&mut writer)?;
+        for operation in &self.operations {
+            match operation {
+                DeltaOperation::Copy { offset, length } => {
+                    0u8.write_le(&mut writer)?;
+                    offset.write_le(&mut writer)?;
+                    length.write_le(&mut writer)?;
+                }
+                DeltaOperation::Insert(bytes) => {
+                    1u8.write_le(&mut writer)?;
+                    (bytes.len() as u64).write_le(&mut writer)?;
+                    writer.write_all(bytes)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the filename of the delta to the given versioned filename, from the version with the given checksum.
+pub fn delta_filename(filename: &str, base_checksum: &str) -> String {
+    format!("{filename}.{}.{DELTA_EXTENSION}", short_checksum(base_checksum))
+}
+
+/// Returns the versioned filename of the version with the given checksum, for the given versioned filename.
+pub fn base_filename(filename: &str, base_checksum: &str) -> String {
+    // Remove the checksum suffix of the versioned filename.
+    let unversioned = match filename.rsplit_once('.') {
+        Some((unversioned, suffix)) if suffix.len() == 7 => unversioned,
+        _ => filename,
+    };
+    format!("{unversioned}.{}", short_checksum(base_checksum))
+}
+
+/// Returns the checksums of the previous versions in the given metadata, paired with the sizes of the deltas
+/// from them and the paths of the previous versions next to the given path, for the previous versions that are stored.
+pub fn stored_bases(file_path: &Path, filename: &str, metadata: &serde_json::Value) -> Vec<(String, usize, PathBuf)> {
+    let bases = metadata["bases"].as_array().map(Vec::as_slice).unwrap_or_default();
+    bases
+        .iter()
+        .filter_map(|base| {
+            let base_checksum = base["checksum"].as_str()?;
+            let delta_size = usize::try_from(base["delta_size"].as_u64()?).ok()?;
+            Some((
+                base_checksum.to_string(),
+                delta_size,
+                file_path.with_file_name(base_filename(filename, base_checksum)),
+            ))
+        })
+        .filter(|(_, _, base_path)| base_path.exists())
+        .collect()
+}
+
+/// Returns the first 7 characters of the given checksum, which version the filenames.
+fn short_checksum(checksum: &str) -> &str {
+    checksum.get(0..7).unwrap_or(checksum)
+}
+
+/// Returns the sha256 digest of the given bytes.
+fn sha256(bytes: &[u8]) -> [u8; 32] {
+    use sha2::Digest;
+    sha2::Sha256::digest(bytes).into()
+}
+
+/// Returns the operations that construct the given new version from the given previous version.
+///
+/// The blocks of the previous version are indexed by a rolling hash, and each block of the new version that
+/// matches is extended in both directions, so that an insertion or a removal only shifts the remaining bytes.
+fn diff(base: &[u8], target: &[u8]) -> Vec<DeltaOperation> {
+    let mut operations = Vec::new();
+    // Returns the operations, inserting the remaining bytes of the new version.
+    let finish = |mut operations: Vec<DeltaOperation>, remaining: &[u8]| {
+        if !remaining.is_empty() {
+            operations.push(DeltaOperation::Insert(remaining.to_vec()));
+        }
+        operations
+    };
+    if base.len() < BLOCK_SIZE || target.len() < BLOCK_SIZE {
+        return finish(operations, target);
+    }
+
+    // Index the blocks of the previous version by their hash.
+    let mut blocks = HashMap::with_capacity(base.len() / BLOCK_SIZE);
+    for offset in (0..=base.len() - BLOCK_SIZE).step_by(BLOCK_SIZE) {
+        blocks.entry(block_hash(&base[offset..offset + BLOCK_SIZE])).or_insert(offset);
+    }
+    // The coefficient of the outgoing byte of the rolling hash.
+    let outgoing_coefficient = (1..BLOCK_SIZE).fold(1u64, |coefficient, _| coefficient.wrapping_mul(HASH_BASE));
+
+    // The start of the bytes of the new version that are not yet encoded.
+    let mut pending = 0;
+    let mut position = 0;
+    let mut hash = block_hash(&target[..BLOCK_SIZE]);
+    while position + BLOCK_SIZE <= target.len() {
+        let block = &target[position..position + BLOCK_SIZE];
+        match blocks.get(&hash).filter(|offset| &base[**offset..**offset + BLOCK_SIZE] == block) {
+            Some(&offset) => {
+                // Extend the match backwards, into the pending bytes.
+                let (mut start, mut base_start) = (position, offset);
+                while start > pending && base_start > 0 && target[start - 1] == base[base_start - 1] {
+                    start -= 1;
+                    base_start -= 1;
+                }
+                // Extend the match forwards.
+                let mut length = position + BLOCK_SIZE - start;
+                while start + length < target.len()
+                    && base_start + length < base.len()
+                    && target[start + length] == base[base_start + length]
+                {
+                    length += 1;
+                }
+
+                // Insert the pending bytes, and copy the match.
+                if start > pending {
+                    operations.push(DeltaOperation::Insert(target[pending..start].to_vec()));
+                }
+                operations.push(DeltaOperation::Copy { offset: base_start as u64, length: length as u64 });
+                position = start + length;
+                pending = position;
+                if position + BLOCK_SIZE <= target.len() {
+                    hash = block_hash(&target[position..position + BLOCK_SIZE]);
+                }
+            }
+            None => {
+                // Roll the hash forward by one byte.
+                if position + BLOCK_SIZE < target.len() {
+                    hash = hash
+                        .wrapping_sub((target[position] as u64).wrapping_mul(outgoing_coefficient))
+                        .wrapping_mul(HASH_BASE)
+                        .wrapping_add(target[position + BLOCK_SIZE] as u64);
+                }
+                position += 1;
+            }
+        }
+    }
+    finish(operations, &target[pending..])
+}
+
+/// Returns the rolling hash of the given block.
+fn block_hash(block: &[u8]) -> u64 {
+    block.iter().fold(0u64, |hash, byte| hash.wrapping_mul(HASH_BASE).wrapping_add(*byte as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    use rand::RngCore;
+
+    /// Returns the given number of random bytes.
+    fn sample_bytes(rng: &mut TestRng, num_bytes: usize) -> Vec<u8> {
+        let mut bytes = vec![0u8; num_bytes];
+        rng.fill_bytes(&mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn test_delta() {
+        let rng = &mut TestRng::default();
+
+        let base = sample_bytes(rng, 100_000);
+        // Construct the new version, with an insertion, a removal, and a modification.
+        let mut target = base.clone();
+        target.splice(10_000..10_000, sample_bytes(rng, 123));
+        target.drain(50_000..50_777);
+        target[80_000] ^= 1;
+
+        let delta = Delta::new(&base, &target);
+        assert_eq!(delta.apply(&base).unwrap(), target);
+        // Ensure the delta is much smaller than the new version.
+        let bytes = delta.to_bytes_le().unwrap();
+        assert!(bytes.len() < 1_000, "The delta is {} bytes", bytes.len());
+        assert_eq!(Delta::from_bytes_le(&bytes).unwrap(), delta);
+
+        // Ensure the delta is not applied to a different base.
+        let mut other_base = base.clone();
+        other_base[0] ^= 1;
+        assert!(matches!(delta.apply(&other_base), Err(ParameterError::ChecksumMismatch(..))));
+    }
+
+    #[test]
+    fn test_delta_unrelated_and_small() {
+        let rng = &mut TestRng::default();
+
+        for (base_size, target_size) in [(0, 0), (0, 100), (100, 0), (10, 1_000), (1_000, 10), (5_000, 5_000)] {
+            let base = sample_bytes(rng, base_size);
+            let target = sample_bytes(rng, target_size);
+            let delta = Delta::new(&base, &target);
+            assert_eq!(delta.apply(&base).unwrap(), target);
+            assert_eq!(Delta::from_bytes_le(&delta.to_bytes_le().unwrap()).unwrap(), delta);
+        }
+    }
+
+    #[test]
+    fn test_delta_rejects_invalid_operations() {
+        let base = vec![7u8; 1_000];
+        let target = vec![7u8; 500];
+        let mut delta = Delta::new(&base, &target);
+        // Ensure a copy beyond the base is rejected.
+        delta.operations = vec![DeltaOperation::Copy { offset: 900, length: 500 }];
+        assert!(delta.apply(&base).is_err());
+        // Ensure a new version that exceeds its size is rejected.
+        delta.operations = vec![DeltaOperation::Copy { offset: 0, length: 501 }];
+        assert!(matches!(delta.apply(&base), Err(ParameterError::SizeMismatch(..))));
+        // Ensure invalid bytes are rejected.
+        assert!(Delta::from_bytes_le(b"svmdlt02").is_err());
+    }
+
+    #[test]
+    fn test_filenames() {
+        let checksum = "a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90";
+        assert_eq!(delta_filename("split.prover.1234567", checksum), "split.prover.1234567.a1b2c3d.delta");
+        assert_eq!(base_filename("split.prover.1234567", checksum), "split.prover.a1b2c3d");
+        assert_eq!(base_filename("split.prover", checksum), "split.prover.a1b2c3d");
+
+        let directory = std::env::temp_dir().join(format!("snarkvm-delta-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_path = directory.join("split.prover.1234567");
+        let metadata = serde_json::json!({ "bases": [
+            { "checksum": checksum, "delta_size": 100 },
+            { "checksum": "ffffffffff", "delta_size": 100 },
+            { "checksum": checksum },
+        ] });
+        // Ensure only the stored previous versions are returned.
+        assert!(stored_bases(&file_path, "split.prover.1234567", &metadata).is_empty());
+        std::fs::write(directory.join("split.prover.a1b2c3d"), b"base").unwrap();
+        // Ensure the previous versions without a delta size are skipped.
+        assert_eq!(
+            stored_bases(&file_path, "split.prover.1234567", &metadata),
+            vec![(checksum.to_string(), 100, directory.join("split.prover.a1b2c3d"))]
+        );
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -19,9 +19,15 @@ pub enum ParameterError {
     #[error("expected checksum of {}, found checksum of {}", _0, _1)]
     ChecksumMismatch(String, String),
 
+    #[error("Compression is disabled, enable compiler flag for feature")]
+    CompressionDisabled,
+
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("Failed to load parameters from {}:\n{}", _0, _1.join("\n"))]
+    InvalidDirectory(String, Vec<String>),
+
     #[error("{}", _0)]
     Message(String),
 
@@ -42,6 +48,13 @@ impl From<curl::Error> for ParameterError {
     }
 }
 
+#[cfg(all(feature = "async", not(feature = "wasm")))]
+impl From<reqwest::Error> for ParameterError {
+    fn from(error: reqwest::Error) -> Self {
+        ParameterError::Crate("reqwest", format!("{error:?}"))
+    }
+}
+
 impl From<std::io::Error> for ParameterError {
     fn from(error: std::io::Error) -> Self {
         ParameterError::Crate("std::io", format!("{error:?}"))
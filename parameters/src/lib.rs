@@ -13,7 +13,8 @@
 // limitations under the License.
 
 #![allow(clippy::module_inception)]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "mmap"), forbid(unsafe_code))]
+#![cfg_attr(feature = "mmap", deny(unsafe_code))]
 
 #[cfg(feature = "wasm")]
 #[macro_use]
@@ -27,11 +28,29 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+pub mod ceremony;
+
+#[cfg(not(feature = "wasm"))]
+pub mod compression;
+
+pub mod delta;
+
 pub mod errors;
 pub use errors::*;
 
+#[cfg(all(feature = "mmap", not(feature = "wasm")))]
+pub mod mmap;
+
+pub mod progress;
+
+pub mod store;
+
 pub mod testnet3;
 
+pub mod transcript;
+
+pub mod transport;
+
 pub mod prelude {
     pub use crate::errors::*;
 }
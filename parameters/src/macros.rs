@@ -67,38 +67,146 @@ macro_rules! impl_store_and_remote_fetch {
             Ok(())
         }
 
+        /// Downloads the file at the given URL into the buffer, resuming from the partial file at the given path.
+        ///
+        /// The received bytes are appended to the partial file as they arrive, so that an interrupted download
+        /// resumes from the last received byte with an HTTP range request. The caller removes the partial file
+        /// once the checksum of the download is verified.
+        ///
+        /// The expected size is the size of this file, which bounds the partial download and is the progress total.
+        /// If the size is unknown, a complete partial download cannot be told apart, so the download starts over.
         #[cfg(not(feature = "wasm"))]
-        fn remote_fetch(buffer: &mut Vec<u8>, url: &str) -> Result<(), $crate::errors::ParameterError> {
+        fn remote_fetch(
+            buffer: &mut Vec<u8>,
+            url: &str,
+            partial_path: &std::path::Path,
+            expected_size: Option<usize>,
+            timeout: Option<std::time::Duration>,
+        ) -> Result<(), $crate::errors::ParameterError> {
+            use std::{cell::RefCell, io::Write};
+
+            // Load the partial download, if it exists.
+            let mut partial = std::fs::read(partial_path).unwrap_or_default();
+            match expected_size {
+                // If the partial download is complete, return it without a request.
+                Some(expected_size) if partial.len() == expected_size => {
+                    *buffer = partial;
+                    return Ok(());
+                }
+                // If the partial download is too large, discard it.
+                Some(expected_size) if partial.len() > expected_size => partial.clear(),
+                Some(_) => (),
+                // If the size is unknown, discard the partial download.
+                None => partial.clear(),
+            }
+            let offset = partial.len() as u64;
+
+            // Open the partial file for appending.
+            if let Some(directory_path) = partial_path.parent() {
+                std::fs::create_dir_all(directory_path)?;
+            }
+            let file = std::fs::OpenOptions::new().create(true).append(true).open(partial_path)?;
+            file.set_len(offset)?;
+            let state = RefCell::new((partial, file));
+
             let mut easy = curl::easy::Easy::new();
             easy.follow_location(true)?;
+            easy.fail_on_error(true)?;
             easy.url(url)?;
+            // Abort the download once the timeout elapses, keeping the partial download for the next URL.
+            if let Some(timeout) = timeout {
+                easy.timeout(timeout)?;
+            }
+            // Request only the remaining bytes.
+            // Note: A server without range support may respond with the whole file, which is handled below.
+            if offset > 0 {
+                easy.range(&format!("{offset}-"))?;
+            }
 
             #[cfg(not(feature = "no_std_out"))]
             {
                 use colored::*;
 
-                let output = format!("{:>15} - Downloading \"{}\"", "Installation", url);
+                let output = match offset {
+                    0 => format!("{:>15} - Downloading \"{}\"", "Installation", url),
+                    _ => format!("{:>15} - Resuming \"{}\" from byte {}", "Installation", url, offset),
+                };
                 println!("{}", output.dimmed());
+            }
+
+            // Report the download progress, counting the bytes of the partial download.
+            let filename = partial_path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+            easy.progress(true)?;
+            easy.progress_function(move |_total_download, current_download, _, _| {
+                let total = expected_size.map_or(offset + _total_download as u64, |size| size as u64);
+                $crate::progress::report_download(&filename, offset + current_download as u64, total);
+
+                #[cfg(not(feature = "no_std_out"))]
+                {
+                    use colored::*;
 
-                easy.progress(true)?;
-                easy.progress_function(|total_download, current_download, _, _| {
-                    let percent = (current_download / total_download) * 100.0;
-                    let size_in_megabytes = total_download as u64 / 1_048_576;
+                    let percent = (current_download / _total_download) * 100.0;
+                    let size_in_megabytes = _total_download as u64 / 1_048_576;
                     let output = format!(
                         "\r{:>15} - {:.2}% complete ({:#} MB total)",
                         "Installation", percent, size_in_megabytes
                     );
                     print!("{}", output.dimmed());
-                    true
-                })?;
-            }
+                }
+                true
+            })?;
 
             let mut transfer = easy.transfer();
+            transfer.header_function(|header| {
+                // Retrieve the status code, if this is a status line.
+                let status = std::str::from_utf8(header)
+                    .ok()
+                    .filter(|line| line.starts_with("HTTP/"))
+                    .and_then(|line| line.split_whitespace().nth(1))
+                    .and_then(|code| code.parse::<u32>().ok());
+                // If the server ignored the range request and sends the whole file, discard the partial download.
+                if status == Some(200) {
+                    let (buffer, file) = &mut *state.borrow_mut();
+                    buffer.clear();
+                    return file.set_len(0).is_ok();
+                }
+                true
+            })?;
             transfer.write_function(|data| {
+                let (buffer, file) = &mut *state.borrow_mut();
+                // Persist the bytes, so the download can resume if it is interrupted.
+                // Note: Reporting fewer bytes than received aborts the transfer.
+                if file.write_all(data).is_err() {
+                    return Ok(0);
+                }
                 buffer.extend_from_slice(data);
                 Ok(data.len())
             })?;
-            Ok(transfer.perform()?)
+            transfer.perform()?;
+            drop(transfer);
+
+            *buffer = state.into_inner().0;
+            Ok(())
+        }
+
+        /// Downloads the file at the given URL with `remote_fetch`, and returns the output of the given verification.
+        /// If the verification fails, the partial file is removed, so that the next attempt does not return the
+        /// same bytes again.
+        #[cfg(not(feature = "wasm"))]
+        fn remote_fetch_verified<T>(
+            url: &str,
+            partial_path: &std::path::Path,
+            expected_size: Option<usize>,
+            timeout: Option<std::time::Duration>,
+            verify: impl FnOnce(Vec<u8>) -> Result<T, $crate::errors::ParameterError>,
+        ) -> Result<T, $crate::errors::ParameterError> {
+            let mut buffer = vec![];
+            Self::remote_fetch(&mut buffer, url, partial_path, expected_size, timeout)?;
+            verify(buffer).map_err(|error| {
+                // Remove the partial download, as its bytes are invalid.
+                let _ = std::fs::remove_file(partial_path);
+                error
+            })
         }
 
         #[cfg(feature = "wasm")]
@@ -166,16 +274,43 @@ macro_rules! impl_load_bytes_logic_local {
     };
 }
 
+#[cfg(not(feature = "wasm"))]
+macro_rules! load_compressed {
+    ($file_path: expr, $filename: expr, $expected_size: expr) => {
+        match $crate::compression::load_compressed(&$file_path, $expected_size) {
+            Ok(compressed) => compressed,
+            Err(error) => {
+                // Remove the corrupt compressed file, so the parameter file is downloaded again.
+                eprintln!("Failed to load the compressed \"{}\": {}", $filename, error);
+                let _ = std::fs::remove_file($crate::compression::compressed_path(&$file_path));
+                None
+            }
+        }
+    };
+}
+
 macro_rules! impl_load_bytes_logic_remote {
     ($remote_url: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr) => {
         // Compose the correct file path for the parameter file.
-        let mut file_path = aleo_std::aleo_dir();
-        file_path.push($local_dir);
-        file_path.push($filename);
+        let file_path = $crate::store::parameter_store().resolve($local_dir, $filename);
+
+        // Load the compressed parameter file, if it is stored instead of the parameter file.
+        #[cfg(not(feature = "wasm"))]
+        let compressed = match file_path.exists() {
+            true => None,
+            false => load_compressed!(file_path, $filename, $expected_size),
+        };
+        #[cfg(feature = "wasm")]
+        let compressed: Option<Vec<u8>> = None;
 
         let buffer = if file_path.exists() {
             // Attempts to load the parameter file locally with an absolute path.
-            std::fs::read(&file_path)?
+            let buffer = std::fs::read(&file_path)?;
+            $crate::progress::report_stage($filename, $crate::progress::LoadStage::Verifying);
+            buffer
+        } else if let Some(buffer) = compressed {
+            $crate::progress::report_stage($filename, $crate::progress::LoadStage::Verifying);
+            buffer
         } else {
             // Downloads the missing parameters and stores it in the local directory for use.
              #[cfg(not(feature = "no_std_out"))]
@@ -188,41 +323,135 @@ macro_rules! impl_load_bytes_logic_remote {
                 );
             }
 
-            // Construct the URL.
-            let url = format!("{}/{}", $remote_url, $filename);
+            // Construct the candidate URLs, using the checksum as the content address.
+            let sources = $crate::transport::remote_sources($remote_url, $filename, &$expected_checksum)?;
 
             // Load remote file
             cfg_if::cfg_if! {
                 if #[cfg(not(feature = "wasm"))] {
-                    let mut buffer = vec![];
-                    Self::remote_fetch(&mut buffer, &url)?;
+                    // Compose the file path for the partial download, which is shared by all URLs.
+                    let partial_path = file_path.with_file_name(format!("{}.partial", $filename));
+
+                    // Fetch the file from the first URL that serves the expected content.
+                    let mut result = Err($crate::errors::ParameterError::RemoteFetchDisabled);
+                    // Whether the compressed file was fetched, and stored in place of the parameter file.
+                    let mut is_stored = false;
+
+                    // If a previous version of the file is stored, fetch the delta from it first.
+                    for (base_checksum, delta_size, base_path) in $crate::delta::stored_bases(&file_path, $filename, &$metadata) {
+                        let delta_filename = $crate::delta::delta_filename($filename, &base_checksum);
+                        let delta_partial_path = file_path.with_file_name(format!("{}.partial", delta_filename));
+                        for (url, timeout) in &$crate::transport::filename_sources($remote_url, &delta_filename) {
+                            $crate::progress::report_stage($filename, $crate::progress::LoadStage::Downloading);
+                            result = Self::remote_fetch_verified(url, &delta_partial_path, Some(delta_size), *timeout, |delta_bytes| {
+                                // Ensure the delta is from the stored version, to the expected version.
+                                $crate::progress::report_stage($filename, $crate::progress::LoadStage::Verifying);
+                                let delta = <$crate::delta::Delta as snarkvm_utilities::FromBytes>::from_bytes_le(&delta_bytes)
+                                    .map_err(|error| $crate::errors::ParameterError::Message(format!("Invalid delta: {error}")))?;
+                                if delta.base_checksum() != base_checksum || delta.target_checksum() != $expected_checksum {
+                                    return checksum_error!($expected_checksum.clone(), delta.target_checksum());
+                                }
+                                delta.apply(&std::fs::read(&base_path)?)
+                            });
+                            // Remove the partial download, as the deltas are small, and are not resumed.
+                            let _ = std::fs::remove_file(&delta_partial_path);
+                            match &result {
+                                Ok(_) => break,
+                                Err(error) => eprintln!("Failed to fetch \"{}\" from \"{}\": {}", delta_filename, url, error),
+                            }
+                        }
+                        if result.is_ok() {
+                            break;
+                        }
+                    }
 
-                    // Ensure the checksum matches.
-                    let candidate_checksum = checksum!(&buffer);
-                    if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
+                    // If compression is enabled, fetch the compressed file next.
+                    if result.is_err() && $crate::compression::is_enabled() {
+                        let compressed_filename = $crate::compression::compressed_filename($filename);
+                        let compressed_partial_path = file_path.with_file_name(format!("{}.partial", compressed_filename));
+                        let compressed_sources = $crate::transport::remote_sources($remote_url, &compressed_filename, &$expected_checksum)?;
+                        for (url, timeout) in &compressed_sources {
+                            $crate::progress::report_stage($filename, $crate::progress::LoadStage::Downloading);
+                            // Note: The metadata does not list the size of the compressed file, so it is not resumed.
+                            result = Self::remote_fetch_verified(url, &compressed_partial_path, None, *timeout, |compressed| {
+                                // Ensure the checksum of the decompressed file matches.
+                                $crate::progress::report_stage($filename, $crate::progress::LoadStage::Verifying);
+                                let buffer = $crate::compression::decompress(&compressed, $expected_size)?;
+                                let candidate_checksum = checksum!(&buffer);
+                                match $expected_checksum == candidate_checksum {
+                                    true => Ok(buffer),
+                                    false => checksum_error!($expected_checksum.clone(), candidate_checksum),
+                                }
+                            });
+                            match &result {
+                                Ok(_) => break,
+                                Err(error) => eprintln!("Failed to fetch \"{}\" from \"{}\": {}", compressed_filename, url, error),
+                            }
+                        }
+                        // Store the compressed file, which is the verified partial download.
+                        if result.is_ok() {
+                            is_stored = std::fs::rename(&compressed_partial_path, $crate::compression::compressed_path(&file_path)).is_ok();
+                        }
                     }
 
-                    match Self::store_bytes(&buffer, &file_path) {
-                        Ok(()) => buffer,
-                        Err(_) => {
-                            eprintln!(
-                                "\n❗ Error - Failed to store \"{}\" locally. Please download this file manually and ensure it is stored in {:?}.\n",
-                                $filename, file_path
-                            );
-                            buffer
+                    // Otherwise, fetch the parameter file.
+                    for (url, timeout) in &sources {
+                        if result.is_ok() {
+                            break;
+                        }
+                        $crate::progress::report_stage($filename, $crate::progress::LoadStage::Downloading);
+                        result = Self::remote_fetch_verified(url, &partial_path, Some($expected_size), *timeout, |buffer| {
+                            // Ensure the checksum matches.
+                            $crate::progress::report_stage($filename, $crate::progress::LoadStage::Verifying);
+                            let candidate_checksum = checksum!(&buffer);
+                            match $expected_checksum == candidate_checksum {
+                                true => Ok(buffer),
+                                false => checksum_error!($expected_checksum.clone(), candidate_checksum),
+                            }
+                        });
+                        match &result {
+                            Ok(_) => break,
+                            Err(error) => eprintln!("Failed to fetch \"{}\" from \"{}\": {}", $filename, url, error),
                         }
                     }
-                } else if #[cfg(feature = "wasm")] {
-                    let buffer = Self::remote_fetch(&url)?;
+                    let buffer = result?;
+                    // Remove the partial download, as the checksum is verified.
+                    let _ = std::fs::remove_file(&partial_path);
 
-                    // Ensure the checksum matches.
-                    let candidate_checksum = checksum!(&buffer);
-                    if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
+                    $crate::progress::report_stage($filename, $crate::progress::LoadStage::Storing);
+                    match is_stored {
+                        true => buffer,
+                        false => match Self::store_bytes(&buffer, &file_path) {
+                            Ok(()) => buffer,
+                            Err(_) => {
+                                eprintln!(
+                                    "\n❗ Error - Failed to store \"{}\" locally. Please download this file manually and ensure it is stored in {:?}.\n",
+                                    $filename, file_path
+                                );
+                                buffer
+                            }
+                        },
+                    }
+                } else if #[cfg(feature = "wasm")] {
+                    // Fetch the file from the first URL that serves the expected content.
+                    let mut result = Err($crate::errors::ParameterError::RemoteFetchDisabled);
+                    for (url, _) in &sources {
+                        $crate::progress::report_stage($filename, $crate::progress::LoadStage::Downloading);
+                        result = Self::remote_fetch(url).and_then(|buffer| {
+                            // Ensure the checksum matches.
+                            $crate::progress::report_stage($filename, $crate::progress::LoadStage::Verifying);
+                            let candidate_checksum = checksum!(&buffer);
+                            match $expected_checksum == candidate_checksum {
+                                true => Ok(buffer),
+                                false => checksum_error!($expected_checksum.clone(), candidate_checksum),
+                            }
+                        });
+                        if result.is_ok() {
+                            break;
+                        }
                     }
 
-                    buffer
+                    result?
                 } else {
                     return Err($crate::errors::ParameterError::RemoteFetchDisabled);
                 }
@@ -241,10 +470,126 @@ macro_rules! impl_load_bytes_logic_remote {
             return checksum_error!($expected_checksum, candidate_checksum)
         }
 
+        $crate::progress::report_stage($filename, $crate::progress::LoadStage::Complete);
         return Ok(buffer)
     }
 }
 
+#[cfg(all(feature = "async", not(feature = "wasm")))]
+macro_rules! impl_load_bytes_logic_remote_async {
+    ($remote_url: expr, $local_dir: expr, $filename: expr, $expected_checksum: expr, $expected_size: expr) => {
+        // Compose the correct file path for the parameter file.
+        let file_path = $crate::store::parameter_store().resolve($local_dir, $filename);
+
+        // Compose the file path for the compressed parameter file, which is loaded if it is stored instead.
+        let compressed_path = $crate::compression::compressed_path(&file_path);
+
+        let buffer = match tokio::fs::try_exists(&file_path).await? {
+            // Attempts to load the parameter file locally with an absolute path.
+            true => {
+                let buffer = tokio::fs::read(&file_path).await?;
+                $crate::progress::report_stage($filename, $crate::progress::LoadStage::Verifying);
+                buffer
+            }
+            // Attempts to load and decompress the compressed parameter file locally.
+            false if cfg!(feature = "zstd") && tokio::fs::try_exists(&compressed_path).await? => {
+                let compressed = tokio::fs::read(&compressed_path).await?;
+                $crate::progress::report_stage($filename, $crate::progress::LoadStage::Verifying);
+                match $crate::compression::decompress(&compressed, $expected_size) {
+                    Ok(buffer) => buffer,
+                    Err(error) => {
+                        // Remove the corrupt compressed file, so the parameter file is downloaded next time.
+                        let _ = tokio::fs::remove_file(&compressed_path).await;
+                        return Err(error);
+                    }
+                }
+            }
+            // Downloads the missing parameters and stores it in the local directory for use.
+            false => {
+                // Construct the candidate URLs, using the checksum as the content address.
+                let sources = $crate::transport::remote_sources($remote_url, $filename, &$expected_checksum)?;
+
+                // Fetch the file from the first URL that serves the expected content.
+                let mut result = Err($crate::errors::ParameterError::RemoteFetchDisabled);
+                for (url, timeout) in &sources {
+                    $crate::progress::report_stage($filename, $crate::progress::LoadStage::Downloading);
+                    result = $crate::transport::remote_fetch_async(url, $filename, *timeout).await.and_then(|buffer| {
+                        // Ensure the checksum matches.
+                        $crate::progress::report_stage($filename, $crate::progress::LoadStage::Verifying);
+                        let candidate_checksum = checksum!(&buffer);
+                        match $expected_checksum == candidate_checksum {
+                            true => Ok(buffer),
+                            false => checksum_error!($expected_checksum.clone(), candidate_checksum),
+                        }
+                    });
+                    match &result {
+                        Ok(_) => break,
+                        Err(error) => eprintln!("Failed to fetch \"{}\" from \"{}\": {}", $filename, url, error),
+                    }
+                }
+                let buffer = result?;
+
+                // Store the file, so it is loaded locally next time.
+                $crate::progress::report_stage($filename, $crate::progress::LoadStage::Storing);
+                if let Some(directory_path) = file_path.parent() {
+                    let _ = tokio::fs::create_dir_all(directory_path).await;
+                }
+                if let Err(error) = tokio::fs::write(&file_path, &buffer).await {
+                    eprintln!("\n❗ Error - Failed to store \"{}\" in {:?}: {error}\n", $filename, file_path);
+                }
+                buffer
+            }
+        };
+
+        // Ensure the size matches.
+        if $expected_size != buffer.len() {
+            let _ = tokio::fs::remove_file(&file_path).await;
+            return Err($crate::errors::ParameterError::SizeMismatch($expected_size, buffer.len()));
+        }
+
+        // Ensure the checksum matches.
+        let candidate_checksum = checksum!(buffer.as_slice());
+        if $expected_checksum != candidate_checksum {
+            return checksum_error!($expected_checksum, candidate_checksum);
+        }
+
+        $crate::progress::report_stage($filename, $crate::progress::LoadStage::Complete);
+        return Ok(buffer);
+    };
+}
+
+#[cfg(not(feature = "wasm"))]
+macro_rules! impl_load_bytes_logic_directory {
+    ($directory: expr, $filename: expr, $expected_checksum: expr, $expected_size: expr) => {
+        // Compose the file path for the parameter file.
+        let file_path = $directory.join($filename);
+        // Note: The file is never removed, as the directory is managed by the caller.
+        let compressed = match file_path.exists() {
+            true => None,
+            false => $crate::compression::load_compressed(&file_path, $expected_size)?,
+        };
+        let buffer = match compressed {
+            Some(buffer) => buffer,
+            None => std::fs::read(&file_path).map_err(|error| {
+                $crate::errors::ParameterError::Message(format!("Failed to read {:?}: {error}", file_path))
+            })?,
+        };
+
+        // Ensure the size matches.
+        if $expected_size != buffer.len() {
+            return Err($crate::errors::ParameterError::SizeMismatch($expected_size, buffer.len()));
+        }
+
+        // Ensure the checksum matches.
+        let candidate_checksum = checksum!(buffer.as_slice());
+        if $expected_checksum != candidate_checksum {
+            return checksum_error!($expected_checksum, candidate_checksum);
+        }
+
+        return Ok(buffer);
+    };
+}
+
 #[macro_export]
 macro_rules! impl_local {
     ($name: ident, $local_dir: expr, $fname: tt, "usrs") => {
@@ -342,6 +687,79 @@ macro_rules! impl_remote {
                     expected_size
                 );
             }
+
+            /// Maps the parameter file into memory, downloading it first if it is missing.
+            #[cfg(all(feature = "mmap", not(feature = "wasm")))]
+            pub fn load_mmap() -> Result<$crate::mmap::MappedParameters, $crate::errors::ParameterError> {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+
+                // Compose the file path for the parameter file.
+                let file_path = $crate::store::parameter_store().resolve($local_dir, &Self::filename());
+
+                // Download the parameter file, if it is missing.
+                if !file_path.exists() {
+                    let buffer = Self::load_bytes()?;
+                    // Store the parameter file, if only the compressed file is stored, as it cannot be mapped.
+                    if !file_path.exists() {
+                        Self::store_bytes(&buffer, &file_path)?;
+                    }
+                }
+                $crate::mmap::MappedParameters::open(&file_path, expected_size, &expected_checksum)
+            }
+
+            /// Loads the parameter file without blocking the async runtime, downloading it if it is missing.
+            #[cfg(all(feature = "async", not(feature = "wasm")))]
+            pub async fn load_bytes_async() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+
+                impl_load_bytes_logic_remote_async!(
+                    $remote_url,
+                    $local_dir,
+                    &Self::filename(),
+                    expected_checksum,
+                    expected_size
+                );
+            }
+
+            /// Returns the versioned filename of the parameter file.
+            pub fn filename() -> String {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
+
+                // Construct the versioned filename.
+                match expected_checksum.get(0..7) {
+                    Some(sum) => format!("{}.{}.{}", $fname, "usrs", sum),
+                    _ => format!("{}.{}", $fname, "usrs"),
+                }
+            }
+
+            /// Loads the parameter file from the given directory, without accessing the network.
+            /// The file must have the versioned filename, and match the size and checksum in the metadata.
+            #[cfg(not(feature = "wasm"))]
+            pub fn load_bytes_from_directory(
+                directory: &std::path::Path,
+            ) -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+
+                impl_load_bytes_logic_directory!(directory, &Self::filename(), expected_checksum, expected_size);
+            }
         }
         paste::item! {
             #[cfg(test)]
@@ -382,6 +800,79 @@ macro_rules! impl_remote {
                     expected_size
                 );
             }
+
+            /// Maps the parameter file into memory, downloading it first if it is missing.
+            #[cfg(all(feature = "mmap", not(feature = "wasm")))]
+            pub fn load_mmap() -> Result<$crate::mmap::MappedParameters, $crate::errors::ParameterError> {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
+
+                // Compose the file path for the parameter file.
+                let file_path = $crate::store::parameter_store().resolve($local_dir, &Self::filename());
+
+                // Download the parameter file, if it is missing.
+                if !file_path.exists() {
+                    let buffer = Self::load_bytes()?;
+                    // Store the parameter file, if only the compressed file is stored, as it cannot be mapped.
+                    if !file_path.exists() {
+                        Self::store_bytes(&buffer, &file_path)?;
+                    }
+                }
+                $crate::mmap::MappedParameters::open(&file_path, expected_size, &expected_checksum)
+            }
+
+            /// Loads the parameter file without blocking the async runtime, downloading it if it is missing.
+            #[cfg(all(feature = "async", not(feature = "wasm")))]
+            pub async fn load_bytes_async() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
+
+                impl_load_bytes_logic_remote_async!(
+                    $remote_url,
+                    $local_dir,
+                    &Self::filename(),
+                    expected_checksum,
+                    expected_size
+                );
+            }
+
+            /// Returns the versioned filename of the parameter file.
+            pub fn filename() -> String {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
+
+                // Construct the versioned filename.
+                match expected_checksum.get(0..7) {
+                    Some(sum) => format!("{}.{}.{}", $fname, $ftype, sum),
+                    _ => format!("{}.{}", $fname, $ftype),
+                }
+            }
+
+            /// Loads the parameter file from the given directory, without accessing the network.
+            /// The file must have the versioned filename, and match the size and checksum in the metadata.
+            #[cfg(not(feature = "wasm"))]
+            pub fn load_bytes_from_directory(
+                directory: &std::path::Path,
+            ) -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
+
+                impl_load_bytes_logic_directory!(directory, &Self::filename(), expected_checksum, expected_size);
+            }
         }
 
         paste::item! {
@@ -393,3 +884,177 @@ macro_rules! impl_remote {
         }
     };
 }
+
+#[cfg(all(test, not(feature = "wasm")))]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    struct Fetcher;
+
+    #[allow(dead_code)]
+    impl Fetcher {
+        impl_store_and_remote_fetch!();
+    }
+
+    /// Serves the given file for each of the given requests, sending at most `limit` bytes of the body.
+    /// If `supports_range` is `true`, range requests are answered with the requested bytes.
+    fn serve(file: Vec<u8>, requests: Vec<(Option<usize>, bool)>) -> (String, thread::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/file", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            for (limit, supports_range) in requests {
+                let (mut stream, _) = listener.accept().unwrap();
+                // Read the request headers, and retrieve the range start.
+                let mut start = 0;
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(range) = line.to_lowercase().strip_prefix("range: bytes=") {
+                        start = range.trim().trim_end_matches('-').parse().unwrap();
+                    }
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                }
+                // Send the response.
+                let (status, body) = match supports_range && start > 0 {
+                    true => ("206 Partial Content", &file[start..]),
+                    false => ("200 OK", &file[..]),
+                };
+                let header =
+                    format!("HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", body.len());
+                stream.write_all(header.as_bytes()).unwrap();
+                let _ = stream.write_all(&body[..limit.unwrap_or(body.len()).min(body.len())]);
+            }
+        });
+        (url, handle)
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_remote_fetch_async() {
+        let file = (0..100_000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let (url, handle) = serve(file.clone(), vec![(None, false)]);
+
+        // Initialize a single-threaded runtime, so the download must not block it.
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        let buffer = runtime.block_on(crate::transport::remote_fetch_async(&url, "file", None)).unwrap();
+        handle.join().unwrap();
+        assert_eq!(buffer, file);
+    }
+
+    fn check_resume(supports_range: bool) {
+        let file = (0..100_000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let partial_path = std::env::temp_dir().join(format!("resume-{supports_range}-{}.partial", std::process::id()));
+        let _ = std::fs::remove_file(&partial_path);
+
+        // Interrupt the first download, and complete the second one.
+        let (url, handle) = serve(file.clone(), vec![(Some(40_000), supports_range), (None, supports_range)]);
+        let mut buffer = vec![];
+        assert!(Fetcher::remote_fetch(&mut buffer, &url, &partial_path, Some(file.len()), None).is_err());
+        assert_eq!(std::fs::read(&partial_path).unwrap(), file[..40_000]);
+        Fetcher::remote_fetch(&mut buffer, &url, &partial_path, Some(file.len()), None).unwrap();
+        handle.join().unwrap();
+        assert_eq!(buffer, file);
+        assert_eq!(std::fs::read(&partial_path).unwrap(), file);
+
+        // Ensure a complete partial download is returned without a request.
+        let mut buffer = vec![];
+        Fetcher::remote_fetch(&mut buffer, "http://127.0.0.1:0/file", &partial_path, Some(file.len()), None).unwrap();
+        assert_eq!(buffer, file);
+        std::fs::remove_file(&partial_path).unwrap();
+    }
+
+    #[test]
+    fn test_progress_reporter() {
+        use crate::progress::{clear_progress_reporter, set_progress_reporter, ProgressReporter};
+        use parking_lot::Mutex;
+        use std::sync::Arc;
+
+        /// A reporter that records the download progress of a single file.
+        struct Recorder(String, Mutex<Vec<(u64, u64)>>);
+
+        impl ProgressReporter for Recorder {
+            fn on_download(&self, filename: &str, downloaded_bytes: u64, total_bytes: u64) {
+                if filename == self.0 {
+                    self.1.lock().push((downloaded_bytes, total_bytes));
+                }
+            }
+        }
+
+        let file = (0..100_000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let filename = format!("progress-{}", std::process::id());
+        let partial_path = std::env::temp_dir().join(format!("{filename}.partial"));
+        let _ = std::fs::remove_file(&partial_path);
+
+        // Download the file, with a registered reporter.
+        let recorder = Arc::new(Recorder(filename, Default::default()));
+        set_progress_reporter(recorder.clone());
+        let (url, handle) = serve(file.clone(), vec![(None, false)]);
+        let mut buffer = vec![];
+        Fetcher::remote_fetch(&mut buffer, &url, &partial_path, Some(file.len()), None).unwrap();
+        handle.join().unwrap();
+        clear_progress_reporter();
+        std::fs::remove_file(&partial_path).unwrap();
+
+        // Ensure the progress is monotonic, and ends with the complete file.
+        let progress = recorder.1.lock().clone();
+        assert!(progress.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert_eq!(progress.last(), Some(&(file.len() as u64, file.len() as u64)));
+    }
+
+    #[test]
+    fn test_remote_fetch_timeout() {
+        let partial_path = std::env::temp_dir().join(format!("timeout-{}.partial", std::process::id()));
+        let _ = std::fs::remove_file(&partial_path);
+
+        // Initialize a server that never responds.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/file", listener.local_addr().unwrap());
+
+        // Ensure the download fails once the timeout elapses.
+        let timeout = std::time::Duration::from_millis(200);
+        let mut buffer = vec![];
+        assert!(Fetcher::remote_fetch(&mut buffer, &url, &partial_path, Some(1), Some(timeout)).is_err());
+        let _ = std::fs::remove_file(&partial_path);
+    }
+
+    #[test]
+    fn test_remote_fetch_verified() {
+        let file = (0..100_000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let partial_path = std::env::temp_dir().join(format!("verified-{}.partial", std::process::id()));
+
+        // Ensure a complete partial download that fails verification is removed.
+        std::fs::write(&partial_path, &file).unwrap();
+        let url = "http://127.0.0.1:0/file";
+        let result: Result<Vec<u8>, _> =
+            Fetcher::remote_fetch_verified(url, &partial_path, Some(file.len()), None, |_| {
+                checksum_error!("expected".to_string(), "candidate".to_string())
+            });
+        assert!(result.is_err());
+        assert!(!partial_path.exists());
+
+        // Ensure a partial download of unknown size is discarded, and the file is downloaded again.
+        std::fs::write(&partial_path, &file[..40_000]).unwrap();
+        let (url, handle) = serve(file.clone(), vec![(None, true)]);
+        let buffer = Fetcher::remote_fetch_verified(&url, &partial_path, None, None, Ok).unwrap();
+        handle.join().unwrap();
+        assert_eq!(buffer, file);
+        std::fs::remove_file(&partial_path).unwrap();
+    }
+
+    #[test]
+    fn test_remote_fetch_resumes_with_range() {
+        check_resume(true);
+    }
+
+    #[test]
+    fn test_remote_fetch_restarts_without_range() {
+        check_resume(false);
+    }
+}
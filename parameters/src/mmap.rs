@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Memory-mapped parameter files.
+//!
+//! Loading a proving key with `load_bytes` reads the whole file onto the heap before it is deserialized,
+//! which doubles the peak memory during startup. A [`MappedParameters`] maps the file into memory instead,
+//! so the key is deserialized straight from the page cache, which is shared by every process that maps it.
+
+use crate::errors::ParameterError;
+
+use std::{fs::File, ops::Deref, path::Path};
+
+/// A parameter file mapped into memory, read-only.
+pub struct MappedParameters {
+    /// The memory map of the file.
+    mmap: memmap2::Mmap,
+}
+
+impl MappedParameters {
+    /// Maps the given parameter file into memory, ensuring its size and checksum match.
+    pub fn open(path: &Path, expected_size: usize, expected_checksum: &str) -> Result<Self, ParameterError> {
+        let file = File::open(path)?;
+        // Ensure the size matches, before the file is mapped.
+        let size = file.metadata()?.len() as usize;
+        if size != expected_size {
+            return Err(ParameterError::SizeMismatch(expected_size, size));
+        }
+
+        // SAFETY: The file is mapped read-only. Parameter files are write-once: they are only created by
+        // downloading them in full, and are never modified in place. The checksum below ensures the content
+        // is the expected one at the time it is mapped.
+        #[allow(unsafe_code)]
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+
+        // Ensure the checksum matches.
+        let candidate_checksum = checksum!(&mmap[..]);
+        if expected_checksum != candidate_checksum {
+            return checksum_error!(expected_checksum.to_string(), candidate_checksum);
+        }
+        Ok(Self { mmap })
+    }
+
+    /// Returns the bytes of the parameter file.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    /// Returns a zero-copy reader over the bytes of the parameter file.
+    pub fn reader(&self) -> &[u8] {
+        &self.mmap
+    }
+}
+
+impl Deref for MappedParameters {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.mmap
+    }
+}
+
+impl AsRef<[u8]> for MappedParameters {
+    fn as_ref(&self) -> &[u8] {
+        &self.mmap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn test_open() {
+        let bytes = (0..10_000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let checksum = checksum!(&bytes);
+        let path = std::env::temp_dir().join(format!("mmap-{}.prover", std::process::id()));
+        std::fs::write(&path, &bytes).unwrap();
+
+        // Ensure the mapped file reads as the original bytes.
+        let mapped = MappedParameters::open(&path, bytes.len(), &checksum).unwrap();
+        assert_eq!(mapped.as_bytes(), &bytes[..]);
+        let mut reader = mapped.reader();
+        let mut prefix = [0u8; 4];
+        reader.read_exact(&mut prefix).unwrap();
+        assert_eq!(prefix, bytes[..4]);
+
+        // Ensure mismatching sizes and checksums are rejected.
+        assert!(matches!(
+            MappedParameters::open(&path, bytes.len() + 1, &checksum),
+            Err(ParameterError::SizeMismatch(..))
+        ));
+        assert!(matches!(
+            MappedParameters::open(&path, bytes.len(), &checksum!(b"")),
+            Err(ParameterError::ChecksumMismatch(..))
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Progress reporting for loading parameter files.
+//!
+//! Applications may register a [`ProgressReporter`] to render the progress of loading parameter files,
+//! such as the download of the proving keys, which can take a while on slower connections.
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+lazy_static! {
+    /// The registered progress reporter.
+    static ref PROGRESS_REPORTER: RwLock<Option<Arc<dyn ProgressReporter>>> = RwLock::new(None);
+}
+
+/// A stage in loading a parameter file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LoadStage {
+    /// The parameter file is being downloaded.
+    Downloading,
+    /// The checksum of the parameter file is being verified.
+    Verifying,
+    /// The parameter file is being stored in the local directory.
+    Storing,
+    /// The parameter file is loaded.
+    Complete,
+}
+
+/// A hook to observe the progress of loading parameter files.
+pub trait ProgressReporter: Send + Sync {
+    /// Reports the number of bytes downloaded so far, out of the total number of bytes of the parameter file.
+    fn on_download(&self, _filename: &str, _downloaded_bytes: u64, _total_bytes: u64) {}
+
+    /// Reports that loading the parameter file entered the given stage.
+    fn on_stage(&self, _filename: &str, _stage: LoadStage) {}
+}
+
+/// Registers the progress reporter, replacing any previously-registered reporter.
+pub fn set_progress_reporter(reporter: Arc<dyn ProgressReporter>) {
+    *PROGRESS_REPORTER.write() = Some(reporter);
+}
+
+/// Removes the registered progress reporter.
+pub fn clear_progress_reporter() {
+    *PROGRESS_REPORTER.write() = None;
+}
+
+/// Reports the download progress of the given parameter file to the registered reporter, if any.
+pub fn report_download(filename: &str, downloaded_bytes: u64, total_bytes: u64) {
+    if let Some(reporter) = PROGRESS_REPORTER.read().as_ref() {
+        reporter.on_download(filename, downloaded_bytes, total_bytes);
+    }
+}
+
+/// Reports the stage of loading the given parameter file to the registered reporter, if any.
+pub fn report_stage(filename: &str, stage: LoadStage) {
+    if let Some(reporter) = PROGRESS_REPORTER.read().as_ref() {
+        reporter.on_stage(filename, stage);
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The on-disk store of the downloaded parameter files.
+//!
+//! By default, parameter files are stored in the platform's cache directory:
+//! - on Linux, `$XDG_CACHE_HOME/snarkvm` or `~/.cache/snarkvm`,
+//! - on macOS, `~/Library/Caches/snarkvm`,
+//! - on Windows, `%LOCALAPPDATA%\snarkvm`.
+//!
+//! The root directory may be overridden with the `SNARKVM_PARAMETER_DIR` environment variable,
+//! or by registering a [`ParameterStore`] with [`set_parameter_store`].
+//! Parameter files found in the legacy `~/.aleo` directory are copied to the store on first use, and left
+//! in place for older binaries that share it. A marker file in the store ensures the copy is only made once.
+
+use crate::errors::ParameterError;
+
+use parking_lot::RwLock;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that overrides the root directory of the parameter store.
+pub const PARAMETER_DIR_ENV_VAR: &str = "SNARKVM_PARAMETER_DIR";
+
+/// The name of the application directory, within the platform's cache directory.
+const APPLICATION_DIR: &str = "snarkvm";
+/// The name of the marker file, in a directory of the store, once its legacy files have been copied.
+const MIGRATION_MARKER: &str = ".legacy-migrated";
+
+lazy_static! {
+    /// The registered parameter store.
+    static ref PARAMETER_STORE: RwLock<ParameterStore> = RwLock::new(ParameterStore::default());
+}
+
+/// The location of the downloaded parameter files on disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterStore {
+    /// The root directory of the store.
+    root: PathBuf,
+    /// The root directory of the legacy store.
+    legacy_root: PathBuf,
+    /// Whether parameter files in the legacy directory are copied into the store.
+    migrate_legacy: bool,
+    /// Whether downloaded parameter files are fetched and stored compressed.
+    compression: bool,
+}
+
+impl Default for ParameterStore {
+    /// Initializes the store at the directory in `SNARKVM_PARAMETER_DIR`, or else at the platform's cache directory.
+    fn default() -> Self {
+        let root = match std::env::var_os(PARAMETER_DIR_ENV_VAR) {
+            Some(root) if !root.is_empty() => PathBuf::from(root),
+            _ => Self::platform_root(),
+        };
+        Self { root, legacy_root: aleo_std::aleo_dir(), migrate_legacy: true, compression: false }
+    }
+}
+
+impl ParameterStore {
+    /// Returns the store with the given root directory.
+    pub fn with_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = root.into();
+        self
+    }
+
+    /// Returns the store with the given legacy root directory, in place of `~/.aleo`.
+    pub fn with_legacy_root(mut self, legacy_root: impl Into<PathBuf>) -> Self {
+        self.legacy_root = legacy_root.into();
+        self
+    }
+
+    /// Returns the store, with the migration from the legacy directory enabled or disabled.
+    pub fn with_legacy_migration(mut self, migrate_legacy: bool) -> Self {
+        self.migrate_legacy = migrate_legacy;
+        self
+    }
+
+    /// Returns the store, with the compression of downloaded parameter files enabled or disabled.
+    ///
+    /// When enabled, the compressed `<filename>.zst` files are fetched in preference to the uncompressed files,
+    /// and stored compressed. This requires the `zstd` feature, and has no effect otherwise.
+    pub fn with_compression(mut self, compression: bool) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Returns `true` if downloaded parameter files are fetched and stored compressed.
+    pub fn compression(&self) -> bool {
+        self.compression
+    }
+
+    /// Returns the root directory of the store.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the path of the given parameter file in the store.
+    pub fn path(&self, local_dir: &str, filename: &str) -> PathBuf {
+        self.root.join(local_dir).join(filename)
+    }
+
+    /// Returns the path of the given parameter file in the legacy directory.
+    pub fn legacy_path(&self, local_dir: &str, filename: &str) -> PathBuf {
+        self.legacy_root.join(local_dir).join(filename)
+    }
+
+    /// Returns the path of the given parameter file in the store,
+    /// first copying the legacy parameter files into the store, if they have not been copied yet.
+    pub fn resolve(&self, local_dir: &str, filename: &str) -> PathBuf {
+        if self.migrate_legacy {
+            if let Err(error) = self.migrate(local_dir) {
+                eprintln!("Failed to copy the legacy parameter files in \"{local_dir}\": {error}");
+            }
+        }
+        self.path(local_dir, filename)
+    }
+
+    /// Copies the parameter files in the given directory of the legacy store into the store, once.
+    /// The legacy files are left in place, and files already in the store are not replaced.
+    /// Returns the number of files copied, which is zero once the marker file exists.
+    pub fn migrate(&self, local_dir: &str) -> Result<usize, ParameterError> {
+        let (legacy_directory, directory) = (self.legacy_root.join(local_dir), self.root.join(local_dir));
+        let marker = directory.join(MIGRATION_MARKER);
+        if legacy_directory == directory || marker.exists() {
+            return Ok(0);
+        }
+        std::fs::create_dir_all(&directory)?;
+
+        let mut num_copied = 0;
+        if legacy_directory.is_dir() {
+            for entry in std::fs::read_dir(&legacy_directory)? {
+                let legacy_path = entry?.path();
+                // Skip directories and the partial downloads.
+                if !legacy_path.is_file() || legacy_path.extension().is_some_and(|extension| extension == "partial") {
+                    continue;
+                }
+                let Some(filename) = legacy_path.file_name() else { continue };
+                let path = directory.join(filename);
+                if path.exists() {
+                    continue;
+                }
+                // Link the file, copying it if the store is on a different file system.
+                // Note: The copy is written to a temporary file first, so an interrupted copy is never loaded.
+                if std::fs::hard_link(&legacy_path, &path).is_err() {
+                    let temporary_path = path.with_extension("migrating");
+                    std::fs::copy(&legacy_path, &temporary_path)?;
+                    std::fs::rename(&temporary_path, &path)?;
+                }
+                num_copied += 1;
+            }
+        }
+        // Write the marker, so the legacy files are only copied once.
+        std::fs::write(&marker, [])?;
+        Ok(num_copied)
+    }
+
+    /// Returns the platform's cache directory for the store.
+    fn platform_root() -> PathBuf {
+        let cache_dir = if cfg!(target_os = "windows") {
+            std::env::var_os("LOCALAPPDATA").map(PathBuf::from)
+        } else if cfg!(target_os = "macos") {
+            std::env::var_os("HOME").map(|home| PathBuf::from(home).join("Library").join("Caches"))
+        } else {
+            match std::env::var_os("XDG_CACHE_HOME") {
+                Some(cache_home) if Path::new(&cache_home).is_absolute() => Some(PathBuf::from(cache_home)),
+                _ => std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")),
+            }
+        };
+        match cache_dir {
+            Some(cache_dir) => cache_dir.join(APPLICATION_DIR),
+            // If the cache directory is unknown, fall back to the legacy directory.
+            None => aleo_std::aleo_dir(),
+        }
+    }
+}
+
+/// Registers the parameter store, replacing the store from the environment.
+pub fn set_parameter_store(store: ParameterStore) {
+    *PARAMETER_STORE.write() = store;
+}
+
+/// Returns the registered parameter store.
+pub fn parameter_store() -> ParameterStore {
+    PARAMETER_STORE.read().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let store = ParameterStore::default().with_root("/tmp/snarkvm").with_legacy_migration(false);
+        assert_eq!(store.root(), Path::new("/tmp/snarkvm"));
+        assert_eq!(store.path("resources/", "transfer_public.prover.a1b2c3d"), {
+            Path::new("/tmp/snarkvm/resources/transfer_public.prover.a1b2c3d").to_path_buf()
+        });
+        // Ensure resolving the path does not migrate the file, when disabled.
+        assert_eq!(store.resolve("resources/", "missing.prover"), store.path("resources/", "missing.prover"));
+    }
+
+    #[test]
+    fn test_migrate() {
+        let directory = std::env::temp_dir().join(format!("snarkvm-store-{}", std::process::id()));
+        let (root, legacy_root) = (directory.join("store"), directory.join("legacy"));
+        let store = ParameterStore::default().with_root(&root).with_legacy_root(legacy_root);
+        // Place the files in the legacy directory.
+        let legacy_path = store.legacy_path("resources/", "test.prover");
+        std::fs::create_dir_all(legacy_path.parent().unwrap()).unwrap();
+        std::fs::write(&legacy_path, b"parameters").unwrap();
+        std::fs::write(store.legacy_path("resources/", "test.prover.partial"), b"param").unwrap();
+
+        // Ensure the file is copied into the store, and left in the legacy directory.
+        let path = store.resolve("resources/", "test.prover");
+        assert_eq!(path, root.join("resources/").join("test.prover"));
+        assert_eq!(std::fs::read(&path).unwrap(), b"parameters");
+        assert_eq!(std::fs::read(&legacy_path).unwrap(), b"parameters");
+        // Ensure the partial download is not copied.
+        assert!(!store.path("resources/", "test.prover.partial").exists());
+
+        // Ensure the files are only copied once, even if a legacy file is added later.
+        std::fs::write(store.legacy_path("resources/", "other.prover"), b"parameters").unwrap();
+        assert_eq!(store.migrate("resources/").unwrap(), 0);
+        assert!(!store.path("resources/", "other.prover").exists());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
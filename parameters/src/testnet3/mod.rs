@@ -18,6 +18,11 @@ pub use genesis::*;
 pub mod powers;
 pub use powers::*;
 
+#[cfg(not(feature = "wasm"))]
+mod offline;
+#[cfg(not(feature = "wasm"))]
+pub use offline::*;
+
 const REMOTE_URL: &str = "https://s3-us-west-1.amazonaws.com/testnet3.parameters";
 
 // Degrees
@@ -132,6 +137,27 @@ macro_rules! insert_credit_keys {
     }};
 }
 
+#[macro_export]
+macro_rules! insert_credit_key_loaders {
+    ($map:ident, $variant:ident) => {{
+        paste::paste! {
+            $map.insert("bond_public".to_string(), $crate::testnet3::[<BondPublic $variant>]::load_bytes);
+            $map.insert("unbond_public".to_string(), $crate::testnet3::[<UnbondPublic $variant>]::load_bytes);
+            $map.insert("unbond_delegator_as_validator".to_string(), $crate::testnet3::[<UnbondDelegatorAsValidator $variant>]::load_bytes);
+            $map.insert("claim_unbond_public".to_string(), $crate::testnet3::[<ClaimUnbondPublic $variant>]::load_bytes);
+            $map.insert("set_validator_state".to_string(), $crate::testnet3::[<SetValidatorState $variant>]::load_bytes);
+            $map.insert("transfer_private".to_string(), $crate::testnet3::[<TransferPrivate $variant>]::load_bytes);
+            $map.insert("transfer_public".to_string(), $crate::testnet3::[<TransferPublic $variant>]::load_bytes);
+            $map.insert("transfer_private_to_public".to_string(), $crate::testnet3::[<TransferPrivateToPublic $variant>]::load_bytes);
+            $map.insert("transfer_public_to_private".to_string(), $crate::testnet3::[<TransferPublicToPrivate $variant>]::load_bytes);
+            $map.insert("join".to_string(), $crate::testnet3::[<Join $variant>]::load_bytes);
+            $map.insert("split".to_string(), $crate::testnet3::[<Split $variant>]::load_bytes);
+            $map.insert("fee_private".to_string(), $crate::testnet3::[<FeePrivate $variant>]::load_bytes);
+            $map.insert("fee_public".to_string(), $crate::testnet3::[<FeePublic $variant>]::load_bytes);
+        }
+    }};
+}
+
 #[macro_export]
 macro_rules! insert_key {
     ($map:ident, $string:tt, $type:ident<$network:ident>, ($name:tt, $circuit_key:expr)) => {{
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::errors::ParameterError;
+
+use indexmap::IndexMap;
+use std::path::Path;
+
+/// A parameter file that is fetched remotely, given by its name, versioned filename, and directory loader.
+type RemoteKey = (&'static str, fn() -> String, fn(&Path) -> Result<Vec<u8>, ParameterError>);
+
+macro_rules! remote_key {
+    ($name:expr, $type:ident) => {
+        ($name, $type::filename, $type::load_bytes_from_directory)
+    };
+}
+
+/// The proving keys that are fetched remotely.
+const REMOTE_PROVING_KEYS: [RemoteKey; 14] = [
+    remote_key!("bond_public", BondPublicProver),
+    remote_key!("unbond_public", UnbondPublicProver),
+    remote_key!("unbond_delegator_as_validator", UnbondDelegatorAsValidatorProver),
+    remote_key!("claim_unbond_public", ClaimUnbondPublicProver),
+    remote_key!("set_validator_state", SetValidatorStateProver),
+    remote_key!("transfer_private", TransferPrivateProver),
+    remote_key!("transfer_public", TransferPublicProver),
+    remote_key!("transfer_private_to_public", TransferPrivateToPublicProver),
+    remote_key!("transfer_public_to_private", TransferPublicToPrivateProver),
+    remote_key!("join", JoinProver),
+    remote_key!("split", SplitProver),
+    remote_key!("fee_private", FeePrivateProver),
+    remote_key!("fee_public", FeePublicProver),
+    remote_key!(TESTNET3_INCLUSION_FUNCTION_NAME, InclusionProver),
+];
+
+/// The proving keys, loaded from a local directory without accessing the network.
+///
+/// The verifying keys are embedded in the binary, so only the proving keys are loaded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameters {
+    /// The proving key bytes, keyed by function name.
+    proving_keys: IndexMap<&'static str, Vec<u8>>,
+}
+
+impl Parameters {
+    /// Loads every proving key from the given directory, validating each against its embedded metadata.
+    /// Each file must have its versioned filename, as listed by [`Parameters::filenames`], or be stored compressed
+    /// with a `.zst` extension, which requires the `zstd` feature.
+    /// If any file is missing or invalid, the error lists every such file.
+    pub fn from_directory(directory: impl AsRef<Path>) -> Result<Self, ParameterError> {
+        let directory = directory.as_ref();
+
+        let mut proving_keys = IndexMap::with_capacity(REMOTE_PROVING_KEYS.len());
+        let mut errors = Vec::new();
+        for (name, filename, load_bytes_from_directory) in REMOTE_PROVING_KEYS {
+            match load_bytes_from_directory(directory) {
+                Ok(bytes) => {
+                    proving_keys.insert(name, bytes);
+                }
+                Err(error) => errors.push(format!("  - {} ({name}): {error}", filename())),
+            }
+        }
+        // Ensure every proving key was loaded.
+        if !errors.is_empty() {
+            return Err(ParameterError::InvalidDirectory(format!("{directory:?}"), errors));
+        }
+        Ok(Self { proving_keys })
+    }
+
+    /// Returns the versioned filenames that [`Parameters::from_directory`] expects.
+    pub fn filenames() -> Vec<String> {
+        REMOTE_PROVING_KEYS.iter().map(|(_, filename, _)| filename()).collect()
+    }
+
+    /// Returns the proving key bytes for the given function name, if it exists.
+    pub fn proving_key(&self, name: &str) -> Option<&[u8]> {
+        self.proving_keys.get(name).map(Vec::as_slice)
+    }
+
+    /// Returns an iterator over the function names and proving key bytes.
+    pub fn iter(&self) -> impl '_ + Iterator<Item = (&'static str, &[u8])> {
+        self.proving_keys.iter().map(|(name, bytes)| (*name, bytes.as_slice()))
+    }
+
+    /// Stores the proving keys in the local parameter directory, so that loading them never accesses the network.
+    pub fn install(&self) -> Result<(), ParameterError> {
+        // Compose the local parameter directory.
+        let directory = crate::store::parameter_store().path("resources/", "");
+        std::fs::create_dir_all(&directory)?;
+
+        for (name, filename, _) in REMOTE_PROVING_KEYS {
+            if let Some(bytes) = self.proving_keys.get(name) {
+                std::fs::write(directory.join(filename()), bytes)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_directory_lists_invalid_files() {
+        let directory = std::env::temp_dir().join(format!("snarkvm-parameters-offline-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        // Write an invalid proving key, and leave the remaining proving keys missing.
+        let filenames = Parameters::filenames();
+        assert_eq!(filenames.len(), REMOTE_PROVING_KEYS.len());
+        std::fs::write(directory.join(&filenames[0]), b"invalid").unwrap();
+
+        let error = Parameters::from_directory(&directory).unwrap_err();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        // Ensure the error lists every missing or invalid file.
+        match &error {
+            ParameterError::InvalidDirectory(_, errors) => assert_eq!(errors.len(), REMOTE_PROVING_KEYS.len()),
+            error => panic!("Unexpected error: {error}"),
+        }
+        let message = error.to_string();
+        for filename in &filenames {
+            assert!(message.contains(filename.as_str()), "The error does not list {filename}");
+        }
+        assert!(message.contains("Expected size of"));
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An append-only audit transcript for parameter setups.
+//!
+//! Each entry records one step of a setup - its inputs, degrees, or outputs - along with the
+//! wall-clock time it was recorded at. The transcript digest is a SHA-256 hash chain over the
+//! entries, so the final digest commits to every entry and its position. Storing the digest in
+//! the parameter metadata lets anyone check a published transcript against the parameters.
+
+use crate::errors::ParameterError;
+
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The domain separator for the initial transcript digest.
+const TRANSCRIPT_DOMAIN: &[u8] = b"snarkVM.SetupTranscript";
+
+/// An append-only audit transcript for a parameter setup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetupTranscript {
+    /// The entries, in the order they were recorded.
+    entries: Vec<Value>,
+    /// The digest over all entries.
+    digest: [u8; 32],
+}
+
+impl SetupTranscript {
+    /// Initializes a new transcript for the given setup, recording the code version and, optionally, the hostname.
+    pub fn new(setup: &str, hostname: Option<&str>) -> Self {
+        let mut transcript = Self { entries: Vec::new(), digest: Sha256::digest(TRANSCRIPT_DOMAIN).into() };
+        let mut value = json!({ "setup": setup, "code_version": env!("CARGO_PKG_VERSION") });
+        if let Some(hostname) = hostname {
+            value["hostname"] = json!(hostname);
+        }
+        transcript.record("start", value);
+        transcript
+    }
+
+    /// Appends an entry with the given label and value.
+    pub fn record(&mut self, label: &str, value: Value) {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or_default();
+        self.push(json!({
+            "index": self.entries.len(),
+            "label": label,
+            "timestamp": timestamp,
+            "value": value,
+        }));
+    }
+
+    /// Appends an entry with the checksum and size of the given output.
+    pub fn record_output(&mut self, label: &str, bytes: &[u8]) {
+        self.record(label, json!({ "checksum": hex::encode(Sha256::digest(bytes)), "size": bytes.len() }));
+    }
+
+    /// Returns the entries, in the order they were recorded.
+    pub fn entries(&self) -> &[Value] {
+        &self.entries
+    }
+
+    /// Returns the hex-encoded transcript digest.
+    pub fn digest(&self) -> String {
+        hex::encode(self.digest)
+    }
+
+    /// Writes the transcript to the given path as one JSON entry per line.
+    /// This does not overwrite an existing transcript.
+    pub fn write_to(&self, path: &Path) -> Result<(), ParameterError> {
+        let mut file = BufWriter::new(File::options().write(true).create_new(true).open(path)?);
+        for entry in &self.entries {
+            serde_json::to_writer(&mut file, entry).map_err(|error| ParameterError::Message(error.to_string()))?;
+            file.write_all(b"\n")?;
+        }
+        Ok(file.flush()?)
+    }
+
+    /// Reads a transcript from the given path, and ensures it matches the given digest.
+    pub fn read_from(path: &Path, expected_digest: &str) -> Result<Self, ParameterError> {
+        let mut transcript = Self { entries: Vec::new(), digest: Sha256::digest(TRANSCRIPT_DOMAIN).into() };
+        for line in BufReader::new(File::open(path)?).lines() {
+            let entry = serde_json::from_str(&line?).map_err(|error| ParameterError::Message(error.to_string()))?;
+            transcript.push(entry);
+        }
+        match transcript.digest() == expected_digest {
+            true => Ok(transcript),
+            false => Err(ParameterError::ChecksumMismatch(expected_digest.to_string(), transcript.digest())),
+        }
+    }
+
+    /// Appends the given entry, and updates the digest.
+    fn push(&mut self, entry: Value) {
+        let mut hasher = Sha256::new();
+        hasher.update(self.digest);
+        hasher.update(entry.to_string().as_bytes());
+        self.digest = hasher.finalize().into();
+        self.entries.push(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_setup_transcript() {
+        let mut transcript = SetupTranscript::new("test", Some("localhost"));
+        transcript.record("degree", json!(1 << 10));
+        transcript.record_output("output", b"parameters");
+        assert_eq!(transcript.entries().len(), 3);
+        assert_eq!(transcript.entries()[2]["value"]["size"], json!(10));
+
+        // Write the transcript, and ensure it is not overwritten.
+        let path = std::env::temp_dir().join(format!("setup-{}.transcript", transcript.digest()));
+        transcript.write_to(&path).unwrap();
+        assert!(transcript.write_to(&path).is_err());
+
+        // Read the transcript back, and ensure the digest is checked.
+        assert_eq!(SetupTranscript::read_from(&path, &transcript.digest()).unwrap(), transcript);
+        assert!(SetupTranscript::read_from(&path, &SetupTranscript::new("test", None).digest()).is_err());
+
+        // Ensure a modified transcript does not match the digest.
+        let contents = std::fs::read_to_string(&path).unwrap().replace("1024", "2048");
+        std::fs::write(&path, contents).unwrap();
+        assert!(SetupTranscript::read_from(&path, &transcript.digest()).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,370 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Content-addressed transports for fetching remote parameters.
+//!
+//! By default, remote parameters are fetched from a single HTTPS host. Additional sources may be
+//! registered as URL templates, which are tried in order before the default host, or as mirrors,
+//! which are tried in order after it. Since every parameter file is verified against the SHA-256
+//! checksum in its metadata, the checksum serves as its content address, and any mirror, gateway,
+//! or cache may serve the file.
+//!
+//! Mirrors serve the files by filename under a base URL, and may be configured with a comma-separated
+//! list of base URLs in the `SNARKVM_PARAMETER_MIRRORS` environment variable, or registered at runtime.
+//! Each mirror has its own timeout, after which the next mirror is tried, resuming the partial download.
+//! The default host and the URL templates share the host timeout, so a host that hangs also fails over.
+//! The last source has no timeout, as there is no source left to fail over to.
+//!
+//! A URL template may contain the following placeholders:
+//! - `{filename}` - the versioned filename, e.g. `powers-of-beta-17.usrs.4b36084`.
+//! - `{checksum}` - the hex-encoded SHA-256 checksum of the file.
+//! - `{cid}` - the CIDv1 of the file as a single raw IPFS block, e.g. for `https://ipfs.io/ipfs/{cid}`.
+//!   Note: Files added to IPFS with the default chunker have a different CID, and should be served by `{checksum}`.
+
+use crate::errors::ParameterError;
+
+use parking_lot::RwLock;
+use std::time::Duration;
+
+/// The environment variable with the comma-separated base URLs of the initial mirrors.
+pub const MIRRORS_ENV_VAR: &str = "SNARKVM_PARAMETER_MIRRORS";
+
+/// The timeout for fetching a parameter file from a mirror configured in the environment.
+pub const DEFAULT_MIRROR_TIMEOUT: Duration = Duration::from_secs(600);
+/// The default timeout for fetching a parameter file from the default host, or a URL template.
+pub const DEFAULT_HOST_TIMEOUT: Duration = Duration::from_secs(600);
+
+lazy_static! {
+    /// The registered URL templates, in order of preference.
+    static ref URL_TEMPLATES: RwLock<Vec<String>> = RwLock::new(Vec::new());
+    /// The timeout for fetching a parameter file from the default host, or a URL template.
+    static ref HOST_TIMEOUT: RwLock<Duration> = RwLock::new(DEFAULT_HOST_TIMEOUT);
+    /// The registered mirrors, in order of preference.
+    static ref MIRRORS: RwLock<Vec<Mirror>> = RwLock::new(mirrors_from_env());
+}
+
+/// A mirror of the default host, serving the parameter files by filename under its base URL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mirror {
+    /// The base URL of the mirror.
+    url: String,
+    /// The timeout for fetching a parameter file from the mirror.
+    timeout: Duration,
+}
+
+impl Mirror {
+    /// Initializes a new mirror with the given base URL and timeout.
+    pub fn new(url: impl Into<String>, timeout: Duration) -> Result<Self, ParameterError> {
+        let url = url.into().trim().trim_end_matches('/').to_string();
+        // Ensure the base URL is not empty.
+        if url.is_empty() {
+            return Err(ParameterError::Message("The mirror URL must not be empty".to_string()));
+        }
+        Ok(Self { url, timeout })
+    }
+
+    /// Returns the base URL of the mirror.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the timeout for fetching a parameter file from the mirror.
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+/// Returns the mirrors configured in the environment, in order of preference.
+fn mirrors_from_env() -> Vec<Mirror> {
+    let mirrors = std::env::var(MIRRORS_ENV_VAR).unwrap_or_default();
+    mirrors.split(',').filter_map(|url| Mirror::new(url, DEFAULT_MIRROR_TIMEOUT).ok()).collect()
+}
+
+/// The CIDv1 prefix for a raw block with a SHA-256 multihash: version 1, codec `raw` (0x55),
+/// hash function `sha2-256` (0x12), and digest length 32 (0x20).
+const CID_V1_RAW_SHA256_PREFIX: [u8; 4] = [0x01, 0x55, 0x12, 0x20];
+
+/// Registers a URL template, to be tried before the default host and after any previously-registered templates.
+pub fn add_url_template(template: impl Into<String>) -> Result<(), ParameterError> {
+    let template = template.into();
+    check_url_template(&template)?;
+    URL_TEMPLATES.write().push(template);
+    Ok(())
+}
+
+/// Ensures the given URL template addresses the file.
+fn check_url_template(template: &str) -> Result<(), ParameterError> {
+    match ["{filename}", "{checksum}", "{cid}"].iter().any(|placeholder| template.contains(placeholder)) {
+        true => Ok(()),
+        false => Err(ParameterError::Message(format!(
+            "The URL template '{template}' must contain one of '{{filename}}', '{{checksum}}', or '{{cid}}'"
+        ))),
+    }
+}
+
+/// Removes all registered URL templates, so that parameters are only fetched from the default host.
+pub fn clear_url_templates() {
+    URL_TEMPLATES.write().clear();
+}
+
+/// Registers a mirror, to be tried after the default host and any previously-registered mirrors.
+pub fn add_mirror(mirror: Mirror) {
+    MIRRORS.write().push(mirror);
+}
+
+/// Removes all mirrors, including the ones configured in the environment.
+pub fn clear_mirrors() {
+    MIRRORS.write().clear();
+}
+
+/// Returns the registered mirrors, in order of preference.
+pub fn mirrors() -> Vec<Mirror> {
+    MIRRORS.read().clone()
+}
+
+/// Sets the timeout for fetching a parameter file from the default host, or a URL template.
+pub fn set_host_timeout(timeout: Duration) {
+    *HOST_TIMEOUT.write() = timeout;
+}
+
+/// Returns the timeout for fetching a parameter file from the default host, or a URL template.
+pub fn host_timeout() -> Duration {
+    *HOST_TIMEOUT.read()
+}
+
+/// Returns the URLs to fetch the given file from, in order of preference, along with the timeout for each URL.
+/// The registered URL templates come first, followed by the default host, and finally the mirrors.
+pub fn remote_sources(
+    remote_url: &str,
+    filename: &str,
+    checksum: &str,
+) -> Result<Vec<(String, Option<Duration>)>, ParameterError> {
+    remote_sources_from(&URL_TEMPLATES.read(), host_timeout(), &MIRRORS.read(), remote_url, filename, checksum)
+}
+
+/// Returns the URLs to fetch the given file from, in order of preference, along with the timeout for each URL,
+/// for a file that is served next to the parameter files, but is not addressed by its own checksum, such as a delta.
+/// Only the URL templates with a `{filename}` placeholder are used, followed by the default host, and the mirrors.
+pub fn filename_sources(remote_url: &str, filename: &str) -> Vec<(String, Option<Duration>)> {
+    filename_sources_from(&URL_TEMPLATES.read(), host_timeout(), &MIRRORS.read(), remote_url, filename)
+}
+
+/// Returns the URLs to fetch the given file from the given sources, along with the timeout for each URL.
+fn remote_sources_from(
+    url_templates: &[String],
+    host_timeout: Duration,
+    mirrors: &[Mirror],
+    remote_url: &str,
+    filename: &str,
+    checksum: &str,
+) -> Result<Vec<(String, Option<Duration>)>, ParameterError> {
+    let mut sources = Vec::new();
+    for template in url_templates {
+        let mut url = template.replace("{filename}", filename).replace("{checksum}", checksum);
+        if url.contains("{cid}") {
+            url = url.replace("{cid}", &checksum_to_cid(checksum)?);
+        }
+        sources.push((url, Some(host_timeout)));
+    }
+    sources.push((format!("{remote_url}/{filename}"), Some(host_timeout)));
+    for mirror in mirrors {
+        sources.push((format!("{}/{filename}", mirror.url), Some(mirror.timeout)));
+    }
+    Ok(without_last_timeout(sources))
+}
+
+/// Returns the URLs to fetch the given file from the given sources, along with the timeout for each URL,
+/// for a file that is not addressed by its own checksum.
+fn filename_sources_from(
+    url_templates: &[String],
+    host_timeout: Duration,
+    mirrors: &[Mirror],
+    remote_url: &str,
+    filename: &str,
+) -> Vec<(String, Option<Duration>)> {
+    let mut sources = Vec::new();
+    for template in url_templates {
+        if template.contains("{filename}") && !template.contains("{checksum}") && !template.contains("{cid}") {
+            sources.push((template.replace("{filename}", filename), Some(host_timeout)));
+        }
+    }
+    sources.push((format!("{remote_url}/{filename}"), Some(host_timeout)));
+    for mirror in mirrors {
+        sources.push((format!("{}/{filename}", mirror.url), Some(mirror.timeout)));
+    }
+    without_last_timeout(sources)
+}
+
+/// Removes the timeout of the last source, as there is no source left to fail over to.
+/// Note: Otherwise, a large file on a slow connection could never finish downloading.
+fn without_last_timeout(mut sources: Vec<(String, Option<Duration>)>) -> Vec<(String, Option<Duration>)> {
+    if let Some((_, timeout)) = sources.last_mut() {
+        *timeout = None;
+    }
+    sources
+}
+
+/// Returns the URLs to fetch the given file from, in order of preference.
+pub fn remote_urls(remote_url: &str, filename: &str, checksum: &str) -> Result<Vec<String>, ParameterError> {
+    Ok(remote_sources(remote_url, filename, checksum)?.into_iter().map(|(url, _)| url).collect())
+}
+
+/// Downloads the file at the given URL, without blocking the async runtime.
+/// If a timeout is given, the download fails once it has elapsed.
+#[cfg(all(feature = "async", not(feature = "wasm")))]
+pub async fn remote_fetch_async(
+    url: &str,
+    filename: &str,
+    timeout: Option<Duration>,
+) -> Result<Vec<u8>, ParameterError> {
+    let mut request = reqwest::Client::new().get(url);
+    if let Some(timeout) = timeout {
+        request = request.timeout(timeout);
+    }
+    let mut response = request.send().await?.error_for_status()?;
+
+    // Download the file in chunks, reporting the progress after each chunk.
+    let total_bytes = response.content_length().unwrap_or_default();
+    let mut buffer = Vec::with_capacity(total_bytes as usize);
+    while let Some(chunk) = response.chunk().await? {
+        buffer.extend_from_slice(&chunk);
+        crate::progress::report_download(filename, buffer.len() as u64, total_bytes);
+    }
+    Ok(buffer)
+}
+
+/// Returns the CIDv1 (in lowercase base32) of a raw IPFS block, given its hex-encoded SHA-256 checksum.
+pub fn checksum_to_cid(checksum: &str) -> Result<String, ParameterError> {
+    // Decode the checksum.
+    let digest = hex::decode(checksum)
+        .map_err(|error| ParameterError::Message(format!("Invalid parameter checksum '{checksum}': {error}")))?;
+    if digest.len() != 32 {
+        return Err(ParameterError::Message(format!("Invalid parameter checksum '{checksum}': expected 32 bytes")));
+    }
+    // Construct the CID bytes, and encode them with the multibase prefix for lowercase base32.
+    let bytes = CID_V1_RAW_SHA256_PREFIX.iter().chain(&digest).copied().collect::<Vec<_>>();
+    Ok(format!("b{}", base32_lower(&bytes)))
+}
+
+/// Returns the unpadded, lowercase RFC 4648 base32 encoding of the given bytes.
+fn base32_lower(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+    let mut output = String::with_capacity((bytes.len() * 8 + 4) / 5);
+    let (mut buffer, mut num_bits) = (0u16, 0u32);
+    for byte in bytes {
+        buffer = (buffer << 8) | *byte as u16;
+        num_bits += 8;
+        while num_bits >= 5 {
+            num_bits -= 5;
+            output.push(ALPHABET[((buffer >> num_bits) & 0x1f) as usize] as char);
+        }
+    }
+    if num_bits > 0 {
+        output.push(ALPHABET[((buffer << (5 - num_bits)) & 0x1f) as usize] as char);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum_to_cid() {
+        // The SHA-256 checksum and CID of the empty file.
+        let checksum = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        assert_eq!(checksum_to_cid(checksum).unwrap(), "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku");
+
+        // Ensure malformed checksums are rejected.
+        assert!(checksum_to_cid("e3b0").is_err());
+        assert!(checksum_to_cid("not a checksum").is_err());
+    }
+
+    #[test]
+    fn test_base32_lower() {
+        // The test vectors from RFC 4648, in lowercase and without padding.
+        for (input, expected) in
+            [("", ""), ("f", "my"), ("fo", "mzxq"), ("foo", "mzxw6"), ("foob", "mzxw6yq"), ("fooba", "mzxw6ytb")]
+        {
+            assert_eq!(base32_lower(input.as_bytes()), expected);
+        }
+    }
+
+    #[test]
+    fn test_remote_urls() {
+        let checksum = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        let host_timeout = Duration::from_secs(30);
+
+        // Ensure templates without a placeholder are rejected.
+        assert!(check_url_template("https://example.com/parameters").is_err());
+        assert!(add_url_template("https://example.com/parameters").is_err());
+
+        // Note: The sources are passed in, rather than registered, as the registry is shared by concurrent tests.
+        let templates = [
+            "https://mirror.example.com/sha256/{checksum}".to_string(),
+            "https://ipfs.example.com/ipfs/{cid}?filename={filename}".to_string(),
+        ];
+        let sources = remote_sources_from(
+            &templates,
+            host_timeout,
+            &[],
+            "https://host.example.com",
+            "file.usrs.e3b0c44",
+            checksum,
+        )
+        .unwrap();
+        assert_eq!(sources, [
+            (format!("https://mirror.example.com/sha256/{checksum}"), Some(host_timeout)),
+            (
+                "https://ipfs.example.com/ipfs/bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku?filename=file.usrs.e3b0c44"
+                    .to_string(),
+                Some(host_timeout)
+            ),
+            ("https://host.example.com/file.usrs.e3b0c44".to_string(), None),
+        ]);
+
+        // Ensure the content-addressed templates are skipped, for a file that is only addressed by its filename.
+        let templates = [templates[0].clone(), "https://cache.example.com/{filename}".to_string()];
+        let delta_sources = filename_sources_from(
+            &templates,
+            host_timeout,
+            &[],
+            "https://host.example.com",
+            "file.usrs.e3b0c44.a1b2c3d.delta",
+        );
+        assert_eq!(delta_sources, [
+            ("https://cache.example.com/file.usrs.e3b0c44.a1b2c3d.delta".to_string(), Some(host_timeout)),
+            ("https://host.example.com/file.usrs.e3b0c44.a1b2c3d.delta".to_string(), None),
+        ]);
+
+        // Ensure empty mirrors are rejected.
+        assert!(Mirror::new(" / ", DEFAULT_MIRROR_TIMEOUT).is_err());
+
+        // Ensure the mirrors are tried in order after the default host, which times out so it fails over.
+        let timeout = Duration::from_secs(5);
+        let mirrors = [
+            Mirror::new("https://cdn.example.com/parameters/", timeout).unwrap(),
+            Mirror::new("https://backup.example.com", DEFAULT_MIRROR_TIMEOUT).unwrap(),
+        ];
+        let sources =
+            remote_sources_from(&[], host_timeout, &mirrors, "https://host.example.com", "file.usrs.e3b0c44", checksum)
+                .unwrap();
+        assert_eq!(sources, [
+            ("https://host.example.com/file.usrs.e3b0c44".to_string(), Some(host_timeout)),
+            ("https://cdn.example.com/parameters/file.usrs.e3b0c44".to_string(), Some(timeout)),
+            ("https://backup.example.com/file.usrs.e3b0c44".to_string(), None),
+        ]);
+    }
+}
@@ -15,9 +15,8 @@
 use crate::{stack::Address, CallStack, Registers, RegistersCall, StackEvaluate, StackExecute};
 use aleo_std::prelude::{finish, lap, timer};
 use console::{
-    account::Field,
     network::prelude::*,
-    program::{Register, Request, Value, ValueType},
+    program::{Plaintext, Record, Register, Request, Value, ValueType},
 };
 use synthesizer_program::{
     Call,
@@ -289,13 +288,12 @@ impl<N: Network> CallTrait<N> for Call<N> {
                                 ValueType::Record(record_name) => {
                                     // Get the register index containing the record.
                                     let index = match output.operand() {
-                                        Operand::Register(Register::Locator(index)) => Field::from_u64(*index),
+                                        Operand::Register(Register::Locator(index)) => *index,
                                         _ => bail!("Expected a `Register::Locator` operand for a record output."),
                                     };
-                                    // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
-                                    let randomizer = N::hash_to_scalar_psd2(&[*request.tvk(), index])?;
                                     // Construct the record nonce.
-                                    let record_nonce = N::g_scalar_multiply(&randomizer);
+                                    let record_nonce =
+                                        Record::<N, Plaintext<N>>::nonce_from_tvk(*request.tvk(), index)?;
                                     Ok(Value::Record(substack.sample_record(
                                         &address,
                                         record_name,
@@ -144,6 +144,12 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Retrieve the next request.
         let console_request = call_stack.pop()?;
+        // Track the peak memory usage of the circuit synthesis, if enabled.
+        let synthesis_memory = utilities::start_memory_scope!(|| format!(
+            "Stack::execute_function synthesis of '{}/{}'",
+            console_request.program_id(),
+            console_request.function_name()
+        ));
 
         // Ensure the network ID matches.
         ensure!(
@@ -395,6 +401,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Eject the circuit assignment and reset the circuit.
         let assignment = A::eject_assignment_and_reset();
+        utilities::end_memory_scope!(synthesis_memory);
 
         // If the circuit is in `Synthesize` or `Execute` mode, synthesize the circuit key, if it does not exist.
         if matches!(registers.call_stack(), CallStack::Synthesize(..))
@@ -308,12 +308,8 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     };
                 }
 
-                // Prepare the index as a field element.
-                let index = Field::from_u64(self.destination.locator());
-                // Compute the randomizer as `HashToScalar(tvk || index)`.
-                let randomizer = N::hash_to_scalar_psd2(&[registers.tvk()?, index])?;
-                // Compute the nonce from the randomizer.
-                let nonce = N::g_scalar_multiply(&randomizer);
+                // Compute the nonce from `HashToScalar(tvk || index)`.
+                let nonce = Record::<N, Plaintext<N>>::nonce_from_tvk(registers.tvk()?, self.destination.locator())?;
 
                 // Construct the record.
                 let record = Record::<N, Plaintext<N>>::from_plaintext(owner, entries, nonce)?;
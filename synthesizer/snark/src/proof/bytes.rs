@@ -20,13 +20,37 @@ impl<N: Network> FromBytes for Proof<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid proof version"));
         }
+        // Read and check the proof system metadata, which is only present from version 2.
+        if version == 2 {
+            // Read the proof system ID.
+            let proof_system_id = u8::read_le(&mut reader)?;
+            if proof_system_id != Self::PROOF_SYSTEM_ID {
+                return Err(error(format!(
+                    "Unsupported proof system (found '{proof_system_id}', expected '{}')",
+                    Self::PROOF_SYSTEM_ID
+                )));
+            }
+            // Read the proof system version.
+            let proof_system_version = u16::read_le(&mut reader)?;
+            if proof_system_version != Self::PROOF_SYSTEM_VERSION {
+                return Err(error(format!(
+                    "Unsupported proof system version (found '{proof_system_version}', expected '{}')",
+                    Self::PROOF_SYSTEM_VERSION
+                )));
+            }
+            // Read the network ID.
+            let network_id = u16::read_le(&mut reader)?;
+            if network_id != N::ID {
+                return Err(error(format!("Proof is for network '{network_id}' (expected network '{}')", N::ID)));
+            }
+        }
         // Read the proof.
         let proof = FromBytes::read_le(&mut reader)?;
         // Return the proof.
-        Ok(Self { proof })
+        Ok(Self { proof, version })
     }
 }
 
@@ -34,7 +58,16 @@ impl<N: Network> ToBytes for Proof<N> {
     /// Writes the proof to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        self.version.write_le(&mut writer)?;
+        // Write the proof system metadata, which is only present from version 2.
+        if self.version == 2 {
+            // Write the proof system ID.
+            Self::PROOF_SYSTEM_ID.write_le(&mut writer)?;
+            // Write the proof system version.
+            Self::PROOF_SYSTEM_VERSION.write_le(&mut writer)?;
+            // Write the network ID.
+            N::ID.write_le(&mut writer)?;
+        }
         // Write the bytes.
         self.proof.write_le(&mut writer)
     }
@@ -43,6 +76,9 @@ impl<N: Network> ToBytes for Proof<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
 
     #[test]
     fn test_bytes() -> Result<()> {
@@ -55,4 +91,44 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_versions() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure new proofs are written in the version 1 layout, without the proof system metadata.
+        assert_eq!(expected.version(), 1);
+        assert_eq!(expected_bytes[0], 1);
+
+        // Construct the version 2 layout, with the proof system metadata.
+        let mut v2_bytes = vec![2u8, Proof::<CurrentNetwork>::PROOF_SYSTEM_ID];
+        v2_bytes.extend_from_slice(&Proof::<CurrentNetwork>::PROOF_SYSTEM_VERSION.to_le_bytes());
+        v2_bytes.extend_from_slice(&CurrentNetwork::ID.to_le_bytes());
+        v2_bytes.extend_from_slice(&expected_bytes[1..]);
+
+        // Ensure the proof system ID records the (hiding) Varuna mode of the network.
+        assert_eq!(v2_bytes[1], 1);
+
+        // Ensure a version 2 proof is read, and written back in the version 2 layout.
+        let candidate = Proof::<CurrentNetwork>::read_le(&v2_bytes[..])?;
+        assert_eq!(candidate.version(), 2);
+        assert_eq!(*candidate, *expected);
+        assert_eq!(candidate.to_bytes_le()?, v2_bytes);
+
+        // Ensure a proof can be rewritten in either layout, and only in a valid version.
+        assert_eq!(expected.clone().with_version(2)?.to_bytes_le()?, v2_bytes);
+        assert_eq!(candidate.clone().with_version(1)?.to_bytes_le()?, expected_bytes);
+        assert!(expected.clone().with_version(3).is_err());
+
+        // Ensure a mismatched proof system ID, proof system version, or network ID is rejected.
+        for offset in [1, 2, 4] {
+            let mut bytes = v2_bytes.clone();
+            bytes[offset] = bytes[offset].wrapping_add(1);
+            let error = Proof::<CurrentNetwork>::read_le(&bytes[..]).unwrap_err();
+            assert!(error.to_string().contains("expected"));
+        }
+        Ok(())
+    }
 }
@@ -22,12 +22,35 @@ mod serialize;
 pub struct Proof<N: Network> {
     /// The proof.
     proof: varuna::Proof<N::PairingCurve>,
+    /// The version of the byte layout the proof is written in.
+    version: u8,
 }
 
 impl<N: Network> Proof<N> {
+    /// The identifier of the proof system, which is Varuna in hiding mode (`1`) or non-hiding mode (`2`).
+    pub const PROOF_SYSTEM_ID: u8 = if <N::VarunaMode as varuna::SNARKMode>::ZK { 1 } else { 2 };
+    /// The version of the proof system.
+    pub const PROOF_SYSTEM_VERSION: u16 = 1;
+    /// The version of the byte layout for new proofs.
+    /// Note: Version 2 prefixes the proof system metadata. The VM selects it from `N::PROOF_VERSION_2_HEIGHT`.
+    pub const VERSION: u8 = 1;
+
     /// Initializes a new proof.
     pub(super) const fn new(proof: varuna::Proof<N::PairingCurve>) -> Self {
-        Self { proof }
+        Self { proof, version: Self::VERSION }
+    }
+
+    /// Returns the version of the byte layout the proof is written in.
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the proof, written in the byte layout of the given version.
+    pub fn with_version(self, version: u8) -> Result<Self> {
+        // Ensure the version is valid.
+        ensure!(version == 1 || version == 2, "Invalid proof version '{version}'");
+        // Return the proof.
+        Ok(Self { proof: self.proof, version })
     }
 }
 
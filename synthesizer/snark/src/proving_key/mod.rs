@@ -23,12 +23,12 @@ use std::collections::BTreeMap;
 #[derive(Clone)]
 pub struct ProvingKey<N: Network> {
     /// The proving key for the function.
-    proving_key: Arc<varuna::CircuitProvingKey<N::PairingCurve, varuna::VarunaHidingMode>>,
+    proving_key: Arc<varuna::CircuitProvingKey<N::PairingCurve, N::VarunaMode>>,
 }
 
 impl<N: Network> ProvingKey<N> {
     /// Initializes a new proving key.
-    pub const fn new(proving_key: Arc<varuna::CircuitProvingKey<N::PairingCurve, varuna::VarunaHidingMode>>) -> Self {
+    pub const fn new(proving_key: Arc<varuna::CircuitProvingKey<N::PairingCurve, N::VarunaMode>>) -> Self {
         Self { proving_key }
     }
 
@@ -85,7 +85,7 @@ impl<N: Network> ProvingKey<N> {
 }
 
 impl<N: Network> Deref for ProvingKey<N> {
-    type Target = varuna::CircuitProvingKey<N::PairingCurve, varuna::VarunaHidingMode>;
+    type Target = varuna::CircuitProvingKey<N::PairingCurve, N::VarunaMode>;
 
     fn deref(&self) -> &Self::Target {
         &self.proving_key
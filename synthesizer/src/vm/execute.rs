@@ -37,40 +37,65 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
         // Determine if a fee is required.
         let is_fee_required = !authorization.is_split();
-        // Determine if a priority fee is declared.
-        let is_priority_fee_declared = priority_fee_in_microcredits > 0;
         // Compute the execution.
         let execution = self.execute_authorization_raw(authorization, query.clone(), rng)?;
         // Compute the fee.
-        let fee = match is_fee_required || is_priority_fee_declared {
-            true => {
-                // Compute the minimum execution cost.
-                let (minimum_execution_cost, (_, _)) = execution_cost(self, &execution)?;
-                // Compute the execution ID.
-                let execution_id = execution.to_execution_id()?;
-                // Authorize the fee.
-                let authorization = match fee_record {
-                    Some(record) => self.authorize_fee_private(
-                        private_key,
-                        record,
-                        minimum_execution_cost,
-                        priority_fee_in_microcredits,
-                        execution_id,
-                        rng,
-                    )?,
-                    None => self.authorize_fee_public(
-                        private_key,
-                        minimum_execution_cost,
-                        priority_fee_in_microcredits,
-                        execution_id,
-                        rng,
-                    )?,
-                };
-                // Execute the fee.
-                Some(self.execute_fee_authorization_raw(authorization, query, rng)?)
-            }
-            false => None,
-        };
+        let fee = self.execute_fee_for_execution(
+            private_key,
+            &execution,
+            is_fee_required,
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )?;
+        // Return the execute transaction.
+        Transaction::from_execution(execution, fee)
+    }
+
+    /// Returns a new execution without a fee, which may then be sponsored by another account via `sponsor_execution`.
+    pub fn execute_unsponsored<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Execution<N>> {
+        // Compute the authorization.
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        // Compute the execution.
+        self.execute_authorization_raw(authorization, query, rng)
+    }
+
+    /// Returns a new execute transaction for the given execution, with the fee paid by the sponsor.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be paid from the sponsor's record;
+    /// otherwise, a public fee will be paid from the sponsor's public balance.
+    ///
+    /// The fee is bound to the execution ID, so the sponsorship cannot be reused for any other execution.
+    pub fn sponsor_execution<R: Rng + CryptoRng>(
+        &self,
+        sponsor_private_key: &PrivateKey<N>,
+        execution: Execution<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Determine if a fee is required.
+        let is_fee_required =
+            !(execution.len() == 1 && execution.transitions().any(|transition| transition.is_split()));
+        // Compute the fee.
+        let fee = self.execute_fee_for_execution(
+            sponsor_private_key,
+            &execution,
+            is_fee_required,
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )?;
         // Return the execute transaction.
         Transaction::from_execution(execution, fee)
     }
@@ -107,6 +132,50 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns the fee for the given execution, authorized by the given private key,
+    /// or `None` if the fee is neither required nor has a priority fee declared.
+    fn execute_fee_for_execution<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        execution: &Execution<N>,
+        is_fee_required: bool,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Option<Fee<N>>> {
+        // Determine if a priority fee is declared.
+        let is_priority_fee_declared = priority_fee_in_microcredits > 0;
+        // If the fee is not required and no priority fee is declared, then skip the fee.
+        if !is_fee_required && !is_priority_fee_declared {
+            return Ok(None);
+        }
+        // Compute the minimum execution cost.
+        let (minimum_execution_cost, (_, _)) = execution_cost(self, execution)?;
+        // Compute the execution ID.
+        let execution_id = execution.to_execution_id()?;
+        // Authorize the fee.
+        let authorization = match fee_record {
+            Some(record) => self.authorize_fee_private(
+                private_key,
+                record,
+                minimum_execution_cost,
+                priority_fee_in_microcredits,
+                execution_id,
+                rng,
+            )?,
+            None => self.authorize_fee_public(
+                private_key,
+                minimum_execution_cost,
+                priority_fee_in_microcredits,
+                execution_id,
+                rng,
+            )?,
+        };
+        // Execute the fee.
+        Ok(Some(self.execute_fee_authorization_raw(authorization, query, rng)?))
+    }
+
     /// Executes a call to the program function for the given authorization.
     /// Returns the execution.
     #[inline]
@@ -146,8 +215,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let execution = trace.prove_execution::<$aleo, _>(&locator, rng)?;
                 lap!(timer, "Compute the proof");
 
-                // Return the execution.
-                Ok(cast_ref!(execution as Execution<N>).clone())
+                // Return the execution, with its proof in the layout for the next block.
+                cast_ref!(execution as Execution<N>).clone().with_proof_version(self.next_proof_version())
             }};
         }
 
@@ -191,8 +260,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let fee = trace.prove_fee::<$aleo, _>(rng)?;
                 lap!(timer, "Compute the proof");
 
-                // Return the fee.
-                Ok(cast_ref!(fee as Fee<N>).clone())
+                // Return the fee, with its proof in the layout for the next block.
+                cast_ref!(fee as Fee<N>).clone().with_proof_version(self.next_proof_version())
             }};
         }
 
@@ -415,4 +484,45 @@ mod tests {
         let fee_size_in_bytes = fee.to_bytes_le().unwrap().len();
         assert_eq!(1384, fee_size_in_bytes, "Update me if serialization has changed");
     }
+
+    #[test]
+    fn test_sponsored_execution() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the sponsor, who pays the fee from their public balance.
+        let sponsor_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let sponsor_address = Address::try_from(&sponsor_private_key).unwrap();
+        // Initialize a new caller, who does not pay a fee.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Prepare the inputs.
+        let inputs = || {
+            [
+                Value::<CurrentNetwork>::from_str(&sponsor_address.to_string()).unwrap(),
+                Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+            ]
+            .into_iter()
+        };
+
+        // Execute without a fee.
+        let execution = vm
+            .execute_unsponsored(&caller_private_key, ("credits.aleo", "transfer_public"), inputs(), None, rng)
+            .unwrap();
+        // Sponsor the execution.
+        let transaction = vm.sponsor_execution(&sponsor_private_key, execution, None, 0, None, rng).unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+
+        // Retrieve the sponsored fee.
+        let Some(fee) = transaction.fee_transition() else { panic!("Expected a sponsored execution") };
+
+        // Ensure the sponsorship cannot be reused for a different execution.
+        let other_execution = vm
+            .execute_unsponsored(&caller_private_key, ("credits.aleo", "transfer_public"), inputs(), None, rng)
+            .unwrap();
+        let other_transaction = Transaction::from_execution(other_execution, Some(fee)).unwrap();
+        assert!(vm.check_transaction(&other_transaction, None, rng).is_err());
+    }
 }
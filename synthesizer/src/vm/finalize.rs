@@ -241,6 +241,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     continue 'outer;
                 }
 
+                // Ensure that the transaction has not expired.
+                if transaction.is_expired(state.block_height()) {
+                    // Store the aborted transaction.
+                    aborted.push((transaction.clone(), format!("Expired at block {:?}", transaction.expires_at())));
+                    // Continue to the next transaction.
+                    continue 'outer;
+                }
+
                 // Ensure that the transaction is not producing a duplicate transition.
                 for transition_id in transaction.transition_ids() {
                     // If the transition ID is already produced in this block or previous blocks, abort the transaction.
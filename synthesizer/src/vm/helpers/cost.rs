@@ -17,7 +17,7 @@ use console::{
     prelude::*,
     program::{LiteralType, PlaintextType},
 };
-use ledger_block::{Deployment, Execution};
+use ledger_block::{Amount, Deployment, Execution};
 use ledger_store::ConsensusStorage;
 use synthesizer_program::{Command, Finalize, Instruction};
 
@@ -33,22 +33,22 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
     let num_characters = u32::try_from(program_id.name().to_string().len())?;
 
     // Compute the storage cost in microcredits.
-    let storage_cost = size_in_bytes
+    let storage_cost = Amount::from_microcredits(size_in_bytes)
         .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
         .ok_or(anyhow!("The storage cost computation overflowed for a deployment"))?;
 
     // Compute the namespace cost in credits: 10^(10 - num_characters).
     let namespace_cost = 10u64
         .checked_pow(10u32.saturating_sub(num_characters))
-        .ok_or(anyhow!("The namespace cost computation overflowed for a deployment"))?
-        .saturating_mul(1_000_000); // 1 microcredit = 1e-6 credits.
+        .and_then(Amount::from_credits)
+        .ok_or(anyhow!("The namespace cost computation overflowed for a deployment"))?;
 
     // Compute the total cost in microcredits.
     let total_cost = storage_cost
         .checked_add(namespace_cost)
         .ok_or(anyhow!("The total cost computation overflowed for a deployment"))?;
 
-    Ok((total_cost, (storage_cost, namespace_cost)))
+    Ok((total_cost.microcredits(), (storage_cost.microcredits(), namespace_cost.microcredits())))
 }
 
 /// Returns the *minimum* cost in microcredits to publish the given execution (total cost, (storage cost, namespace cost)).
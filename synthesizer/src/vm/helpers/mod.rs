@@ -20,5 +20,8 @@ pub use cost::*;
 
 mod macros;
 
+mod proving;
+pub use proving::*;
+
 mod rewards;
 pub use rewards::*;
@@ -0,0 +1,346 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::VM;
+use console::prelude::*;
+use ledger_block::Transaction;
+use ledger_store::ConsensusStorage;
+use synthesizer_process::Authorization;
+
+use indexmap::IndexMap;
+use parking_lot::Mutex;
+use rand::rngs::OsRng;
+use std::sync::{mpsc, Arc};
+
+/// A proving job, consisting of an execution authorization and an optional fee authorization.
+///
+/// A job is self-contained, and proving it has no side effects on the ledger,
+/// so it may be serialized and proven by any prover with access to the chain state.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProvingJob<N: Network> {
+    /// The execution authorization.
+    execution: Authorization<N>,
+    /// The fee authorization, if any.
+    fee: Option<Authorization<N>>,
+}
+
+impl<N: Network> ProvingJob<N> {
+    /// Initializes a new proving job.
+    pub fn new(execution: Authorization<N>, fee: Option<Authorization<N>>) -> Result<Self> {
+        // Ensure the fee authorization is for a fee.
+        if let Some(fee) = &fee {
+            ensure!(fee.is_fee_private() || fee.is_fee_public(), "The proving job has an invalid fee authorization");
+        }
+        Ok(Self { execution, fee })
+    }
+
+    /// Returns the execution authorization.
+    pub const fn execution(&self) -> &Authorization<N> {
+        &self.execution
+    }
+
+    /// Returns the fee authorization, if any.
+    pub const fn fee(&self) -> Option<&Authorization<N>> {
+        self.fee.as_ref()
+    }
+}
+
+impl<N: Network> FromBytes for ProvingJob<N> {
+    /// Reads the proving job from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid proving job version"));
+        }
+        // Read the execution authorization.
+        let execution = Authorization::read_le(&mut reader)?;
+        // Read the fee authorization.
+        let fee = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(Authorization::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid proving job fee variant '{variant}'"))),
+        };
+        Self::new(execution, fee).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for ProvingJob<N> {
+    /// Writes the proving job to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the execution authorization.
+        self.execution.write_le(&mut writer)?;
+        // Write the fee authorization.
+        match &self.fee {
+            None => 0u8.write_le(&mut writer),
+            Some(fee) => {
+                1u8.write_le(&mut writer)?;
+                fee.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network> Serialize for ProvingJob<N> {
+    /// Serializes the proving job into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut job = serializer.serialize_struct("ProvingJob", 2)?;
+                job.serialize_field("execution", &self.execution)?;
+                job.serialize_field("fee", &self.fee)?;
+                job.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProvingJob<N> {
+    /// Deserializes the proving job from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the proving job from a string into a value.
+                let mut job = serde_json::Value::deserialize(deserializer)?;
+                // Recover the proving job.
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut job, "execution")?,
+                    DeserializeExt::take_from_value::<D>(&mut job, "fee")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proving job"),
+        }
+    }
+}
+
+/// The identifier of a proving job, assigned by the proving service.
+pub type ProvingJobId = u64;
+
+/// The status of a proving job.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProvingStatus {
+    /// The job is waiting to be proven.
+    Queued,
+    /// The job is being proven.
+    Running,
+    /// The job was proven, and its transaction is ready to be fetched.
+    Completed,
+    /// The job failed, with the given reason.
+    Failed(String),
+}
+
+/// A service that proves jobs, to be implemented by in-process and remote provers alike.
+pub trait ProvingService<N: Network> {
+    /// Submits the given job, and returns its identifier.
+    fn submit(&self, job: ProvingJob<N>) -> Result<ProvingJobId>;
+
+    /// Returns the status of the given job.
+    fn status(&self, id: ProvingJobId) -> Result<ProvingStatus>;
+
+    /// Returns the transaction for the given completed job, and removes the job from the service.
+    fn fetch(&self, id: ProvingJobId) -> Result<Transaction<N>>;
+}
+
+/// The state of a job in the local proving service.
+enum JobState<N: Network> {
+    /// The job is waiting to be proven.
+    Queued,
+    /// The job is being proven.
+    Running,
+    /// The job was proven.
+    Completed(Box<Transaction<N>>),
+    /// The job failed.
+    Failed(String),
+}
+
+/// The jobs tracked by the local proving service.
+struct Jobs<N: Network> {
+    /// The identifier for the next job.
+    next_id: ProvingJobId,
+    /// The state of each job.
+    states: IndexMap<ProvingJobId, JobState<N>>,
+}
+
+/// An in-process proving service, which proves jobs one at a time on a background thread using the given VM.
+pub struct LocalProvingService<N: Network> {
+    /// The tracked jobs.
+    jobs: Arc<Mutex<Jobs<N>>>,
+    /// The sender for queued jobs.
+    sender: mpsc::Sender<(ProvingJobId, ProvingJob<N>)>,
+}
+
+impl<N: Network> LocalProvingService<N> {
+    /// Initializes a new local proving service, which proves jobs against the given VM.
+    pub fn new<C: ConsensusStorage<N>>(vm: VM<N, C>) -> Self {
+        let jobs = Arc::new(Mutex::new(Jobs { next_id: 0, states: IndexMap::new() }));
+        let (sender, receiver) = mpsc::channel::<(ProvingJobId, ProvingJob<N>)>();
+
+        // Spawn the prover, which exits once the service is dropped.
+        let prover_jobs = jobs.clone();
+        std::thread::spawn(move || {
+            while let Ok((id, job)) = receiver.recv() {
+                // Mark the job as running, unless it was removed.
+                match prover_jobs.lock().states.get_mut(&id) {
+                    Some(state) => *state = JobState::Running,
+                    None => continue,
+                }
+                // Prove the job.
+                let state = match vm.execute_authorization(job.execution, job.fee, None, &mut OsRng) {
+                    Ok(transaction) => JobState::Completed(Box::new(transaction)),
+                    Err(error) => JobState::Failed(error.to_string()),
+                };
+                // Store the result.
+                if let Some(entry) = prover_jobs.lock().states.get_mut(&id) {
+                    *entry = state;
+                }
+            }
+        });
+
+        Self { jobs, sender }
+    }
+}
+
+impl<N: Network> ProvingService<N> for LocalProvingService<N> {
+    /// Submits the given job, and returns its identifier.
+    fn submit(&self, job: ProvingJob<N>) -> Result<ProvingJobId> {
+        let id = {
+            let mut jobs = self.jobs.lock();
+            let id = jobs.next_id;
+            jobs.next_id += 1;
+            jobs.states.insert(id, JobState::Queued);
+            id
+        };
+        // Queue the job.
+        if self.sender.send((id, job)).is_err() {
+            self.jobs.lock().states.shift_remove(&id);
+            bail!("The local proving service has stopped")
+        }
+        Ok(id)
+    }
+
+    /// Returns the status of the given job.
+    fn status(&self, id: ProvingJobId) -> Result<ProvingStatus> {
+        match self.jobs.lock().states.get(&id) {
+            Some(JobState::Queued) => Ok(ProvingStatus::Queued),
+            Some(JobState::Running) => Ok(ProvingStatus::Running),
+            Some(JobState::Completed(_)) => Ok(ProvingStatus::Completed),
+            Some(JobState::Failed(reason)) => Ok(ProvingStatus::Failed(reason.clone())),
+            None => bail!("Proving job {id} does not exist"),
+        }
+    }
+
+    /// Returns the transaction for the given completed job, and removes the job from the service.
+    fn fetch(&self, id: ProvingJobId) -> Result<Transaction<N>> {
+        let mut jobs = self.jobs.lock();
+        match jobs.states.get(&id) {
+            Some(JobState::Completed(_)) => match jobs.states.shift_remove(&id) {
+                Some(JobState::Completed(transaction)) => Ok(*transaction),
+                _ => unreachable!("The job was completed"),
+            },
+            Some(JobState::Failed(reason)) => bail!("Proving job {id} failed - {reason}"),
+            Some(_) => bail!("Proving job {id} has not completed"),
+            None => bail!("Proving job {id} does not exist"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{sample_genesis_private_key, sample_vm, sample_vm_with_genesis_block};
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+        program::Value,
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_proving_job_serialization() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm();
+
+        // Authorize a transfer, and its fee.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let execution = vm.authorize(&private_key, "credits.aleo", "transfer_public", inputs.iter(), rng).unwrap();
+        let execution_id = execution.to_execution_id().unwrap();
+        let fee = vm.authorize_fee_public(&private_key, 100, 0, execution_id, rng).unwrap();
+
+        for job in [ProvingJob::new(execution.clone(), None).unwrap(), ProvingJob::new(execution, Some(fee)).unwrap()] {
+            // Check the byte representation.
+            let bytes = job.to_bytes_le().unwrap();
+            assert!(job == ProvingJob::read_le(&bytes[..]).unwrap());
+            // Check the JSON representation.
+            let json = serde_json::to_string(&job).unwrap();
+            assert!(job == serde_json::from_str(&json).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_local_proving_service_unknown_job() {
+        let service = LocalProvingService::new(sample_vm());
+        assert!(ProvingService::<CurrentNetwork>::status(&service, 0).is_err());
+        assert!(service.fetch(0).is_err());
+    }
+
+    #[test]
+    fn test_local_proving_service() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the caller, who pays the fee from their public balance.
+        let caller_private_key = sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM, and the service.
+        let vm = sample_vm_with_genesis_block(rng);
+        let service = LocalProvingService::new(vm.clone());
+
+        // Authorize a transfer, and its fee.
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let execution =
+            vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs.iter(), rng).unwrap();
+        let execution_id = execution.to_execution_id().unwrap();
+        let fee = vm.authorize_fee_public(&caller_private_key, 10_000_000, 100, execution_id, rng).unwrap();
+
+        // Submit the job.
+        let id = service.submit(ProvingJob::new(execution, Some(fee)).unwrap()).unwrap();
+
+        // Wait for the job to complete.
+        let status = loop {
+            match service.status(id).unwrap() {
+                ProvingStatus::Queued | ProvingStatus::Running => {
+                    std::thread::sleep(std::time::Duration::from_millis(100))
+                }
+                status => break status,
+            }
+        };
+        assert_eq!(status, ProvingStatus::Completed);
+
+        // Fetch the transaction, and ensure it is valid.
+        let transaction = service.fetch(id).unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        // Ensure the job was removed from the service.
+        assert!(service.status(id).is_err());
+    }
+}
@@ -32,12 +32,14 @@ use ledger_block::{
     Block,
     ConfirmedTransaction,
     Deployment,
+    DoubleSpendProof,
     Execution,
     Fee,
     Header,
     Ratifications,
     Ratify,
     Rejected,
+    Spend,
     Transaction,
     Transactions,
 };
@@ -32,7 +32,27 @@ macro_rules! ensure_is_unique {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// The block height from which proofs must be written in the version 2 layout.
+    #[cfg(not(any(test, feature = "test")))]
+    pub const PROOF_VERSION_2_HEIGHT: u32 = N::PROOF_VERSION_2_HEIGHT;
+    /// The block height from which proofs must be written in the version 2 layout.
+    /// This is set to a deliberately low value (2) for testing purposes only.
+    #[cfg(any(test, feature = "test"))]
+    pub const PROOF_VERSION_2_HEIGHT: u32 = 2;
+
+    /// Returns the version of the proof layout for transactions in the next block.
+    pub fn next_proof_version(&self) -> u8 {
+        let next_height = self.block_store().max_height().map_or(0, |height| height.saturating_add(1));
+        match next_height >= Self::PROOF_VERSION_2_HEIGHT {
+            true => 2,
+            false => 1,
+        }
+    }
+
     /// Verifies the transaction in the VM. On failure, returns an error.
+    ///
+    /// The cheap checks (see `VM::verify_cheap`) are performed before any proof is verified
+    /// (see `VM::verify_expensive`), so that invalid transactions are rejected at a low cost.
     #[inline]
     pub fn check_transaction<R: CryptoRng + Rng>(
         &self,
@@ -42,8 +62,54 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<()> {
         let timer = timer!("VM::check_transaction");
 
+        // First, perform the cheap checks.
+        self.verify_cheap(transaction, rejected_id)?;
+        lap!(timer, "Perform the cheap checks");
+
+        // Next, verify the proofs.
+        self.verify_expensive(transaction, rejected_id, rng)?;
+
+        finish!(timer, "Verify the transaction");
+        Ok(())
+    }
+
+    /// Performs the checks on the transaction that do not verify a proof. On failure, returns an error.
+    ///
+    /// These include the size, transaction ID, uniqueness, signature, and fee amount checks, along with
+    /// the ledger lookups. A transaction is only valid if it also passes `VM::verify_expensive`.
+    pub fn verify_cheap(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        let timer = timer!("VM::verify_cheap");
+
         /* Transaction */
 
+        // Ensure the transaction does not exceed the maximum size, before performing any expensive checks.
+        let transaction_size = transaction.size_in_bytes()?;
+        if transaction_size > N::MAX_TRANSACTION_SIZE as u64 {
+            bail!(
+                "Transaction '{}' is {transaction_size} bytes, exceeding the maximum of {} bytes",
+                transaction.id(),
+                N::MAX_TRANSACTION_SIZE
+            )
+        }
+
+        // Ensure the proofs are in the layout for the next block, so each transaction has a single byte encoding.
+        // Note: The version 2 layout, with the proof system metadata, is required from `VM::PROOF_VERSION_2_HEIGHT`.
+        let fee = match transaction {
+            Transaction::Deploy(_, _, _, fee) | Transaction::Fee(_, fee) => Some(fee),
+            Transaction::Execute(_, _, fee) => fee.as_ref(),
+        };
+        let proofs = transaction.execution().and_then(|execution| execution.proof()).into_iter();
+        for proof in proofs.chain(fee.and_then(|fee| fee.proof())) {
+            if proof.version() != self.next_proof_version() {
+                bail!(
+                    "Transaction '{}' contains a version {} proof (expected version {})",
+                    transaction.id(),
+                    proof.version(),
+                    self.next_proof_version()
+                )
+            }
+        }
+
         // Ensure the transaction ID is unique.
         if self.block_store().contains_transaction_id(&transaction.id())? {
             bail!("Transaction '{}' already exists in the ledger", transaction.id())
@@ -92,10 +158,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         lap!(timer, "Check for duplicate elements");
 
-        // First, verify the fee.
-        self.check_fee(transaction, rejected_id)?;
+        // First, check the fee.
+        self.check_fee_cheap(transaction, rejected_id)?;
 
-        // Next, verify the deployment or execution.
+        // Next, check the deployment or execution.
         match transaction {
             Transaction::Deploy(id, owner, deployment, _) => {
                 // Compute the deployment ID.
@@ -112,8 +178,6 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if self.transaction_store().contains_program_id(deployment.program_id())? {
                     bail!("Program ID '{}' is already deployed", deployment.program_id())
                 }
-                // Verify the deployment.
-                self.check_deployment_internal(deployment, rng)?;
             }
             Transaction::Execute(id, execution, _) => {
                 // Compute the execution ID.
@@ -124,19 +188,79 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)? {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
-                // Verify the execution.
-                self.check_execution_internal(execution)?;
+                // Ensure the global state root exists in the block store.
+                self.check_global_state_root("Execution", &execution.global_state_root())?;
+                // Ensure the execution has not expired for the next block.
+                if let Some(expires_at) = execution.expires_at() {
+                    let next_height = self.block_store().max_height().map_or(0, |height| height.saturating_add(1));
+                    ensure!(!execution.is_expired(next_height), "Transaction '{id}' expired at block {expires_at}");
+                }
             }
             Transaction::Fee(..) => { /* no-op */ }
         }
 
-        finish!(timer, "Verify the transaction");
+        finish!(timer, "Perform the cheap checks");
+        Ok(())
+    }
+
+    /// Verifies the proofs in the transaction. On failure, returns an error.
+    ///
+    /// Note: This check does not include the checks in `VM::verify_cheap`, which should be performed first.
+    /// To ensure all components of the transaction are checked, use `VM::check_transaction` instead.
+    pub fn verify_expensive<R: CryptoRng + Rng>(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("VM::verify_expensive");
+
+        // First, verify the fee.
+        self.check_fee_expensive(transaction, rejected_id)?;
+        lap!(timer, "Verify the fee");
+
+        // Next, verify the deployment or execution.
+        match transaction {
+            Transaction::Deploy(_, _, deployment, _) => self.check_deployment_internal(deployment, rng)?,
+            Transaction::Execute(_, execution, _) => self.check_execution_internal(execution)?,
+            Transaction::Fee(..) => { /* no-op */ }
+        }
+
+        finish!(timer, "Verify the deployment or execution");
         Ok(())
     }
 
     /// Verifies the `fee` in the given transaction. On failure, returns an error.
     #[inline]
     pub fn check_fee(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        // First, perform the cheap checks on the fee.
+        self.check_fee_cheap(transaction, rejected_id)?;
+        // Next, verify the fee.
+        self.check_fee_expensive(transaction, rejected_id)
+    }
+
+    /// Verifies the given double-spend proof, by verifying the proofs of both spends. On failure, returns an error.
+    ///
+    /// Note: The spends are not checked against the ledger, so the proof remains valid after either spend is accepted.
+    pub fn check_double_spend_proof(&self, proof: &DoubleSpendProof<N>) -> Result<()> {
+        let timer = timer!("VM::check_double_spend_proof");
+
+        for spend in [proof.first(), proof.second()] {
+            match spend {
+                Spend::Execution(execution) => self.check_execution_internal(execution)?,
+                Spend::Fee(fee) => self.verify_fee_internal(fee, fee.deployment_or_execution_id()?)?,
+            }
+        }
+
+        finish!(timer, "Verify the spends");
+        Ok(())
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns the fee in the given transaction, along with the deployment, execution, or rejected ID that it is bound to.
+    /// If the transaction is an execution without a fee, returns `None`.
+    fn fee_and_id(transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<Option<(&Fee<N>, Field<N>)>> {
         match transaction {
             Transaction::Deploy(id, _, deployment, fee) => {
                 // Ensure the rejected ID is not present.
@@ -145,14 +269,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let Ok(deployment_id) = deployment.to_deployment_id() else {
                     bail!("Failed to compute the Merkle root for deployment transaction '{id}'")
                 };
-                // Compute the deployment cost.
-                let (cost, _) = deployment_cost(deployment)?;
-                // Ensure the fee is sufficient to cover the cost.
-                if *fee.base_amount()? < cost {
-                    bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
-                }
-                // Verify the fee.
-                self.check_fee_internal(fee, deployment_id)?;
+                Ok(Some((fee, deployment_id)))
             }
             Transaction::Execute(id, execution, fee) => {
                 // Ensure the rejected ID is not present.
@@ -161,12 +278,35 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let Ok(execution_id) = execution.to_execution_id() else {
                     bail!("Failed to compute the Merkle root for execution transaction '{id}'")
                 };
+                Ok(fee.as_ref().map(|fee| (fee, execution_id)))
+            }
+            Transaction::Fee(id, fee) => match rejected_id {
+                Some(rejected_id) => Ok(Some((fee, rejected_id))),
+                None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
+            },
+        }
+    }
+
+    /// Performs the checks on the `fee` in the given transaction that do not verify a proof. On failure, returns an error.
+    fn check_fee_cheap(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        // Retrieve the fee.
+        let fee_to_check = Self::fee_and_id(transaction, rejected_id)?.map(|(fee, _)| fee);
+
+        match transaction {
+            Transaction::Deploy(id, _, deployment, fee) => {
+                // Compute the deployment cost.
+                let (cost, _) = deployment_cost(deployment)?;
+                // Ensure the fee is sufficient to cover the cost.
+                if *fee.base_amount()? < cost {
+                    bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
+                }
+            }
+            Transaction::Execute(id, execution, fee) => {
                 // If the transaction contains only 1 transition, and the transition is a split, then the fee can be skipped.
                 let is_fee_required = !(execution.len() == 1 && transaction.contains_split());
-                // Verify the fee.
-                if let Some(fee) = fee {
+                match fee {
                     // If the fee is required, then check that the base fee amount is satisfied.
-                    if is_fee_required {
+                    Some(fee) if is_fee_required => {
                         // Compute the execution cost.
                         let (cost, _) = execution_cost(self, execution)?;
                         // Ensure the fee is sufficient to cover the cost.
@@ -175,29 +315,47 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                 "Transaction '{id}' has an insufficient base fee (execution) - requires {cost} microcredits"
                             )
                         }
-                    } else {
-                        // Ensure the base fee amount is zero.
-                        ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)");
                     }
-                    // Verify the fee.
-                    self.check_fee_internal(fee, execution_id)?;
-                } else {
+                    // Ensure the base fee amount is zero.
+                    Some(fee) => {
+                        ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)")
+                    }
                     // Ensure the fee can be safely skipped.
-                    ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
+                    // Note: An expiration is only committed in the transaction ID through the fee.
+                    None => {
+                        ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
+                        ensure!(execution.expires_at().is_none(), "Transaction '{id}' has an expiration without a fee");
+                    }
                 }
             }
             // Note: This transaction type does not need to check the fee amount, because:
             //  1. The fee is guaranteed to be non-zero by the constructor of `Transaction::Fee`.
             //  2. The fee may be less that the deployment or execution cost, as this is a valid reason it was rejected.
-            Transaction::Fee(id, fee) => {
-                // Verify the fee.
-                match rejected_id {
-                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id)?,
-                    None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
-                }
-            }
+            Transaction::Fee(..) => (),
+        }
+
+        // Check the fee.
+        match fee_to_check {
+            Some(fee) => self.check_fee_internal(fee),
+            None => Ok(()),
+        }
+    }
+
+    /// Verifies the proof of the `fee` in the given transaction. On failure, returns an error.
+    fn check_fee_expensive(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        match Self::fee_and_id(transaction, rejected_id)? {
+            Some((fee, deployment_or_execution_id)) => self.verify_fee_internal(fee, deployment_or_execution_id),
+            None => Ok(()),
+        }
+    }
+
+    /// Ensures the given global state root exists in the block store. On failure, returns an error.
+    fn check_global_state_root(&self, name: &str, global_state_root: &N::StateRoot) -> Result<()> {
+        match self.block_store().contains_state_root(global_state_root) {
+            Ok(true) => Ok(()),
+            Ok(false) => bail!("{name} verification failed: global state root not found"),
+            Err(error) => bail!("{name} verification failed: {error}"),
         }
-        Ok(())
     }
 }
 
@@ -224,7 +382,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         result
     }
 
-    /// Verifies the given execution. On failure, returns an error.
+    /// Verifies the proof of the given execution. On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the execution are checked,
     /// use `VM::check_transaction` instead.
@@ -233,39 +391,28 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let timer = timer!("VM::check_execution");
 
         // Verify the execution.
-        let verification = self.process.read().verify_execution(execution);
-        lap!(timer, "Verify the execution");
+        let result = self
+            .process
+            .read()
+            .verify_execution(execution)
+            .map_err(|error| anyhow!("Execution verification failed: {error}"));
 
-        // Ensure the global state root exists in the block store.
-        let result = match verification {
-            // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
-                Ok(true) => Ok(()),
-                Ok(false) => bail!("Execution verification failed: global state root not found"),
-                Err(error) => bail!("Execution verification failed: {error}"),
-            },
-            Err(error) => bail!("Execution verification failed: {error}"),
-        };
-        finish!(timer, "Check the global state root");
+        finish!(timer, "Verify the execution");
         result
     }
 
-    /// Verifies the given fee. On failure, returns an error.
+    /// Performs the checks on the given fee that do not verify its proof. On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
     /// use `VM::check_fee` instead.
     #[inline]
-    fn check_fee_internal(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+    fn check_fee_internal(&self, fee: &Fee<N>) -> Result<()> {
         let timer = timer!("VM::check_fee");
 
         // Ensure the fee does not exceed the limit.
         let fee_amount = fee.amount()?;
         ensure!(*fee_amount <= N::MAX_FEE, "Fee verification failed: fee exceeds the maximum limit");
 
-        // Verify the fee.
-        let verification = self.process.read().verify_fee(fee, deployment_or_execution_id);
-        lap!(timer, "Verify the fee");
-
         // TODO (howardwu): This check is technically insufficient. Consider moving this upstream
         //  to the speculation layer.
         // If the fee is public, speculatively check the account balance.
@@ -287,19 +434,32 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Ensure the balance is sufficient.
             ensure!(balance >= fee_amount, "Fee verification failed: insufficient balance");
         }
+        lap!(timer, "Check the account balance");
 
         // Ensure the global state root exists in the block store.
-        let result = match verification {
-            Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
-                Ok(true) => Ok(()),
-                Ok(false) => bail!("Fee verification failed: global state root not found"),
-                Err(error) => bail!("Fee verification failed: {error}"),
-            },
-            Err(error) => bail!("Fee verification failed: {error}"),
-        };
+        let result = self.check_global_state_root("Fee", &fee.global_state_root());
         finish!(timer, "Check the global state root");
         result
     }
+
+    /// Verifies the proof of the given fee. On failure, returns an error.
+    ///
+    /// Note: This is an internal check only. To ensure all components of the fee are checked,
+    /// use `VM::check_fee` instead.
+    #[inline]
+    fn verify_fee_internal(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+        let timer = timer!("VM::verify_fee");
+
+        // Verify the fee.
+        let result = self
+            .process
+            .read()
+            .verify_fee(fee, deployment_or_execution_id)
+            .map_err(|error| anyhow!("Fee verification failed: {error}"));
+
+        finish!(timer, "Verify the fee");
+        result
+    }
 }
 
 #[cfg(test)]
@@ -373,6 +533,7 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(execution.proof().is_some());
                     // Verify the execution.
+                    vm.check_global_state_root("Execution", &execution.global_state_root()).unwrap();
                     vm.check_execution_internal(&execution).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
@@ -405,18 +566,65 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(fee.proof().is_some());
                     // Verify the fee.
-                    vm.check_fee_internal(&fee, execution_id).unwrap();
+                    vm.check_fee_internal(&fee).unwrap();
+                    vm.verify_fee_internal(&fee, execution_id).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_fee = fee.to_string();
                     let recovered_fee: Fee<CurrentNetwork> = serde_json::from_str(&serialized_fee).unwrap();
-                    vm.check_fee_internal(&recovered_fee, execution_id).unwrap();
+                    vm.check_fee_internal(&recovered_fee).unwrap();
+                    vm.verify_fee_internal(&recovered_fee, execution_id).unwrap();
                 }
                 _ => panic!("Expected an execution with a fee"),
             }
         }
     }
 
+    #[test]
+    fn test_check_double_spend_proof() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch two transactions that spend the same genesis record, in an execution and in a fee respectively.
+        let first = crate::vm::test_helpers::sample_execution_transaction_without_fee(rng);
+        let second = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let serial_number = *first.serial_numbers().next().unwrap();
+        assert!(second.contains_serial_number(&serial_number));
+
+        // Ensure the double-spend proof is valid.
+        let proof = DoubleSpendProof::from_transactions(serial_number, &first, &second).unwrap();
+        assert!(matches!(proof.first(), Spend::Execution(..)));
+        assert!(matches!(proof.second(), Spend::Fee(..)));
+        vm.check_double_spend_proof(&proof).unwrap();
+    }
+
+    #[test]
+    fn test_verify_cheap_and_expensive() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch execution transactions.
+        let transactions = [
+            crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng),
+            crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng),
+        ];
+
+        for transaction in transactions {
+            // Ensure the transaction passes both halves of the verification.
+            vm.verify_cheap(&transaction, None).unwrap();
+            vm.verify_expensive(&transaction, None, rng).unwrap();
+
+            // Ensure the cheap checks reject a transaction that is missing its fee.
+            if let Transaction::Execute(_, execution, Some(fee)) = &transaction {
+                let without_fee = Transaction::from_execution(execution.clone(), None).unwrap();
+                assert!(vm.verify_cheap(&without_fee, None).is_err());
+                // Ensure the cheap checks reject a fee transaction without a rejected ID.
+                let fee_transaction = Transaction::from_fee(fee.clone()).unwrap();
+                assert!(vm.verify_cheap(&fee_transaction, None).is_err());
+            }
+        }
+    }
+
     #[test]
     fn test_check_transaction_execution() {
         let rng = &mut TestRng::default();
@@ -567,4 +775,77 @@ function compute:
         // Ensure that the program can't be deployed.
         assert!(vm.deploy_raw(&program, rng).is_err());
     }
+
+    #[test]
+    fn test_proof_version_activation() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        assert_eq!(VM::<CurrentNetwork, ledger_store::helpers::memory::ConsensusMemory<_>>::PROOF_VERSION_2_HEIGHT, 2);
+
+        // A helper function to construct a public transfer.
+        let transfer = |rng: &mut TestRng| {
+            let inputs = [
+                Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+                Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+            ];
+            vm.execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+                .unwrap()
+        };
+        // A helper function to return the proof versions of the transaction.
+        let proof_versions = |transaction: &Transaction<CurrentNetwork>| match transaction {
+            Transaction::Execute(_, execution, Some(fee)) => {
+                (execution.proof().unwrap().version(), fee.proof().unwrap().version())
+            }
+            _ => unreachable!("Expected an execute transaction with a fee"),
+        };
+        // A helper function to rewrite the proofs of the transaction in the given version.
+        let with_proof_version = |transaction: &Transaction<CurrentNetwork>, version: u8| match transaction {
+            Transaction::Execute(_, execution, Some(fee)) => Transaction::from_execution(
+                execution.clone().with_proof_version(version).unwrap(),
+                Some(fee.clone().with_proof_version(version).unwrap()),
+            )
+            .unwrap(),
+            _ => unreachable!("Expected an execute transaction with a fee"),
+        };
+
+        // Ensure the proofs for the block below the activation height are written in the version 1 layout.
+        let transaction = transfer(rng);
+        assert_eq!(vm.next_proof_version(), 1);
+        assert_eq!(proof_versions(&transaction), (1, 1));
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        // Ensure the version 2 layout is rejected below the activation height.
+        let rewritten = with_proof_version(&transaction, 2);
+        assert!(vm.verify_cheap(&rewritten, None).is_err());
+        // Ensure the proof version is committed in the transaction ID.
+        assert_ne!(rewritten.id(), transaction.id());
+        // Ensure a transaction cannot mix proof versions.
+        let Transaction::Execute(_, execution, Some(fee)) = &transaction else { unreachable!() };
+        let mixed_fee = Some(fee.clone().with_proof_version(2).unwrap());
+        assert!(Transaction::from_execution(execution.clone(), mixed_fee).is_err());
+
+        // Add the transaction to the ledger.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the proofs for the block at the activation height are written in the version 2 layout.
+        let transaction = transfer(rng);
+        assert_eq!(vm.next_proof_version(), 2);
+        assert_eq!(proof_versions(&transaction), (2, 2));
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        // Ensure the version 2 layout is retained through the byte representation.
+        let candidate = Transaction::read_le(&transaction.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(proof_versions(&candidate), (2, 2));
+        // Ensure the version 1 layout is rejected from the activation height.
+        assert!(vm.verify_cheap(&with_proof_version(&transaction, 1), None).is_err());
+
+        // Add the transaction to the ledger.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+    }
 }
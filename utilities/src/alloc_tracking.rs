@@ -0,0 +1,346 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Allocation tracking, for reporting the peak memory usage during proving and circuit synthesis.
+//!
+//! When the `alloc-tracking` feature is enabled, `start_memory_scope!` returns a guard that measures the peak
+//! memory usage until it is dropped (or passed to `end_memory_scope!`), and then prints it to stderr.
+//! As the report is printed on drop, early returns still finish the scope. The counts are only recorded
+//! if the binary installs the allocator:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: snarkvm_utilities::alloc_tracking::TrackingAllocator =
+//!     snarkvm_utilities::alloc_tracking::TrackingAllocator;
+//! ```
+//!
+//! When the feature is disabled, both macros compile to nothing.
+
+#[cfg(feature = "alloc-tracking")]
+pub use inner::*;
+
+#[cfg(feature = "alloc-tracking")]
+mod inner {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        fmt,
+        sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    };
+
+    /// The maximum number of scopes that can be measured at the same time.
+    const MAX_SCOPES: usize = 64;
+
+    /// The number of bytes currently allocated through the `TrackingAllocator`.
+    static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+    /// The peak number of bytes allocated through the `TrackingAllocator`, since the process started.
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+    /// The initial peak of a scope slot.
+    #[allow(clippy::declare_interior_mutable_const)]
+    const NO_PEAK: AtomicUsize = AtomicUsize::new(0);
+    /// The peak number of allocated bytes of each scope slot.
+    static SCOPE_PEAKS: [AtomicUsize; MAX_SCOPES] = [NO_PEAK; MAX_SCOPES];
+    /// The bitmask of the scope slots that are reserved by a scope.
+    static RESERVED_SCOPES: AtomicU64 = AtomicU64::new(0);
+    /// The bitmask of the scope slots whose peaks are updated on allocation.
+    static ACTIVE_SCOPES: AtomicU64 = AtomicU64::new(0);
+
+    /// A global allocator that wraps the system allocator, and tracks the current and peak allocated bytes.
+    pub struct TrackingAllocator;
+
+    impl TrackingAllocator {
+        /// Records an allocation of the given number of bytes.
+        pub(super) fn record_alloc(size: usize) {
+            let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed).saturating_add(size);
+            PEAK.fetch_max(allocated, Ordering::Relaxed);
+            // Update the peak of every active scope.
+            let mut active = ACTIVE_SCOPES.load(Ordering::Acquire);
+            while active != 0 {
+                SCOPE_PEAKS[active.trailing_zeros() as usize].fetch_max(allocated, Ordering::Relaxed);
+                active &= active - 1;
+            }
+        }
+
+        /// Records a deallocation of the given number of bytes.
+        pub(super) fn record_dealloc(size: usize) {
+            ALLOCATED.fetch_sub(size, Ordering::Relaxed);
+        }
+    }
+
+    unsafe impl GlobalAlloc for TrackingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                Self::record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                Self::record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            Self::record_dealloc(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                match new_size >= layout.size() {
+                    true => Self::record_alloc(new_size - layout.size()),
+                    false => Self::record_dealloc(layout.size() - new_size),
+                }
+            }
+            new_ptr
+        }
+    }
+
+    /// Returns the bitmask of the reserved scope slots.
+    #[cfg(test)]
+    pub(super) fn reserved_scopes() -> u64 {
+        RESERVED_SCOPES.load(Ordering::Acquire)
+    }
+
+    /// Returns the number of bytes currently allocated through the `TrackingAllocator`.
+    pub fn allocated_bytes() -> usize {
+        ALLOCATED.load(Ordering::Relaxed)
+    }
+
+    /// Returns the peak number of bytes allocated through the `TrackingAllocator`, since the process started.
+    pub fn peak_allocated_bytes() -> usize {
+        PEAK.load(Ordering::Relaxed)
+    }
+
+    /// A labeled scope, whose peak memory usage is measured from when it starts until it is dropped.
+    /// On drop, the scope releases its slot and prints its report to stderr.
+    ///
+    /// Each scope has its own peak, so nested and overlapping scopes (e.g. on parallel threads) do not affect
+    /// one another. The counts are process-wide, so allocations from concurrent work are included.
+    /// Note: At most `MAX_SCOPES` scopes are measured at the same time, and further scopes report no peak.
+    pub struct MemoryScope {
+        /// The label of the scope.
+        label: String,
+        /// The number of allocated bytes when the scope started.
+        baseline: usize,
+        /// The slot of the scope peak, if a slot was available.
+        slot: Option<usize>,
+    }
+
+    impl MemoryScope {
+        /// Starts a new scope with the given label.
+        pub fn start(label: impl ToString) -> Self {
+            let baseline = allocated_bytes();
+            // Reserve a free slot, if there is one.
+            let slot = RESERVED_SCOPES
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |reserved| match reserved.trailing_ones() {
+                    free if (free as usize) < MAX_SCOPES => Some(reserved | (1 << free)),
+                    _ => None,
+                })
+                .ok()
+                .map(|reserved| reserved.trailing_ones() as usize);
+            // Initialize the peak of the slot, before it is updated on allocation.
+            if let Some(slot) = slot {
+                SCOPE_PEAKS[slot].store(baseline, Ordering::Relaxed);
+                ACTIVE_SCOPES.fetch_or(1 << slot, Ordering::AcqRel);
+            }
+            Self { label: label.to_string(), baseline, slot }
+        }
+
+        /// Returns the memory report of the scope so far.
+        pub fn report(&self) -> MemoryReport {
+            let peak = self.slot.map(|slot| SCOPE_PEAKS[slot].load(Ordering::Relaxed));
+            MemoryReport { label: self.label.clone(), baseline: self.baseline, peak }
+        }
+    }
+
+    impl Drop for MemoryScope {
+        /// Prints the report of the scope, and releases its slot.
+        fn drop(&mut self) {
+            eprintln!("{}", self.report());
+            if let Some(slot) = self.slot {
+                ACTIVE_SCOPES.fetch_and(!(1 << slot), Ordering::AcqRel);
+                RESERVED_SCOPES.fetch_and(!(1 << slot), Ordering::AcqRel);
+            }
+        }
+    }
+
+    /// The memory usage of a scope.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct MemoryReport {
+        /// The label of the scope.
+        label: String,
+        /// The number of allocated bytes when the scope started.
+        baseline: usize,
+        /// The peak number of allocated bytes during the scope, if it was measured.
+        peak: Option<usize>,
+    }
+
+    impl MemoryReport {
+        /// Returns the label of the scope.
+        pub fn label(&self) -> &str {
+            &self.label
+        }
+
+        /// Returns the number of allocated bytes when the scope started.
+        pub const fn baseline_bytes(&self) -> usize {
+            self.baseline
+        }
+
+        /// Returns the peak number of allocated bytes during the scope, if it was measured.
+        pub const fn peak_bytes(&self) -> Option<usize> {
+            self.peak
+        }
+
+        /// Returns the peak number of bytes allocated by the scope above its baseline, if it was measured.
+        pub fn peak_bytes_above_baseline(&self) -> Option<usize> {
+            self.peak.map(|peak| peak.saturating_sub(self.baseline))
+        }
+    }
+
+    impl fmt::Display for MemoryReport {
+        /// Prints the peak memory usage of the scope in MiB.
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            const MIB: f64 = (1 << 20) as f64;
+            match self.peak {
+                Some(peak) => write!(
+                    f,
+                    "Peak memory for {}: {:.2} MiB ({:.2} MiB above the baseline)",
+                    self.label,
+                    peak as f64 / MIB,
+                    peak.saturating_sub(self.baseline) as f64 / MIB
+                ),
+                None => write!(f, "Peak memory for {}: not measured (too many concurrent scopes)", self.label),
+            }
+        }
+    }
+
+    /// Starts a labeled memory scope, from a closure that returns the label.
+    /// The scope is finished when the returned guard is dropped.
+    #[macro_export]
+    macro_rules! start_memory_scope {
+        ($label:expr) => {
+            $crate::alloc_tracking::MemoryScope::start($label())
+        };
+    }
+
+    /// Finishes a memory scope, and prints its report.
+    #[macro_export]
+    macro_rules! end_memory_scope {
+        ($scope:expr) => {
+            drop($scope)
+        };
+    }
+}
+
+#[cfg(not(feature = "alloc-tracking"))]
+pub use inner::*;
+
+#[cfg(not(feature = "alloc-tracking"))]
+mod inner {
+    /// A placeholder memory scope, used when allocation tracking is disabled.
+    pub struct MemoryScope;
+
+    /// Starts a labeled memory scope, from a closure that returns the label.
+    /// The scope is finished when the returned guard is dropped.
+    #[macro_export]
+    macro_rules! start_memory_scope {
+        ($label:expr) => {
+            $crate::alloc_tracking::MemoryScope
+        };
+    }
+
+    /// Finishes a memory scope, and prints its report.
+    #[macro_export]
+    macro_rules! end_memory_scope {
+        ($scope:expr) => {
+            let _ = $scope;
+        };
+    }
+}
+
+#[cfg(all(test, feature = "alloc-tracking"))]
+mod tests {
+    use super::*;
+
+    use std::sync::Mutex;
+
+    /// The lock to run the tests one at a time, as the counts are process-wide.
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_nested_scopes() {
+        let _lock = LOCK.lock().unwrap_or_else(|error| error.into_inner());
+
+        // Note: The test binary does not install the allocator, so the scopes only track explicit records.
+        let outer = MemoryScope::start("outer");
+        TrackingAllocator::record_alloc(1000);
+        let inner = MemoryScope::start("inner");
+        TrackingAllocator::record_alloc(500);
+        TrackingAllocator::record_dealloc(1500);
+        let inner_report = inner.report();
+        drop(inner);
+        let outer_report = outer.report();
+
+        // Ensure the inner peak is measured from its own baseline, and counts towards the outer peak.
+        assert_eq!(inner_report.peak_bytes_above_baseline(), Some(500));
+        assert_eq!(outer_report.peak_bytes_above_baseline(), Some(1500));
+        assert_eq!(allocated_bytes(), outer_report.baseline_bytes());
+        assert!(inner_report.to_string().starts_with("Peak memory for inner"));
+    }
+
+    #[test]
+    fn test_overlapping_scopes() {
+        let _lock = LOCK.lock().unwrap_or_else(|error| error.into_inner());
+
+        // Start a scope, and an overlapping scope that finishes after it, as on parallel threads.
+        let first = MemoryScope::start("first");
+        TrackingAllocator::record_alloc(300);
+        let second = MemoryScope::start("second");
+        TrackingAllocator::record_alloc(200);
+        TrackingAllocator::record_dealloc(500);
+        let first_report = first.report();
+        drop(first);
+        TrackingAllocator::record_alloc(100);
+        TrackingAllocator::record_dealloc(100);
+        let second_report = second.report();
+        drop(second);
+
+        // Ensure each scope keeps its own peak.
+        assert_eq!(first_report.peak_bytes_above_baseline(), Some(500));
+        assert_eq!(second_report.peak_bytes_above_baseline(), Some(200));
+    }
+
+    #[test]
+    fn test_scope_released_on_early_return() {
+        let _lock = LOCK.lock().unwrap_or_else(|error| error.into_inner());
+
+        // A helper function that returns early, while its scope is alive.
+        fn fails() -> Result<(), ()> {
+            let scope = MemoryScope::start("fails");
+            assert_ne!(reserved_scopes(), 0);
+            Err(())?;
+            crate::end_memory_scope!(scope);
+            Ok(())
+        }
+        // Ensure the early return releases the slot of the scope.
+        assert_eq!(reserved_scopes(), 0);
+        assert!(fails().is_err());
+        assert_eq!(reserved_scopes(), 0);
+    }
+}
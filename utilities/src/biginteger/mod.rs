@@ -15,6 +15,7 @@
 use crate::{rand::Uniform, FromBits, FromBytes, ToBits, ToBytes};
 
 use num_bigint::BigUint;
+use rand::Rng;
 use std::fmt::{Debug, Display};
 
 mod bigint_256;
@@ -93,6 +94,124 @@ pub trait BigInteger:
 
     /// Returns a vector for wnaf.
     fn find_wnaf(&self) -> Vec<i64>;
+
+    /// Returns the full product of `self` and `other`, as its `(low, high)` halves.
+    fn mul_widening(&self, other: &Self) -> (Self, Self) {
+        let (a, b) = (self.as_ref(), other.as_ref());
+        // Compute the schoolbook product over the limbs.
+        let mut product = vec![0u64; 2 * Self::NUM_LIMBS];
+        for i in 0..Self::NUM_LIMBS {
+            let mut carry = 0u64;
+            for j in 0..Self::NUM_LIMBS {
+                product[i + j] = arithmetic::mac_with_carry(product[i + j], a[i], b[j], &mut carry);
+            }
+            product[i + Self::NUM_LIMBS] = carry;
+        }
+        // Split the product into its low and high halves.
+        let (mut low, mut high) = (Self::default(), Self::default());
+        low.as_mut().copy_from_slice(&product[..Self::NUM_LIMBS]);
+        high.as_mut().copy_from_slice(&product[Self::NUM_LIMBS..]);
+        (low, high)
+    }
+
+    /// Returns the quotient and remainder of `self` divided by `divisor`,
+    /// or `None` if `divisor` is zero.
+    fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let mut quotient = Self::default();
+        let remainder = reduce_bits(divisor, (0..self.num_bits() as usize).rev().map(|i| self.get_bit(i)), |i| {
+            quotient.as_mut()[i / 64] |= 1 << (i % 64)
+        });
+        Some((quotient, remainder))
+    }
+
+    /// Returns `self^exponent mod modulus`, or `None` if `modulus` is zero.
+    fn mod_pow(&self, exponent: &Self, modulus: &Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        let base = self.div_rem(modulus)?.1;
+        // Initialize the result to `1 mod modulus`, which is zero when the modulus is one.
+        let mut result = Self::from(1u64).div_rem(modulus)?.1;
+        // Perform square-and-multiply, from the most-significant bit of the exponent.
+        for i in (0..exponent.num_bits() as usize).rev() {
+            result = mul_mod(&result, &result, modulus);
+            if exponent.get_bit(i) {
+                result = mul_mod(&result, &base, modulus);
+            }
+        }
+        Some(result)
+    }
+
+    /// Samples a uniformly random integer in `[0, bound)`, or `None` if `bound` is zero.
+    fn rand_below<R: Rng + ?Sized>(bound: &Self, rng: &mut R) -> Option<Self> {
+        if bound.is_zero() {
+            return None;
+        }
+        let num_bits = bound.num_bits() as usize;
+        // Sample integers of the same bit length as the bound, rejecting any that are out of range.
+        // As the bound is at least 2^(num_bits - 1), each sample is accepted with probability over 1/2.
+        loop {
+            let mut candidate = Self::rand(rng);
+            for (i, limb) in candidate.as_mut().iter_mut().enumerate() {
+                match num_bits.saturating_sub(64 * i) {
+                    0 => *limb = 0,
+                    bits @ 1..=63 => *limb &= (1u64 << bits) - 1,
+                    _ => (),
+                }
+            }
+            if candidate < *bound {
+                return Some(candidate);
+            }
+        }
+    }
+
+    /// Samples a uniformly random integer in `[low, high)`, or `None` if the range is empty.
+    fn rand_in_range<R: Rng + ?Sized>(low: &Self, high: &Self, rng: &mut R) -> Option<Self> {
+        if low >= high {
+            return None;
+        }
+        let mut width = *high;
+        width.sub_noborrow(low);
+        let mut sample = Self::rand_below(&width, rng)?;
+        sample.add_nocarry(low);
+        Some(sample)
+    }
+}
+
+/// Returns `a * b mod modulus`, for a non-zero `modulus`.
+fn mul_mod<B: BigInteger>(a: &B, b: &B, modulus: &B) -> B {
+    let (low, high) = a.mul_widening(b);
+    let bits = (0..2 * 64 * B::NUM_LIMBS).rev().map(|i| match i < 64 * B::NUM_LIMBS {
+        true => low.get_bit(i),
+        false => high.get_bit(i - 64 * B::NUM_LIMBS),
+    });
+    reduce_bits(modulus, bits, |_| ())
+}
+
+/// Returns the remainder of the integer given by `bits` (most-significant first) modulo a non-zero `modulus`,
+/// invoking `on_quotient_bit` with the index of each set bit of the quotient.
+fn reduce_bits<B: BigInteger>(
+    modulus: &B,
+    bits: impl ExactSizeIterator<Item = bool>,
+    mut on_quotient_bit: impl FnMut(usize),
+) -> B {
+    let mut remainder = B::default();
+    let num_bits = bits.len();
+    for (j, bit) in bits.enumerate() {
+        // Compute `remainder = 2 * remainder + bit`, tracking whether the shift overflows the limbs.
+        let overflow = remainder.get_bit(64 * B::NUM_LIMBS - 1);
+        remainder.mul2();
+        remainder.as_mut()[0] |= bit as u64;
+        // As `remainder < 2 * modulus`, a single (wrapping) subtraction suffices.
+        if overflow || remainder >= *modulus {
+            remainder.sub_noborrow(modulus);
+            on_quotient_bit(num_bits - 1 - j);
+        }
+    }
+    remainder
 }
 
 pub mod arithmetic {
@@ -17,6 +17,8 @@ use crate::{
     rand::{TestRng, Uniform},
 };
 
+use rand::Rng;
+
 #[allow(clippy::eq_op)]
 fn biginteger_arithmetic_test<B: BigInteger>(a: B, b: B, zero: B) {
     // zero == zero
@@ -104,6 +106,77 @@ fn biginteger_to_string_test<B: BigInteger>(rng: &mut TestRng) {
     }
 }
 
+fn biginteger_widening_arithmetic_test<B: BigInteger>(rng: &mut TestRng) {
+    const ITERATIONS: usize = 1_000;
+
+    let shift = 64 * B::NUM_LIMBS;
+    for i in 0..ITERATIONS {
+        let a: B = Uniform::rand(rng);
+        // Exercise small divisors and moduli, as well as full-width ones.
+        let mut b: B = Uniform::rand(rng);
+        b.divn((i % shift) as u32);
+        let c: B = Uniform::rand(rng);
+        let (a_ref, b_ref, c_ref) = (a.to_biguint(), b.to_biguint(), c.to_biguint());
+
+        // Check the widening multiplication.
+        let (low, high) = a.mul_widening(&b);
+        assert_eq!((high.to_biguint() << shift) + low.to_biguint(), &a_ref * &b_ref);
+
+        // Check the division with remainder.
+        match b.is_zero() {
+            true => assert!(a.div_rem(&b).is_none()),
+            false => {
+                let (quotient, remainder) = a.div_rem(&b).unwrap();
+                assert_eq!(quotient.to_biguint(), &a_ref / &b_ref);
+                assert_eq!(remainder.to_biguint(), &a_ref % &b_ref);
+            }
+        }
+
+        // Check the modular exponentiation.
+        match b.is_zero() {
+            true => assert!(a.mod_pow(&c, &b).is_none()),
+            false => assert_eq!(a.mod_pow(&c, &b).unwrap().to_biguint(), a_ref.modpow(&c_ref, &b_ref)),
+        }
+    }
+
+    // Check the edge cases.
+    let (zero, one, max) = (B::from(0u64), B::from(1u64), B::read_le(&*vec![u8::MAX; 8 * B::NUM_LIMBS]).unwrap());
+    assert_eq!(max.mul_widening(&one), (max, zero));
+    assert_eq!(max.div_rem(&max), Some((one, zero)));
+    assert_eq!(max.mod_pow(&zero, &one), Some(zero));
+    assert_eq!(max.mod_pow(&zero, &max), Some(one));
+}
+
+fn biginteger_rand_test<B: BigInteger>(rng: &mut TestRng) {
+    const ITERATIONS: usize = 1_000;
+
+    let (zero, one) = (B::from(0u64), B::from(1u64));
+    assert!(B::rand_below(&zero, rng).is_none());
+    assert_eq!(B::rand_below(&one, rng), Some(zero));
+    assert!(B::rand_in_range(&one, &one, rng).is_none());
+
+    for _ in 0..ITERATIONS {
+        let mut low: B = Uniform::rand(rng);
+        let mut high: B = Uniform::rand(rng);
+        low.divn(rng.gen_range(0..64 * B::NUM_LIMBS as u32));
+        if low > high {
+            core::mem::swap(&mut low, &mut high);
+        }
+        match B::rand_in_range(&low, &high, rng) {
+            Some(sample) => assert!(low <= sample && sample < high),
+            None => assert_eq!(low, high),
+        }
+    }
+
+    // Ensure every value in a small range is sampled.
+    let mut seen = [false; 10];
+    for _ in 0..ITERATIONS {
+        let sample = B::rand_in_range(&B::from(5u64), &B::from(15u64), rng).unwrap();
+        seen[sample.as_ref()[0] as usize - 5] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+}
+
 fn test_biginteger<B: BigInteger>(zero: B) {
     let mut rng = TestRng::default();
 
@@ -113,6 +186,8 @@ fn test_biginteger<B: BigInteger>(zero: B) {
     biginteger_bytes_test::<B>(&mut rng);
     biginteger_bits_test::<B>();
     biginteger_to_string_test::<B>(&mut rng);
+    biginteger_widening_arithmetic_test::<B>(&mut rng);
+    biginteger_rand_test::<B>(&mut rng);
 }
 
 #[test]
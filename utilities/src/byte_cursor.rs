@@ -0,0 +1,466 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bounds-checked cursors for reading and writing little-endian bytes.
+//!
+//! A [`ByteReader`] tracks its position in the input, so errors report the byte offset they occurred at.
+//! Errors from nested readers are rebased onto the outer reader, so the reported offset is always relative
+//! to the start of the outermost reader.
+
+use crate::{FromBytes, ToBytes, VarInt};
+
+use std::{
+    fmt,
+    io::{self, Read, Write},
+};
+
+/// An error that occurred at a byte offset in the input.
+#[derive(Debug)]
+pub struct ByteError {
+    /// The byte offset of the error.
+    position: u64,
+    /// The error message.
+    message: String,
+}
+
+impl ByteError {
+    /// Returns the byte offset of the error.
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the byte error in the given I/O error, or `None` if it does not contain one.
+    pub fn from_io_error(error: &io::Error) -> Option<&Self> {
+        error.get_ref().and_then(|error| error.downcast_ref::<Self>())
+    }
+
+    /// Returns the given I/O error as a byte error, with its position offset by `offset`.
+    /// An I/O error without a position is placed at `offset`.
+    fn rebase(error: io::Error, offset: u64) -> io::Error {
+        let (position, message) = match Self::from_io_error(&error) {
+            Some(inner) => (offset.saturating_add(inner.position), inner.message.clone()),
+            None => (offset, error.to_string()),
+        };
+        io::Error::new(error.kind(), Self { position, message })
+    }
+}
+
+impl fmt::Display for ByteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ByteError {}
+
+/// A reader of little-endian bytes, with position tracking, an optional length limit, and one byte of lookahead.
+pub struct ByteReader<R: Read> {
+    /// The underlying reader.
+    reader: R,
+    /// The number of bytes consumed so far.
+    position: u64,
+    /// The number of bytes that may still be consumed, if the reader is limited.
+    remaining: Option<u64>,
+    /// The byte read ahead by `peek_u8`, which is not yet consumed.
+    peeked: Option<u8>,
+}
+
+impl<R: Read> ByteReader<R> {
+    /// Initializes a new byte reader.
+    pub const fn new(reader: R) -> Self {
+        Self { reader, position: 0, remaining: None, peeked: None }
+    }
+
+    /// Initializes a new byte reader that may consume at most `limit` bytes.
+    pub const fn with_limit(reader: R, limit: u64) -> Self {
+        Self { reader, position: 0, remaining: Some(limit), peeked: None }
+    }
+
+    /// Returns the number of bytes consumed so far.
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the number of bytes that may still be consumed, or `None` if the reader is unlimited.
+    pub const fn remaining(&self) -> Option<u64> {
+        self.remaining
+    }
+
+    /// Returns an error with the given message, at the current position.
+    pub fn error<S: ToString>(&self, message: S) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, ByteError { position: self.position, message: message.to_string() })
+    }
+
+    /// Ensures at least `num_bytes` bytes may still be consumed.
+    /// Note: An unlimited reader passes this check, and fails on the read itself if the bytes are missing.
+    pub fn ensure_remaining(&self, num_bytes: u64) -> io::Result<()> {
+        match self.remaining {
+            Some(remaining) if remaining < num_bytes => {
+                Err(self.error(format!("Expected {num_bytes} more bytes, but only {remaining} remain")))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reads a value, rebasing the position of any error onto this reader.
+    pub fn read<T: FromBytes>(&mut self) -> io::Result<T> {
+        let start = self.position;
+        T::read_le(&mut *self).map_err(|error| ByteError::rebase(error, start))
+    }
+
+    /// Reads a `u8`.
+    pub fn read_u8(&mut self) -> io::Result<u8> {
+        self.read()
+    }
+
+    /// Reads a little-endian `u16`.
+    pub fn read_u16(&mut self) -> io::Result<u16> {
+        self.read()
+    }
+
+    /// Reads a little-endian `u32`.
+    pub fn read_u32(&mut self) -> io::Result<u32> {
+        self.read()
+    }
+
+    /// Reads a little-endian `u64`.
+    pub fn read_u64(&mut self) -> io::Result<u64> {
+        self.read()
+    }
+
+    /// Reads a `u8` version, and ensures it is the expected version.
+    pub fn read_version(&mut self, expected: u8, name: &str) -> io::Result<()> {
+        let position = self.position;
+        match self.read_u8()? {
+            version if version == expected => Ok(()),
+            version => Err(ByteError::rebase(
+                error(format!("Invalid {name} version (found '{version}', expected '{expected}')")),
+                position,
+            )),
+        }
+    }
+
+    /// Reads a little-endian `u32` length, and ensures it is at most `max`.
+    /// If the reader is limited, the length is also checked against the remaining bytes,
+    /// as each element takes at least one byte.
+    pub fn read_length_u32(&mut self, max: usize, name: &str) -> io::Result<usize> {
+        let position = self.position;
+        let length = self.read_u32()? as usize;
+        if length > max {
+            return Err(ByteError::rebase(
+                error(format!("Too many {name} (found '{length}', expected at most '{max}')")),
+                position,
+            ));
+        }
+        self.ensure_remaining(length as u64)?;
+        Ok(length)
+    }
+
+    /// Reads an integer in the LEB128 variable-length encoding.
+    pub fn read_varint(&mut self) -> io::Result<u64> {
+        Ok(self.read::<VarInt>()?.0)
+    }
+
+    /// Reads a length in the LEB128 variable-length encoding, and ensures it is at most `max`.
+    /// If the reader is limited, the length is also checked against the remaining bytes,
+    /// as each element takes at least one byte.
+    pub fn read_length_varint(&mut self, max: usize, name: &str) -> io::Result<usize> {
+        let position = self.position;
+        let length = self.read_varint()?;
+        if length > max as u64 {
+            return Err(ByteError::rebase(
+                error(format!("Too many {name} (found '{length}', expected at most '{max}')")),
+                position,
+            ));
+        }
+        self.ensure_remaining(length)?;
+        Ok(length as usize)
+    }
+
+    /// Returns the next byte, without consuming it.
+    pub fn peek_u8(&mut self) -> io::Result<u8> {
+        if let Some(byte) = self.peeked {
+            return Ok(byte);
+        }
+        self.ensure_remaining(1)?;
+        let mut byte = [0u8; 1];
+        self.reader.read_exact(&mut byte).map_err(|error| ByteError::rebase(error, self.position))?;
+        self.peeked = Some(byte[0]);
+        Ok(byte[0])
+    }
+
+    /// Reads a value from the next `length` bytes, ensuring the value consumes all of them.
+    /// The position of any error is rebased onto this reader.
+    pub fn scoped<T>(
+        &mut self,
+        length: u64,
+        f: impl FnOnce(&mut ByteReader<&mut Self>) -> io::Result<T>,
+    ) -> io::Result<T> {
+        let start = self.position;
+        self.ensure_remaining(length)?;
+        let mut scope = ByteReader::with_limit(&mut *self, length);
+        let value = f(&mut scope).map_err(|error| ByteError::rebase(error, start))?;
+        match scope.remaining {
+            Some(0) => Ok(value),
+            Some(remaining) => Err(ByteError::rebase(scope.error(format!("{remaining} unread bytes")), start)),
+            None => unreachable!("A scoped reader is always limited"),
+        }
+    }
+}
+
+impl<R: Read> Read for ByteReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        // Serve the peeked byte first.
+        if let Some(byte) = self.peeked.take() {
+            buf[0] = byte;
+            self.consume(1);
+            return Ok(1);
+        }
+        // Truncate the read to the remaining bytes.
+        let max = match self.remaining {
+            Some(remaining) => buf.len().min(usize::try_from(remaining).unwrap_or(usize::MAX)),
+            None => buf.len(),
+        };
+        if max == 0 {
+            return Ok(0);
+        }
+        let num_bytes = self.reader.read(&mut buf[..max])?;
+        self.consume(num_bytes as u64);
+        Ok(num_bytes)
+    }
+}
+
+impl<R: Read> ByteReader<R> {
+    /// Advances the position by the given number of bytes.
+    fn consume(&mut self, num_bytes: u64) {
+        self.position = self.position.saturating_add(num_bytes);
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining = remaining.saturating_sub(num_bytes);
+        }
+    }
+}
+
+/// Returns an I/O error with the given message.
+fn error(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// A writer of little-endian bytes, with position tracking and checked length prefixes.
+pub struct ByteWriter<W: Write> {
+    /// The underlying writer.
+    writer: W,
+    /// The number of bytes written so far.
+    position: u64,
+}
+
+impl<W: Write> ByteWriter<W> {
+    /// Initializes a new byte writer.
+    pub const fn new(writer: W) -> Self {
+        Self { writer, position: 0 }
+    }
+
+    /// Returns the number of bytes written so far.
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes a value.
+    pub fn write<T: ToBytes>(&mut self, value: &T) -> io::Result<()> {
+        value.write_le(&mut *self)
+    }
+
+    /// Writes a `u8`.
+    pub fn write_u8(&mut self, value: u8) -> io::Result<()> {
+        self.write(&value)
+    }
+
+    /// Writes a little-endian `u16`.
+    pub fn write_u16(&mut self, value: u16) -> io::Result<()> {
+        self.write(&value)
+    }
+
+    /// Writes a little-endian `u32`.
+    pub fn write_u32(&mut self, value: u32) -> io::Result<()> {
+        self.write(&value)
+    }
+
+    /// Writes a little-endian `u64`.
+    pub fn write_u64(&mut self, value: u64) -> io::Result<()> {
+        self.write(&value)
+    }
+
+    /// Writes the given length as a little-endian `u32`, ensuring it is at most `max`.
+    pub fn write_length_u32(&mut self, length: usize, max: usize, name: &str) -> io::Result<()> {
+        if length > max {
+            return Err(error(format!("Too many {name} (found '{length}', expected at most '{max}')")));
+        }
+        let length = u32::try_from(length).map_err(|_| error(format!("Too many {name} (found '{length}')")))?;
+        self.write_u32(length)
+    }
+
+    /// Writes an integer in the LEB128 variable-length encoding.
+    pub fn write_varint(&mut self, value: u64) -> io::Result<()> {
+        self.write(&VarInt(value))
+    }
+
+    /// Writes the given length in the LEB128 variable-length encoding, ensuring it is at most `max`.
+    pub fn write_length_varint(&mut self, length: usize, max: usize, name: &str) -> io::Result<()> {
+        if length > max {
+            return Err(error(format!("Too many {name} (found '{length}', expected at most '{max}')")));
+        }
+        self.write_varint(length as u64)
+    }
+}
+
+impl<W: Write> Write for ByteWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let num_bytes = self.writer.write(buf)?;
+        self.position = self.position.saturating_add(num_bytes as u64);
+        Ok(num_bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A pair of values, where the second value is read with its own byte reader.
+    #[derive(Debug, PartialEq)]
+    struct Pair(u16, Inner);
+
+    #[derive(Debug, PartialEq)]
+    struct Inner(u8);
+
+    impl FromBytes for Inner {
+        fn read_le<R: Read>(reader: R) -> io::Result<Self> {
+            let mut reader = ByteReader::new(reader);
+            reader.read_version(1, "inner")?;
+            Ok(Self(reader.read_u8()?))
+        }
+    }
+
+    impl FromBytes for Pair {
+        fn read_le<R: Read>(reader: R) -> io::Result<Self> {
+            let mut reader = ByteReader::new(reader);
+            Ok(Self(reader.read_u16()?, reader.read()?))
+        }
+    }
+
+    /// Returns the position of the byte error in the given result.
+    fn position<T>(result: io::Result<T>) -> u64 {
+        ByteError::from_io_error(&result.err().unwrap()).unwrap().position()
+    }
+
+    #[test]
+    fn test_read() {
+        let bytes = [1u8, 0, 2, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0];
+        let mut reader = ByteReader::new(&bytes[..]);
+        assert_eq!(reader.peek_u8().unwrap(), 1);
+        assert_eq!(reader.position(), 0);
+        assert_eq!(reader.read_u16().unwrap(), 1);
+        assert_eq!(reader.read_u32().unwrap(), 2);
+        assert_eq!(reader.read_u64().unwrap(), 3);
+        assert_eq!(reader.position(), 14);
+        // Ensure reading past the end reports the position.
+        assert_eq!(position(reader.read_u8()), 14);
+    }
+
+    #[test]
+    fn test_nested_error_position() {
+        // Ensure a valid pair is read.
+        assert_eq!(Pair::read_le(&[7u8, 0, 1, 9][..]).unwrap(), Pair(7, Inner(9)));
+        // Ensure an invalid inner version is reported at its absolute position.
+        assert_eq!(position(Pair::read_le(&[7u8, 0, 2, 9][..])), 2);
+        // Ensure a truncated inner value is reported at the start of the missing value.
+        assert_eq!(position(Pair::read_le(&[7u8, 0, 1][..])), 3);
+    }
+
+    #[test]
+    fn test_limit() {
+        let bytes = [0u8; 8];
+        let mut reader = ByteReader::with_limit(&bytes[..], 3);
+        assert!(reader.ensure_remaining(3).is_ok());
+        assert!(reader.ensure_remaining(4).is_err());
+        assert_eq!(reader.read_u16().unwrap(), 0);
+        assert_eq!(reader.remaining(), Some(1));
+        // Ensure the limit is enforced, even though the underlying reader has more bytes.
+        assert!(reader.read_u16().is_err());
+    }
+
+    #[test]
+    fn test_read_length_u32() {
+        let bytes = [3u8, 0, 0, 0, 1, 2, 3];
+        assert_eq!(ByteReader::new(&bytes[..]).read_length_u32(3, "elements").unwrap(), 3);
+        assert_eq!(position(ByteReader::new(&bytes[..]).read_length_u32(2, "elements")), 0);
+        // Ensure a length beyond the remaining bytes is rejected.
+        assert!(ByteReader::with_limit(&bytes[..], 6).read_length_u32(3, "elements").is_err());
+    }
+
+    #[test]
+    fn test_varint_length() {
+        let mut writer = ByteWriter::new(vec![]);
+        writer.write_length_varint(300, 300, "elements").unwrap();
+        assert!(writer.write_length_varint(301, 300, "elements").is_err());
+        let bytes = writer.into_inner();
+        assert_eq!(bytes, [0xac, 0x02]);
+
+        assert_eq!(ByteReader::new(&bytes[..]).read_length_varint(300, "elements").unwrap(), 300);
+        assert_eq!(position(ByteReader::new(&bytes[..]).read_length_varint(299, "elements")), 0);
+    }
+
+    #[test]
+    fn test_scoped() {
+        let bytes = [5u8, 1, 2, 3, 4];
+        let mut reader = ByteReader::new(&bytes[..]);
+        assert_eq!(reader.read_u8().unwrap(), 5);
+        // Ensure the scope must consume all of its bytes.
+        let scoped = reader.scoped(4, |scope| scope.read_u16());
+        assert_eq!(position(scoped), 3);
+
+        // Ensure a scope that consumes all of its bytes succeeds.
+        let mut reader = ByteReader::new(&bytes[..]);
+        assert_eq!(reader.scoped(2, |scope| scope.read_u16()).unwrap(), 261);
+        // Ensure an error in a scope is reported at its absolute position.
+        assert_eq!(position(reader.scoped(3, |scope| scope.read_u32())), 2);
+    }
+
+    #[test]
+    fn test_write() {
+        let mut writer = ByteWriter::new(vec![]);
+        writer.write_u8(1).unwrap();
+        writer.write_u16(2).unwrap();
+        writer.write_length_u32(3, 3, "elements").unwrap();
+        writer.write_u64(4).unwrap();
+        assert!(writer.write_length_u32(4, 3, "elements").is_err());
+        assert_eq!(writer.position(), 15);
+        assert_eq!(writer.into_inner(), [1u8, 2, 0, 3, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+    }
+}
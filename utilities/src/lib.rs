@@ -41,6 +41,9 @@ pub use std::{boxed::Box, vec::Vec};
 #[macro_use]
 extern crate thiserror;
 
+#[macro_use]
+pub mod alloc_tracking;
+
 pub mod biginteger;
 pub use biginteger::*;
 
@@ -51,6 +54,11 @@ pub use bititerator::*;
 pub mod bits;
 pub use bits::*;
 
+#[cfg(feature = "std")]
+pub mod byte_cursor;
+#[cfg(feature = "std")]
+pub use byte_cursor::*;
+
 #[macro_use]
 pub mod bytes;
 pub use bytes::*;
@@ -71,6 +79,9 @@ pub use self::rand::*;
 pub mod serialize;
 pub use serialize::*;
 
+pub mod varint;
+pub use varint::*;
+
 #[cfg(not(feature = "std"))]
 pub mod io;
 
@@ -65,6 +65,21 @@ impl TestRng {
         Self(XorShiftRng::seed_from_u64(seed))
     }
 
+    /// Returns a `TestRng` whose seed is derived from the given namespace (e.g. `"module::case"`),
+    /// so that each test case has its own fixed, reproducible sequence of values.
+    pub fn for_namespace(namespace: &str) -> Self {
+        println!("\nInitializing 'TestRng' for namespace '{namespace}'");
+        Self::fixed(namespace_seed(namespace))
+    }
+
+    /// Returns a new `TestRng` forked from `self` for the given namespace.
+    ///
+    /// The forked RNG is independent of any later use of `self`, and forks with distinct
+    /// namespaces produce distinct sequences, even when forked from the same state.
+    pub fn fork(&mut self, namespace: &str) -> Self {
+        Self::from_seed(self.gen::<u64>() ^ namespace_seed(namespace))
+    }
+
     /// Returns a randomly-sampled `String`, given the maximum size in bytes and an RNG.
     ///
     /// Some of the snarkVM internal tests involve the random generation of strings,
@@ -128,6 +143,16 @@ impl TestRng {
     }
 }
 
+/// Returns a `TestRng` whose seed is derived from the given namespace (e.g. `"module::case"`).
+pub fn test_rng_for(namespace: &str) -> TestRng {
+    TestRng::for_namespace(namespace)
+}
+
+/// Returns the 64-bit FNV-1a hash of the given namespace, which is stable across platforms and releases.
+fn namespace_seed(namespace: &str) -> u64 {
+    namespace.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
 impl rand::RngCore for TestRng {
     fn next_u32(&mut self) -> u32 {
         self.0.next_u32()
@@ -147,3 +172,21 @@ impl rand::RngCore for TestRng {
 }
 
 impl rand::CryptoRng for TestRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespaced_rng() {
+        // Ensure the namespaced RNG is reproducible.
+        let expected = TestRng::for_namespace("rand::tests::a").gen::<u64>();
+        assert_eq!(expected, test_rng_for("rand::tests::a").gen::<u64>());
+        assert_ne!(expected, test_rng_for("rand::tests::b").gen::<u64>());
+
+        // Ensure the forks are reproducible, and distinct across namespaces.
+        let fork_a = TestRng::from_seed(123456789).fork("a").gen::<u64>();
+        assert_eq!(fork_a, TestRng::from_seed(123456789).fork("a").gen::<u64>());
+        assert_ne!(fork_a, TestRng::from_seed(123456789).fork("b").gen::<u64>());
+    }
+}
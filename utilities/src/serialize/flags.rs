@@ -32,8 +32,21 @@ impl Flags for EmptyFlags {
     }
 }
 
-/// Flags to be encoded into the serialization.
+/// Flags to be encoded into the serialization of a short Weierstrass point.
 /// The default flags (empty) should not change the binary representation.
+///
+/// The flags occupy the two most-significant bits of the last byte of the encoding,
+/// which is the last byte of `x` in compressed form, and the last byte of `y` in uncompressed form:
+/// - bit 7 (`PositiveY`) is set iff the point is compressed, is not infinity, and `y > -y`, where `y` and `-y`
+///   are compared as field elements (i.e. by their canonical integer representations, or
+///   lexicographically by `(c1, c0)` in an extension field), so the set bit selects the largest `y`.
+///   This bit is ignored when deserializing an uncompressed point.
+/// - bit 6 (`Infinity`) is set iff the point is the point at infinity.
+/// - Setting both bits is invalid and is always rejected.
+///
+/// The point at infinity is encoded with coordinates `x = 0` (compressed), or `x = 0, y = 1` (uncompressed).
+/// When deserializing with `Validate::Yes` (strict), any other coordinates alongside the infinity bit are rejected;
+/// with `Validate::No` (lenient), they are ignored.
 #[derive(Clone, Copy, Default, PartialEq, Eq)]
 pub enum SWFlags {
     Infinity,
@@ -97,8 +110,16 @@ impl Flags for SWFlags {
     }
 }
 
-/// Flags to be encoded into the serialization.
+/// Flags to be encoded into the compressed serialization of a twisted Edwards point.
 /// The default flags (empty) should not change the binary representation.
+///
+/// The flag occupies the most-significant bit of the last byte of the encoding of `x`:
+/// - bit 7 (`PositiveY`) is set iff `y > -y`, where `y` and `-y` are compared as field elements,
+///   so the set bit selects the largest `y`.
+///
+/// A zero `x` always decodes to the identity `(0, 1)`, which is encoded with the bit unset.
+/// When deserializing with `Validate::Yes` (strict), a zero `x` with the bit set is rejected;
+/// with `Validate::No` (lenient), the bit is ignored.
 #[derive(Clone, Copy, Default, PartialEq, Eq)]
 pub enum EdwardsFlags {
     PositiveY,
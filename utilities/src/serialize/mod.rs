@@ -24,6 +24,10 @@ pub use impls::*;
 mod flags;
 pub use flags::*;
 
+pub mod serde_decimal;
+
+pub mod serde_hex;
+
 mod traits;
 pub use traits::*;
 
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serde helpers that encode a type as its decimal string representation.
+//!
+//! Human-readable formats (e.g. JSON) use the `Display` and `FromStr` implementations, which are decimal
+//! for field elements and scalars. Binary formats (e.g. bincode) use the compressed canonical encoding,
+//! matching [`crate::serialize::serde_hex`]:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Foo {
+//!     #[serde(with = "snarkvm_utilities::serialize::serde_decimal")]
+//!     scalar: Fr,
+//! }
+//! ```
+
+use crate::{
+    fmt::Display,
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    str::FromStr,
+    string::String,
+    Vec,
+};
+
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes the given value as a decimal string (or as bytes, for binary formats).
+pub fn serialize<T: Display + CanonicalSerialize, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    match serializer.is_human_readable() {
+        true => serializer.collect_str(value),
+        false => super::serde_hex::to_compressed_bytes(value).map_err(ser::Error::custom)?.serialize(serializer),
+    }
+}
+
+/// Deserializes a value from a decimal string (or from bytes, for binary formats).
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr + CanonicalDeserialize,
+    <T as FromStr>::Err: Display,
+    D: Deserializer<'de>,
+{
+    match deserializer.is_human_readable() {
+        true => T::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+        false => {
+            super::serde_hex::from_compressed_bytes(&Vec::<u8>::deserialize(deserializer)?).map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Foo(u64);
+
+    impl Serialize for Foo {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Foo {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::deserialize(deserializer).map(Foo)
+        }
+    }
+
+    #[test]
+    fn test_serde_json() {
+        let expected = Foo(1234567890);
+        let candidate = serde_json::to_string(&expected).unwrap();
+        assert_eq!(candidate, r#""1234567890""#);
+        assert_eq!(expected, serde_json::from_str(&candidate).unwrap());
+        assert!(serde_json::from_str::<Foo>(r#""0x12""#).is_err());
+    }
+
+    #[test]
+    fn test_bincode() {
+        let expected = Foo(1234567890);
+        let candidate = bincode::serialize(&expected).unwrap();
+        assert_eq!(expected, bincode::deserialize(&candidate).unwrap());
+        // Ensure trailing bytes are rejected.
+        let candidate = bincode::serialize(&[0u8; 9][..]).unwrap();
+        assert!(bincode::deserialize::<Foo>(&candidate).is_err());
+    }
+}
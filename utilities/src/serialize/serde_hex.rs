@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serde helpers that encode a `CanonicalSerialize` type as a hex string of its compressed encoding.
+//!
+//! Human-readable formats (e.g. JSON) use a lowercase hex string (an optional `0x` prefix is accepted
+//! when deserializing), and binary formats (e.g. bincode) use the raw compressed bytes.
+//! This applies to field elements, scalars, and affine group elements alike:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Foo {
+//!     #[serde(with = "snarkvm_utilities::serialize::serde_hex")]
+//!     point: G1Affine,
+//! }
+//! ```
+
+use crate::{
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    string::String,
+    Vec,
+};
+
+use anyhow::{ensure, Result};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes the given value as a hex string (or as bytes, for binary formats).
+pub fn serialize<T: CanonicalSerialize, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    let bytes = to_compressed_bytes(value).map_err(ser::Error::custom)?;
+    match serializer.is_human_readable() {
+        true => serializer.serialize_str(&hex::encode(bytes)),
+        false => bytes.serialize(serializer),
+    }
+}
+
+/// Deserializes a value from a hex string (or from bytes, for binary formats).
+pub fn deserialize<'de, T: CanonicalDeserialize, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let bytes = match deserializer.is_human_readable() {
+        true => {
+            let string = String::deserialize(deserializer)?;
+            // Strip the optional prefix.
+            hex::decode(string.strip_prefix("0x").unwrap_or(&string)).map_err(de::Error::custom)?
+        }
+        false => Vec::<u8>::deserialize(deserializer)?,
+    };
+    from_compressed_bytes(&bytes).map_err(de::Error::custom)
+}
+
+/// Returns the compressed canonical encoding of the given value.
+pub(super) fn to_compressed_bytes<T: CanonicalSerialize>(value: &T) -> Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value.serialize_compressed(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Returns the value for the given compressed canonical encoding, ensuring every byte is consumed.
+pub(super) fn from_compressed_bytes<T: CanonicalDeserialize>(bytes: &[u8]) -> Result<T> {
+    let mut reader = bytes;
+    let value = T::deserialize_compressed(&mut reader)?;
+    // Ensure there are no trailing bytes.
+    ensure!(reader.is_empty(), "Found {} trailing bytes after the compressed encoding", reader.len());
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Foo(u64);
+
+    impl Serialize for Foo {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Foo {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::deserialize(deserializer).map(Foo)
+        }
+    }
+
+    #[test]
+    fn test_serde_json() {
+        let expected = Foo(0x0102_0304_0506_0708);
+        let candidate = serde_json::to_string(&expected).unwrap();
+        assert_eq!(candidate, r#""0807060504030201""#);
+        assert_eq!(expected, serde_json::from_str(&candidate).unwrap());
+        assert_eq!(expected, serde_json::from_str(r#""0x0807060504030201""#).unwrap());
+        assert!(serde_json::from_str::<Foo>(r#""08070605""#).is_err());
+        assert!(serde_json::from_str::<Foo>(r#""080706050403020""#).is_err());
+        assert!(serde_json::from_str::<Foo>(r#""080706050403020g""#).is_err());
+        // Ensure trailing bytes are rejected.
+        assert!(serde_json::from_str::<Foo>(r#""080706050403020100""#).is_err());
+    }
+
+    #[test]
+    fn test_bincode() {
+        let expected = Foo(0x0102_0304_0506_0708);
+        let candidate = bincode::serialize(&expected).unwrap();
+        assert_eq!(expected, bincode::deserialize(&candidate).unwrap());
+        // Ensure trailing bytes are rejected.
+        let candidate = bincode::serialize(&[8u8, 7, 6, 5, 4, 3, 2, 1, 0][..]).unwrap();
+        assert!(bincode::deserialize::<Foo>(&candidate).is_err());
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Big-endian and variable-length integer encodings.
+//!
+//! The [`ToBytes`] and [`FromBytes`] traits encode integers in little-endian order. The [`ToBytesBe`] and
+//! [`FromBytesBe`] traits are their big-endian counterparts, for wire formats in network byte order, and
+//! [`VarInt`] encodes an unsigned integer in the LEB128 variable-length encoding, for compact length prefixes.
+
+use crate::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+    Vec,
+};
+
+/// Writes `self` as big-endian bytes.
+pub trait ToBytesBe {
+    /// Writes `self` into `writer` as big-endian bytes.
+    fn write_be<W: Write>(&self, writer: W) -> IoResult<()>
+    where
+        Self: Sized;
+
+    /// Returns `self` as a byte array in big-endian order.
+    fn to_bytes_be(&self) -> anyhow::Result<Vec<u8>>
+    where
+        Self: Sized,
+    {
+        let mut buffer = Vec::new();
+        self.write_be(&mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+/// Reads `Self` from big-endian bytes.
+pub trait FromBytesBe {
+    /// Reads `Self` from `reader` as big-endian bytes.
+    fn read_be<R: Read>(reader: R) -> IoResult<Self>
+    where
+        Self: Sized;
+
+    /// Returns `Self` from a byte array in big-endian order.
+    fn from_bytes_be(bytes: &[u8]) -> anyhow::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self::read_be(bytes)?)
+    }
+}
+
+macro_rules! impl_bytes_be_for_integer {
+    ($int:ty) => {
+        impl ToBytesBe for $int {
+            #[inline]
+            fn write_be<W: Write>(&self, mut writer: W) -> IoResult<()> {
+                writer.write_all(&self.to_be_bytes())
+            }
+        }
+
+        impl FromBytesBe for $int {
+            #[inline]
+            fn read_be<R: Read>(mut reader: R) -> IoResult<Self> {
+                let mut bytes = [0u8; core::mem::size_of::<$int>()];
+                reader.read_exact(&mut bytes)?;
+                Ok(<$int>::from_be_bytes(bytes))
+            }
+        }
+    };
+}
+
+impl_bytes_be_for_integer!(u8);
+impl_bytes_be_for_integer!(u16);
+impl_bytes_be_for_integer!(u32);
+impl_bytes_be_for_integer!(u64);
+impl_bytes_be_for_integer!(u128);
+
+impl_bytes_be_for_integer!(i8);
+impl_bytes_be_for_integer!(i16);
+impl_bytes_be_for_integer!(i32);
+impl_bytes_be_for_integer!(i64);
+impl_bytes_be_for_integer!(i128);
+
+/// An unsigned integer in the LEB128 variable-length encoding.
+///
+/// Each byte holds 7 bits of the integer, starting from the least significant bits, and its most significant
+/// bit is set if more bytes follow. Only the shortest encoding of each integer is accepted, so that every
+/// integer has exactly one encoding.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VarInt(pub u64);
+
+impl VarInt {
+    /// The maximum number of bytes in the encoding of a `u64`.
+    pub const MAX_ENCODED_LEN: usize = 10;
+
+    /// Returns the number of bytes in the encoding of the integer.
+    pub const fn encoded_len(&self) -> usize {
+        let num_bits = u64::BITS - self.0.leading_zeros();
+        match num_bits {
+            0 => 1,
+            _ => ((num_bits + 6) / 7) as usize,
+        }
+    }
+}
+
+impl From<u64> for VarInt {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<VarInt> for u64 {
+    fn from(value: VarInt) -> Self {
+        value.0
+    }
+}
+
+impl ToBytes for VarInt {
+    /// Writes the integer in the LEB128 encoding.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let mut buffer = [0u8; Self::MAX_ENCODED_LEN];
+        let mut value = self.0;
+        let mut num_bytes = 0;
+        loop {
+            // Write the next 7 bits, and set the continuation bit if more bits remain.
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            buffer[num_bytes] = if value == 0 { byte } else { byte | 0x80 };
+            num_bytes += 1;
+            if value == 0 {
+                break;
+            }
+        }
+        writer.write_all(&buffer[..num_bytes])
+    }
+}
+
+impl FromBytes for VarInt {
+    /// Reads an integer in the LEB128 encoding, rejecting overlong and overflowing encodings.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut value = 0u64;
+        for index in 0..Self::MAX_ENCODED_LEN {
+            let byte = u8::read_le(&mut reader)?;
+            // Ensure the last byte of a `u64` holds only its most significant bit.
+            if index == Self::MAX_ENCODED_LEN - 1 && byte > 1 {
+                return Err(error("Variable-length integer overflows a u64"));
+            }
+            value |= ((byte & 0x7f) as u64) << (7 * index);
+            if byte & 0x80 == 0 {
+                // Ensure the encoding is the shortest one, i.e. it does not end with a zero byte.
+                if byte == 0 && index > 0 {
+                    return Err(error("Variable-length integer is not minimally encoded"));
+                }
+                return Ok(Self(value));
+            }
+        }
+        Err(error("Variable-length integer overflows a u64"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestRng;
+
+    use rand::Rng;
+
+    const ITERATIONS: usize = 10_000;
+
+    #[test]
+    fn test_big_endian() {
+        // Check against the standard library.
+        assert_eq!(0x0102u16.to_bytes_be().unwrap(), [1, 2]);
+        assert_eq!(0x01020304u32.to_bytes_be().unwrap(), [1, 2, 3, 4]);
+        assert_eq!((-2i16).to_bytes_be().unwrap(), [0xff, 0xfe]);
+        assert_eq!(u64::from_bytes_be(&[0, 0, 0, 0, 0, 0, 1, 0]).unwrap(), 256);
+        assert!(u32::from_bytes_be(&[1, 2, 3]).is_err());
+
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let value: u128 = rng.gen();
+            assert_eq!(value.to_bytes_be().unwrap(), value.to_be_bytes());
+            assert_eq!(u128::from_bytes_be(&value.to_be_bytes()).unwrap(), value);
+            let value: i64 = rng.gen();
+            assert_eq!(i64::from_bytes_be(&value.to_bytes_be().unwrap()).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_varint_vectors() {
+        // The test vectors from the LEB128 encodings in DWARF and Protocol Buffers.
+        for (value, expected) in [
+            (0u64, &[0x00][..]),
+            (1, &[0x01]),
+            (2, &[0x02]),
+            (127, &[0x7f]),
+            (128, &[0x80, 0x01]),
+            (129, &[0x81, 0x01]),
+            (150, &[0x96, 0x01]),
+            (300, &[0xac, 0x02]),
+            (12857, &[0xb9, 0x64]),
+            (16383, &[0xff, 0x7f]),
+            (16384, &[0x80, 0x80, 0x01]),
+            (624485, &[0xe5, 0x8e, 0x26]),
+            (u32::MAX as u64, &[0xff, 0xff, 0xff, 0xff, 0x0f]),
+            (u64::MAX, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]),
+        ] {
+            let varint = VarInt(value);
+            assert_eq!(varint.to_bytes_le().unwrap(), expected, "{value}");
+            assert_eq!(varint.encoded_len(), expected.len(), "{value}");
+            assert_eq!(VarInt::from_bytes_le(expected).unwrap(), varint, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_varint_rejects_invalid_encodings() {
+        // Ensure overlong encodings are rejected.
+        assert!(VarInt::from_bytes_le(&[0x80, 0x00]).is_err());
+        assert!(VarInt::from_bytes_le(&[0x81, 0x80, 0x00]).is_err());
+        // Ensure encodings that overflow a u64 are rejected.
+        assert!(VarInt::from_bytes_le(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02]).is_err());
+        assert!(VarInt::from_bytes_le(&[0x80; 11]).is_err());
+        // Ensure truncated encodings are rejected.
+        assert!(VarInt::from_bytes_le(&[]).is_err());
+        assert!(VarInt::from_bytes_le(&[0x80]).is_err());
+    }
+
+    #[test]
+    fn test_varint_roundtrip() {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            // Sample integers of every bit length.
+            let value = rng.gen::<u64>() >> rng.gen_range(0..64);
+            let bytes = VarInt(value).to_bytes_le().unwrap();
+            assert_eq!(bytes.len(), VarInt(value).encoded_len());
+            assert_eq!(VarInt::from_bytes_le(&bytes).unwrap(), VarInt(value));
+        }
+    }
+}
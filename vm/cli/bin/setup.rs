@@ -0,0 +1,30 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm::cli::setup::Setup;
+
+use clap::Parser;
+
+fn main() {
+    // Parse the given arguments.
+    let setup = Setup::parse();
+    // Run the setup.
+    match setup.run() {
+        Ok(output) => println!("{output}\n"),
+        Err(error) => {
+            eprintln!("⚠️  {error:#}\n");
+            std::process::exit(1);
+        }
+    }
+}
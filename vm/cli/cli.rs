@@ -20,7 +20,7 @@ use clap::{builder::Styles, Parser};
 
 const HEADER_COLOR: Option<Color> = Some(Color::Ansi(AnsiColor::Yellow));
 const LITERAL_COLOR: Option<Color> = Some(Color::Ansi(AnsiColor::Green));
-const STYLES: Styles = Styles::plain()
+pub(crate) const STYLES: Styles = Styles::plain()
     .header(Style::new().bold().fg_color(HEADER_COLOR))
     .usage(Style::new().bold().fg_color(HEADER_COLOR))
     .literal(Style::new().bold().fg_color(LITERAL_COLOR));
@@ -23,3 +23,6 @@ pub use errors::*;
 
 pub mod helpers;
 pub use helpers::*;
+
+#[cfg(feature = "setup")]
+pub mod setup;
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::synthesizer::{Process, Program};
+
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use serde_json::json;
+
+/// Synthesizes the circuit keys of the credits program.
+#[derive(Debug, Parser)]
+pub struct Credits {
+    /// The seed of the ChaCha RNG used to synthesize the circuit keys.
+    /// Running the setup twice with the same seed writes byte-identical parameters.
+    #[clap(default_value = "1245897092", long)]
+    pub seed: u64,
+}
+
+impl Credits {
+    /// Writes the metadata, transcript, and circuit keys of each function in the credits program.
+    pub fn parse(self, options: &SetupOptions) -> Result<String> {
+        // Initialize an RNG from the seed.
+        let rng = &mut ChaChaRng::seed_from_u64(self.seed);
+        self.setup_with_rng(options, rng)
+    }
+
+    /// Writes the metadata, transcript, and circuit keys of each function in the credits program,
+    /// synthesizing the circuit keys with the given RNG.
+    pub fn setup_with_rng<R: Rng + CryptoRng>(&self, options: &SetupOptions, rng: &mut R) -> Result<String> {
+        // Initialize the process.
+        let process = Process::setup::<Aleo, _>(rng)?;
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::credits()?;
+        let program_id = program.id();
+
+        let mut uploads = vec![];
+        for function_name in program.functions().keys() {
+            let proving_key = process.get_proving_key(program_id, function_name)?;
+            let proving_key_bytes = proving_key.to_bytes_le()?;
+            let proving_key_checksum = checksum(&proving_key_bytes);
+
+            let verifying_key = process.get_verifying_key(program_id, function_name)?;
+            let verifying_key_bytes = verifying_key.to_bytes_le()?;
+            let verifying_key_checksum = checksum(&verifying_key_bytes);
+
+            // Record the setup in a transcript.
+            let metadata_filename = format!("{function_name}.metadata");
+            let mut transcript = SetupTranscript::new("credits", options.hostname.as_deref());
+            transcript.record(
+                "input",
+                json!({
+                    "seed": self.seed,
+                    "rng": "ChaCha20",
+                    "program_id": program_id.to_string(),
+                    "function_name": function_name.to_string(),
+                }),
+            );
+            let circuit_info = verifying_key.circuit_info;
+            transcript.record(
+                "degrees",
+                json!({
+                    "num_public_inputs": circuit_info.num_public_inputs,
+                    "num_variables": circuit_info.num_variables,
+                    "num_constraints": circuit_info.num_constraints,
+                    "num_non_zero_a": circuit_info.num_non_zero_a,
+                    "num_non_zero_b": circuit_info.num_non_zero_b,
+                    "num_non_zero_c": circuit_info.num_non_zero_c,
+                }),
+            );
+            transcript.record_output("prover", &proving_key_bytes);
+            transcript.record_output("verifier", &verifying_key_bytes);
+            let transcript_digest = write_transcript(options, &metadata_filename, &transcript)?;
+
+            let metadata = json!({
+                "prover_checksum": proving_key_checksum,
+                "prover_size": proving_key_bytes.len(),
+                "verifier_checksum": verifying_key_checksum,
+                "verifier_size": verifying_key_bytes.len(),
+                "transcript_digest": transcript_digest,
+            });
+
+            write_metadata(options, &metadata_filename, &metadata)?;
+            uploads.extend(write_remote(
+                options,
+                &format!("{function_name}.prover"),
+                &proving_key_checksum,
+                &proving_key_bytes,
+            )?);
+            write_file(&options.path(&format!("{function_name}.verifier")), &verifying_key_bytes)?;
+        }
+
+        let path_string = format!("(in \"{}\")", options.output.display());
+        Ok(format!(
+            "✅ Synthesized the circuit keys of {} functions in '{}' {}\n\n{}",
+            program.functions().len(),
+            program_id.to_string().bold(),
+            path_string.dimmed(),
+            upload_commands(&uploads)
+        ))
+    }
+}
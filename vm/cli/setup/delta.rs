@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::parameters::delta::{self, Delta as ParameterDelta};
+
+use serde_json::json;
+
+/// Writes the delta between two versions of a parameter file.
+#[derive(Debug, Parser)]
+pub struct Delta {
+    /// The path of the previous version.
+    pub base: PathBuf,
+    /// The path of the new version.
+    pub target: PathBuf,
+}
+
+impl Delta {
+    /// Writes the delta from the previous version to the new version, keyed by the checksum of the previous version.
+    pub fn parse(self, options: &SetupOptions) -> Result<String> {
+        let base = fs::read(&self.base).with_context(|| format!("Failed to read {:?}", self.base))?;
+        let target = fs::read(&self.target).with_context(|| format!("Failed to read {:?}", self.target))?;
+        let delta = ParameterDelta::new(&base, &target);
+
+        // Write the delta, keyed by the checksum of the previous version.
+        let filename = match self.target.file_name().and_then(|name| name.to_str()) {
+            Some(filename) => filename,
+            None => bail!("Invalid parameter file path {:?}", self.target),
+        };
+        let delta_filename = delta::delta_filename(filename, &delta.base_checksum());
+        let delta_bytes = delta.to_bytes_le()?;
+        write_file(&options.path(&delta_filename), &delta_bytes)?;
+
+        Ok(format!(
+            "✅ Wrote {} bytes to \"{}\" ({} bytes in {:?})\n\nNow, add the previous version to the metadata of {:?}:\n\n{}\n\nand upload \"{delta_filename}\"",
+            delta_bytes.len(),
+            options.path(&delta_filename).display(),
+            target.len(),
+            self.target,
+            self.target,
+            serde_json::to_string_pretty(&json!({
+                "bases": [{ "checksum": delta.base_checksum(), "delta_size": delta_bytes.len() }]
+            }))?,
+        ))
+    }
+}
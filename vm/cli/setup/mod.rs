@@ -0,0 +1,220 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod credits;
+pub use credits::*;
+
+pub mod delta;
+pub use delta::*;
+
+pub mod universal;
+pub use universal::*;
+
+use crate::{
+    algorithms::crypto_hash::sha256::sha256,
+    cli::{
+        commands::{Aleo, CurrentNetwork},
+        STYLES,
+    },
+    parameters::{compression, transcript::SetupTranscript},
+    utilities::ToBytes,
+};
+
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand};
+use colored::Colorize;
+use serde_json::Value;
+use std::{
+    fs,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+/// Performs the parameter setups. Run `snarkvm-setup --help` for the list of setups.
+#[derive(Debug, Parser)]
+#[clap(name = "snarkvm-setup", author = "The Aleo Team <hello@aleo.org>", styles = STYLES)]
+pub struct Setup {
+    /// Specify the options shared by every setup.
+    #[clap(flatten)]
+    pub options: SetupOptions,
+    /// Specify a setup.
+    #[clap(subcommand)]
+    pub command: SetupCommand,
+}
+
+impl Setup {
+    /// Runs the setup.
+    pub fn run(self) -> Result<String> {
+        // Ensure the output directory exists.
+        fs::create_dir_all(&self.options.output)
+            .with_context(|| format!("Failed to create the output directory {:?}", self.options.output))?;
+        self.command.parse(&self.options)
+    }
+}
+
+/// The options shared by every setup.
+#[derive(Debug, Args)]
+pub struct SetupOptions {
+    /// The directory to write the parameter files to.
+    #[clap(default_value = ".", short, long, global = true)]
+    pub output: PathBuf,
+    /// The hostname to record in the setup transcripts.
+    #[clap(long, global = true)]
+    pub hostname: Option<String>,
+    /// Also write the remote files zstd-compressed.
+    #[clap(long, global = true)]
+    pub compress: bool,
+}
+
+impl SetupOptions {
+    /// Returns the path of the given filename in the output directory.
+    pub fn path(&self, filename: &str) -> PathBuf {
+        self.output.join(filename)
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SetupCommand {
+    /// Writes the metadata of the universal SRS files.
+    #[clap(name = "universal", alias = "usrs")]
+    Universal(Universal),
+    /// Synthesizes the circuit keys of the credits program.
+    #[clap(name = "credits")]
+    Credits(Credits),
+    /// Writes the delta between two versions of a parameter file.
+    #[clap(name = "delta")]
+    Delta(Delta),
+    /// Performs the universal and credits setups.
+    #[clap(name = "all")]
+    All(All),
+}
+
+impl SetupCommand {
+    /// Parse the setup.
+    pub fn parse(self, options: &SetupOptions) -> Result<String> {
+        match self {
+            Self::Universal(command) => command.parse(options),
+            Self::Credits(command) => command.parse(options),
+            Self::Delta(command) => command.parse(options),
+            Self::All(command) => command.parse(options),
+        }
+    }
+}
+
+/// Performs the universal and credits setups.
+#[derive(Debug, Parser)]
+pub struct All {
+    #[clap(flatten)]
+    universal: Universal,
+    #[clap(flatten)]
+    credits: Credits,
+}
+
+impl All {
+    /// Performs the universal setup, followed by the credits setup.
+    pub fn parse(self, options: &SetupOptions) -> Result<String> {
+        let universal = self.universal.parse(options)?;
+        let credits = self.credits.parse(options)?;
+        Ok(format!("{universal}\n\n{credits}"))
+    }
+}
+
+/// Returns the checksum of the given bytes, in hex.
+fn checksum(bytes: &[u8]) -> String {
+    hex::encode(sha256(bytes))
+}
+
+/// Returns the given filename, suffixed with the first 7 characters of the given checksum.
+fn versioned_filename(filename: &str, checksum: &str) -> String {
+    match checksum.get(0..7) {
+        Some(sum) => format!("{filename}.{sum}"),
+        _ => filename.to_string(),
+    }
+}
+
+/// Writes the given bytes to the given path.
+fn write_file(path: &Path, bytes: &[u8]) -> Result<()> {
+    let mut file = BufWriter::new(File::create(path).with_context(|| format!("Failed to create {path:?}"))?);
+    file.write_all(bytes).with_context(|| format!("Failed to write {path:?}"))?;
+    Ok(())
+}
+
+/// Writes the given bytes to the given versioned filename in the output directory, and returns the filenames to upload.
+/// If `compress` is set, the bytes are also written compressed to the versioned filename with a `.zst` extension.
+fn write_remote(options: &SetupOptions, filename: &str, version: &str, bytes: &[u8]) -> Result<Vec<String>> {
+    let versioned_filename = versioned_filename(filename, version);
+    let mut uploads = vec![];
+    if options.compress {
+        let compressed_bytes = compression::compress(bytes, compression::DEFAULT_COMPRESSION_LEVEL)?;
+        let compressed_filename = compression::compressed_filename(&versioned_filename);
+        write_file(&options.path(&compressed_filename), &compressed_bytes)?;
+        uploads.push(compressed_filename);
+    }
+    write_file(&options.path(&versioned_filename), bytes)?;
+    uploads.push(versioned_filename);
+    Ok(uploads)
+}
+
+/// Writes the given metadata as JSON to the given filename in the output directory.
+fn write_metadata(options: &SetupOptions, filename: &str, metadata: &Value) -> Result<()> {
+    write_file(&options.path(filename), &serde_json::to_vec_pretty(metadata)?)
+}
+
+/// Writes the given transcript for the given metadata filename in the output directory, and returns its digest.
+fn write_transcript(options: &SetupOptions, filename: &str, transcript: &SetupTranscript) -> Result<String> {
+    transcript.write_to(&options.path(filename).with_extension("transcript"))?;
+    Ok(transcript.digest())
+}
+
+/// Returns the commands to upload the given files.
+fn upload_commands(uploads: &[String]) -> String {
+    let commands = uploads.iter().map(|filename| format!("upload \"{filename}\"")).collect::<Vec<_>>();
+    format!("Now, perform the following operations:\n\n{}", commands.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_setup() {
+        use clap::CommandFactory;
+        Setup::command().debug_assert()
+    }
+
+    #[test]
+    fn test_parse_setup() {
+        let setup = Setup::try_parse_from(["snarkvm-setup", "credits", "--seed", "7", "-o", "out"]).unwrap();
+        assert_eq!(setup.options.output, PathBuf::from("out"));
+        assert!(!setup.options.compress);
+        assert!(matches!(setup.command, SetupCommand::Credits(Credits { seed: 7 })));
+
+        let setup = Setup::try_parse_from(["snarkvm-setup", "usrs", "--max-degree", "16", "--compress"]).unwrap();
+        assert!(setup.options.compress);
+        assert!(matches!(setup.command, SetupCommand::Universal(Universal { max_degree: Some(16), .. })));
+
+        // Ensure invalid arguments are reported, instead of panicking.
+        assert!(Setup::try_parse_from(["snarkvm-setup"]).is_err());
+        assert!(Setup::try_parse_from(["snarkvm-setup", "inner"]).is_err());
+        assert!(Setup::try_parse_from(["snarkvm-setup", "credits", "--seed", "seed"]).is_err());
+        assert!(Setup::try_parse_from(["snarkvm-setup", "delta", "base"]).is_err());
+    }
+
+    #[test]
+    fn test_versioned_filename() {
+        assert_eq!(versioned_filename("join.prover", "0123456789abcdef"), "join.prover.0123456");
+        assert_eq!(versioned_filename("join.prover", "012"), "join.prover");
+    }
+}
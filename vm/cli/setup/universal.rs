@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::json;
+
+/// Writes the metadata of the universal SRS files.
+#[derive(Debug, Parser)]
+pub struct Universal {
+    /// The directory containing the universal SRS files.
+    #[clap(default_value = "parameters/src/testnet3/resources", long)]
+    pub resources: PathBuf,
+    /// The maximum degree of the powers of beta to include, as a power of two.
+    #[clap(long)]
+    pub max_degree: Option<u8>,
+}
+
+impl Universal {
+    /// Writes the metadata, transcript, and versioned file of each universal SRS file within the degree bound.
+    pub fn parse(self, options: &SetupOptions) -> Result<String> {
+        // Collect the universal SRS files, in order.
+        let mut paths = vec![];
+        let entries = fs::read_dir(&self.resources)
+            .with_context(|| format!("Failed to read the resources directory {:?}", self.resources))?;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().and_then(|extension| extension.to_str()) == Some("usrs") && self.is_in_bound(&path) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut uploads = vec![];
+        for path in &paths {
+            let file_bytes = fs::read(path).with_context(|| format!("Failed to read {path:?}"))?;
+            let checksum = checksum(&file_bytes);
+            let filename = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+            let metadata_filename = format!("{}.metadata", filename.trim_end_matches(".usrs"));
+
+            // Record the setup in a transcript.
+            let mut transcript = SetupTranscript::new("usrs", options.hostname.as_deref());
+            transcript.record("input", json!({ "path": path.to_str() }));
+            transcript.record_output("usrs", &file_bytes);
+            let transcript_digest = write_transcript(options, &metadata_filename, &transcript)?;
+
+            let metadata = json!({
+                "checksum": checksum,
+                "size": file_bytes.len(),
+                "transcript_digest": transcript_digest,
+            });
+
+            write_metadata(options, &metadata_filename, &metadata)?;
+            uploads.extend(write_remote(options, filename, &checksum, &file_bytes)?);
+        }
+
+        let path_string = format!("(in \"{}\")", options.output.display());
+        Ok(format!(
+            "✅ Wrote the metadata of {} universal SRS files {}\n\n{}",
+            paths.len(),
+            path_string.dimmed(),
+            upload_commands(&uploads)
+        ))
+    }
+
+    /// Returns `true` if the given universal SRS file is within the degree bound.
+    /// Files without a degree suffix, such as `beta-h.usrs`, are always included.
+    fn is_in_bound(&self, path: &Path) -> bool {
+        let degree = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.rsplit('-').next())
+            .and_then(|suffix| suffix.parse::<u8>().ok());
+        match (self.max_degree, degree) {
+            (Some(max_degree), Some(degree)) => degree <= max_degree,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_in_bound() {
+        let universal = Universal { resources: PathBuf::new(), max_degree: Some(16) };
+        assert!(universal.is_in_bound(Path::new("powers-of-beta-15.usrs")));
+        assert!(universal.is_in_bound(Path::new("shifted-powers-of-beta-16.usrs")));
+        assert!(!universal.is_in_bound(Path::new("powers-of-beta-17.usrs")));
+        assert!(universal.is_in_bound(Path::new("beta-h.usrs")));
+        assert!(universal.is_in_bound(Path::new("powers-of-beta-gamma.usrs")));
+
+        let universal = Universal { resources: PathBuf::new(), max_degree: None };
+        assert!(universal.is_in_bound(Path::new("powers-of-beta-28.usrs")));
+    }
+}
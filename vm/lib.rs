@@ -24,6 +24,8 @@ extern crate thiserror;
 pub mod cli;
 pub mod file;
 pub mod package;
+#[cfg(all(feature = "console", feature = "ledger"))]
+pub mod stable;
 
 #[cfg(feature = "algorithms")]
 pub use snarkvm_algorithms as algorithms;
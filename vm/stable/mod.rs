@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A curated, stable subset of the snarkVM API, for wallets and other downstream tools.
+//!
+//! Each module documents its [`Stability`] level in its `STABILITY` constant. The items in a `Stable`
+//! module are not removed, renamed, or changed incompatibly outside a major release, even when the
+//! crates that define them are refactored. A module is only `Stable` if every type it re-exports is
+//! opaque, that is, a struct with private fields or a `#[non_exhaustive]` enum, and if it re-exports
+//! no trait that downstream crates implement, so that adding to a type or trait is not a breaking
+//! change. Anything reachable only through the underlying crates may change in any release.
+
+/// The stability level of a module in the stable API.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Stability {
+    /// Changes follow semantic versioning; breaking changes only land in a major release.
+    Stable,
+    /// Items may still change incompatibly in a minor release, e.g. when an exhaustive enum gains a variant.
+    Evolving,
+}
+
+/// The network definitions.
+///
+/// Note: This module is `Evolving`, as downstream crates implement `Network`, so adding a required item breaks them.
+pub mod network {
+    /// The stability level of this module.
+    pub const STABILITY: super::Stability = super::Stability::Evolving;
+
+    pub use crate::console::network::{Network, Testnet3};
+}
+
+/// The account keys, addresses, and signatures.
+pub mod account {
+    /// The stability level of this module.
+    pub const STABILITY: super::Stability = super::Stability::Stable;
+
+    pub use crate::console::account::{
+        Address,
+        ComputeKey,
+        EncryptedPrivateKey,
+        KeyDerivation,
+        PrivateKey,
+        Signature,
+        SignedMessage,
+        ViewKey,
+    };
+}
+
+/// The records, and the values they hold.
+///
+/// Note: This module is `Evolving`, as `Entry`, `Literal`, `Owner`, and `Plaintext` are exhaustive enums.
+pub mod record {
+    /// The stability level of this module.
+    pub const STABILITY: super::Stability = super::Stability::Evolving;
+
+    pub use crate::console::program::{Ciphertext, Entry, Identifier, Literal, Owner, Plaintext, ProgramID, Record};
+}
+
+/// The transactions, and the transitions they execute.
+///
+/// Note: This module is `Evolving`, as `Transaction`, `Input`, and `Output` are exhaustive enums.
+pub mod transaction {
+    /// The stability level of this module.
+    pub const STABILITY: super::Stability = super::Stability::Evolving;
+
+    pub use crate::ledger::block::{Deployment, Execution, Fee, Input, Output, Transaction, Transition};
+}
+
+/// The blocks, and their headers.
+///
+/// Note: This module is `Evolving`, as the header layout is still changing with its extensions.
+pub mod block {
+    /// The stability level of this module.
+    pub const STABILITY: super::Stability = super::Stability::Evolving;
+
+    pub use crate::ledger::block::{Block, Header, Transactions};
+}
+
+/// The checks a wallet performs on signatures and records.
+pub mod verification {
+    use super::{
+        account::{Address, Signature, SignedMessage, ViewKey},
+        network::Network,
+        record::{Ciphertext, Plaintext, Record},
+    };
+    use crate::console::types::Field;
+
+    use anyhow::Result;
+
+    /// The stability level of this module.
+    pub const STABILITY: super::Stability = super::Stability::Stable;
+
+    /// Returns `true` if the signature is valid for the given address and message (as field elements).
+    pub fn verify_signature<N: Network>(signature: &Signature<N>, address: &Address<N>, message: &[Field<N>]) -> bool {
+        signature.verify(address, message)
+    }
+
+    /// Returns `true` if the signed message is valid for its address.
+    pub fn verify_signed_message<N: Network>(signed_message: &SignedMessage<N>) -> bool {
+        signed_message.verify()
+    }
+
+    /// Returns `true` if the given view key owns the record.
+    pub fn is_record_owner<N: Network>(record: &Record<N, Ciphertext<N>>, view_key: &ViewKey<N>) -> bool {
+        record.is_owner(view_key)
+    }
+
+    /// Returns the decrypted record, if the given view key owns it.
+    pub fn decrypt_record<N: Network>(
+        record: &Record<N, Ciphertext<N>>,
+        view_key: &ViewKey<N>,
+    ) -> Result<Record<N, Plaintext<N>>> {
+        record.decrypt(view_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{account::*, network::Testnet3, record::*, verification::*};
+    use crate::console::{
+        network::{prelude::*, Network},
+        types::Scalar,
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verification() -> anyhow::Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Check the signatures.
+        let message = [Uniform::rand(rng), Uniform::rand(rng)];
+        assert!(verify_signature(&private_key.sign(&message, rng)?, &address, &message));
+        assert!(verify_signed_message(&SignedMessage::sign(&private_key, b"message".to_vec(), rng)?));
+
+        // Check the record ownership and decryption.
+        let randomizer = Scalar::rand(rng);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 100u64.private, _nonce: {}.public }}",
+            CurrentNetwork::g_scalar_multiply(&randomizer)
+        ))?;
+        let ciphertext = record.encrypt(randomizer)?;
+        assert!(is_record_owner(&ciphertext, &view_key));
+        assert_eq!(decrypt_record(&ciphertext, &view_key)?, record);
+
+        // Check that another account does not own the record.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert!(!is_record_owner(&ciphertext, &other_view_key));
+        assert!(decrypt_record(&ciphertext, &other_view_key).is_err());
+        Ok(())
+    }
+}